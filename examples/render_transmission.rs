@@ -128,21 +128,15 @@ fn main() {
                     },
                 ],
                 v9: None,
-                se: SE {
-                    _01: "9".to_string(),
-                    _02: "00001".to_string(),
-                },
+                se: SE::default(),
             }],
-            ge: GE {
-                _01: "1".to_string(),
-                _02: "1".to_string(),
-            },
+            ge: GE::default(),
         }],
-        iea: IEA {
-            _01: "1".to_string(),
-            _02: "000000001".to_string(),
-        },
-    };
+        iea: IEA::default(),
+        ..Default::default()
+    }
+    // fills SE, GE and IEA
+    .finalize();
     let serialized = format!("{x}");
     println!("{serialized}");
 }
@@ -0,0 +1,308 @@
+//! Builder for the ISA/IEA and GS/GE envelope segments of any version.
+
+use crate::util::{declares_repetition, Delimiters, DEFAULT_REPETITION};
+use chrono::{Local, NaiveDateTime};
+use serde::de::value::{Error, MapDeserializer};
+use serde::de::{DeserializeOwned, Deserializer, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// Builds matching envelope segments, e.g. for outbound interchanges.
+///
+/// Fixed-width ISA elements are padded with spaces or truncated, the dates and times of
+/// ISA09/ISA10 and GS04/GS05 are taken from a single timestamp and the trailers repeat the
+/// control numbers of their headers.
+///
+/// The segments are returned as the types of the requested version, e.g.
+/// ```rust
+/// use x12_types::envelope::EnvelopeBuilder;
+/// use x12_types::v005010::{GS, ISA};
+///
+/// let builder = EnvelopeBuilder::new()
+///     .sender("ZZ", "SOURCE")
+///     .receiver("ZZ", "TARGET")
+///     .functional_id("HC")
+///     .interchange_control_number(1);
+/// let isa: ISA = builder.isa().unwrap();
+/// let gs: GS = builder.gs().unwrap();
+/// assert_eq!(isa._06, "SOURCE         ");
+/// assert_eq!(gs._06, "1");
+/// ```
+#[derive(Clone, Debug)]
+pub struct EnvelopeBuilder {
+    authorization: (String, String),
+    security: (String, String),
+    sender: (String, String),
+    receiver: (String, String),
+    application_sender: Option<String>,
+    application_receiver: Option<String>,
+    functional_id: String,
+    timestamp: NaiveDateTime,
+    version: String,
+    release: String,
+    interchange_control_number: u32,
+    group_control_number: Option<u32>,
+    acknowledgment_requested: bool,
+    usage_indicator: char,
+    delimiters: Delimiters,
+}
+
+impl Default for EnvelopeBuilder {
+    fn default() -> Self {
+        EnvelopeBuilder {
+            authorization: ("00".to_string(), String::new()),
+            security: ("00".to_string(), String::new()),
+            sender: ("ZZ".to_string(), String::new()),
+            receiver: ("ZZ".to_string(), String::new()),
+            application_sender: None,
+            application_receiver: None,
+            functional_id: String::new(),
+            timestamp: Local::now().naive_local(),
+            version: "00501".to_string(),
+            release: "005010".to_string(),
+            interchange_control_number: 1,
+            group_control_number: None,
+            acknowledgment_requested: false,
+            usage_indicator: 'P',
+            delimiters: Delimiters::default(),
+        }
+    }
+}
+
+impl EnvelopeBuilder {
+    /// Creates a builder for a 005010 production interchange at the current local time.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Authorization Information Qualifier and Authorization Information (ISA01/ISA02), `00` and blank by default
+    pub fn authorization(mut self, qualifier: &str, information: &str) -> Self {
+        self.authorization = (qualifier.to_string(), information.to_string());
+        self
+    }
+
+    /// Security Information Qualifier and Security Information (ISA03/ISA04), `00` and blank by default
+    pub fn security(mut self, qualifier: &str, information: &str) -> Self {
+        self.security = (qualifier.to_string(), information.to_string());
+        self
+    }
+
+    /// Interchange ID Qualifier and Interchange Sender ID (ISA05/ISA06)
+    pub fn sender(mut self, qualifier: &str, id: &str) -> Self {
+        self.sender = (qualifier.to_string(), id.to_string());
+        self
+    }
+
+    /// Interchange ID Qualifier and Interchange Receiver ID (ISA07/ISA08)
+    pub fn receiver(mut self, qualifier: &str, id: &str) -> Self {
+        self.receiver = (qualifier.to_string(), id.to_string());
+        self
+    }
+
+    /// Application Sender's Code (GS02), the sender ID by default
+    pub fn application_sender(mut self, code: &str) -> Self {
+        self.application_sender = Some(code.to_string());
+        self
+    }
+
+    /// Application Receiver's Code (GS03), the receiver ID by default
+    pub fn application_receiver(mut self, code: &str) -> Self {
+        self.application_receiver = Some(code.to_string());
+        self
+    }
+
+    /// Functional Identifier Code (GS01), e.g. `HC` or `SH`
+    pub fn functional_id(mut self, code: &str) -> Self {
+        self.functional_id = code.to_string();
+        self
+    }
+
+    /// Date and time of the interchange and the group (ISA09/ISA10 and GS04/GS05)
+    pub fn timestamp(mut self, timestamp: NaiveDateTime) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Interchange Control Version Number (ISA12) and Version / Release / Industry Identifier Code (GS08),
+    /// e.g. `00401` and `004010`, or `00501` and `005010X222A1`
+    pub fn version(mut self, version: &str, release: &str) -> Self {
+        self.version = version.to_string();
+        self.release = release.to_string();
+        self
+    }
+
+    /// Interchange Control Number (ISA13)
+    pub fn interchange_control_number(mut self, control_number: u32) -> Self {
+        self.interchange_control_number = control_number;
+        self
+    }
+
+    /// Group Control Number (GS06), the interchange control number by default
+    pub fn group_control_number(mut self, control_number: u32) -> Self {
+        self.group_control_number = Some(control_number);
+        self
+    }
+
+    /// Acknowledgment Requested (ISA14)
+    pub fn acknowledgment_requested(mut self, requested: bool) -> Self {
+        self.acknowledgment_requested = requested;
+        self
+    }
+
+    /// Usage Indicator (ISA15), `P` (production), `T` (test) or `I` (information)
+    pub fn usage_indicator(mut self, indicator: char) -> Self {
+        self.usage_indicator = indicator;
+        self
+    }
+
+    /// Delimiters declared in ISA11 and ISA16, the current delimiters by default
+    pub fn delimiters(mut self, delimiters: Delimiters) -> Self {
+        self.delimiters = delimiters;
+        self
+    }
+
+    /// Interchange Control Header
+    pub fn isa<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        // the repetition separator replaced the standards identifier with 00501
+        let standards_identifier = match (
+            declares_repetition(&self.version),
+            self.delimiters.repetition,
+        ) {
+            (true, Some(repetition)) => repetition.to_string(),
+            (true, None) => DEFAULT_REPETITION.to_string(),
+            (false, _) => "U".to_string(),
+        };
+        let acknowledgment_requested = match self.acknowledgment_requested {
+            true => "1",
+            false => "0",
+        };
+        deserialize(vec![
+            fixed(&self.authorization.0, 2),
+            fixed(&self.authorization.1, 10),
+            fixed(&self.security.0, 2),
+            fixed(&self.security.1, 10),
+            fixed(&self.sender.0, 2),
+            fixed(&self.sender.1, 15),
+            fixed(&self.receiver.0, 2),
+            fixed(&self.receiver.1, 15),
+            self.timestamp.format("%y%m%d").to_string(),
+            self.timestamp.format("%H%M").to_string(),
+            standards_identifier,
+            fixed(&self.version, 5),
+            self.isa13(),
+            acknowledgment_requested.to_string(),
+            self.usage_indicator.to_string(),
+            self.delimiters.component.to_string(),
+        ])
+    }
+
+    /// Interchange Control Trailer for `groups` functional groups
+    pub fn iea<T: DeserializeOwned>(&self, groups: usize) -> Result<T, Error> {
+        deserialize(vec![groups.to_string(), self.isa13()])
+    }
+
+    /// Functional Group Header
+    pub fn gs<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        // the century was added to GS04 with 004010
+        let date_format = match self.version.as_str() < "00401" {
+            true => "%y%m%d",
+            false => "%Y%m%d",
+        };
+        let application_sender = self
+            .application_sender
+            .as_deref()
+            .unwrap_or(self.sender.1.trim());
+        let application_receiver = self
+            .application_receiver
+            .as_deref()
+            .unwrap_or(self.receiver.1.trim());
+        deserialize(vec![
+            self.functional_id.clone(),
+            truncated(application_sender, 15),
+            truncated(application_receiver, 15),
+            self.timestamp.format(date_format).to_string(),
+            self.timestamp.format("%H%M").to_string(),
+            self.gs06(),
+            "X".to_string(),
+            self.release.clone(),
+        ])
+    }
+
+    /// Functional Group Trailer for `transaction_sets` transaction sets
+    pub fn ge<T: DeserializeOwned>(&self, transaction_sets: usize) -> Result<T, Error> {
+        deserialize(vec![transaction_sets.to_string(), self.gs06()])
+    }
+
+    fn isa13(&self) -> String {
+        format!("{:09}", self.interchange_control_number % 1_000_000_000)
+    }
+
+    fn gs06(&self) -> String {
+        self.group_control_number
+            .unwrap_or(self.interchange_control_number)
+            .to_string()
+    }
+}
+
+/// Pads `value` with spaces or truncates it to `width` characters.
+fn fixed(value: &str, width: usize) -> String {
+    format!("{value:<width$.width$}")
+}
+
+/// Truncates `value` to `width` characters.
+fn truncated(value: &str, width: usize) -> String {
+    value.chars().take(width).collect()
+}
+
+/// Deserializes the elements into a segment with fields named `01`, `02`, ...
+fn deserialize<T: DeserializeOwned>(elements: Vec<String>) -> Result<T, Error> {
+    const NAMES: [&str; 16] = [
+        "01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12", "13", "14", "15",
+        "16",
+    ];
+    T::deserialize(MapDeserializer::new(
+        NAMES.into_iter().zip(elements.into_iter().map(Element)),
+    ))
+}
+
+/// A single element value, deserialized into a `String`, an `Option<String>` or a unit enum.
+struct Element(String);
+
+impl<'de> IntoDeserializer<'de, Error> for Element {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for Element {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_string(self.0)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0.is_empty() {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.0
+            .into_deserializer()
+            .deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
@@ -0,0 +1,202 @@
+//! Allocation of unique control numbers for outbound interchanges.
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The highest control number, followed by 1 again
+pub const MAX_CONTROL_NUMBER: u32 = 999_999_999;
+
+/// How long a [`FileAllocator`] waits for the lock held by another allocator
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The control number elements stamped by a [`ControlNumberAllocator`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ControlNumberKind {
+    /// Interchange Control Number (ISA13)
+    Interchange,
+    /// Group Control Number (GS06)
+    Group,
+    /// Transaction Set Control Number (ST02)
+    TransactionSet,
+}
+
+impl ControlNumberKind {
+    /// the element holding the control number, e.g. `ISA13`
+    pub fn element(self) -> &'static str {
+        match self {
+            ControlNumberKind::Interchange => "ISA13",
+            ControlNumberKind::Group => "GS06",
+            ControlNumberKind::TransactionSet => "ST02",
+        }
+    }
+
+    fn from_element(element: &str) -> Option<ControlNumberKind> {
+        [
+            ControlNumberKind::Interchange,
+            ControlNumberKind::Group,
+            ControlNumberKind::TransactionSet,
+        ]
+        .into_iter()
+        .find(|kind| kind.element() == element)
+    }
+}
+
+impl Display for ControlNumberKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.element())
+    }
+}
+
+/// Hands out increasing control numbers per trading partner, e.g. for `Transmission::finalize_with`.
+pub trait ControlNumberAllocator {
+    /// Returns the next control number of `kind` for `partner`, from 1 up to [`MAX_CONTROL_NUMBER`]
+    /// and starting over at 1.
+    fn next(&mut self, partner: &str, kind: ControlNumberKind) -> io::Result<u32>;
+}
+
+/// Returns the control number following `last`.
+fn following(last: u32) -> u32 {
+    match last {
+        MAX_CONTROL_NUMBER.. => 1,
+        last => last + 1,
+    }
+}
+
+/// Keeps the last control numbers in memory, starting at 1 for each partner.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryAllocator {
+    last: HashMap<(String, ControlNumberKind), u32>,
+}
+
+impl InMemoryAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the last control number of `kind` for `partner`, continuing with the number after it.
+    pub fn set_last(&mut self, partner: &str, kind: ControlNumberKind, last: u32) {
+        self.last.insert((partner.to_string(), kind), last);
+    }
+}
+
+impl ControlNumberAllocator for InMemoryAllocator {
+    fn next(&mut self, partner: &str, kind: ControlNumberKind) -> io::Result<u32> {
+        let last = self.last.entry((partner.to_string(), kind)).or_default();
+        *last = following(*last);
+        Ok(*last)
+    }
+}
+
+/// Keeps the last control numbers in a file, so they continue across runs.
+///
+/// The file holds one line per partner and kind, like `TARGET ISA13 42`, and is
+/// read and replaced on each allocation. It is created by the first allocation.
+///
+/// Each allocation holds an exclusive lock, the file with `.lock` appended to the path, so
+/// allocators of several threads or processes sharing the file hand out distinct numbers. A lock
+/// left behind by a crashed process is not removed, allocations fail after waiting for it for
+/// 10 seconds until the lock file is deleted.
+#[derive(Clone, Debug)]
+pub struct FileAllocator {
+    path: PathBuf,
+}
+
+impl FileAllocator {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FileAllocator { path: path.into() }
+    }
+
+    fn load(&self) -> io::Result<InMemoryAllocator> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+        let mut numbers = InMemoryAllocator::new();
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid control number line '{line}'"),
+                )
+            };
+            // partner IDs may contain spaces, so the kind and number are taken from the end
+            let mut fields = line.rsplitn(3, ' ');
+            let last = fields.next().and_then(|last| last.parse().ok());
+            let kind = fields.next().and_then(ControlNumberKind::from_element);
+            match (fields.next(), kind, last) {
+                (Some(partner), Some(kind), Some(last)) => numbers.set_last(partner, kind, last),
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(numbers)
+    }
+
+    fn store(&self, numbers: &InMemoryAllocator) -> io::Result<()> {
+        let mut lines: Vec<String> = numbers
+            .last
+            .iter()
+            .map(|((partner, kind), last)| format!("{partner} {kind} {last}\n"))
+            .collect();
+        lines.sort();
+        // replace the file at once, so an interrupted write does not lose all numbers
+        let temporary = self.sibling(".tmp");
+        fs::write(&temporary, lines.concat())?;
+        fs::rename(&temporary, &self.path)
+    }
+
+    /// Creates the lock file, waiting up to [`LOCK_TIMEOUT`] for another allocator to release it.
+    fn lock(&self) -> io::Result<FileLock> {
+        let path = self.sibling(".lock");
+        let start = Instant::now();
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(FileLock { path }),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    if start.elapsed() > LOCK_TIMEOUT {
+                        return Err(io::Error::new(
+                            io::ErrorKind::WouldBlock,
+                            format!("control numbers are locked by '{}'", path.display()),
+                        ));
+                    }
+                    thread::sleep(Duration::from_millis(5));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// The path of the file with `suffix` appended
+    fn sibling(&self, suffix: &str) -> PathBuf {
+        let mut path: OsString = self.path.clone().into_os_string();
+        path.push(suffix);
+        path.into()
+    }
+}
+
+impl ControlNumberAllocator for FileAllocator {
+    fn next(&mut self, partner: &str, kind: ControlNumberKind) -> io::Result<u32> {
+        let _lock = self.lock()?;
+        let mut numbers = self.load()?;
+        let next = numbers.next(partner, kind)?;
+        self.store(&numbers)?;
+        Ok(next)
+    }
+}
+
+/// The lock of a [`FileAllocator`], released by removing the lock file when dropped
+struct FileLock {
+    path: PathBuf,
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
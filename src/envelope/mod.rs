@@ -0,0 +1,509 @@
+//! Envelopes shared by all versions.
+//!
+//! Each transaction set is enclosed by ST/SE, each functional group by GS/GE and each interchange by ISA/IEA.
+//! The trailers repeat the control number of their header and count the enclosed segments, sets or groups.
+//! An interchange is acknowledged by a TA1 segment, reporting the first problem as [`InterchangeNote`].
+//! New envelopes are built by the [`EnvelopeBuilder`] and numbered by a [`ControlNumberAllocator`].
+//! [`sniff`] reads the envelope metadata of any version without parsing the transaction sets.
+
+mod builder;
+mod control_number;
+mod summary;
+mod transmission;
+pub use builder::*;
+pub use control_number::*;
+pub use summary::*;
+pub use transmission::*;
+pub(crate) use transmission::{impl_version, ControlElement};
+
+use crate::raw::{RawSegment, RawTransaction};
+use crate::util::writer;
+use crate::util::{declares_repetition, repetition_separator};
+use chrono::{NaiveDate, NaiveTime};
+use serde::Serialize;
+use std::fmt::Display;
+use std::io;
+
+/// Access to the control elements of a transaction set
+pub trait TransactionSet {
+    /// Transaction Set Control Number (ST02)
+    fn control_number(&self) -> &str;
+    /// Sets ST02.
+    fn set_control_number(&mut self, control_number: String);
+    /// Number of Included Segments (SE01) and Transaction Set Control Number (SE02)
+    fn trailer(&self) -> (&str, &str);
+    /// Sets SE01 and SE02.
+    fn set_trailer(&mut self, segment_count: String, control_number: String);
+}
+
+/// Implements [`TransactionSet`] for transaction sets with an ST and SE field.
+macro_rules! impl_transaction_set {
+    ($($name:ty => $st:ident, $se:ident;)*) => {
+        $(
+            impl $crate::envelope::TransactionSet for $name {
+                fn control_number(&self) -> &str {
+                    &self.$st._02
+                }
+
+                fn set_control_number(&mut self, control_number: String) {
+                    self.$st._02 = control_number;
+                }
+
+                fn trailer(&self) -> (&str, &str) {
+                    (&self.$se._01, &self.$se._02)
+                }
+
+                fn set_trailer(&mut self, segment_count: String, control_number: String) {
+                    self.$se._01 = segment_count;
+                    self.$se._02 = control_number;
+                }
+            }
+        )*
+    };
+}
+pub(crate) use impl_transaction_set;
+
+/// Declares the enum of the transaction sets of a version, dispatching on ST01 by the given `"ID" => type` pairs.
+///
+/// Transaction sets without a typed binding are kept as [`RawTransaction`].
+macro_rules! any_transaction {
+    ($(#[$attr:meta])* $enum:ident { $($(#[$doc:meta])* $id:literal => $name:ident,)* }) => {
+        $(#[$attr])*
+        #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum $enum {
+            $($(#[$doc])* $name($name),)*
+            /// any other transaction set
+            Raw($crate::raw::RawTransaction),
+        }
+
+        const _: () = {
+            // the traits may already be imported by the calling module
+            #[allow(unused_imports)]
+            use {$crate::envelope::TransactionSet as _, $crate::util::Parser as _, nom::Parser as _};
+
+            impl Default for $enum {
+                fn default() -> Self {
+                    $enum::Raw($crate::raw::RawTransaction::default())
+                }
+            }
+
+            impl $enum {
+                /// Transaction Set Identifier Code (ST01)
+                pub fn id(&self) -> &str {
+                    match self {
+                        $($enum::$name(_) => $id,)*
+                        $enum::Raw(obj) => obj.id().unwrap_or_default(),
+                    }
+                }
+            }
+
+            impl<'a> $crate::util::Parser<&'a str, $enum, nom::error::Error<&'a str>> for $enum {
+                fn parse(input: &'a str) -> nom::IResult<&'a str, $enum> {
+                    let (_, st) = nom::combinator::peek(ST::parse).parse(input)?;
+                    match st._01.as_str() {
+                        $($id => $name::parse(input).map(|(rest, obj)| (rest, $enum::$name(obj))),)*
+                        _ => $crate::raw::RawTransaction::parse(input)
+                            .map(|(rest, obj)| (rest, $enum::Raw(obj))),
+                    }
+                }
+            }
+
+            impl std::fmt::Display for $enum {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        $($enum::$name(obj) => write!(f, "{obj}"),)*
+                        $enum::Raw(obj) => write!(f, "{obj}"),
+                    }
+                }
+            }
+
+            impl $crate::envelope::TransactionSet for $enum {
+                fn control_number(&self) -> &str {
+                    match self {
+                        $($enum::$name(obj) => obj.control_number(),)*
+                        $enum::Raw(obj) => obj.control_number(),
+                    }
+                }
+
+                fn set_control_number(&mut self, control_number: String) {
+                    match self {
+                        $($enum::$name(obj) => obj.set_control_number(control_number),)*
+                        $enum::Raw(obj) => obj.set_control_number(control_number),
+                    }
+                }
+
+                fn trailer(&self) -> (&str, &str) {
+                    match self {
+                        $($enum::$name(obj) => obj.trailer(),)*
+                        $enum::Raw(obj) => obj.trailer(),
+                    }
+                }
+
+                fn set_trailer(&mut self, segment_count: String, control_number: String) {
+                    match self {
+                        $($enum::$name(obj) => obj.set_trailer(segment_count, control_number),)*
+                        $enum::Raw(obj) => obj.set_trailer(segment_count, control_number),
+                    }
+                }
+            }
+        };
+    };
+}
+pub(crate) use any_transaction;
+
+impl TransactionSet for RawTransaction {
+    fn control_number(&self) -> &str {
+        self.segments
+            .first()
+            .and_then(|st| st.element(2))
+            .unwrap_or_default()
+    }
+
+    fn set_control_number(&mut self, control_number: String) {
+        if let Some(st02) = self
+            .segments
+            .first_mut()
+            .and_then(|st| st.elements.get_mut(1))
+        {
+            *st02 = vec![control_number];
+        }
+    }
+
+    fn trailer(&self) -> (&str, &str) {
+        match self.segments.last() {
+            Some(se) if se.id == "SE" => (
+                se.element(1).unwrap_or_default(),
+                se.element(2).unwrap_or_default(),
+            ),
+            _ => ("", ""),
+        }
+    }
+
+    fn set_trailer(&mut self, segment_count: String, control_number: String) {
+        let elements = vec![vec![segment_count], vec![control_number]];
+        match self.segments.last_mut() {
+            Some(se) if se.id == "SE" => se.elements = elements,
+            _ => self.segments.push(RawSegment {
+                id: "SE".to_string(),
+                elements,
+                delimiters: self.delimiters,
+            }),
+        }
+    }
+}
+
+/// A trailer element that does not match its envelope
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnvelopeFinding {
+    /// the mismatching element, one of `SE01`, `SE02`, `GE01`, `GE02`, `IEA01` or `IEA02`
+    pub element: &'static str,
+    /// control number of the envelope, i.e. ST02, GS06 or ISA13
+    pub control_number: String,
+    /// value derived from the envelope
+    pub expected: String,
+    /// value of the element
+    pub found: String,
+}
+
+impl Display for EnvelopeFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let envelope = match self.element {
+            "SE01" | "SE02" => "transaction set",
+            "GE01" | "GE02" => "functional group",
+            _ => "interchange",
+        };
+        write!(
+            f,
+            "{} of {envelope} {} is '{}', expected '{}'",
+            self.element, self.control_number, self.found, self.expected
+        )
+    }
+}
+
+/// Checks SE01 against the number of segments and SE02 against ST02.
+pub fn validate_transaction_set<T: TransactionSet + Serialize>(set: &T) -> Vec<EnvelopeFinding> {
+    let control_number = set.control_number();
+    let (se01, se02) = set.trailer();
+    let mut findings = vec![];
+    if let Ok(count) = writer::segment_count(set) {
+        check_count(&mut findings, "SE01", control_number, count, se01);
+    }
+    check_control_number(&mut findings, "SE02", control_number, se02);
+    findings
+}
+
+/// Sets SE01 to the number of segments and SE02 to ST02.
+pub fn finalize_transaction_set<T: TransactionSet + Serialize>(set: &mut T) {
+    let control_number = set.control_number().to_string();
+    // the SE is only rendered, and thereby counted, once it has a value
+    set.set_trailer(String::new(), control_number.clone());
+    let count = writer::segment_count(set).unwrap_or_default();
+    set.set_trailer(count.to_string(), control_number);
+}
+
+/// Allocates the Interchange Control Number (ISA13) for `partner`.
+pub(crate) fn allocate_interchange<A: ControlNumberAllocator + ?Sized>(
+    partner: &str,
+    allocator: &mut A,
+) -> io::Result<String> {
+    let control_number = allocator.next(partner, ControlNumberKind::Interchange)?;
+    Ok(format!("{control_number:09}"))
+}
+
+/// Allocates ST02 of each transaction set and returns the allocated Group Control Number (GS06) for `partner`.
+pub(crate) fn allocate_group<T: TransactionSet, A: ControlNumberAllocator + ?Sized>(
+    sets: &mut [T],
+    partner: &str,
+    allocator: &mut A,
+) -> io::Result<String> {
+    let gs06 = allocator.next(partner, ControlNumberKind::Group)?;
+    for set in sets {
+        let st02 = allocator.next(partner, ControlNumberKind::TransactionSet)?;
+        // ST02 has at least 4 digits
+        set.set_control_number(format!("{st02:04}"));
+    }
+    Ok(gs06.to_string())
+}
+
+/// Checks the transaction sets of a group, GE01 against their number and GE02 against GS06.
+pub(crate) fn validate_group<T: TransactionSet + Serialize>(
+    gs06: &str,
+    ge01: &str,
+    ge02: &str,
+    sets: &[T],
+) -> Vec<EnvelopeFinding> {
+    let mut findings: Vec<EnvelopeFinding> =
+        sets.iter().flat_map(validate_transaction_set).collect();
+    check_count(&mut findings, "GE01", gs06, sets.len(), ge01);
+    check_control_number(&mut findings, "GE02", gs06, ge02);
+    findings
+}
+
+/// Checks IEA01 against the number of groups and IEA02 against ISA13.
+pub(crate) fn validate_interchange(
+    isa13: &str,
+    iea01: &str,
+    iea02: &str,
+    groups: usize,
+) -> Vec<EnvelopeFinding> {
+    let mut findings = vec![];
+    check_count(&mut findings, "IEA01", isa13, groups, iea01);
+    check_control_number(&mut findings, "IEA02", isa13, iea02);
+    findings
+}
+
+fn check_count(
+    findings: &mut Vec<EnvelopeFinding>,
+    element: &'static str,
+    control_number: &str,
+    count: usize,
+    found: &str,
+) {
+    if found.trim().parse::<usize>().ok() != Some(count) {
+        findings.push(EnvelopeFinding {
+            element,
+            control_number: control_number.to_string(),
+            expected: count.to_string(),
+            found: found.to_string(),
+        });
+    }
+}
+
+fn check_control_number(
+    findings: &mut Vec<EnvelopeFinding>,
+    element: &'static str,
+    control_number: &str,
+    found: &str,
+) {
+    if found != control_number {
+        findings.push(EnvelopeFinding {
+            element,
+            control_number: control_number.to_string(),
+            expected: control_number.to_string(),
+            found: found.to_string(),
+        });
+    }
+}
+
+/// Interchange Note Code (TA105)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InterchangeNote {
+    /// 000 - No error
+    NoError,
+    /// 001 - The Interchange Control Number in the Header and Trailer Do Not Match
+    ControlNumberMismatch,
+    /// 002 - This Standard as Noted in the Control Standards Identifier is Not Supported
+    StandardNotSupported,
+    /// 003 - This Version of the Controls is Not Supported
+    VersionNotSupported,
+    /// 004 - The Segment Terminator is Invalid
+    InvalidSegmentTerminator,
+    /// 005 - Invalid Interchange ID Qualifier for Sender
+    InvalidSenderQualifier,
+    /// 006 - Invalid Interchange Sender ID
+    InvalidSenderId,
+    /// 007 - Invalid Interchange ID Qualifier for Receiver
+    InvalidReceiverQualifier,
+    /// 008 - Invalid Interchange Receiver ID
+    InvalidReceiverId,
+    /// 009 - Unknown Interchange Receiver ID
+    UnknownReceiverId,
+    /// 010 - Invalid Authorization Information Qualifier Value
+    InvalidAuthorizationQualifier,
+    /// 011 - Invalid Authorization Information Value
+    InvalidAuthorization,
+    /// 012 - Invalid Security Information Qualifier Value
+    InvalidSecurityQualifier,
+    /// 013 - Invalid Security Information Value
+    InvalidSecurity,
+    /// 014 - Invalid Interchange Date Value
+    InvalidDate,
+    /// 015 - Invalid Interchange Time Value
+    InvalidTime,
+    /// 016 - Invalid Interchange Standards Identifier Value
+    InvalidStandardsIdentifier,
+    /// 017 - Invalid Interchange Version ID Value
+    InvalidVersion,
+    /// 018 - Invalid Interchange Control Number Value
+    InvalidControlNumber,
+    /// 019 - Invalid Acknowledgment Requested Value
+    InvalidAcknowledgmentRequested,
+    /// 020 - Invalid Test Indicator Value
+    InvalidTestIndicator,
+    /// 021 - Invalid Number of Included Groups Value
+    InvalidGroupCount,
+    /// 022 - Invalid Control Structure
+    InvalidControlStructure,
+    /// 023 - Improper (Premature) End-of-File (Transmission)
+    PrematureEndOfFile,
+    /// 024 - Invalid Interchange Content (e.g., Invalid GS Segment)
+    InvalidContent,
+    /// 025 - Duplicate Interchange Control Number
+    DuplicateControlNumber,
+    /// 026 - Invalid Data Element Separator
+    InvalidElementSeparator,
+    /// 027 - Invalid Component Element Separator
+    InvalidComponentSeparator,
+    /// 028 - Invalid Delivery Date in Deferred Delivery Request
+    InvalidDeliveryDate,
+    /// 029 - Invalid Delivery Time in Deferred Delivery Request
+    InvalidDeliveryTime,
+    /// 030 - Invalid Delivery Time Code in Deferred Delivery Request
+    InvalidDeliveryTimeCode,
+    /// 031 - Invalid Grade of Service Code
+    InvalidGradeOfService,
+}
+
+impl InterchangeNote {
+    /// The three digit code, e.g. `001`
+    pub fn code(self) -> &'static str {
+        use InterchangeNote::*;
+        match self {
+            NoError => "000",
+            ControlNumberMismatch => "001",
+            StandardNotSupported => "002",
+            VersionNotSupported => "003",
+            InvalidSegmentTerminator => "004",
+            InvalidSenderQualifier => "005",
+            InvalidSenderId => "006",
+            InvalidReceiverQualifier => "007",
+            InvalidReceiverId => "008",
+            UnknownReceiverId => "009",
+            InvalidAuthorizationQualifier => "010",
+            InvalidAuthorization => "011",
+            InvalidSecurityQualifier => "012",
+            InvalidSecurity => "013",
+            InvalidDate => "014",
+            InvalidTime => "015",
+            InvalidStandardsIdentifier => "016",
+            InvalidVersion => "017",
+            InvalidControlNumber => "018",
+            InvalidAcknowledgmentRequested => "019",
+            InvalidTestIndicator => "020",
+            InvalidGroupCount => "021",
+            InvalidControlStructure => "022",
+            PrematureEndOfFile => "023",
+            InvalidContent => "024",
+            DuplicateControlNumber => "025",
+            InvalidElementSeparator => "026",
+            InvalidComponentSeparator => "027",
+            InvalidDeliveryDate => "028",
+            InvalidDeliveryTime => "029",
+            InvalidDeliveryTimeCode => "030",
+            InvalidGradeOfService => "031",
+        }
+    }
+
+    /// Interchange Acknowledgment Code (TA104), `A` (accepted) without error, `R` (rejected) otherwise
+    pub fn acknowledgment_code(self) -> &'static str {
+        match self {
+            InterchangeNote::NoError => "A",
+            _ => "R",
+        }
+    }
+}
+
+/// Checks the elements of an ISA and IEA against the interchange control `version` (ISA12) and the number of groups.
+///
+/// Returns the note for the first invalid element, in the order of the ISA and IEA elements.
+pub(crate) fn check_interchange(
+    isa: [&str; 16],
+    iea: [&str; 2],
+    groups: usize,
+    version: &str,
+) -> InterchangeNote {
+    use InterchangeNote::*;
+    let is_id = |value: &str, len: usize| {
+        value.len() == len && value.chars().all(|c| c.is_ascii_alphanumeric())
+    };
+    let is_number =
+        |value: &str, len: usize| value.len() == len && value.chars().all(|c| c.is_ascii_digit());
+    let is_id_code = |value: &str| value.len() == 15 && !value.trim().is_empty();
+    let checks = [
+        (
+            matches!(isa[0], "00" | "01" | "02" | "03" | "04" | "05" | "06"),
+            InvalidAuthorizationQualifier,
+        ),
+        (isa[1].len() == 10, InvalidAuthorization),
+        (matches!(isa[2], "00" | "01"), InvalidSecurityQualifier),
+        (isa[3].len() == 10, InvalidSecurity),
+        (is_id(isa[4], 2), InvalidSenderQualifier),
+        (is_id_code(isa[5]), InvalidSenderId),
+        (is_id(isa[6], 2), InvalidReceiverQualifier),
+        (is_id_code(isa[7]), InvalidReceiverId),
+        (
+            NaiveDate::parse_from_str(isa[8], "%y%m%d").is_ok() && isa[8].len() == 6,
+            InvalidDate,
+        ),
+        (
+            NaiveTime::parse_from_str(isa[9], "%H%M").is_ok() && isa[9].len() == 4,
+            InvalidTime,
+        ),
+        (
+            match declares_repetition(version) {
+                false => isa[10] == "U",
+                true => repetition_separator(isa[10]).is_some(),
+            },
+            InvalidStandardsIdentifier,
+        ),
+        (is_number(isa[11], 5), InvalidVersion),
+        (isa[11] == version, VersionNotSupported),
+        (is_number(isa[12], 9), InvalidControlNumber),
+        (matches!(isa[13], "0" | "1"), InvalidAcknowledgmentRequested),
+        (matches!(isa[14], "P" | "T" | "I"), InvalidTestIndicator),
+        (
+            isa[15].chars().count() == 1 && !isa[15].chars().all(char::is_alphanumeric),
+            InvalidComponentSeparator,
+        ),
+        (
+            iea[0].trim().parse::<usize>().ok() == Some(groups),
+            InvalidGroupCount,
+        ),
+        (iea[1] == isa[12], ControlNumberMismatch),
+    ];
+    checks
+        .into_iter()
+        .find(|(valid, _)| !valid)
+        .map_or(NoError, |(_, note)| note)
+}
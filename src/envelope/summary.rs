@@ -0,0 +1,109 @@
+//! Envelope metadata of an interchange, read without parsing its transaction sets.
+
+use crate::raw::RawSegmentRef;
+use crate::util::{Delimiters, Parser};
+
+/// The envelope metadata of an interchange, e.g. to choose a typed parser
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InterchangeSummary {
+    /// Interchange ID Qualifier (ISA05)
+    pub sender_qualifier: String,
+    /// Interchange Sender ID (ISA06), without padding
+    pub sender_id: String,
+    /// Interchange ID Qualifier (ISA07)
+    pub receiver_qualifier: String,
+    /// Interchange Receiver ID (ISA08), without padding
+    pub receiver_id: String,
+    /// Interchange Control Version Number (ISA12), e.g. `00401`
+    pub version: String,
+    /// Interchange Control Number (ISA13)
+    pub control_number: String,
+    /// delimiters declared by the ISA
+    pub delimiters: Delimiters,
+    pub groups: Vec<GroupSummary>,
+    /// number of segments from ISA to IEA
+    pub segment_count: usize,
+}
+
+/// The header metadata of a functional group
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GroupSummary {
+    /// Functional Identifier Code (GS01)
+    pub functional_id: String,
+    /// Application Sender's Code (GS02)
+    pub sender: String,
+    /// Application Receiver's Code (GS03)
+    pub receiver: String,
+    /// Group Control Number (GS06)
+    pub control_number: String,
+    /// Version / Release / Industry Identifier Code (GS08), e.g. `005010X222A1`
+    pub version: String,
+    /// Transaction Set Identifier Code (ST01) of each transaction set
+    pub transaction_sets: Vec<String>,
+}
+
+impl InterchangeSummary {
+    /// Number of transaction sets in all groups
+    pub fn transaction_count(&self) -> usize {
+        self.groups
+            .iter()
+            .map(|group| group.transaction_sets.len())
+            .sum()
+    }
+}
+
+/// Reads the envelope metadata of the first interchange in `input`.
+///
+/// Only the segment IDs and the elements of ISA, GS and ST are looked at. Returns `None` if
+/// the input does not start with an ISA. A truncated or malformed interchange is summarized
+/// up to the first segment that cannot be read.
+pub fn sniff(input: &str) -> Option<InterchangeSummary> {
+    let delimiters = Delimiters::from_isa(input)?;
+    let _scope = delimiters.scope();
+    let (mut rest, isa) = RawSegmentRef::parse(input).ok()?;
+    let element = |position| isa.element(position).unwrap_or_default().to_string();
+    let mut summary = InterchangeSummary {
+        sender_qualifier: element(5),
+        sender_id: element(6).trim_end().to_string(),
+        receiver_qualifier: element(7),
+        receiver_id: element(8).trim_end().to_string(),
+        version: element(12),
+        control_number: element(13),
+        delimiters,
+        groups: vec![],
+        segment_count: 1,
+    };
+    while let Ok((next, segment)) = RawSegmentRef::parse(rest) {
+        rest = next;
+        summary.segment_count += 1;
+        let element = |position| segment.element(position).unwrap_or_default().to_string();
+        match segment.id {
+            "GS" => summary.groups.push(GroupSummary {
+                functional_id: element(1),
+                sender: element(2),
+                receiver: element(3),
+                control_number: element(6),
+                version: element(8),
+                transaction_sets: vec![],
+            }),
+            "ST" => {
+                if let Some(group) = summary.groups.last_mut() {
+                    group.transaction_sets.push(element(1));
+                }
+            }
+            "IEA" => break,
+            _ => {}
+        }
+    }
+    Some(summary)
+}
+
+/// Reads the envelope metadata of the first interchange in `input`, see [`sniff`].
+///
+/// Bytes which are not valid UTF-8 are read as Latin-1.
+pub fn sniff_bytes(input: &[u8]) -> Option<InterchangeSummary> {
+    match std::str::from_utf8(input) {
+        Ok(text) => sniff(text),
+        Err(_) => sniff(&input.iter().copied().map(char::from).collect::<String>()),
+    }
+}
@@ -0,0 +1,738 @@
+//! Interchanges and functional groups of all versions.
+//!
+//! Each version module defines its own envelope segments and a marker type implementing [`Version`],
+//! and exposes `Transmission<T>` and `FunctionalGroup<T>` as aliases of the types in this module.
+
+use super::{
+    allocate_group, allocate_interchange, check_interchange, finalize_transaction_set,
+    validate_group, validate_interchange, ControlNumberAllocator, EnvelopeFinding, InterchangeNote,
+    TransactionSet, MAX_CONTROL_NUMBER,
+};
+use crate::raw::RawSegmentRef;
+use crate::util::{writer, Delimiters, Parser};
+use crate::validation::{ElementSyntaxError, StructureEntry};
+use nom::error::ErrorKind;
+use nom::multi::many0;
+use nom::IResult;
+use nom::Parser as _;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Display};
+use std::io;
+use validator::{Validate, ValidationErrors};
+
+/// The envelope segments of a version
+pub trait Version {
+    /// Interchange Control Version Number (ISA12) of this version, e.g. `00401`
+    const INTERCHANGE_VERSION: &'static str;
+    /// ISA - Interchange Control Header
+    type Isa: InterchangeHeader + Segment;
+    /// TA1 - Interchange Acknowledgment
+    type Ta1: InterchangeAcknowledgment + Segment;
+    /// GS - Functional Group Header
+    type Gs: GroupHeader + Segment;
+    /// GE - Functional Group Trailer
+    type Ge: Trailer + Segment;
+    /// IEA - Interchange Control Trailer
+    type Iea: Trailer + Segment;
+
+    /// Returns the syntax notes of the segment with the given ID, see [`SegmentSyntax`](crate::validation::SegmentSyntax).
+    fn syntax_notes(segment_id: &str) -> &'static [&'static str];
+
+    /// Returns the element positions of the segment with the given ID which have a code list, see [`SegmentCodes`](crate::validation::SegmentCodes).
+    fn code_lists(segment_id: &str) -> &'static [(usize, &'static str)];
+
+    /// Returns the positions of the transaction set with the given identifier, see [`TransactionStructure`](crate::validation::TransactionStructure).
+    fn transaction_structure(transaction_set_id: &str) -> &'static [StructureEntry];
+
+    /// Checks the types and lengths of the elements of a segment, see [`check_elements`](crate::validation::check_elements).
+    ///
+    /// Segments without an element table are not checked, see [`datatype`](crate::util::datatype) for the covered segments.
+    fn element_errors(segment: &RawSegmentRef) -> Vec<(usize, ElementSyntaxError)>;
+}
+
+/// A segment which can be parsed and serialized
+pub trait Segment:
+    Clone
+    + Default
+    + Debug
+    + Serialize
+    + DeserializeOwned
+    + for<'a> Parser<&'a str, Self, nom::error::Error<&'a str>>
+{
+}
+
+impl<S> Segment for S where
+    S: Clone
+        + Default
+        + Debug
+        + Serialize
+        + DeserializeOwned
+        + for<'a> Parser<&'a str, S, nom::error::Error<&'a str>>
+{
+}
+
+/// Access to the elements of an ISA
+pub trait InterchangeHeader {
+    /// ISA01 to ISA16
+    fn elements(&self) -> [String; 16];
+    /// Interchange Receiver ID (ISA08)
+    fn receiver_id(&self) -> &str;
+    /// Interchange Control Number (ISA13)
+    fn control_number(&self) -> &str;
+    /// Sets ISA13.
+    fn set_control_number(&mut self, control_number: String);
+}
+
+/// Construction of a TA1
+pub trait InterchangeAcknowledgment {
+    /// Acknowledges the interchange with the given Interchange Control Number, Date and Time (ISA13, ISA09 and ISA10).
+    fn from_note(control_number: &str, date: &str, time: &str, note: InterchangeNote) -> Self;
+}
+
+/// Access to the control elements of a GS
+pub trait GroupHeader {
+    /// Functional Identifier Code (GS01)
+    fn functional_id(&self) -> &str;
+    /// Application Sender's Code (GS02) and Application Receiver's Code (GS03)
+    fn application_partners(&self) -> (&str, &str);
+    /// Group Control Number (GS06)
+    fn control_number(&self) -> &str;
+    /// Sets GS06.
+    fn set_control_number(&mut self, control_number: String);
+    /// Version / Release / Industry Identifier Code (GS08)
+    fn version(&self) -> &str;
+}
+
+/// Access to the elements of a GE or IEA
+pub trait Trailer {
+    /// Number of included transaction sets or groups (GE01/IEA01) and the control number (GE02/IEA02)
+    fn trailer(&self) -> (&str, &str);
+    /// Sets GE01/GE02 or IEA01/IEA02.
+    fn set_trailer(&mut self, count: String, control_number: String);
+}
+
+/// Implements [`Version`] for a marker type and the envelope traits for the `ISA`, `TA1`, `GS`, `GE`
+/// and `IEA` of the calling module, along with `TA1::new` and `TA1::acknowledge`.
+macro_rules! impl_version {
+    ($version:ident, $isa12:literal) => {
+        impl $crate::envelope::Version for $version {
+            const INTERCHANGE_VERSION: &'static str = $isa12;
+            type Isa = ISA;
+            type Ta1 = TA1;
+            type Gs = GS;
+            type Ge = GE;
+            type Iea = IEA;
+
+            fn syntax_notes(segment_id: &str) -> &'static [&'static str] {
+                syntax_notes(segment_id)
+            }
+
+            fn code_lists(segment_id: &str) -> &'static [(usize, &'static str)] {
+                code_lists(segment_id)
+            }
+
+            fn transaction_structure(
+                transaction_set_id: &str,
+            ) -> &'static [$crate::validation::StructureEntry] {
+                transaction_structure(transaction_set_id)
+            }
+
+            fn element_errors(
+                segment: &$crate::raw::RawSegmentRef,
+            ) -> Vec<(usize, $crate::validation::ElementSyntaxError)> {
+                element_errors(segment)
+            }
+        }
+
+        impl $crate::envelope::InterchangeHeader for ISA {
+            fn elements(&self) -> [String; 16] {
+                [
+                    self._01.to_string(),
+                    self._02.to_string(),
+                    self._03.to_string(),
+                    self._04.to_string(),
+                    self._05.to_string(),
+                    self._06.to_string(),
+                    self._07.to_string(),
+                    self._08.to_string(),
+                    self._09.to_string(),
+                    self._10.to_string(),
+                    self._11.to_string(),
+                    self._12.to_string(),
+                    self._13.to_string(),
+                    self._14.to_string(),
+                    self._15.to_string(),
+                    self._16.to_string(),
+                ]
+            }
+
+            fn receiver_id(&self) -> &str {
+                &self._08
+            }
+
+            fn control_number(&self) -> &str {
+                &self._13
+            }
+
+            fn set_control_number(&mut self, control_number: String) {
+                self._13 = control_number;
+            }
+        }
+
+        impl $crate::envelope::InterchangeAcknowledgment for TA1 {
+            fn from_note(
+                control_number: &str,
+                date: &str,
+                time: &str,
+                note: $crate::envelope::InterchangeNote,
+            ) -> Self {
+                TA1 {
+                    _01: control_number.to_string(),
+                    _02: date.to_string(),
+                    _03: time.to_string(),
+                    _04: note.acknowledgment_code().to_string(),
+                    _05: note.code().to_string(),
+                }
+            }
+        }
+
+        impl $crate::envelope::GroupHeader for GS {
+            fn functional_id(&self) -> &str {
+                &self._01
+            }
+
+            fn application_partners(&self) -> (&str, &str) {
+                (&self._02, &self._03)
+            }
+
+            fn control_number(&self) -> &str {
+                $crate::envelope::ControlElement::as_str(&self._06)
+            }
+
+            fn set_control_number(&mut self, control_number: String) {
+                self._06 = $crate::envelope::ControlElement::from_string(control_number);
+            }
+
+            fn version(&self) -> &str {
+                &self._08
+            }
+        }
+
+        impl $crate::envelope::Trailer for GE {
+            fn trailer(&self) -> (&str, &str) {
+                (&self._01, &self._02)
+            }
+
+            fn set_trailer(&mut self, count: String, control_number: String) {
+                self._01 = count;
+                self._02 = control_number;
+            }
+        }
+
+        impl $crate::envelope::Trailer for IEA {
+            fn trailer(&self) -> (&str, &str) {
+                (&self._01, &self._02)
+            }
+
+            fn set_trailer(&mut self, count: String, control_number: String) {
+                self._01 = count;
+                self._02 = control_number;
+            }
+        }
+
+        impl TA1 {
+            /// Acknowledges the interchange started by `isa`, accepting it for `InterchangeNote::NoError`
+            /// and rejecting it otherwise.
+            pub fn new(isa: &ISA, note: $crate::envelope::InterchangeNote) -> TA1 {
+                $crate::envelope::acknowledgment::<$version>(isa, note)
+            }
+
+            /// Checks an ISA and IEA enclosing `groups` functional groups and acknowledges the interchange.
+            ///
+            #[doc = concat!("The ISA elements are checked for their format and ISA12 for version ", $isa12, ".")]
+            /// Duplicate control numbers can only be detected by the receiver,
+            /// which rejects them with `TA1::new(isa, InterchangeNote::DuplicateControlNumber)`.
+            pub fn acknowledge(isa: &ISA, iea: &IEA, groups: usize) -> TA1 {
+                $crate::envelope::acknowledge_interchange::<$version>(isa, iea, groups)
+            }
+        }
+    };
+}
+pub(crate) use impl_version;
+
+/// A control element, which is optional in some versions
+pub(crate) trait ControlElement {
+    fn as_str(&self) -> &str;
+    fn from_string(value: String) -> Self;
+}
+
+impl ControlElement for String {
+    fn as_str(&self) -> &str {
+        self
+    }
+
+    fn from_string(value: String) -> Self {
+        value
+    }
+}
+
+impl ControlElement for Option<String> {
+    fn as_str(&self) -> &str {
+        self.as_deref().unwrap_or_default()
+    }
+
+    fn from_string(value: String) -> Self {
+        Some(value)
+    }
+}
+
+/// An interchange, from ISA to IEA
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+pub struct Transmission<V: Version, T> {
+    /// delimiters declared by the ISA, used for rendering
+    #[serde(skip_deserializing, serialize_with = "writer::serialize_declared")]
+    pub delimiters: Delimiters,
+    pub isa: V::Isa,
+    /// interchange acknowledgments, preceding the functional groups
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ta1: Vec<V::Ta1>,
+    pub functional_group: Vec<FunctionalGroup<V, T>>,
+    pub iea: V::Iea,
+}
+
+/// A functional group, from GS to GE
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+pub struct FunctionalGroup<V: Version, T> {
+    pub gs: V::Gs,
+    pub segments: Vec<T>,
+    pub ge: V::Ge,
+}
+
+impl<V: Version, T: PartialEq> PartialEq for Transmission<V, T>
+where
+    V::Isa: PartialEq,
+    V::Ta1: PartialEq,
+    V::Gs: PartialEq,
+    V::Ge: PartialEq,
+    V::Iea: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.isa == other.isa
+            && self.ta1 == other.ta1
+            && self.functional_group == other.functional_group
+            && self.iea == other.iea
+            && self.delimiters == other.delimiters
+    }
+}
+
+impl<V: Version, T: Eq> Eq for Transmission<V, T>
+where
+    V::Isa: Eq,
+    V::Ta1: Eq,
+    V::Gs: Eq,
+    V::Ge: Eq,
+    V::Iea: Eq,
+{
+}
+
+impl<V: Version, T: PartialEq> PartialEq for FunctionalGroup<V, T>
+where
+    V::Gs: PartialEq,
+    V::Ge: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.gs == other.gs && self.segments == other.segments && self.ge == other.ge
+    }
+}
+
+impl<V: Version, T: Eq> Eq for FunctionalGroup<V, T>
+where
+    V::Gs: Eq,
+    V::Ge: Eq,
+{
+}
+
+/// Checks whether `input` starts with a segment with the given `id`.
+fn starts_with_segment(input: &str, id: &str) -> bool {
+    matches!(RawSegmentRef::parse(input), Ok((_, segment)) if segment.id == id)
+}
+
+impl<'a, V, T> Parser<&'a str, Transmission<V, T>, nom::error::Error<&'a str>>
+    for Transmission<V, T>
+where
+    V: Version,
+    T: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    fn parse(input: &'a str) -> IResult<&'a str, Transmission<V, T>> {
+        let delimiters = Delimiters::from_isa(input)
+            .ok_or_else(|| nom::Err::Error(nom::error::Error::new(input, ErrorKind::Tag)))?;
+        let _scope = delimiters.scope();
+        let (rest, isa) = V::Isa::parse(input)?;
+        let (mut rest, ta1) = many0(V::Ta1::parse).parse(rest)?;
+        let mut functional_group = vec![];
+        while starts_with_segment(rest, "GS") {
+            let (next, group) = FunctionalGroup::parse(rest)?;
+            rest = next;
+            functional_group.push(group);
+        }
+        let (rest, iea) = V::Iea::parse(rest)?;
+        Ok((
+            rest,
+            Transmission {
+                isa,
+                ta1,
+                functional_group,
+                iea,
+                delimiters,
+            },
+        ))
+    }
+}
+
+impl<'a, V, T> Parser<&'a str, FunctionalGroup<V, T>, nom::error::Error<&'a str>>
+    for FunctionalGroup<V, T>
+where
+    V: Version,
+    T: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    fn parse(input: &'a str) -> IResult<&'a str, FunctionalGroup<V, T>> {
+        let (mut rest, gs) = V::Gs::parse(input)?;
+        let mut segments = vec![];
+        while starts_with_segment(rest, "ST") {
+            let (next, set) = crate::error::resume(rest, T::parse)?;
+            rest = next;
+            segments.push(set);
+        }
+        let (rest, ge) = V::Ge::parse(rest)?;
+        Ok((rest, FunctionalGroup { gs, segments, ge }))
+    }
+}
+
+impl<V: Version, T> Display for Transmission<V, T>
+where
+    Transmission<V, T>: Serialize,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let all = writer::to_string(self, self.delimiters).map_err(|_| std::fmt::Error)?;
+        write!(f, "{all}")
+    }
+}
+
+impl<V: Version, T> Validate for Transmission<V, T>
+where
+    V::Isa: Validate,
+    V::Iea: Validate,
+{
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let result = ValidationErrors::merge(Ok(()), "isa", self.isa.validate());
+        ValidationErrors::merge(result, "iea", self.iea.validate())
+    }
+}
+
+impl<V: Version, T> Validate for FunctionalGroup<V, T>
+where
+    V::Gs: Validate,
+    V::Ge: Validate,
+{
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let result = ValidationErrors::merge(Ok(()), "gs", self.gs.validate());
+        ValidationErrors::merge(result, "ge", self.ge.validate())
+    }
+}
+
+impl<V: Version, T> FunctionalGroup<V, T> {
+    /// Functional Identifier Code (GS01), Application Sender's and Receiver's Codes (GS02, GS03)
+    /// and Version / Release / Industry Identifier Code (GS08)
+    fn key(&self) -> (&str, (&str, &str), &str) {
+        (
+            self.gs.functional_id(),
+            self.gs.application_partners(),
+            self.gs.version(),
+        )
+    }
+}
+
+impl<V: Version, T: TransactionSet + Serialize> FunctionalGroup<V, T> {
+    /// Checks SE01/SE02 of each transaction set and GE01/GE02 against this group.
+    pub fn validate_envelope(&self) -> Vec<EnvelopeFinding> {
+        let (ge01, ge02) = self.ge.trailer();
+        validate_group(self.gs.control_number(), ge01, ge02, &self.segments)
+    }
+
+    /// Fills SE01/SE02 of each transaction set and GE01/GE02 from their headers.
+    pub fn finalize(&mut self) {
+        self.segments.iter_mut().for_each(finalize_transaction_set);
+        self.ge.set_trailer(
+            self.segments.len().to_string(),
+            self.gs.control_number().to_string(),
+        );
+    }
+
+    /// Stamps GS06 and ST02 of each transaction set with control numbers for `partner`,
+    /// then fills the trailers like [`finalize`](Self::finalize).
+    pub fn finalize_with<A: ControlNumberAllocator + ?Sized>(
+        &mut self,
+        partner: &str,
+        allocator: &mut A,
+    ) -> io::Result<()> {
+        let gs06 = allocate_group(&mut self.segments, partner, allocator)?;
+        self.gs.set_control_number(gs06);
+        self.finalize();
+        Ok(())
+    }
+}
+
+impl<V: Version, T: TransactionSet + Serialize> Transmission<V, T> {
+    /// Checks the envelopes of all groups and transaction sets, and IEA01/IEA02 against this interchange.
+    pub fn validate_envelope(&self) -> Vec<EnvelopeFinding> {
+        let mut findings: Vec<EnvelopeFinding> = self
+            .functional_group
+            .iter()
+            .flat_map(FunctionalGroup::validate_envelope)
+            .collect();
+        let (iea01, iea02) = self.iea.trailer();
+        findings.extend(validate_interchange(
+            self.isa.control_number(),
+            iea01,
+            iea02,
+            self.functional_group.len(),
+        ));
+        findings
+    }
+
+    /// Recounts all trailers and fills their control numbers from the headers, ready for rendering.
+    pub fn finalize(mut self) -> Self {
+        self.functional_group
+            .iter_mut()
+            .for_each(FunctionalGroup::finalize);
+        self.finalize_trailer();
+        self
+    }
+
+    /// Stamps ISA13, GS06 and ST02 with control numbers for the receiver (ISA08),
+    /// then fills the trailers like [`finalize`](Self::finalize).
+    pub fn finalize_with<A: ControlNumberAllocator + ?Sized>(
+        mut self,
+        allocator: &mut A,
+    ) -> io::Result<Self> {
+        let partner = self.isa.receiver_id().trim().to_string();
+        let isa13 = allocate_interchange(&partner, allocator)?;
+        self.isa.set_control_number(isa13);
+        for group in &mut self.functional_group {
+            group.finalize_with(&partner, allocator)?;
+        }
+        self.finalize_trailer();
+        Ok(self)
+    }
+}
+
+/// The maximum size of each interchange returned by [`Transmission::split`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitLimit {
+    /// number of transaction sets
+    TransactionSets(usize),
+    /// number of bytes, as rendered by `Display`
+    Bytes(usize),
+}
+
+impl<V: Version, T: TransactionSet + Serialize> Transmission<V, T> {
+    /// Splits this interchange into interchanges below `limit`, numbered for the receiver (ISA08).
+    ///
+    /// The groups are merged by GS01 to GS03 and GS08 like [`regroup`](Self::regroup), then the transaction
+    /// sets are distributed in order, repeating the ISA and GS in each interchange. All control numbers are
+    /// allocated and all trailers recounted like [`finalize_with`](Self::finalize_with). A transaction set exceeding
+    /// the limit on its own ends up alone in an interchange. TA1 segments are kept in the first interchange.
+    pub fn split<A: ControlNumberAllocator + ?Sized>(
+        mut self,
+        limit: SplitLimit,
+        allocator: &mut A,
+    ) -> io::Result<Vec<Self>> {
+        self.regroup();
+        let groups = std::mem::take(&mut self.functional_group);
+        let (max, interchange_size, group_size) = match limit {
+            SplitLimit::TransactionSets(max) => (max, 0, 0),
+            SplitLimit::Bytes(max) => (max, self.interchange_size()?, self.group_size(&groups)?),
+        };
+        let mut parts = vec![];
+        let mut part = self.without_groups();
+        let mut size = interchange_size;
+        for group in groups {
+            let FunctionalGroup { gs, segments, ge } = group;
+            let mut open = false;
+            for mut set in segments {
+                let set_size = match limit {
+                    SplitLimit::TransactionSets(_) => 1,
+                    SplitLimit::Bytes(_) => {
+                        finalize_transaction_set(&mut set);
+                        // ST02 and SE02 may grow to 9 digits when renumbered
+                        let growth = 2 * 9usize.saturating_sub(set.control_number().len());
+                        rendered_len(&set, self.delimiters)? + growth
+                    }
+                };
+                let added = set_size + if open { 0 } else { group_size };
+                if !part.functional_group.is_empty() && size + added > max {
+                    let mut next = self.without_groups();
+                    next.ta1.clear();
+                    parts.push(std::mem::replace(&mut part, next));
+                    size = interchange_size;
+                    open = false;
+                }
+                if !open {
+                    part.functional_group.push(FunctionalGroup {
+                        gs: gs.clone(),
+                        segments: vec![],
+                        ge: ge.clone(),
+                    });
+                    size += group_size;
+                    open = true;
+                }
+                if let Some(last) = part.functional_group.last_mut() {
+                    last.segments.push(set);
+                }
+                size += set_size;
+            }
+        }
+        if parts.is_empty() || !part.functional_group.is_empty() {
+            parts.push(part);
+        }
+        parts
+            .into_iter()
+            .map(|part| part.finalize_with(allocator))
+            .collect()
+    }
+
+    /// Merges interchanges into one interchange per sender and receiver (ISA05 to ISA08), numbered for the receiver.
+    ///
+    /// Each merged interchange keeps the ISA of its first interchange and the groups are merged by GS01 to
+    /// GS03 and GS08 like [`regroup`](Self::regroup). All control numbers are allocated and all trailers recounted
+    /// like [`finalize_with`](Self::finalize_with).
+    pub fn merge<A: ControlNumberAllocator + ?Sized>(
+        transmissions: impl IntoIterator<Item = Self>,
+        allocator: &mut A,
+    ) -> io::Result<Vec<Self>> {
+        let mut merged: Vec<Self> = vec![];
+        for transmission in transmissions {
+            let partners = transmission.partners();
+            match merged.iter_mut().find(|other| other.partners() == partners) {
+                Some(other) => {
+                    other.ta1.extend(transmission.ta1);
+                    other.functional_group.extend(transmission.functional_group);
+                }
+                None => merged.push(transmission),
+            }
+        }
+        merged
+            .into_iter()
+            .map(|mut transmission| {
+                transmission.regroup();
+                transmission.finalize_with(allocator)
+            })
+            .collect()
+    }
+
+    /// Upper bound of the rendered ISA, TA1 and IEA segments.
+    fn interchange_size(&self) -> io::Result<usize> {
+        let mut iea = self.iea.clone();
+        iea.set_trailer(
+            MAX_CONTROL_NUMBER.to_string(),
+            MAX_CONTROL_NUMBER.to_string(),
+        );
+        let ta1 = self
+            .ta1
+            .iter()
+            .map(|ta1| rendered_len(ta1, self.delimiters))
+            .sum::<io::Result<usize>>()?;
+        Ok(rendered_len(&self.isa, self.delimiters)? + ta1 + rendered_len(&iea, self.delimiters)?)
+    }
+
+    /// Upper bound of the rendered GS and GE segments of the largest group.
+    fn group_size(&self, groups: &[FunctionalGroup<V, T>]) -> io::Result<usize> {
+        let mut size = 0;
+        for group in groups {
+            let mut gs = group.gs.clone();
+            gs.set_control_number(MAX_CONTROL_NUMBER.to_string());
+            let mut ge = group.ge.clone();
+            ge.set_trailer(
+                MAX_CONTROL_NUMBER.to_string(),
+                MAX_CONTROL_NUMBER.to_string(),
+            );
+            size =
+                size.max(rendered_len(&gs, self.delimiters)? + rendered_len(&ge, self.delimiters)?);
+        }
+        Ok(size)
+    }
+}
+
+impl<V: Version, T> Transmission<V, T> {
+    /// Moves the transaction sets of groups with the same GS01 to GS03 and GS08 into the first of these groups.
+    ///
+    /// The trailers are not recounted, see [`finalize`](Self::finalize).
+    pub fn regroup(&mut self) {
+        let mut groups: Vec<FunctionalGroup<V, T>> = vec![];
+        for group in std::mem::take(&mut self.functional_group) {
+            match groups.iter_mut().find(|other| other.key() == group.key()) {
+                Some(other) => other.segments.extend(group.segments),
+                None => groups.push(group),
+            }
+        }
+        self.functional_group = groups;
+    }
+
+    /// Interchange ID Qualifiers and IDs of the sender and receiver (ISA05 to ISA08), without padding
+    fn partners(&self) -> [String; 4] {
+        let [_, _, _, _, isa05, isa06, isa07, isa08, ..] = self.isa.elements();
+        [isa05, isa06, isa07, isa08].map(|element| element.trim().to_string())
+    }
+
+    /// A copy of this interchange without functional groups
+    fn without_groups(&self) -> Self {
+        Transmission {
+            isa: self.isa.clone(),
+            ta1: self.ta1.clone(),
+            functional_group: vec![],
+            iea: self.iea.clone(),
+            delimiters: self.delimiters,
+        }
+    }
+
+    /// Checks the ISA and IEA of this interchange and acknowledges it with a TA1.
+    pub fn acknowledge(&self) -> V::Ta1 {
+        acknowledge_interchange::<V>(&self.isa, &self.iea, self.functional_group.len())
+    }
+
+    /// Sets IEA01 to the number of groups and IEA02 to ISA13.
+    fn finalize_trailer(&mut self) {
+        self.iea.set_trailer(
+            self.functional_group.len().to_string(),
+            self.isa.control_number().to_string(),
+        );
+    }
+}
+
+/// Length of `value` as rendered with `delimiters`.
+fn rendered_len<S: Serialize + ?Sized>(value: &S, delimiters: Delimiters) -> io::Result<usize> {
+    writer::to_string(value, delimiters)
+        .map(|rendered| rendered.len())
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Acknowledges the interchange started by `isa` with the given note.
+pub fn acknowledgment<V: Version>(isa: &V::Isa, note: InterchangeNote) -> V::Ta1 {
+    let elements = isa.elements();
+    V::Ta1::from_note(&elements[12], &elements[8], &elements[9], note)
+}
+
+/// Checks an ISA and IEA enclosing `groups` functional groups against version `V` and acknowledges the interchange.
+pub fn acknowledge_interchange<V: Version>(isa: &V::Isa, iea: &V::Iea, groups: usize) -> V::Ta1 {
+    let elements = isa.elements();
+    let note = check_interchange(
+        elements.each_ref().map(String::as_str),
+        iea.trailer().into(),
+        groups,
+        V::INTERCHANGE_VERSION,
+    );
+    acknowledgment::<V>(isa, note)
+}
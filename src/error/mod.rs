@@ -0,0 +1,609 @@
+//! Crate-level errors with the position and context of a parse failure.
+//!
+//! The parsers themselves return `nom` errors, which only carry the remaining input.
+//! [`parse`] runs a parser while keeping track of the segments that were tried at the
+//! furthest position and of the loop that was being parsed, and turns a failure into a [`ParseError`].
+//! [`parse_lenient`] skips segments that do not fit and reports them instead of failing.
+//! [`parse_bytes`] parses byte input that may not be UTF-8; like [`parse`], it reports characters outside the X12
+//! character sets.
+
+use crate::raw::{RawSegment, RawSegmentRef};
+use crate::util::{charset, Delimiters, Parser};
+use nom::IResult;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+thread_local! {
+    static TRACE: RefCell<Option<Trace>> = const { RefCell::new(None) };
+}
+
+/// A parse failure, as reported by [`parse`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParseError {
+    /// index of the failing segment, starting at 0
+    pub segment_index: usize,
+    /// line of the failing segment, starting at 1
+    pub line: usize,
+    /// byte offset of the failing segment, or of the invalid character
+    pub offset: usize,
+    /// Transaction Set Control Number (ST02) of the transaction set being parsed
+    pub control_number: Option<String>,
+    /// innermost loop or transaction set being parsed, e.g. `_837Loop2300`
+    pub loop_name: Option<String>,
+    /// segment IDs that would have been accepted at this position
+    pub expected: Vec<String>,
+    /// segment ID found at this position, `None` at the end of the input
+    pub found: Option<String>,
+    /// a character outside the X12 character sets, found by [`parse`] or [`parse_bytes`]
+    pub invalid_character: Option<char>,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.invalid_character, &self.found) {
+            (Some(c), Some(found)) => {
+                write!(f, "invalid character U+{:04X} in segment {found}", c as u32)?
+            }
+            (Some(c), None) => write!(f, "invalid character U+{:04X}", c as u32)?,
+            (None, Some(found)) => write!(f, "unexpected segment {found}")?,
+            (None, None) => write!(f, "unexpected end of input")?,
+        }
+        write!(
+            f,
+            " at segment {} (line {}, offset {})",
+            self.segment_index, self.line, self.offset
+        )?;
+        if let Some(control_number) = &self.control_number {
+            write!(f, " in transaction set {control_number}")?;
+        }
+        if let Some(loop_name) = &self.loop_name {
+            write!(f, " while parsing {loop_name}")?;
+        }
+        if !self.expected.is_empty() {
+            write!(f, ", expected one of {}", self.expected.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses the complete `input`, reporting failures as [`ParseError`].
+///
+/// Trailing line breaks are accepted, any other remaining input is an error. Characters outside the X12
+/// character sets, other than the delimiters and line breaks, are reported with [`ParseError::invalid_character`].
+pub fn parse<'a, T>(input: &'a str) -> Result<T, ParseError>
+where
+    T: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    if let Some(offset) = charset::find_invalid(input) {
+        return Err(invalid_character(input, offset));
+    }
+    run(input, false)
+        .map(|(value, _)| value)
+        .map_err(|(err, _)| err)
+}
+
+/// Runs the parser of `T` on the complete `input`, skipping segments unknown to the transaction sets if
+/// `lenient`, and returns the skipped segments along with the value, or the repeat of the loop along with the error.
+fn run<'a, T>(
+    input: &'a str,
+    lenient: bool,
+) -> Result<(T, Vec<Skipped>), (ParseError, Option<usize>)>
+where
+    T: Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    let previous = TRACE.with(|trace| trace.replace(Some(Trace::new(input, lenient))));
+    let result = resume(input, T::parse);
+    let trace = TRACE
+        .with(|trace| trace.replace(previous))
+        .unwrap_or_default();
+    let rest = match result {
+        Ok((rest, obj)) if rest.trim_start_matches(['\r', '\n']).is_empty() => {
+            let skipped = trace.skipped.into_values().filter(|s| s.kept).collect();
+            return Ok((obj, skipped));
+        }
+        Ok((rest, _)) => rest,
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => err.input,
+        Err(nom::Err::Incomplete(_)) => "",
+    };
+    // the furthest position that any segment was tried at is closer to the actual error
+    let offset = trace.offset_of(rest).unwrap_or(input.len());
+    let offset = match &trace.failure {
+        Some(failure) => offset.max(failure.offset),
+        None => offset,
+    };
+    let (expected, context) = match trace.failure {
+        Some(failure) if failure.offset == offset => (failure.expected, failure.context),
+        _ => (vec![], None),
+    };
+    let err = ParseError {
+        expected,
+        loop_name: context.map(|(name, _)| name.to_string()),
+        ..locate(input, offset)
+    };
+    Err((err, context.map(|(_, index)| index)))
+}
+
+/// Parses the complete byte `input` like [`parse`].
+///
+/// The input is decoded as UTF-8, or as Latin-1 if it is not valid UTF-8.
+/// Characters outside the X12 character sets, other than the delimiters and line breaks,
+/// are reported with [`ParseError::invalid_character`]. Offsets refer to the bytes of `input`.
+pub fn parse_bytes<T>(input: &[u8]) -> Result<T, ParseError>
+where
+    T: for<'a> Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    let decoded = charset::Decoded::new(input);
+    parse(&decoded.text).map_err(|err| ParseError {
+        offset: decoded.byte_offset(err.offset),
+        ..err
+    })
+}
+
+/// Reports the character at `offset` in `input`, which is outside the X12 character sets.
+fn invalid_character(input: &str, offset: usize) -> ParseError {
+    let delimiters = Delimiters::from_isa(input).unwrap_or_else(Delimiters::current);
+    let start = input[..offset]
+        .rfind(delimiters.segment)
+        .map_or(0, |end| end + delimiters.segment.len_utf8());
+    ParseError {
+        line: input[..offset].matches('\n').count() + 1,
+        offset,
+        invalid_character: input[offset..].chars().next(),
+        ..locate(input, start)
+    }
+}
+
+/// Result of [`parse_lenient`]
+#[derive(Clone, Debug)]
+pub struct Lenient<T> {
+    pub value: T,
+    /// segments that were skipped, in the order of the input
+    pub unknown_segments: Vec<UnknownSegment>,
+}
+
+impl<T> Lenient<T> {
+    /// Returns a warning for each skipped segment.
+    pub fn warnings(&self) -> impl Iterator<Item = &ParseError> {
+        self.unknown_segments.iter().map(|unknown| &unknown.warning)
+    }
+}
+
+/// A segment skipped by [`parse_lenient`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownSegment {
+    /// the segment as found in the input
+    pub segment: RawSegment,
+    /// position of the segment, the loop it was found in and the segments expected instead
+    pub warning: ParseError,
+    /// repeat of the loop it was found in, counting from 0 within the enclosing loop, or within the input
+    /// for a transaction set
+    pub loop_index: Option<usize>,
+}
+
+/// Envelope segments, which are never skipped
+const ENVELOPE: [&str; 6] = ["ISA", "GS", "ST", "SE", "GE", "IEA"];
+
+/// Parses the complete `input` like [`parse`], but skips segments that are unknown or out of order.
+///
+/// Segments which are not part of the transaction set are skipped by the loop parsers in a single pass and
+/// reported as [`UnknownSegment`] with the loop instance they were found in. A segment of the transaction set
+/// found out of order ends the pass of its transaction set, which is parsed again skipping that segment,
+/// without parsing the preceding transaction sets again.
+/// Envelope segments (`ISA`, `GS`, `ST`, `SE`, `GE`, `IEA`) are never skipped,
+/// so missing mandatory segments are still reported as error.
+pub fn parse_lenient<T>(input: &str) -> Result<Lenient<T>, ParseError>
+where
+    T: for<'a> Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    if let Some(offset) = charset::find_invalid(input) {
+        return Err(invalid_character(input, offset));
+    }
+    let delimiters = Delimiters::from_isa(input).unwrap_or_else(Delimiters::current);
+    let _scope = delimiters.scope();
+    let (value, skipped) = run::<T>(input, true).map_err(|(err, _)| err)?;
+    let mut locator = Locator::new(input);
+    let unknown_segments = skipped
+        .into_iter()
+        .filter_map(|skipped| {
+            let (_, segment) = RawSegment::parse(&input[skipped.offset..]).ok()?;
+            let warning = ParseError {
+                expected: skipped.expected,
+                loop_name: skipped.context.map(|(name, _)| name.to_string()),
+                ..locator.locate(skipped.offset)
+            };
+            Some(UnknownSegment {
+                segment,
+                warning,
+                loop_index: skipped.context.map(|(_, index)| index),
+            })
+        })
+        .collect();
+    Ok(Lenient {
+        value,
+        unknown_segments,
+    })
+}
+
+/// Parses the transaction set at the start of `input` with `parse`.
+///
+/// When parsing lenient, a segment of the transaction set found out of order is noted, so that
+/// [`skip_unknown`] skips it, and the transaction set is parsed again from `input`, see [`parse_lenient`].
+pub(crate) fn resume<'a, T>(
+    input: &'a str,
+    parse: impl Fn(&'a str) -> IResult<&'a str, T>,
+) -> IResult<&'a str, T> {
+    loop {
+        let snapshot = TRACE.with(|trace| {
+            let trace = trace.borrow();
+            let trace = trace.as_ref().filter(|trace| trace.lenient)?;
+            Some((trace.offset_of(input)?, trace.snapshot()))
+        });
+        let Some((start, snapshot)) = snapshot else {
+            return parse(input);
+        };
+        let result = parse(input);
+        let rest = match &result {
+            Err(nom::Err::Error(err) | nom::Err::Failure(err)) => err.input,
+            _ => return result,
+        };
+        let skipped = TRACE.with(|trace| {
+            let mut trace = trace.borrow_mut();
+            let trace = trace.as_mut()?;
+            // the furthest position that any segment was tried at is closer to the actual error
+            let offset = trace.offset_of(rest).unwrap_or(start).max(start);
+            let offset = match &trace.failure {
+                Some(failure) => offset.max(failure.offset),
+                None => offset,
+            };
+            let (_, segment) = RawSegmentRef::parse(&input[offset - start..]).ok()?;
+            if ENVELOPE.contains(&segment.id) || trace.out_of_order.contains(&offset) {
+                return None;
+            }
+            let (expected, context) = match trace.failure.take() {
+                Some(failure) if failure.offset == offset => (failure.expected, failure.context),
+                _ => (vec![], None),
+            };
+            trace.restore(start, snapshot);
+            trace.out_of_order.insert(offset);
+            trace.skipped.insert(
+                offset,
+                Skipped {
+                    offset,
+                    expected,
+                    context,
+                    kept: true,
+                },
+            );
+            Some(())
+        });
+        if skipped.is_none() {
+            return result;
+        }
+    }
+}
+
+/// Marks the loop currently being parsed, until the returned scope is dropped.
+pub(crate) fn context(name: &'static str) -> ContextScope {
+    enter(name, &[])
+}
+
+/// Marks the transaction set currently being parsed, until the returned scope is dropped.
+///
+/// `segments` are the IDs of all segments of the transaction set, [`parse_lenient`] skips any other segment.
+pub(crate) fn transaction_set(
+    name: &'static str,
+    segments: &'static [&'static str],
+) -> ContextScope {
+    enter(name, segments)
+}
+
+fn enter(name: &'static str, segments: &'static [&'static str]) -> ContextScope {
+    TRACE.with(|trace| {
+        if let Some(trace) = trace.borrow_mut().as_mut() {
+            let repeats = match trace.context.last_mut() {
+                Some(frame) => &mut frame.repeats,
+                None => &mut trace.repeats,
+            };
+            let index = match repeats.iter_mut().find(|(loop_name, _)| *loop_name == name) {
+                Some((_, count)) => {
+                    *count += 1;
+                    *count - 1
+                }
+                None => {
+                    repeats.push((name, 1));
+                    0
+                }
+            };
+            trace.context.push(Frame {
+                name,
+                index,
+                segments,
+                repeats: vec![],
+            });
+        }
+    });
+    ContextScope
+}
+
+/// Created by [`context`]
+#[must_use = "the context ends as soon as the scope is dropped"]
+pub(crate) struct ContextScope;
+
+impl Drop for ContextScope {
+    fn drop(&mut self) {
+        TRACE.with(|trace| {
+            if let Some(trace) = trace.borrow_mut().as_mut() {
+                trace.context.pop();
+            }
+        });
+    }
+}
+
+/// Records that `segment_name` did not match at the start of `input`.
+pub(crate) fn record_failure(input: &str, segment_name: &str) {
+    TRACE.with(|trace| {
+        let mut trace = trace.borrow_mut();
+        let Some(trace) = trace.as_mut() else {
+            return;
+        };
+        let Some(offset) = trace.offset_of(input) else {
+            return;
+        };
+        match &mut trace.failure {
+            Some(failure) if failure.offset > offset => {}
+            Some(failure) if failure.offset == offset => {
+                if !failure.expected.iter().any(|id| id == segment_name) {
+                    failure.expected.push(segment_name.to_string());
+                }
+            }
+            _ => {
+                trace.failure = Some(Failure {
+                    offset,
+                    expected: vec![segment_name.to_string()],
+                    context: trace.context.last().map(|frame| (frame.name, frame.index)),
+                })
+            }
+        }
+    });
+}
+
+/// Skips the segments at the start of `input` which are not part of the transaction set being parsed by
+/// [`parse_lenient`], noting `segment_name` as expected instead.
+pub(crate) fn skip_unknown<'a>(input: &'a str, segment_name: &str) -> &'a str {
+    TRACE.with(|trace| {
+        let mut trace = trace.borrow_mut();
+        let Some(trace) = trace.as_mut().filter(|trace| trace.lenient) else {
+            return input;
+        };
+        let Some(segments) = trace
+            .context
+            .iter()
+            .rev()
+            .map(|frame| frame.segments)
+            .find(|segments| !segments.is_empty())
+        else {
+            return input;
+        };
+        let mut rest = input;
+        loop {
+            let id_len = rest
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len());
+            let id = &rest[..id_len];
+            let Some(offset) = trace.offset_of(rest) else {
+                return rest;
+            };
+            let out_of_order = trace.out_of_order.contains(&offset);
+            if id.is_empty()
+                || ENVELOPE.contains(&id)
+                || (!out_of_order && (id == segment_name || segments.contains(&id)))
+            {
+                return rest;
+            }
+            let Ok((next, _)) = RawSegmentRef::parse(rest) else {
+                return rest;
+            };
+            if !out_of_order {
+                let skipped = trace.skipped.entry(offset).or_insert_with(|| Skipped {
+                    offset,
+                    expected: vec![],
+                    context: None,
+                    kept: false,
+                });
+                if !skipped.expected.iter().any(|id| id == segment_name) {
+                    skipped.expected.push(segment_name.to_string());
+                }
+            }
+            rest = next;
+        }
+    })
+}
+
+/// Keeps the segments skipped between `input` and `rest`, as a segment was parsed at `rest`.
+///
+/// A skipped segment is reported with the loop it was kept in first.
+pub(crate) fn keep_skipped(input: &str, rest: &str) {
+    if input.len() == rest.len() {
+        return;
+    }
+    TRACE.with(|trace| {
+        let mut trace = trace.borrow_mut();
+        let Some(trace) = trace.as_mut() else {
+            return;
+        };
+        let (Some(start), Some(end)) = (trace.offset_of(input), trace.offset_of(rest)) else {
+            return;
+        };
+        let context = trace.context.last().map(|frame| (frame.name, frame.index));
+        for (_, skipped) in trace.skipped.range_mut(start..end) {
+            if !skipped.kept {
+                skipped.kept = true;
+                skipped.context = context;
+            }
+        }
+    });
+}
+
+#[derive(Default)]
+struct Trace {
+    /// address range of the input given to [`parse`]
+    start: usize,
+    end: usize,
+    context: Vec<Frame>,
+    /// number of repeats of the transaction sets
+    repeats: Vec<(&'static str, usize)>,
+    failure: Option<Failure>,
+    /// skip segments which are not part of the transaction set, see [`parse_lenient`]
+    lenient: bool,
+    /// segments skipped by [`skip_unknown`], by offset
+    skipped: BTreeMap<usize, Skipped>,
+    /// offsets of the segments of a transaction set found out of order, see [`resume`]
+    out_of_order: BTreeSet<usize>,
+}
+
+impl Trace {
+    fn new(input: &str, lenient: bool) -> Trace {
+        let start = input.as_ptr() as usize;
+        Trace {
+            start,
+            end: start + input.len(),
+            lenient,
+            ..Default::default()
+        }
+    }
+
+    fn offset_of(&self, input: &str) -> Option<usize> {
+        let address = input.as_ptr() as usize;
+        (self.start..=self.end)
+            .contains(&address)
+            .then(|| address - self.start)
+    }
+
+    /// The state changed by parsing a transaction set, see [`resume`]
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            repeats: self.repeats.clone(),
+            frame_repeats: self.context.last().map(|frame| frame.repeats.clone()),
+            failure: self.failure.clone(),
+        }
+    }
+
+    /// Restores the state before parsing the transaction set at `start`, keeping the segments out of order.
+    fn restore(&mut self, start: usize, snapshot: Snapshot) {
+        self.repeats = snapshot.repeats;
+        if let (Some(frame), Some(repeats)) = (self.context.last_mut(), snapshot.frame_repeats) {
+            frame.repeats = repeats;
+        }
+        self.failure = snapshot.failure;
+        let out_of_order = &self.out_of_order;
+        self.skipped
+            .retain(|offset, _| *offset < start || out_of_order.contains(offset));
+    }
+}
+
+/// See [`Trace::snapshot`]
+struct Snapshot {
+    repeats: Vec<(&'static str, usize)>,
+    frame_repeats: Option<Vec<(&'static str, usize)>>,
+    failure: Option<Failure>,
+}
+
+/// A loop or transaction set being parsed
+struct Frame {
+    name: &'static str,
+    /// repeat of the loop within the enclosing loop, counting from 0
+    index: usize,
+    /// IDs of the segments of a transaction set, see [`transaction_set`]
+    segments: &'static [&'static str],
+    /// number of repeats of the loops within this repeat
+    repeats: Vec<(&'static str, usize)>,
+}
+
+/// The segments tried at the furthest position
+#[derive(Clone)]
+struct Failure {
+    offset: usize,
+    expected: Vec<String>,
+    /// innermost context and its repeat when the first segment failed at this position
+    context: Option<(&'static str, usize)>,
+}
+
+/// A segment skipped by [`skip_unknown`]
+struct Skipped {
+    offset: usize,
+    /// segment IDs tried at this position
+    expected: Vec<String>,
+    /// loop and its repeat the segment was kept in
+    context: Option<(&'static str, usize)>,
+    /// `true` if a segment following it was parsed
+    kept: bool,
+}
+
+/// Determines segment index, line, ST02 and the segment found at `offset`.
+fn locate(input: &str, offset: usize) -> ParseError {
+    Locator::new(input).locate(offset)
+}
+
+/// Locates increasing offsets in a single pass over the input
+struct Locator<'a> {
+    input: &'a str,
+    delimiters: Delimiters,
+    /// end of the input located so far
+    end: usize,
+    segment_index: usize,
+    line: usize,
+    control_number: Option<String>,
+}
+
+impl<'a> Locator<'a> {
+    fn new(input: &'a str) -> Locator<'a> {
+        Locator {
+            input,
+            delimiters: Delimiters::from_isa(input).unwrap_or_else(Delimiters::current),
+            end: 0,
+            segment_index: 0,
+            line: 1,
+            control_number: None,
+        }
+    }
+
+    /// Determines segment index, line, ST02 and the segment found at `offset`.
+    fn locate(&mut self, offset: usize) -> ParseError {
+        let input = self.input;
+        let rest = input[offset.min(input.len())..].trim_start_matches(['\r', '\n']);
+        let end = input.len() - rest.len();
+        if end < self.end {
+            *self = Locator::new(input);
+        }
+        let consumed = &input[self.end..end];
+        for segment in consumed.split(self.delimiters.segment) {
+            let segment = segment.trim_start_matches(['\r', '\n']);
+            if segment.is_empty() {
+                continue;
+            }
+            let mut elements = segment.split(self.delimiters.element);
+            match elements.next() {
+                Some("ST") => self.control_number = elements.nth(1).map(str::to_string),
+                Some("SE") => self.control_number = None,
+                _ => {}
+            }
+            self.segment_index += 1;
+        }
+        self.line += consumed.matches('\n').count();
+        self.end = end;
+        let id_len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        ParseError {
+            segment_index: self.segment_index,
+            line: self.line,
+            offset: end,
+            control_number: self.control_number.clone(),
+            found: (id_len > 0).then(|| rest[..id_len].to_string()),
+            ..Default::default()
+        }
+    }
+}
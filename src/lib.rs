@@ -13,4 +13,8 @@ pub mod v005010;
 #[cfg(feature = "v005030")]
 pub mod v005030;
 
+pub mod envelope;
+pub mod error;
+pub mod raw;
 pub mod util;
+pub mod validation;
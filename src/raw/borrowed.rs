@@ -0,0 +1,302 @@
+//! Borrowed counterparts of the raw types, referring to the parsed input instead of copying it.
+
+use super::{RawGroup, RawInterchange, RawSegment, RawTransaction};
+use crate::error::ParseError;
+use crate::util::{Delimiters, Parser};
+use nom::bytes::complete::{tag, take_until, take_while1};
+use nom::character::complete::line_ending;
+use nom::combinator::{opt, peek};
+use nom::error::ErrorKind;
+use nom::IResult;
+use nom::Parser as _;
+
+/// A segment borrowing its elements from the input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RawSegmentRef<'a> {
+    /// Segment ID, e.g. `N1`
+    pub id: &'a str,
+    /// elements following the segment ID, each preceded by the element separator
+    body: &'a str,
+    delimiters: Delimiters,
+}
+
+impl<'a> RawSegmentRef<'a> {
+    /// Returns the elements of the segment, including their component separators.
+    pub fn elements(&self) -> impl Iterator<Item = &'a str> {
+        let element = self.delimiters.element;
+        self.body.split(element).skip(1)
+    }
+
+    /// Returns the components of the element at `position` (starting at 1, like `N101`).
+    pub fn components(&self, position: usize) -> impl Iterator<Item = &'a str> {
+        let element = position
+            .checked_sub(1)
+            .and_then(|idx| self.elements().nth(idx));
+        let segment = *self;
+        element
+            .into_iter()
+            .flat_map(move |element| segment.split_components(element))
+    }
+
+    /// Returns the first component of the element at `position` (starting at 1, like `N101`).
+    pub fn element(&self, position: usize) -> Option<&'a str> {
+        self.components(position).next()
+    }
+
+    /// Copies the segment into a [`RawSegment`].
+    pub fn into_owned(self) -> RawSegment {
+        RawSegment {
+            id: self.id.to_string(),
+            elements: self
+                .elements()
+                .map(|element| self.split_components(element).map(str::to_string).collect())
+                .collect(),
+            delimiters: self.delimiters,
+        }
+    }
+
+    /// Parses the segment into a typed segment, e.g. `v004010::N1`.
+    pub fn to_typed<S>(&self) -> Result<S, ParseError>
+    where
+        S: for<'b> Parser<&'b str, S, nom::error::Error<&'b str>>,
+    {
+        let source = format!("{}{}{}", self.id, self.body, self.delimiters.segment);
+        let _scope = self.delimiters.scope();
+        crate::error::parse(&source)
+    }
+
+    fn split_components(&self, element: &'a str) -> impl Iterator<Item = &'a str> {
+        // ISA16 is the component separator itself
+        let max = match self.id {
+            "ISA" => 1,
+            _ => usize::MAX,
+        };
+        element.splitn(max, self.delimiters.component)
+    }
+}
+
+impl<'a> Parser<&'a str, RawSegmentRef<'a>, nom::error::Error<&'a str>> for RawSegmentRef<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, RawSegmentRef<'a>> {
+        let delimiters = Delimiters::current();
+        let terminator = delimiters.segment.to_string();
+        let (rest, id) = take_while1(|c: char| c.is_ascii_alphanumeric()).parse(input)?;
+        let (rest, body) = match take_until(terminator.as_str()).parse(rest) {
+            Ok((rest, body)) => tag(terminator.as_str()).map(|_| body).parse(rest)?,
+            // a line break terminator is commonly missing after the last segment of a file
+            Err(_) if delimiters.segment == '\n' => ("", rest),
+            Err(err) => return Err(err),
+        };
+        let (rest, _) = opt(line_ending).parse(rest)?;
+        if !body.is_empty() && !body.starts_with(delimiters.element) {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                body,
+                ErrorKind::Char,
+            )));
+        }
+        Ok((
+            rest,
+            RawSegmentRef {
+                id,
+                body,
+                delimiters,
+            },
+        ))
+    }
+}
+
+/// A transaction set borrowing its segments from the input, from ST to SE
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawTransactionRef<'a> {
+    pub segments: Vec<RawSegmentRef<'a>>,
+    /// the transaction set as found in the input
+    source: &'a str,
+}
+
+impl<'a> RawTransactionRef<'a> {
+    /// Transaction Set Identifier Code (ST01)
+    pub fn id(&self) -> Option<&'a str> {
+        self.segments.first().and_then(|st| st.element(1))
+    }
+
+    /// Returns the transaction set as found in the input, from ST up to and including the SE.
+    pub fn as_str(&self) -> &'a str {
+        self.source
+    }
+
+    /// Copies the transaction set into a [`RawTransaction`].
+    pub fn into_owned(self) -> RawTransaction {
+        RawTransaction {
+            delimiters: self.delimiters(),
+            segments: self
+                .segments
+                .into_iter()
+                .map(RawSegmentRef::into_owned)
+                .collect(),
+        }
+    }
+
+    /// Parses the transaction set into a typed transaction set, e.g. `v005010::_835`,
+    /// or a borrowed one referring to the input, e.g. `v005010::_835Ref`.
+    pub fn to_typed<T>(&self) -> Result<T, ParseError>
+    where
+        T: Parser<&'a str, T, nom::error::Error<&'a str>>,
+    {
+        let _scope = self.delimiters().scope();
+        crate::error::parse(self.source)
+    }
+
+    /// The delimiters the transaction set was parsed with
+    fn delimiters(&self) -> Delimiters {
+        self.segments
+            .first()
+            .map_or_else(Delimiters::current, |st| st.delimiters)
+    }
+}
+
+impl<'a> Parser<&'a str, RawTransactionRef<'a>, nom::error::Error<&'a str>>
+    for RawTransactionRef<'a>
+{
+    fn parse(input: &'a str) -> IResult<&'a str, RawTransactionRef<'a>> {
+        let (mut rest, st) = segment(input, "ST")?;
+        let mut segments = vec![st];
+        loop {
+            let (next, segment) = RawSegmentRef::parse(rest)?;
+            rest = next;
+            let is_trailer = segment.id == "SE";
+            segments.push(segment);
+            if is_trailer {
+                break;
+            }
+        }
+        let source = &input[..input.len() - rest.len()];
+        Ok((rest, RawTransactionRef { segments, source }))
+    }
+}
+
+/// A functional group borrowing its segments from the input, from GS to GE
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawGroupRef<'a> {
+    pub gs: RawSegmentRef<'a>,
+    pub transactions: Vec<RawTransactionRef<'a>>,
+    pub ge: RawSegmentRef<'a>,
+}
+
+impl<'a> RawGroupRef<'a> {
+    /// Version / Release / Industry Identifier Code (GS08), e.g. `005010X222A1`
+    pub fn version(&self) -> Option<&'a str> {
+        self.gs.element(8)
+    }
+
+    /// Copies the functional group into a [`RawGroup`].
+    pub fn into_owned(self) -> RawGroup {
+        RawGroup {
+            delimiters: self.gs.delimiters,
+            gs: self.gs.into_owned(),
+            transactions: self
+                .transactions
+                .into_iter()
+                .map(RawTransactionRef::into_owned)
+                .collect(),
+            ge: self.ge.into_owned(),
+        }
+    }
+}
+
+impl<'a> Parser<&'a str, RawGroupRef<'a>, nom::error::Error<&'a str>> for RawGroupRef<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, RawGroupRef<'a>> {
+        let (mut rest, gs) = segment(input, "GS")?;
+        let mut transactions = vec![];
+        while peek(opt(|i| segment(i, "ST"))).parse(rest)?.1.is_some() {
+            let (next, transaction) = RawTransactionRef::parse(rest)?;
+            rest = next;
+            transactions.push(transaction);
+        }
+        let (rest, ge) = segment(rest, "GE")?;
+        Ok((
+            rest,
+            RawGroupRef {
+                gs,
+                transactions,
+                ge,
+            },
+        ))
+    }
+}
+
+/// An interchange of any version borrowing its segments from the input, from ISA to IEA
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawInterchangeRef<'a> {
+    pub isa: RawSegmentRef<'a>,
+    pub groups: Vec<RawGroupRef<'a>>,
+    pub iea: RawSegmentRef<'a>,
+    /// delimiters declared by the ISA
+    pub delimiters: Delimiters,
+}
+
+impl<'a> RawInterchangeRef<'a> {
+    /// Interchange Control Number (ISA13)
+    pub fn control_number(&self) -> Option<&'a str> {
+        self.isa.element(13)
+    }
+
+    /// All transaction sets of all functional groups
+    pub fn transactions(&self) -> impl Iterator<Item = &RawTransactionRef<'a>> {
+        self.groups.iter().flat_map(|group| &group.transactions)
+    }
+
+    /// Copies the interchange into a [`RawInterchange`].
+    pub fn into_owned(self) -> RawInterchange {
+        RawInterchange {
+            isa: self.isa.into_owned(),
+            groups: self
+                .groups
+                .into_iter()
+                .map(RawGroupRef::into_owned)
+                .collect(),
+            iea: self.iea.into_owned(),
+            delimiters: self.delimiters,
+        }
+    }
+}
+
+impl<'a> Parser<&'a str, RawInterchangeRef<'a>, nom::error::Error<&'a str>>
+    for RawInterchangeRef<'a>
+{
+    fn parse(input: &'a str) -> IResult<&'a str, RawInterchangeRef<'a>> {
+        let delimiters = Delimiters::from_isa(input)
+            .ok_or_else(|| nom::Err::Error(nom::error::Error::new(input, ErrorKind::Tag)))?;
+        let _scope = delimiters.scope();
+        let (mut rest, isa) = segment(input, "ISA")?;
+        let mut groups = vec![];
+        while peek(opt(|i| segment(i, "GS"))).parse(rest)?.1.is_some() {
+            let (next, group) = RawGroupRef::parse(rest)?;
+            rest = next;
+            groups.push(group);
+        }
+        let (rest, iea) = segment(rest, "IEA")?;
+        Ok((
+            rest,
+            RawInterchangeRef {
+                isa,
+                groups,
+                iea,
+                delimiters,
+            },
+        ))
+    }
+}
+
+/// Parses a segment with the given `id`.
+fn segment<'a>(input: &'a str, id: &str) -> IResult<&'a str, RawSegmentRef<'a>> {
+    match RawSegmentRef::parse(input) {
+        Ok((rest, segment)) if segment.id == id => Ok((rest, segment)),
+        Ok(_) | Err(nom::Err::Error(_)) => {
+            crate::error::record_failure(input, id);
+            Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                ErrorKind::Tag,
+            )))
+        }
+        Err(err) => Err(err),
+    }
+}
@@ -0,0 +1,163 @@
+//! Untyped representation of X12 segments, independent of version and transaction set.
+//!
+//! [`RawInterchange`] parses any interchange into its functional groups, transaction sets and segments,
+//! so that interchanges without a typed binding can be routed, inspected or logged.
+//! [`RawInterchangeRef`] and the other borrowed types refer to the input instead of copying it,
+//! [`RawTransactionRef::to_typed`] parses a single transaction set into its typed binding.
+//! The 005010 835 also has a borrowed typed binding, [`v005010::_835Ref`](crate::v005010::_835Ref).
+
+use crate::util::{writer, Delimiters, Parser};
+use nom::IResult;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+mod borrowed;
+pub use borrowed::*;
+
+#[cfg(test)]
+mod raw_test;
+
+/// A segment without a typed binding
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(rename = "$x12::RawSegment")]
+pub struct RawSegment {
+    /// Segment ID, e.g. `N1`
+    pub id: String,
+    /// Elements of the segment, each consisting of one or more components
+    pub elements: Vec<Vec<String>>,
+    /// delimiters the segment was parsed with, used for rendering
+    #[serde(skip_deserializing, serialize_with = "writer::serialize_declared")]
+    pub delimiters: Delimiters,
+}
+
+impl RawSegment {
+    /// Returns the first component of the element at `position` (starting at 1, like `N101`).
+    pub fn element(&self, position: usize) -> Option<&str> {
+        let element = self.elements.get(position.checked_sub(1)?)?;
+        element.first().map(String::as_str)
+    }
+}
+
+impl<'a> Parser<&'a str, RawSegment, nom::error::Error<&'a str>> for RawSegment {
+    fn parse(input: &'a str) -> IResult<&'a str, RawSegment> {
+        RawSegmentRef::parse(input).map(|(rest, segment)| (rest, segment.into_owned()))
+    }
+}
+
+impl Display for RawSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let all = writer::to_string(self, self.delimiters).map_err(|_| std::fmt::Error)?;
+        write!(f, "{all}")
+    }
+}
+
+/// A transaction set without a typed binding, from ST to SE
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct RawTransaction {
+    /// delimiters the transaction set was parsed with, used for rendering
+    #[serde(skip_deserializing, serialize_with = "writer::serialize_declared")]
+    pub delimiters: Delimiters,
+    pub segments: Vec<RawSegment>,
+}
+
+impl RawTransaction {
+    /// Transaction Set Identifier Code (ST01)
+    pub fn id(&self) -> Option<&str> {
+        self.segments.first().and_then(|st| st.element(1))
+    }
+}
+
+impl<'a> Parser<&'a str, RawTransaction, nom::error::Error<&'a str>> for RawTransaction {
+    fn parse(input: &'a str) -> IResult<&'a str, RawTransaction> {
+        RawTransactionRef::parse(input).map(|(rest, transaction)| (rest, transaction.into_owned()))
+    }
+}
+
+impl Display for RawTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let all = writer::to_string(self, self.delimiters).map_err(|_| std::fmt::Error)?;
+        write!(f, "{all}")
+    }
+}
+
+/// A functional group without a typed binding, from GS to GE
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct RawGroup {
+    /// delimiters the functional group was parsed with, used for rendering
+    #[serde(skip_deserializing, serialize_with = "writer::serialize_declared")]
+    pub delimiters: Delimiters,
+    pub gs: RawSegment,
+    pub transactions: Vec<RawTransaction>,
+    pub ge: RawSegment,
+}
+
+impl RawGroup {
+    /// Functional Identifier Code (GS01)
+    pub fn functional_id(&self) -> Option<&str> {
+        self.gs.element(1)
+    }
+
+    /// Group Control Number (GS06)
+    pub fn control_number(&self) -> Option<&str> {
+        self.gs.element(6)
+    }
+
+    /// Version / Release / Industry Identifier Code (GS08), e.g. `005010X222A1`
+    pub fn version(&self) -> Option<&str> {
+        self.gs.element(8)
+    }
+}
+
+impl<'a> Parser<&'a str, RawGroup, nom::error::Error<&'a str>> for RawGroup {
+    fn parse(input: &'a str) -> IResult<&'a str, RawGroup> {
+        RawGroupRef::parse(input).map(|(rest, group)| (rest, group.into_owned()))
+    }
+}
+
+impl Display for RawGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let all = writer::to_string(self, self.delimiters).map_err(|_| std::fmt::Error)?;
+        write!(f, "{all}")
+    }
+}
+
+/// An interchange of any version, from ISA to IEA
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct RawInterchange {
+    /// delimiters declared by the ISA, used for rendering
+    #[serde(skip_deserializing, serialize_with = "writer::serialize_declared")]
+    pub delimiters: Delimiters,
+    pub isa: RawSegment,
+    pub groups: Vec<RawGroup>,
+    pub iea: RawSegment,
+}
+
+impl RawInterchange {
+    /// Interchange Control Version Number (ISA12), e.g. `00501`
+    pub fn version(&self) -> Option<&str> {
+        self.isa.element(12)
+    }
+
+    /// Interchange Control Number (ISA13)
+    pub fn control_number(&self) -> Option<&str> {
+        self.isa.element(13)
+    }
+
+    /// All transaction sets of all functional groups
+    pub fn transactions(&self) -> impl Iterator<Item = &RawTransaction> {
+        self.groups.iter().flat_map(|group| &group.transactions)
+    }
+}
+
+impl<'a> Parser<&'a str, RawInterchange, nom::error::Error<&'a str>> for RawInterchange {
+    fn parse(input: &'a str) -> IResult<&'a str, RawInterchange> {
+        RawInterchangeRef::parse(input).map(|(rest, interchange)| (rest, interchange.into_owned()))
+    }
+}
+
+impl Display for RawInterchange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let all = writer::to_string(self, self.delimiters).map_err(|_| std::fmt::Error)?;
+        write!(f, "{all}")
+    }
+}
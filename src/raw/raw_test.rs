@@ -0,0 +1,145 @@
+use super::*;
+use crate::envelope::TransactionSet;
+
+#[test]
+fn parse_raw_interchange_004060_856() {
+    let str = include_str!("../../test-data/004060_856.edi");
+    let obj: RawInterchange = crate::error::parse(str).unwrap();
+    assert_eq!(obj.delimiters.segment, '\n');
+    assert_eq!(obj.version(), Some("00400"));
+    assert_eq!(obj.control_number(), Some("000003438"));
+    assert_eq!(obj.groups.len(), 1);
+    assert_eq!(obj.groups[0].version(), Some("004060"));
+    let transaction = obj.transactions().next().unwrap();
+    assert_eq!(transaction.id(), Some("856"));
+    assert_eq!(transaction.segments[1].id, "BSN");
+    assert_eq!(format!("{obj}"), format!("{str}\n"));
+}
+
+#[test]
+fn parse_raw_interchange_005010_824() {
+    let str = include_str!("../../test-data/005010_824.edi");
+    let obj: RawInterchange = crate::error::parse(str).unwrap();
+    assert_eq!(obj.version(), Some("00501"));
+    assert_eq!(obj.groups[0].functional_id(), Some("AG"));
+    let transaction = &obj.groups[0].transactions[0];
+    assert_eq!(transaction.id(), Some("824"));
+    let (se01, se02) = transaction.trailer();
+    assert_eq!(se01, transaction.segments.len().to_string());
+    assert_eq!(se02, transaction.control_number());
+    assert_eq!(format!("{obj}"), format!("{str}\n"));
+}
+
+#[test]
+fn parse_raw_interchange_components() {
+    let str = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *200101*1253*^*00501*000000905*0*T*|~
+GS*HP*SENDER*RECEIVER*20200101*0802*1*X*005010X221A1~
+ST*835*0001~
+SVC*HC|99213*150*80**1~
+SE*3*0001~
+GE*1*1~
+IEA*1*000000905~
+";
+    let obj: RawInterchange = crate::error::parse(str).unwrap();
+    let svc = &obj.groups[0].transactions[0].segments[1];
+    assert_eq!(svc.elements[0], ["HC", "99213"]);
+    assert_eq!(svc.element(1), Some("HC"));
+    assert_eq!(format!("{obj}"), str);
+    let err = crate::error::parse::<RawInterchange>(&str.replace("GE*1*1~\n", "")).unwrap_err();
+    assert_eq!(err.found.as_deref(), Some("IEA"));
+    assert_eq!(err.expected, ["ST", "GE"]);
+}
+
+#[test]
+fn parse_raw_interchange_borrowed() {
+    let str = include_str!("../../test-data/005010_824.edi");
+    let (rest, obj) = RawInterchangeRef::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let input = str.as_bytes().as_ptr_range();
+    let transaction = obj.transactions().next().unwrap();
+    assert_eq!(transaction.id(), Some("824"));
+    assert!(transaction.as_str().starts_with("ST*824*"));
+    assert!(transaction.as_str().ends_with("~\n"));
+    for segment in &transaction.segments {
+        assert!(input.contains(&segment.id.as_ptr()));
+        assert!(segment.elements().all(|e| input.contains(&e.as_ptr())));
+    }
+    assert_eq!(obj.isa.element(16), Some(">"));
+    let (_, owned) = RawInterchange::parse(str).unwrap();
+    assert_eq!(obj.into_owned(), owned);
+}
+
+#[test]
+fn sniff_interchange_summary() {
+    let str = include_str!("../../test-data/005010_837.edi");
+    let summary = crate::envelope::sniff(str).unwrap();
+    assert_eq!(summary.sender_id, "000000060000000");
+    assert_eq!(summary.receiver_id, "000000010000000");
+    assert_eq!(summary.version, "00501");
+    assert_eq!(summary.control_number, "110705001");
+    assert_eq!(summary.delimiters.component, ':');
+    assert_eq!(summary.groups[0].functional_id, "HC");
+    assert_eq!(summary.groups[0].version, "005010X222A1");
+    assert_eq!(summary.groups[0].transaction_sets, vec!["837"]);
+    assert_eq!(summary.transaction_count(), 1);
+    assert_eq!(summary.segment_count, str.matches('~').count());
+
+    let summary = crate::envelope::sniff_bytes(include_bytes!("../../test-data/004060_856.edi"));
+    let summary = summary.unwrap();
+    assert_eq!(summary.version, "00400");
+    assert_eq!(summary.groups[0].version, "004060");
+    assert_eq!(summary.groups[0].transaction_sets, vec!["856"]);
+
+    assert_eq!(crate::envelope::sniff("GS*HC*A*B~"), None);
+}
+
+#[test]
+fn validate_codes_of_all_fixtures() {
+    use crate::validation::validate_codes;
+    let mut paths: Vec<_> = std::fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/test-data"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty());
+    for path in paths {
+        let str = std::fs::read_to_string(&path).unwrap();
+        let obj: RawInterchange = crate::error::parse(&str).unwrap();
+        for group in &obj.groups {
+            // versions without a module of their own are checked with the closest earlier version
+            let code_lists = match group.version().unwrap_or_default().get(..6) {
+                Some("003030") => crate::v003030::code_lists,
+                Some("004010" | "004060") => crate::v004010::code_lists,
+                Some("005010") => crate::v005010::code_lists,
+                Some("005030") => crate::v005030::code_lists,
+                version => panic!("no code lists for {version:?} of {}", path.display()),
+            };
+            for transaction in &group.transactions {
+                let errors = validate_codes(transaction, code_lists).unwrap();
+                assert!(errors.is_empty(), "{}: {errors:?}", path.display());
+            }
+        }
+    }
+}
+
+#[test]
+fn display_raw_with_declared_delimiters() {
+    let str = "ISA|00|          |00|          |ZZ|SENDER         |ZZ|RECEIVER       |200101|1253|^|00501|000000905|0|T|>'GS|HP|SENDER|RECEIVER|20200101|0802|1|X|005010X221A1'ST|835|0001'SVC|HC>99213|150|80||1'SE|3|0001'GE|1|1'IEA|1|000000905'";
+    let obj: RawInterchange = crate::error::parse(str).unwrap();
+    let transaction = &obj.groups[0].transactions[0];
+    assert_eq!(
+        transaction.to_string(),
+        "ST|835|0001'\nSVC|HC>99213|150|80||1'\nSE|3|0001'\n"
+    );
+    assert_eq!(
+        transaction.segments[1].to_string(),
+        "SVC|HC>99213|150|80||1'\n"
+    );
+    assert!(obj.groups[0].to_string().starts_with("GS|HP|"));
+    let writer = crate::util::writer::Writer::new();
+    assert_eq!(
+        writer.to_string(transaction).unwrap(),
+        transaction.to_string()
+    );
+    assert!(writer.to_string(&obj).unwrap().starts_with("ISA|00|"));
+}
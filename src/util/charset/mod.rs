@@ -0,0 +1,68 @@
+//! X12 character sets and decoding of byte input.
+//!
+//! The basic character set consists of upper case letters, digits, space and `!"&'()*+,-./:;?=`,
+//! the extended character set adds lower case letters, `%@[]_{}\|<>~^#$` and national characters.
+//! Delimiters may be any character that does not occur in the data, including control characters.
+
+use super::Delimiters;
+
+/// Returns `true` for characters of the basic or extended character set.
+///
+/// This includes printable ASCII except the grave accent (`` ` ``), which is in neither character set,
+/// and the printable characters of Latin-1, like `é` or `§`.
+pub fn is_x12_character(c: char) -> bool {
+    matches!(c, ' '..='_' | 'a'..='~' | '\u{a0}'..='\u{ff}')
+}
+
+/// Byte input decoded to text
+pub(crate) struct Decoded {
+    pub(crate) text: String,
+    /// `true` if the input was not UTF-8 and was decoded as Latin-1
+    latin1: bool,
+}
+
+impl Decoded {
+    /// Decodes `input` as UTF-8, or as Latin-1 if it is not valid UTF-8.
+    pub(crate) fn new(input: &[u8]) -> Decoded {
+        match std::str::from_utf8(input) {
+            Ok(text) => Decoded {
+                text: text.to_string(),
+                latin1: false,
+            },
+            Err(_) => Decoded {
+                text: input.iter().copied().map(char::from).collect(),
+                latin1: true,
+            },
+        }
+    }
+
+    /// Maps an offset in `text` to the offset in the input bytes.
+    pub(crate) fn byte_offset(&self, offset: usize) -> usize {
+        match self.latin1 {
+            true => self.text[..offset].chars().count(),
+            false => offset,
+        }
+    }
+}
+
+/// Returns the offset of the first character in `text` that is neither part of the X12 character sets,
+/// nor a delimiter declared by the ISA or of the current scope, nor a line break.
+pub(crate) fn find_invalid(text: &str) -> Option<usize> {
+    let delimiters = Delimiters::from_isa(text).unwrap_or_else(Delimiters::current);
+    text.char_indices()
+        .find(|(_, c)| {
+            !is_element_character(*c, &delimiters)
+                && *c != delimiters.element
+                && *c != delimiters.segment
+        })
+        .map(|(offset, _)| offset)
+}
+
+/// Returns `true` for the characters allowed within an element: the X12 character sets, line breaks and
+/// the component and repetition separator.
+pub(crate) fn is_element_character(c: char, delimiters: &Delimiters) -> bool {
+    is_x12_character(c)
+        || matches!(c, '\r' | '\n')
+        || c == delimiters.component
+        || Some(c) == delimiters.repetition
+}
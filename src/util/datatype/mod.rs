@@ -0,0 +1,85 @@
+//! Checks of the X12 data element types, used by the `#[validate(custom(...))]` attributes of segment fields.
+//!
+//! The element tables of the segments list the type and the minimum and maximum length of each element:
+//! `AN` (string), `ID` (identifier), `N0` to `N9` (numeric with implied decimals), `R` (decimal),
+//! `DT` (date) and `TM` (time). Each check takes the length as `MIN` and `MAX`, counting the digits
+//! of numeric elements without sign and decimal point.
+//!
+//! An empty value is an omitted element and passes all checks, mandatory elements are checked by `length(min = 1)`.
+//! A failed check reports the code of the check, e.g. `numeric`, with `MIN` and `MAX` as the parameters `min` and `max`.
+//!
+//! Only segments with an element table have checks: the segments of 003030 and 004010, except 35 segments
+//! of 004010 without a table like W05, PID and TD4, and ISA, IEA, REF and TA1 of 005010 and ISA, IEA, GS, GE,
+//! ST, SE and TA1 of 005030. The other segments of 005010 and 005030 are not checked.
+
+use super::charset::is_x12_character;
+use validator::ValidationError;
+
+/// Checks the length of an element without a type, like the component element separator in ISA16.
+pub fn length<const MIN: usize, const MAX: usize>(value: &str) -> Result<(), ValidationError> {
+    check::<MIN, MAX>(value, "length", |value| {
+        (MIN..=MAX).contains(&value.chars().count())
+    })
+}
+
+/// Checks an `AN` or `ID` element for `MIN` to `MAX` characters of the X12 character sets.
+pub fn string<const MIN: usize, const MAX: usize>(value: &str) -> Result<(), ValidationError> {
+    check::<MIN, MAX>(value, "string", |value| {
+        (MIN..=MAX).contains(&value.chars().count()) && value.chars().all(is_x12_character)
+    })
+}
+
+/// Checks an `N0` to `N9` element for an optional minus sign followed by `MIN` to `MAX` digits.
+pub fn numeric<const MIN: usize, const MAX: usize>(value: &str) -> Result<(), ValidationError> {
+    check::<MIN, MAX>(value, "numeric", |value| {
+        let digits = value.strip_prefix('-').unwrap_or(value);
+        (MIN.max(1)..=MAX).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+/// Checks an `R` element for an optional minus sign followed by `MIN` to `MAX` digits and an optional decimal point.
+pub fn decimal<const MIN: usize, const MAX: usize>(value: &str) -> Result<(), ValidationError> {
+    check::<MIN, MAX>(value, "decimal", |value| {
+        let number = value.strip_prefix('-').unwrap_or(value);
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+        let digits = || integer.chars().chain(fraction.chars());
+        (MIN.max(1)..=MAX).contains(&digits().count()) && digits().all(|c| c.is_ascii_digit())
+    })
+}
+
+/// Checks a `DT` element for the digits of a date as `YYMMDD` or `CCYYMMDD`.
+///
+/// Only the syntax is checked, not the calendar, e.g. the placeholder `999999` passes.
+pub fn date<const MIN: usize, const MAX: usize>(value: &str) -> Result<(), ValidationError> {
+    check::<MIN, MAX>(value, "date", |value| {
+        (MIN..=MAX).contains(&value.len())
+            && matches!(value.len(), 6 | 8)
+            && value.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+/// Checks a `TM` element for the digits of a time as `HHMM`, `HHMMSS` or `HHMMSSD` to `HHMMSSDD` with decimal seconds.
+///
+/// Only the syntax is checked, not the clock, like for [`date`].
+pub fn time<const MIN: usize, const MAX: usize>(value: &str) -> Result<(), ValidationError> {
+    check::<MIN, MAX>(value, "time", |value| {
+        (MIN..=MAX).contains(&value.len())
+            && matches!(value.len(), 4 | 6..=8)
+            && value.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+/// Fails with `code` and the parameters `min` and `max` unless `value` is empty or `valid`.
+fn check<const MIN: usize, const MAX: usize>(
+    value: &str,
+    code: &'static str,
+    valid: impl FnOnce(&str) -> bool,
+) -> Result<(), ValidationError> {
+    if value.is_empty() || valid(value) {
+        return Ok(());
+    }
+    let mut error = ValidationError::new(code);
+    error.add_param("min".into(), &MIN);
+    error.add_param("max".into(), &MAX);
+    Err(error)
+}
@@ -0,0 +1,132 @@
+use std::cell::Cell;
+
+const DEFAULT_DELIMITERS: Delimiters = Delimiters {
+    element: '*',
+    component: ':',
+    repetition: None,
+    segment: '~',
+};
+
+/// The repetition separator commonly declared in ISA11 of 005010, used to render repeated
+/// elements if no repetition separator is declared
+pub(crate) const DEFAULT_REPETITION: char = '^';
+
+thread_local! {
+    static CURRENT: Cell<Delimiters> = const { Cell::new(DEFAULT_DELIMITERS) };
+}
+
+/// Delimiters of an interchange
+///
+/// All delimiters are declared by the ISA segment. The element separator is the 4th character of the ISA,
+/// the component separator is ISA16 and the segment terminator is the character following ISA16.
+/// Starting with 00501, ISA11 holds the repetition separator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Delimiters {
+    /// separates the elements of a segment
+    pub element: char,
+    /// separates the components of a composite element
+    pub component: char,
+    /// separates the repeated occurrences of an element
+    pub repetition: Option<char>,
+    /// terminates each segment
+    pub segment: char,
+}
+
+impl Default for Delimiters {
+    fn default() -> Self {
+        DEFAULT_DELIMITERS
+    }
+}
+
+impl Delimiters {
+    /// Detects the delimiters declared by the ISA segment at the start of `input`.
+    ///
+    /// Returns `None` if the input does not start with a complete ISA segment.
+    pub fn from_isa(input: &str) -> Option<Delimiters> {
+        let body = input.strip_prefix("ISA")?;
+        let mut chars = body.chars();
+        let element = chars.next()?;
+        if element.is_alphanumeric() {
+            return None;
+        }
+        // ISA01 to ISA15 are each followed by an element separator
+        let mut elements = Vec::with_capacity(15);
+        let mut current = String::new();
+        while elements.len() < 15 {
+            let c = chars.next()?;
+            if c == element {
+                elements.push(std::mem::take(&mut current));
+            } else {
+                current.push(c);
+            }
+        }
+        let mut component = chars.next()?;
+        let mut segment = chars.next()?;
+        if matches!(segment, '\n' | '\r') {
+            // some senders omit ISA16 and follow the segment terminator with a line break,
+            // which shows by the next segment ending with the same character
+            let next = chars.as_str().trim_start_matches(['\r', '\n']);
+            if next
+                .lines()
+                .next()
+                .is_some_and(|line| line.ends_with(component))
+            {
+                segment = component;
+                component = DEFAULT_DELIMITERS.component;
+            }
+        }
+        Some(Delimiters {
+            element,
+            component,
+            repetition: repetition_separator(&elements[10]),
+            segment,
+        })
+    }
+
+    /// Returns the delimiters currently used for parsing on this thread.
+    pub(crate) fn current() -> Delimiters {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Uses these delimiters for all parsing on this thread,
+    /// until the returned scope is dropped.
+    ///
+    /// The derived segment parsers take no delimiters, so they are passed on within the thread by
+    /// [`Parser::parse_with`](super::Parser::parse_with) and the parsers of the envelope.
+    pub(crate) fn scope(self) -> DelimiterScope {
+        let previous = CURRENT.with(|current| current.replace(self));
+        DelimiterScope { previous }
+    }
+}
+
+/// Restores the previously used delimiters when dropped
+///
+/// Created by [`Delimiters::scope`].
+#[must_use = "the delimiters are reset as soon as the scope is dropped"]
+pub(crate) struct DelimiterScope {
+    previous: Delimiters,
+}
+
+impl Drop for DelimiterScope {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(self.previous));
+    }
+}
+
+/// Whether ISA11 holds the repetition separator in the interchange control version `isa12`, i.e. 00501 or later
+pub(crate) fn declares_repetition(isa12: &str) -> bool {
+    isa12
+        .trim()
+        .parse::<u32>()
+        .is_ok_and(|version| version >= 501)
+}
+
+/// ISA11 is the repetition separator starting with 00501,
+/// before it holds the Interchange Control Standards Identifier (`U`).
+pub(crate) fn repetition_separator(isa11: &str) -> Option<char> {
+    let mut chars = isa11.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if !c.is_alphanumeric() => Some(c),
+        _ => None,
+    }
+}
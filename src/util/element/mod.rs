@@ -0,0 +1,238 @@
+//! Element types other than plain strings.
+//!
+//! Segment fields of these types are parsed via [`X12Element`], using the delimiters passed to
+//! [`Parser::parse_with`](super::Parser::parse_with), and rendered with the delimiters declared in the ISA.
+//! Composite elements are structs with one field per component, see [`Component`].
+
+use super::{Delimiters, DEFAULT_REPETITION};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::ops::{Deref, DerefMut};
+
+/// Builds a segment field from the text of its element
+pub trait X12Element {
+    fn from_x12(value: &str) -> Self;
+}
+
+impl X12Element for String {
+    fn from_x12(value: &str) -> Self {
+        value.to_string()
+    }
+}
+
+/// A repeating element
+///
+/// Starting with 00501, an element may occur multiple times, separated by the repetition separator
+/// declared in ISA11, e.g. `EB*1**1^33^35~`. Without a declared repetition separator, e.g. when parsing
+/// a single segment with the default delimiters, the occurrences are separated by `^`.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct Repeated<T>(pub Vec<T>);
+
+impl<T> Deref for Repeated<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Repeated<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec<T>> for Repeated<T> {
+    fn from(value: Vec<T>) -> Self {
+        Repeated(value)
+    }
+}
+
+impl<T> FromIterator<T> for Repeated<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Repeated(iter.into_iter().collect())
+    }
+}
+
+impl<T: X12Element> X12Element for Repeated<T> {
+    fn from_x12(value: &str) -> Self {
+        if value.is_empty() {
+            return Repeated(vec![]);
+        }
+        match Delimiters::current().repetition {
+            Some(repetition) => value.split(repetition).map(T::from_x12).collect(),
+            None => Repeated(vec![T::from_x12(value)]),
+        }
+    }
+}
+
+impl<T: Display> Display for Repeated<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repetition = Delimiters::current()
+            .repetition
+            .unwrap_or(DEFAULT_REPETITION);
+        for (idx, value) in self.0.iter().enumerate() {
+            match idx {
+                0 => write!(f, "{value}")?,
+                _ => write!(f, "{repetition}{value}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A component of a composite element, either mandatory (`String`) or optional (`Option<String>`)
+pub trait Component {
+    fn from_component(value: Option<&str>) -> Self;
+    fn as_component(&self) -> &str;
+}
+
+impl Component for String {
+    fn from_component(value: Option<&str>) -> Self {
+        value.unwrap_or_default().to_string()
+    }
+
+    fn as_component(&self) -> &str {
+        self
+    }
+}
+
+impl Component for Option<String> {
+    fn from_component(value: Option<&str>) -> Self {
+        value.filter(|v| !v.is_empty()).map(str::to_string)
+    }
+
+    fn as_component(&self) -> &str {
+        self.as_deref().unwrap_or_default()
+    }
+}
+
+/// Implements [`X12Element`] and [`Display`] for a composite element with the given component fields.
+///
+/// Components are split and joined with the component separator declared in ISA16,
+/// trailing empty components are omitted.
+macro_rules! impl_composite {
+    ($name:ty { $($field:ident),* }) => {
+        impl $crate::util::X12Element for $name {
+            fn from_x12(value: &str) -> Self {
+                use $crate::util::Component;
+                let component = $crate::util::Delimiters::current().component;
+                let mut components = value.split(component);
+                Self {
+                    $($field: Component::from_component(components.next()),)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                use $crate::util::Component;
+                let mut components = vec![$(self.$field.as_component()),*];
+                while components.last().is_some_and(|c| c.is_empty()) {
+                    components.pop();
+                }
+                let component = $crate::util::Delimiters::current().component;
+                write!(f, "{}", components.join(&component.to_string()))
+            }
+        }
+
+        impl<'a> $crate::util::ElementRef<'a> for $name {
+            type Ref = $name;
+
+            fn from_value(value: &'a str) -> Self::Ref {
+                $crate::util::X12Element::from_x12(value)
+            }
+
+            fn into_owned(value: Self::Ref) -> Self {
+                value
+            }
+        }
+    };
+}
+pub(crate) use impl_composite;
+
+/// The borrowed counterpart of a segment field, see [`segment_ref`]
+///
+/// String fields borrow from the input, `String` as `&'a str` and `Option<String>` as `Option<&'a str>`.
+/// Composite elements are small and keep their owned type, they are split with the delimiters of the
+/// transmission, or those passed to [`Parser::parse_with`](super::Parser::parse_with), while parsing.
+pub trait ElementRef<'a>: Sized {
+    type Ref;
+
+    fn from_value(value: &'a str) -> Self::Ref;
+
+    fn into_owned(value: Self::Ref) -> Self;
+
+    /// Builds the field from an element which may be omitted, an omitted mandatory element is empty
+    fn from_element(element: super::Element<'_, 'a>) -> Self::Ref {
+        Self::from_value(element.unwrap())
+    }
+}
+
+impl<'a> ElementRef<'a> for String {
+    type Ref = &'a str;
+
+    fn from_value(value: &'a str) -> Self::Ref {
+        value
+    }
+
+    fn into_owned(value: Self::Ref) -> Self {
+        value.to_string()
+    }
+}
+
+impl<'a, T: ElementRef<'a>> ElementRef<'a> for Option<T> {
+    type Ref = Option<T::Ref>;
+
+    fn from_value(value: &'a str) -> Self::Ref {
+        Some(T::from_value(value))
+    }
+
+    fn into_owned(value: Self::Ref) -> Self {
+        value.map(T::into_owned)
+    }
+
+    fn from_element(element: super::Element<'_, 'a>) -> Self::Ref {
+        element.map(|value| T::from_value(value))
+    }
+}
+
+/// Declares the borrowed variant of a segment, with the same fields as the owned segment.
+///
+/// The segment is parsed like the `ParseSegment` derive of the owned segment, so that
+/// `into_owned` returns the same segment as parsing it directly.
+macro_rules! segment_ref {
+    ($(#[$attr:meta])* $name:ident => $owned:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+        $(#[$attr])*
+        #[derive(Clone, Default, Debug, PartialEq, Eq)]
+        pub struct $name<'a> {
+            $(pub $field: <$ty as $crate::util::ElementRef<'a>>::Ref,)*
+        }
+
+        impl<'a> $name<'a> {
+            /// Copies the borrowed fields into the owned segment
+            pub fn into_owned(self) -> $owned {
+                use $crate::util::ElementRef;
+                $owned {
+                    $($field: <$ty>::into_owned(self.$field),)*
+                }
+            }
+        }
+
+        impl<'a> $crate::util::Parser<&'a str, $name<'a>, nom::error::Error<&'a str>> for $name<'a> {
+            fn parse(input: &'a str) -> nom::IResult<&'a str, $name<'a>> {
+                use $crate::util::ElementRef;
+                let (rest, vars) = $crate::util::parse_line(input, stringify!($owned))?;
+                let mut elements = (0..).map(|idx| vars.get(idx));
+                Ok((
+                    rest,
+                    $name {
+                        $($field: <$ty>::from_element(elements.next().unwrap()),)*
+                    },
+                ))
+            }
+        }
+    };
+}
+pub(crate) use segment_ref;
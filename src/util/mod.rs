@@ -2,15 +2,28 @@ use crate::v004010::Transmission;
 use nom::bytes::complete::tag;
 use nom::bytes::complete::take_until;
 use nom::bytes::complete::take_while;
-use nom::character::complete::newline;
+use nom::character::complete::char;
+use nom::character::complete::line_ending;
 use nom::combinator::opt;
 use nom::multi::separated_list0;
 use nom::sequence::delimited;
 use nom::IResult;
 use nom::Parser as _;
+use std::ops::Deref;
 
+pub mod charset;
+pub mod datatype;
+mod delimiters;
 pub mod dt;
+mod element;
+pub mod reader;
 pub mod tm;
+pub mod writer;
+
+pub use delimiters::Delimiters;
+pub(crate) use delimiters::{declares_repetition, repetition_separator, DEFAULT_REPETITION};
+pub(crate) use element::{impl_composite, segment_ref};
+pub use element::{Component, ElementRef, Repeated, X12Element};
 
 pub fn is_equal_payload<T: PartialEq>(src: &Transmission<T>, target: &Transmission<T>) -> bool {
     let src_group = &src.functional_group;
@@ -23,25 +36,122 @@ pub fn is_equal_payload<T: PartialEq>(src: &Transmission<T>, target: &Transmissi
     true
 }
 
-pub fn parse_line<'a>(input: &'a str, segment_name: &str) -> IResult<&'a str, Vec<&'a str>> {
-    let tag_name = format!("{segment_name}*");
-    let (rest, vars) = delimited(tag(tag_name.as_str()), take_until("~"), tag("~")).parse(input)?;
-    let (_, vars) = separated_list0(
-        tag("*"),
+/// Parses a single segment, using the delimiters passed to [`Parser::parse_with`].
+///
+/// The ISA is always parsed with the delimiters it declares itself. Control characters other than the delimiters
+/// and line breaks fail the segment.
+/// Trailing elements may be omitted, see [`Elements`].
+pub fn parse_line<'a>(input: &'a str, segment_name: &str) -> IResult<&'a str, Elements<'a>> {
+    // `error::parse_lenient` skips segments which are not part of the transaction set
+    let start = crate::error::skip_unknown(input, segment_name);
+    let delimiters = match segment_name {
+        "ISA" => Delimiters::from_isa(start).unwrap_or_else(Delimiters::current),
+        _ => Delimiters::current(),
+    };
+    let element = delimiters.element;
+    let terminator = delimiters.segment.to_string();
+    let tag_name = format!("{segment_name}{element}");
+    let (rest, vars) = delimited(
+        tag(tag_name.as_str()),
+        take_until(terminator.as_str()),
+        tag(terminator.as_str()),
+    )
+    .parse(start)
+    .inspect_err(|_| crate::error::record_failure(start, segment_name))?;
+    // control characters are only allowed as delimiters, other characters outside the X12 character sets
+    // are reported by `error::parse`
+    let (invalid, vars) = separated_list0(
+        char(element),
         take_while(|x: char| {
-            x != '*' && (x.is_alphanumeric() || x.is_whitespace() || x.is_ascii_punctuation())
+            x != element && (!x.is_control() || charset::is_element_character(x, &delimiters))
         }),
     )
     .parse(vars)?;
-    // look for trailing newline
-    let (rest, _) = opt(newline).parse(rest)?;
-    Ok((rest, vars))
+    if !invalid.is_empty() {
+        crate::error::record_failure(start, segment_name);
+        return Err(nom::Err::Error(nom::error::Error::new(
+            invalid,
+            nom::error::ErrorKind::Char,
+        )));
+    }
+    // look for trailing line break
+    let (rest, _) = opt(line_ending).parse(rest)?;
+    crate::error::keep_skipped(input, start);
+    Ok((rest, Elements(vars)))
+}
+
+/// The elements of a segment parsed by [`parse_line`], without the segment ID
+///
+/// Omitted trailing elements are empty, so that a segment like `B2**SUDU~` fills the mandatory
+/// elements after `SUDU` with empty values, which are reported by `Validate`, instead of failing.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Elements<'a>(pub Vec<&'a str>);
+
+impl<'a> Elements<'a> {
+    /// Returns the element at `index`, starting at 0 for the first element
+    pub fn get(&self, index: usize) -> Element<'_, 'a> {
+        Element(self.0.get(index))
+    }
+}
+
+impl<'a> Deref for Elements<'a> {
+    type Target = [&'a str];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// An element of [`Elements`], which may be omitted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Element<'e, 'a>(Option<&'e &'a str>);
+
+impl<'e, 'a> Element<'e, 'a> {
+    /// Returns the value of a mandatory element, or an empty value if it is omitted.
+    pub fn unwrap(self) -> &'e &'a str {
+        self.0.unwrap_or(&"")
+    }
+
+    /// Maps the value of an optional element, `None` if it is omitted.
+    pub fn map<T>(self, f: impl FnOnce(&'e &'a str) -> T) -> Option<T> {
+        self.0.map(f)
+    }
 }
 
 pub trait Parser<I, O, E> {
+    /// Parses with the default delimiters, or with the delimiters declared by the ISA for a transmission.
     fn parse(str: I) -> IResult<I, O>;
+
+    /// Parses with the given delimiters, e.g. a segment or transaction set taken from an interchange
+    /// with a `|` element separator.
+    fn parse_with(str: I, delimiters: &Delimiters) -> IResult<I, O> {
+        let _scope = delimiters.scope();
+        Self::parse(str)
+    }
 }
 
 pub fn unborrow_string(input: &&str) -> String {
     input.to_string()
 }
+
+/// Parses byte input with the [`Parser`] of a transmission, transaction set or segment.
+///
+/// The input is decoded as UTF-8, or as Latin-1 if it is not valid UTF-8, see [`charset`].
+/// The remaining input is returned as bytes of `input`.
+pub trait ParseBytes: Sized {
+    fn parse_bytes(input: &[u8]) -> IResult<&[u8], Self>;
+}
+
+impl<T> ParseBytes for T
+where
+    T: for<'a> Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    fn parse_bytes(input: &[u8]) -> IResult<&[u8], T> {
+        let decoded = charset::Decoded::new(input);
+        let rest = |rest: &str| &input[decoded.byte_offset(decoded.text.len() - rest.len())..];
+        match T::parse(&decoded.text) {
+            Ok((remaining, value)) => Ok((rest(remaining), value)),
+            Err(err) => Err(err.map(|err| nom::error::Error::new(rest(err.input), err.code))),
+        }
+    }
+}
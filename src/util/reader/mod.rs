@@ -0,0 +1,308 @@
+//! Streaming access to an interchange.
+//!
+//! [`TransmissionReader`] reads one segment at a time from a [`BufRead`] and yields the envelope
+//! segments and transaction sets as soon as they are complete, so only a single transaction set
+//! is kept in memory. Each version module provides a `TransmissionReader<R, T>` alias
+//! with its own envelope segments.
+
+use super::{Delimiters, Parser};
+use crate::error::{self, ParseError};
+use std::io::{self, BufRead};
+use std::marker::PhantomData;
+
+/// An item of an interchange, in the order it appears
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event<Isa, Gs, T, Ge, Iea> {
+    /// ISA - Interchange Control Header
+    Interchange(Isa),
+    /// GS - Functional Group Header
+    FunctionalGroup(Gs),
+    /// a complete transaction set, from ST to SE
+    Transaction(T),
+    /// GE - Functional Group Trailer
+    FunctionalGroupEnd(Ge),
+    /// IEA - Interchange Control Trailer
+    InterchangeEnd(Iea),
+}
+
+/// Reads an interchange from a [`BufRead`], yielding one [`Event`] at a time.
+///
+/// A plain [`std::io::Read`] can be wrapped in a [`std::io::BufReader`].
+/// Several interchanges may follow each other, the delimiters are detected again for each ISA.
+pub struct TransmissionReader<R, Isa, Gs, T, Ge, Iea> {
+    segments: SegmentReader<R>,
+    _marker: PhantomData<Event<Isa, Gs, T, Ge, Iea>>,
+}
+
+impl<R, Isa, Gs, T, Ge, Iea> TransmissionReader<R, Isa, Gs, T, Ge, Iea>
+where
+    R: BufRead,
+{
+    pub fn new(reader: R) -> Self {
+        TransmissionReader {
+            segments: SegmentReader::new(reader),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Delimiters of the current interchange, `None` before the first ISA has been read.
+    pub fn delimiters(&self) -> Option<Delimiters> {
+        self.segments.delimiters
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.segments.inner
+    }
+}
+
+impl<R, Isa, Gs, T, Ge, Iea> Iterator for TransmissionReader<R, Isa, Gs, T, Ge, Iea>
+where
+    R: BufRead,
+    Isa: for<'a> Parser<&'a str, Isa, nom::error::Error<&'a str>>,
+    Gs: for<'a> Parser<&'a str, Gs, nom::error::Error<&'a str>>,
+    T: for<'a> Parser<&'a str, T, nom::error::Error<&'a str>>,
+    Ge: for<'a> Parser<&'a str, Ge, nom::error::Error<&'a str>>,
+    Iea: for<'a> Parser<&'a str, Iea, nom::error::Error<&'a str>>,
+{
+    type Item = io::Result<Event<Isa, Gs, T, Ge, Iea>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.segments.next_segment() {
+            Ok(Some(segment)) => Some(self.next_event(segment)),
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+impl<R, Isa, Gs, T, Ge, Iea> TransmissionReader<R, Isa, Gs, T, Ge, Iea>
+where
+    R: BufRead,
+    Isa: for<'a> Parser<&'a str, Isa, nom::error::Error<&'a str>>,
+    Gs: for<'a> Parser<&'a str, Gs, nom::error::Error<&'a str>>,
+    T: for<'a> Parser<&'a str, T, nom::error::Error<&'a str>>,
+    Ge: for<'a> Parser<&'a str, Ge, nom::error::Error<&'a str>>,
+    Iea: for<'a> Parser<&'a str, Iea, nom::error::Error<&'a str>>,
+{
+    fn next_event(&mut self, segment: String) -> io::Result<Event<Isa, Gs, T, Ge, Iea>> {
+        let delimiters = self.segments.current_delimiters();
+        let _scope = delimiters.scope();
+        let mut positions = vec![self.segments.position];
+        let event = match segment_id(&segment, delimiters) {
+            "ISA" => Event::Interchange(parse_segment(&segment, &positions)?),
+            "GS" => Event::FunctionalGroup(parse_segment(&segment, &positions)?),
+            "ST" => {
+                let mut transaction = segment;
+                loop {
+                    let Some(segment) = self.segments.next_segment()? else {
+                        return Err(io::Error::new(
+                            io::ErrorKind::UnexpectedEof,
+                            "transaction set without SE",
+                        ));
+                    };
+                    let is_trailer = segment_id(&segment, delimiters) == "SE";
+                    positions.push(self.segments.position);
+                    transaction.push_str(&segment);
+                    if is_trailer {
+                        break;
+                    }
+                }
+                Event::Transaction(parse_segment(&transaction, &positions)?)
+            }
+            "GE" => Event::FunctionalGroupEnd(parse_segment(&segment, &positions)?),
+            "IEA" => {
+                self.segments.delimiters = None;
+                Event::InterchangeEnd(parse_segment(&segment, &positions)?)
+            }
+            id => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unexpected segment {id} outside of a transaction set"),
+                ))
+            }
+        };
+        Ok(event)
+    }
+}
+
+fn segment_id(segment: &str, delimiters: Delimiters) -> &str {
+    segment
+        .split([delimiters.element, delimiters.segment])
+        .next()
+        .unwrap_or_default()
+}
+
+/// Parses the segments read at `positions`, reporting errors at their position in the input.
+fn parse_segment<O>(input: &str, positions: &[Position]) -> io::Result<O>
+where
+    O: for<'a> Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    error::parse(input).map_err(|err| {
+        let position = positions
+            .get(err.segment_index)
+            .or(positions.last())
+            .copied()
+            .unwrap_or_default();
+        let err = ParseError {
+            segment_index: position.segment_index,
+            line: position.line,
+            offset: position.offset,
+            ..err
+        };
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    })
+}
+
+/// Start of a segment in the input
+#[derive(Clone, Copy, Debug, Default)]
+struct Position {
+    segment_index: usize,
+    line: usize,
+    offset: usize,
+}
+
+/// Splits the input into segments, detecting the delimiters from each ISA.
+struct SegmentReader<R> {
+    inner: R,
+    delimiters: Option<Delimiters>,
+    /// text read from `inner`, but not yet returned as segment
+    buffer: String,
+    /// start of the segment returned last
+    position: Position,
+    /// start of `buffer` in the input
+    next: Position,
+}
+
+impl<R: BufRead> SegmentReader<R> {
+    fn new(inner: R) -> Self {
+        SegmentReader {
+            inner,
+            delimiters: None,
+            buffer: String::new(),
+            position: Position::default(),
+            next: Position {
+                line: 1,
+                ..Default::default()
+            },
+        }
+    }
+
+    fn current_delimiters(&self) -> Delimiters {
+        self.delimiters.unwrap_or_default()
+    }
+
+    /// Returns the next segment including its terminator, or `None` at the end of the input.
+    fn next_segment(&mut self) -> io::Result<Option<String>> {
+        if self.delimiters.is_none() && !self.read_isa()? {
+            return Ok(None);
+        }
+        let terminator = self.current_delimiters().segment;
+        loop {
+            let start = self.buffer.len() - self.buffer.trim_start_matches(['\r', '\n']).len();
+            if let Some(end) = self.buffer[start..].find(terminator) {
+                let end = start + end + terminator.len_utf8();
+                let segment = self.buffer[start..end].to_string();
+                self.advance(start);
+                self.position = self.next;
+                self.advance(end - start);
+                self.next.segment_index += 1;
+                return Ok(Some(segment));
+            }
+            if self.read_until(terminator)? == 0 {
+                if self.buffer.trim().is_empty() {
+                    self.buffer.clear();
+                    return Ok(None);
+                }
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "segment without terminator",
+                ));
+            }
+        }
+    }
+
+    /// Reads the ISA up to the segment terminator and detects the delimiters.
+    ///
+    /// Returns `false` if there is no further interchange.
+    fn read_isa(&mut self) -> io::Result<bool> {
+        self.advance(self.buffer.len() - self.buffer.trim_start().len());
+        let mut head = std::mem::take(&mut self.buffer).into_bytes();
+        // "ISA" and the element separator
+        while head.len() < 4 {
+            match self.read_byte()? {
+                Some(b) if head.is_empty() && b.is_ascii_whitespace() => {
+                    self.next.offset += 1;
+                    self.next.line += usize::from(b == b'\n');
+                }
+                Some(b) => head.push(b),
+                None if head.is_empty() => return Ok(false),
+                None => return Err(invalid_isa()),
+            }
+        }
+        if !head.starts_with(b"ISA") {
+            return Err(invalid_isa());
+        }
+        let element = head[3];
+        // ISA01 to ISA15 are each followed by an element separator, then ISA16 and the terminator
+        let mut separators = head.iter().filter(|b| **b == element).count();
+        let mut trailing = 0;
+        while separators < 16 || trailing < 2 {
+            let b = self.read_byte()?.ok_or_else(invalid_isa)?;
+            head.push(b);
+            if separators < 16 {
+                if b == element {
+                    separators += 1;
+                }
+            } else {
+                trailing += 1;
+            }
+        }
+        let mut text = String::from_utf8(head).map_err(|_| invalid_isa())?;
+        if text.ends_with(['\r', '\n']) {
+            // the next line is needed to tell a line break terminator from a missing ISA16
+            let mut line = vec![];
+            self.inner.read_until(b'\n', &mut line)?;
+            text.push_str(std::str::from_utf8(&line).map_err(|_| invalid_isa())?);
+        }
+        self.delimiters = Some(Delimiters::from_isa(&text).ok_or_else(invalid_isa)?);
+        self.buffer = text;
+        Ok(true)
+    }
+
+    /// Moves past `len` bytes of the buffer.
+    fn advance(&mut self, len: usize) {
+        let skipped = self.buffer.drain(..len);
+        self.next.line += skipped.as_str().matches('\n').count();
+        self.next.offset += len;
+    }
+
+    fn read_byte(&mut self) -> io::Result<Option<u8>> {
+        let buf = self.inner.fill_buf()?;
+        let Some(b) = buf.first().copied() else {
+            return Ok(None);
+        };
+        self.inner.consume(1);
+        Ok(Some(b))
+    }
+
+    /// Appends input up to and including the next `terminator` to the buffer.
+    fn read_until(&mut self, terminator: char) -> io::Result<usize> {
+        let mut encoded = [0; 4];
+        let last = *terminator
+            .encode_utf8(&mut encoded)
+            .as_bytes()
+            .last()
+            .unwrap_or(&0);
+        let mut bytes = vec![];
+        let read = self.inner.read_until(last, &mut bytes)?;
+        let text = String::from_utf8(bytes)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        self.buffer.push_str(&text);
+        Ok(read)
+    }
+}
+
+fn invalid_isa() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "invalid ISA segment")
+}
@@ -0,0 +1,795 @@
+//! Rendering of X12 structures with the delimiters of an interchange.
+//!
+//! Segments are tagged with `#[serde(rename = "$x12::segment::N1")]` and rendered with one element per field,
+//! everything else (transmissions, functional groups, transaction sets and loops) is walked in field order.
+//! [`Writer`] renders with configurable delimiters and line breaks into a `String` or any [`io::Write`].
+
+use super::delimiters::{repetition_separator, DEFAULT_REPETITION};
+use super::Delimiters;
+use serde::ser::{self, Impossible, Serialize};
+use std::fmt::Display;
+use std::io;
+
+/// Error raised if a value has no X12 representation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+/// Line break written after each segment, or after each line of wrapped output
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    /// no line breaks, all segments on a single line
+    None,
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl LineEnding {
+    fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::None => "",
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// Renders a `Transmission`, `FunctionalGroup`, transaction set or segment with the configured layout
+///
+/// By default, the delimiters declared by the value are used, i.e. `Transmission::delimiters` or the delimiters
+/// a raw interchange, group or transaction set was parsed with, falling back to `*` and `~` for the element
+/// and segment delimiters. The component and repetition separator are taken from ISA16 and ISA11, and each
+/// segment is followed by a line break.
+/// If delimiters are set explicitly, ISA11 and ISA16 are rendered with the configured separators.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Writer {
+    delimiters: Option<Delimiters>,
+    line_ending: LineEnding,
+    wrap: Option<usize>,
+}
+
+impl Writer {
+    pub fn new() -> Self {
+        Writer::default()
+    }
+
+    /// Renders with the given delimiters instead of those declared by the value.
+    pub fn delimiters(mut self, delimiters: Delimiters) -> Self {
+        self.delimiters = Some(delimiters);
+        self
+    }
+
+    /// Sets the line break, `LineEnding::Lf` by default.
+    pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Wraps the output into lines of `width` characters, regardless of segment boundaries.
+    pub fn wrap(mut self, width: usize) -> Self {
+        self.wrap = Some(width).filter(|width| *width > 0);
+        self
+    }
+
+    /// Renders `value` into a `String`.
+    pub fn to_string<T: Serialize + ?Sized>(&self, value: &T) -> Result<String, Error> {
+        let mut output = vec![];
+        self.render(&mut output, value)?;
+        // only `str`s are written
+        String::from_utf8(output).map_err(|err| Error(err.to_string()))
+    }
+
+    /// Renders `value` into `out`, writing each segment as soon as it is rendered.
+    pub fn write<W: io::Write, T: Serialize + ?Sized>(
+        &self,
+        mut out: W,
+        value: &T,
+    ) -> io::Result<()> {
+        self.render(&mut out, value).map_err(|err| match err {
+            Failure::Io(err) => err,
+            Failure::Render(err) => io::Error::new(io::ErrorKind::InvalidData, err),
+        })
+    }
+
+    fn render<T: Serialize + ?Sized>(
+        &self,
+        out: &mut dyn io::Write,
+        value: &T,
+    ) -> Result<(), Failure> {
+        let mut writer = SegmentWriter {
+            delimiters: self.delimiters.unwrap_or_default(),
+            fixed: self.delimiters.is_some(),
+            declaring: self.delimiters.is_none(),
+            line_ending: self.line_ending.as_str(),
+            wrap: self.wrap,
+            column: 0,
+            out,
+            failure: None,
+            segments: 0,
+        };
+        let result = value.serialize(Tree(&mut writer));
+        if let Some(err) = writer.failure.take() {
+            return Err(Failure::Io(err));
+        }
+        result?;
+        writer.finish().map_err(Failure::Io)
+    }
+}
+
+/// Failure of [`Writer::render`], keeping the error of the underlying `io::Write`
+enum Failure {
+    Io(io::Error),
+    Render(Error),
+}
+
+impl From<Error> for Failure {
+    fn from(err: Error) -> Self {
+        Failure::Render(err)
+    }
+}
+
+impl From<Failure> for Error {
+    fn from(failure: Failure) -> Self {
+        match failure {
+            Failure::Io(err) => Error(err.to_string()),
+            Failure::Render(err) => err,
+        }
+    }
+}
+
+/// Name of the newtype struct the declared delimiters are serialized as, see [`serialize_declared`]
+const DECLARED: &str = "$x12::Delimiters";
+
+/// Prefix of the serde name of a segment, followed by the segment ID
+const SEGMENT: &str = "$x12::segment::";
+
+/// Name of [`crate::raw::RawSegment`] for serde
+const RAW_SEGMENT: &str = "$x12::RawSegment";
+
+/// Passes the declared delimiters of a value to the [`Writer`], for `serialize_with`.
+///
+/// Other serializers get the delimiters as a string of the element separator, component separator,
+/// segment terminator and the repetition separator, if any, e.g. `*:~^`.
+/// The field has to precede the segments, so that all of them are rendered with the declared delimiters.
+pub(crate) fn serialize_declared<S: ser::Serializer>(
+    delimiters: &Delimiters,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut declared = String::with_capacity(4);
+    declared.push(delimiters.element);
+    declared.push(delimiters.component);
+    declared.push(delimiters.segment);
+    declared.extend(delimiters.repetition);
+    serializer.serialize_newtype_struct(DECLARED, &declared)
+}
+
+/// Renders `value` as X12, terminating each segment with the segment terminator and a line break.
+pub(crate) fn to_string<T: Serialize + ?Sized>(
+    value: &T,
+    delimiters: Delimiters,
+) -> Result<String, Error> {
+    let mut output = vec![];
+    let mut writer = SegmentWriter {
+        delimiters,
+        fixed: false,
+        declaring: false,
+        line_ending: LineEnding::Lf.as_str(),
+        wrap: None,
+        column: 0,
+        out: &mut output,
+        failure: None,
+        segments: 0,
+    };
+    value.serialize(Tree(&mut writer))?;
+    String::from_utf8(output).map_err(|err| Error(err.to_string()))
+}
+
+/// Counts the segments `value` is rendered as.
+pub(crate) fn segment_count<T: Serialize + ?Sized>(value: &T) -> Result<usize, Error> {
+    let mut sink = io::sink();
+    let mut writer = SegmentWriter {
+        delimiters: Delimiters::default(),
+        fixed: false,
+        declaring: false,
+        line_ending: "",
+        wrap: None,
+        column: 0,
+        out: &mut sink,
+        failure: None,
+        segments: 0,
+    };
+    value.serialize(Tree(&mut writer))?;
+    Ok(writer.segments)
+}
+
+struct SegmentWriter<'o> {
+    delimiters: Delimiters,
+    /// `true` if the delimiters are configured, instead of being declared by the value and the ISA
+    fixed: bool,
+    /// `true` if the delimiters declared by the value are adopted, see [`serialize_declared`]
+    declaring: bool,
+    /// written after each segment, or after each line of wrapped output
+    line_ending: &'static str,
+    /// width of the lines of wrapped output
+    wrap: Option<usize>,
+    /// characters written to the current line of wrapped output
+    column: usize,
+    out: &'o mut dyn io::Write,
+    /// the error of `out` which aborted rendering
+    failure: Option<io::Error>,
+    /// number of segments written
+    segments: usize,
+}
+
+impl SegmentWriter<'_> {
+    /// Adopts the delimiters declared by the value, see [`serialize_declared`].
+    fn declare(&mut self, declared: &str) {
+        let mut chars = declared.chars();
+        if let (Some(element), Some(component), Some(segment)) =
+            (chars.next(), chars.next(), chars.next())
+        {
+            self.delimiters = Delimiters {
+                element,
+                component,
+                repetition: chars.next(),
+                segment,
+            };
+        }
+    }
+
+    fn segment(&mut self, id: &str, fields: Vec<Value>) -> Result<(), Error> {
+        if id == "ISA" && !self.fixed {
+            // the ISA declares the component and repetition separator for all following segments
+            if let Some(Value::Text(isa16)) = fields.get(15) {
+                if let Some(component) = isa16.chars().next() {
+                    self.delimiters.component = component;
+                }
+            }
+            self.delimiters.repetition = match fields.get(10) {
+                Some(Value::Text(isa11)) => repetition_separator(isa11),
+                _ => None,
+            };
+        }
+        let elements = fields.iter().map(|v| self.element(v)).collect();
+        self.write(id, elements)
+    }
+
+    /// A raw segment has an `id` and `elements`, each element being a list of components, followed by
+    /// its delimiters if they are declared
+    fn raw_segment(&mut self, fields: Vec<Value>) -> Result<(), Error> {
+        if let (true, Some(Value::Text(declared))) = (self.declaring, fields.get(2)) {
+            self.declare(declared);
+        }
+        let mut fields = fields.into_iter();
+        let Some(Value::Text(id)) = fields.next() else {
+            return Ok(());
+        };
+        let component = self.delimiters.component.to_string();
+        let elements = match fields.next() {
+            Some(Value::List(elements)) => elements
+                .iter()
+                .map(|element| match element {
+                    Value::List(components) => components
+                        .iter()
+                        .map(|c| self.element(c))
+                        .collect::<Vec<_>>()
+                        .join(&component),
+                    other => self.element(other),
+                })
+                .collect(),
+            _ => vec![],
+        };
+        self.write(&id, elements)
+    }
+
+    fn element(&self, value: &Value) -> String {
+        match value {
+            Value::Empty => String::new(),
+            Value::Text(text) => text.clone(),
+            Value::List(repeats) => self.join(
+                repeats,
+                self.delimiters.repetition.unwrap_or(DEFAULT_REPETITION),
+            ),
+            Value::Struct(components) => self.join(components, self.delimiters.component),
+        }
+    }
+
+    fn join(&self, values: &[Value], separator: char) -> String {
+        let mut parts: Vec<String> = values.iter().map(|v| self.element(v)).collect();
+        while parts.last().is_some_and(|e| e.is_empty()) {
+            parts.pop();
+        }
+        parts.join(&separator.to_string())
+    }
+
+    fn write(&mut self, id: &str, mut elements: Vec<String>) -> Result<(), Error> {
+        while elements.last().is_some_and(|e| e.is_empty()) {
+            elements.pop();
+        }
+        if elements.is_empty() {
+            return Ok(());
+        }
+        if id == "ISA" && self.fixed {
+            self.declare_delimiters(&mut elements);
+        }
+        self.segments += 1;
+        let mut segment = id.to_string();
+        for element in elements {
+            segment.push(self.delimiters.element);
+            segment.push_str(&element);
+        }
+        segment.push(self.delimiters.segment);
+        if self.wrap.is_none() && !matches!(self.delimiters.segment, '\n' | '\r') {
+            segment.push_str(self.line_ending);
+        }
+        self.emit(&segment).map_err(|err| {
+            let error = Error(err.to_string());
+            self.failure = Some(err);
+            error
+        })
+    }
+
+    /// Writes `text` to `out`, breaking the lines of wrapped output.
+    fn emit(&mut self, mut text: &str) -> io::Result<()> {
+        let Some(width) = self.wrap else {
+            return self.out.write_all(text.as_bytes());
+        };
+        while !text.is_empty() {
+            let end = text
+                .char_indices()
+                .nth(width - self.column)
+                .map_or(text.len(), |(idx, _)| idx);
+            let (line, rest) = text.split_at(end);
+            self.out.write_all(line.as_bytes())?;
+            self.column += line.chars().count();
+            if self.column == width {
+                self.out.write_all(self.line_ending.as_bytes())?;
+                self.column = 0;
+            }
+            text = rest;
+        }
+        Ok(())
+    }
+
+    /// Terminates the last line of wrapped output.
+    fn finish(&mut self) -> io::Result<()> {
+        if self.column > 0 {
+            self.out.write_all(self.line_ending.as_bytes())?;
+            self.column = 0;
+        }
+        self.out.flush()
+    }
+
+    /// Replaces ISA11 and ISA16 with the configured separators.
+    fn declare_delimiters(&self, elements: &mut [String]) {
+        if let (Some(isa11), Some(repetition)) = (elements.get_mut(10), self.delimiters.repetition)
+        {
+            // before 00501, ISA11 is the Interchange Control Standards Identifier
+            if repetition_separator(isa11).is_some() {
+                *isa11 = repetition.to_string();
+            }
+        }
+        if let Some(isa16) = elements.get_mut(15) {
+            *isa16 = self.delimiters.component.to_string();
+        }
+    }
+}
+
+fn unsupported(what: &str) -> Error {
+    Error(format!("{what} can not be rendered as X12"))
+}
+
+/// Walks transmissions, groups, transaction sets and loops
+struct Tree<'w, 'o>(&'w mut SegmentWriter<'o>);
+
+impl<'w, 'o> ser::Serializer for Tree<'w, 'o> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Seq<'w, 'o>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = Struct<'w, 'o>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_bool(self, _: bool) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_i8(self, _: i8) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_i16(self, _: i16) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_i32(self, _: i32) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_i64(self, _: i64) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_u8(self, _: u8) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_u16(self, _: u16) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_u32(self, _: u32) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_u64(self, _: u64) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_f32(self, _: f32) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_f64(self, _: f64) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_char(self, _: char) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_str(self, _: &str) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), Error> {
+        Err(unsupported("a value outside of a segment"))
+    }
+    fn serialize_none(self) -> Result<(), Error> {
+        Ok(())
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<(), Error> {
+        Ok(())
+    }
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        if name != DECLARED {
+            return value.serialize(self);
+        }
+        if let (true, Value::Text(declared)) = (self.0.declaring, value.serialize(Collect)?) {
+            self.0.declare(&declared);
+        }
+        Ok(())
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+    fn serialize_seq(self, _: Option<usize>) -> Result<Seq<'w, 'o>, Error> {
+        Ok(Seq(self.0))
+    }
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Error> {
+        Err(unsupported("a tuple"))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(unsupported("a tuple struct"))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(unsupported("a tuple variant"))
+    }
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Err(unsupported("a map"))
+    }
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Struct<'w, 'o>, Error> {
+        let fields =
+            (name == RAW_SEGMENT || name.starts_with(SEGMENT)).then(|| Vec::with_capacity(len));
+        Ok(Struct {
+            writer: self.0,
+            name,
+            fields,
+        })
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(unsupported("a struct variant"))
+    }
+}
+
+struct Seq<'w, 'o>(&'w mut SegmentWriter<'o>);
+
+impl ser::SerializeSeq for Seq<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(Tree(self.0))
+    }
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Collects the fields of a segment, or walks the fields of any other struct
+struct Struct<'w, 'o> {
+    writer: &'w mut SegmentWriter<'o>,
+    name: &'static str,
+    fields: Option<Vec<Value>>,
+}
+
+impl ser::SerializeStruct for Struct<'_, '_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        match self.fields.as_mut() {
+            Some(fields) => {
+                fields.push(value.serialize(Collect)?);
+                Ok(())
+            }
+            None => value.serialize(Tree(self.writer)),
+        }
+    }
+    fn skip_field(&mut self, _: &'static str) -> Result<(), Error> {
+        if let Some(fields) = self.fields.as_mut() {
+            fields.push(Value::Empty);
+        }
+        Ok(())
+    }
+    fn end(self) -> Result<(), Error> {
+        match self.fields {
+            Some(fields) => match self.name.strip_prefix(SEGMENT) {
+                Some(id) => self.writer.segment(id, fields),
+                None => self.writer.raw_segment(fields),
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+/// Content of a segment field
+enum Value {
+    Empty,
+    Text(String),
+    /// repeated element
+    List(Vec<Value>),
+    /// composite element
+    Struct(Vec<Value>),
+}
+
+/// Collects the value of a segment field
+struct Collect;
+
+impl ser::Serializer for Collect {
+    type Ok = Value;
+    type Error = Error;
+    type SerializeSeq = CollectList;
+    type SerializeTuple = CollectList;
+    type SerializeTupleStruct = Impossible<Value, Error>;
+    type SerializeTupleVariant = Impossible<Value, Error>;
+    type SerializeMap = Impossible<Value, Error>;
+    type SerializeStruct = CollectList;
+    type SerializeStructVariant = Impossible<Value, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(Value::Text(v.to_string()))
+    }
+    fn serialize_bytes(self, _: &[u8]) -> Result<Value, Error> {
+        Err(unsupported("a byte array"))
+    }
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::Empty)
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::Empty)
+    }
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Value, Error> {
+        Ok(Value::Empty)
+    }
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        Ok(Value::Text(variant.to_string()))
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+    fn serialize_seq(self, len: Option<usize>) -> Result<CollectList, Error> {
+        Ok(CollectList {
+            values: Vec::with_capacity(len.unwrap_or_default()),
+            composite: false,
+        })
+    }
+    fn serialize_tuple(self, len: usize) -> Result<CollectList, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(unsupported("a tuple struct"))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(unsupported("a tuple variant"))
+    }
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Err(unsupported("a map"))
+    }
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<CollectList, Error> {
+        Ok(CollectList {
+            values: Vec::with_capacity(len),
+            composite: true,
+        })
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Err(unsupported("a struct variant"))
+    }
+}
+
+struct CollectList {
+    values: Vec<Value>,
+    composite: bool,
+}
+
+impl CollectList {
+    fn finish(self) -> Value {
+        match self.composite {
+            true => Value::Struct(self.values),
+            false => Value::List(self.values),
+        }
+    }
+}
+
+impl ser::SerializeSeq for CollectList {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.values.push(value.serialize(Collect)?);
+        Ok(())
+    }
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTuple for CollectList {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStruct for CollectList {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+    fn skip_field(&mut self, _: &'static str) -> Result<(), Error> {
+        self.values.push(Value::Empty);
+        Ok(())
+    }
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
@@ -2,65 +2,36 @@
 
 use nom::IResult;
 use serde::{Deserialize, Serialize};
-use std::fmt::{Debug, Display};
+use std::fmt::Debug;
 use x12_types_macros::DisplayX12;
+mod any_transaction;
 mod segment;
+pub use any_transaction::*;
 pub use segment::*;
 
-use crate::util::Parser;
+use crate::envelope;
+use crate::util::{reader, Parser};
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug)]
-pub struct Transmission<T> {
-    pub isa: ISA,
-    pub functional_group: Vec<FunctionalGroup<T>>,
-    pub iea: IEA,
-}
+/// The envelope segments of 003030
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct V003030;
 
-impl<T: Display> Display for Transmission<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut lines = vec![];
-        lines.push(format!("{}", self.isa));
-        for fg in &self.functional_group {
-            lines.push(format!("{}", fg.gs));
-            for segment in &fg.segments {
-                lines.push(format!("{segment}"));
-            }
-            lines.push(format!("{}", fg.ge));
-        }
-        lines.push(format!("{}", self.iea));
-        let all = lines.join("");
-        write!(f, "{all}")
-    }
-}
+envelope::impl_version!(V003030, "00303");
 
-impl<'a, T: Default + Parser<&'a str, T, nom::error::Error<&'a str>>>
-    Parser<&'a str, Transmission<T>, nom::error::Error<&'a str>> for Transmission<T>
-{
-    fn parse(input: &'a str) -> IResult<&'a str, Transmission<T>> {
-        let mut output = Transmission::default();
-        let (input, obj) = ISA::parse(input)?;
-        output.isa = obj;
-        // functional group
-        let (input, gs) = GS::parse(input)?;
-        let (input, t_obj) = T::parse(input)?;
-        let (input, ge) = GE::parse(input)?;
-        let fg = FunctionalGroup {
-            gs,
-            segments: vec![t_obj],
-            ge,
-        };
-        output.functional_group.push(fg);
-        let (input, obj) = IEA::parse(input)?;
-        output.iea = obj;
-        Ok((input, output))
-    }
-}
+/// An interchange of 003030, from ISA to IEA
+pub type Transmission<T> = envelope::Transmission<V003030, T>;
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug)]
-pub struct FunctionalGroup<T> {
-    pub gs: GS,
-    pub segments: Vec<T>,
-    pub ge: GE,
+/// A functional group of 003030, from GS to GE
+pub type FunctionalGroup<T> = envelope::FunctionalGroup<V003030, T>;
+
+/// Streaming reader yielding the envelope segments and one transaction set at a time
+pub type TransmissionReader<R, T> = reader::TransmissionReader<R, ISA, GS, T, GE, IEA>;
+
+/// Item of a [`TransmissionReader`]
+pub type Event<T> = reader::Event<ISA, GS, T, GE, IEA>;
+
+envelope::impl_transaction_set! {
+    _998 => st, se;
 }
 
 /// 998 - Set Cancellation NEW
@@ -81,6 +52,7 @@ pub struct _998 {
 
 impl<'a> Parser<&'a str, _998, nom::error::Error<&'a str>> for _998 {
     fn parse(input: &'a str) -> IResult<&'a str, _998> {
+        let _context = crate::error::transaction_set("_998", &["SE", "ST", "ZD"]);
         let mut output = _998::default();
         let (input, obj) = ST::parse(input)?;
         output.st = obj;
@@ -91,3 +63,11 @@ impl<'a> Parser<&'a str, _998, nom::error::Error<&'a str>> for _998 {
         Ok((input, output))
     }
 }
+
+crate::validation::impl_transaction_structure! {
+    _998 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "ZD", M, 1),
+        segment("0030", "SE", M, 1),
+    ],
+}
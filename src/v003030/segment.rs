@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// IEA - Interchange Control Trailer NEW
@@ -9,10 +11,25 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// ----|----|------|--------|----|------|-------
 /// 01 | I16 | Number of Included Functional Groups | 1 | M | N0 | 1/5
 /// 02 | I12 | Interchange Control Number | 1 | M | N0 | 9/9
-#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplaySegment, ParseSegment)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, Validate, DisplaySegment, ParseSegment)]
+#[serde(rename = "$x12::segment::IEA")]
 pub struct IEA {
+    #[validate(
+        length(min = 1, message = "IEA-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 5>",
+            message = "IEA-01 must be a number of 1-5 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "IEA-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "IEA-02 must be a number of 9 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -39,38 +56,151 @@ pub struct IEA {
 /// 14 | I13 | Acknowledgment Requested | 1 | M | ID | 1/1
 /// 15 | I14 | Test Indicator | 1 | M | ID | 1/1
 /// 16 | I15 | Subelement Separator | 1 | M | AN | 1/1
-#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplaySegment, ParseSegment)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, Validate, DisplaySegment, ParseSegment)]
+#[serde(rename = "$x12::segment::ISA")]
 pub struct ISA {
+    #[validate(
+        length(min = 1, message = "ISA-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "ISA-02 is mandatory"),
+        custom(
+            function = "datatype::string::<10, 10>",
+            message = "ISA-02 must be 10 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "ISA-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-03 must be 2 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(
+        length(min = 1, message = "ISA-04 is mandatory"),
+        custom(
+            function = "datatype::string::<10, 10>",
+            message = "ISA-04 must be 10 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
+    #[validate(
+        length(min = 1, message = "ISA-05 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-05 must be 2 characters"
+        )
+    )]
     #[serde(rename = "05")]
     pub _05: String,
+    #[validate(
+        length(min = 1, message = "ISA-06 is mandatory"),
+        custom(
+            function = "datatype::string::<15, 15>",
+            message = "ISA-06 must be 15 characters"
+        )
+    )]
     #[serde(rename = "06")]
     pub _06: String,
+    #[validate(
+        length(min = 1, message = "ISA-07 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-07 must be 2 characters"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
+    #[validate(
+        length(min = 1, message = "ISA-08 is mandatory"),
+        custom(
+            function = "datatype::string::<15, 15>",
+            message = "ISA-08 must be 15 characters"
+        )
+    )]
     #[serde(rename = "08")]
     pub _08: String,
+    #[validate(
+        length(min = 1, message = "ISA-09 is mandatory"),
+        custom(
+            function = "datatype::date::<6, 6>",
+            message = "ISA-09 must be a date of 6 digits"
+        )
+    )]
     #[serde(rename = "09")]
     pub _09: String,
+    #[validate(
+        length(min = 1, message = "ISA-10 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 4>",
+            message = "ISA-10 must be a time of 4 digits"
+        )
+    )]
     #[serde(rename = "10")]
     pub _10: String,
+    #[validate(
+        length(min = 1, message = "ISA-11 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-11 must be 1 character"
+        )
+    )]
     #[serde(rename = "11")]
     pub _11: String,
+    #[validate(
+        length(min = 1, message = "ISA-12 is mandatory"),
+        custom(
+            function = "datatype::string::<5, 5>",
+            message = "ISA-12 must be 5 characters"
+        )
+    )]
     #[serde(rename = "12")]
     pub _12: String,
+    #[validate(
+        length(min = 1, message = "ISA-13 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "ISA-13 must be a number of 9 digits"
+        )
+    )]
     #[serde(rename = "13")]
     pub _13: String,
+    #[validate(
+        length(min = 1, message = "ISA-14 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-14 must be 1 character"
+        )
+    )]
     #[serde(rename = "14")]
     pub _14: String,
+    #[validate(
+        length(min = 1, message = "ISA-15 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-15 must be 1 character"
+        )
+    )]
     #[serde(rename = "15")]
     pub _15: String,
+    #[validate(
+        length(min = 1, message = "ISA-16 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-16 must be 1 character"
+        )
+    )]
     #[serde(rename = "16")]
     pub _16: String,
 }
@@ -83,10 +213,25 @@ pub struct ISA {
 /// ----|----|------|--------|----|------|-------
 /// 01 | 97 | Number of Transaction Sets Included | 1 | M | N0 | 1/6
 /// 02 | 28 | Group Control Number | 1 | M/Z | N0 | 1/9
-#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplaySegment, ParseSegment)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, Validate, DisplaySegment, ParseSegment)]
+#[serde(rename = "$x12::segment::GE")]
 pub struct GE {
+    #[validate(
+        length(min = 1, message = "GE-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 6>",
+            message = "GE-01 must be a number of 1-6 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "GE-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 9>",
+            message = "GE-02 must be a number of 1-9 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -105,22 +250,70 @@ pub struct GE {
 /// 06 | 28 | Group Control Number | 1 | M/Z | N0 | 1/9
 /// 07 | 455 | Responsible Agency Code | 1 | M | ID | 1/2
 /// 08 | 480 | Version / Release / Industry Identifier Code | 1 | M | AN | 1/12
-#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplaySegment, ParseSegment)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, Validate, DisplaySegment, ParseSegment)]
+#[serde(rename = "$x12::segment::GS")]
 pub struct GS {
+    #[validate(
+        length(min = 1, message = "GS-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "GS-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "GS-02 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 15>",
+            message = "GS-02 must be 2-15 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "GS-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 15>",
+            message = "GS-03 must be 2-15 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::date::<6, 6>",
+        message = "GS-04 must be a date of 6 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::time::<4, 6>",
+        message = "GS-05 must be a time of 4-6 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 9>",
+        message = "GS-06 must be a number of 1-9 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(
+        length(min = 1, message = "GS-07 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 2>",
+            message = "GS-07 must be 1-2 characters"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
+    #[validate(
+        length(min = 1, message = "GS-08 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 12>",
+            message = "GS-08 must be 1-12 characters"
+        )
+    )]
     #[serde(rename = "08")]
     pub _08: String,
 }
@@ -133,10 +326,25 @@ pub struct GS {
 /// ----|----|------|--------|-----|------|-------
 /// 01 | 96 | Number of Included Segments | 1 | M | N0 | 1/10
 /// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
-#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplaySegment, ParseSegment)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, Validate, DisplaySegment, ParseSegment)]
+#[serde(rename = "$x12::segment::SE")]
 pub struct SE {
+    #[validate(
+        length(min = 1, message = "SE-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 10>",
+            message = "SE-01 must be a number of 1-10 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "SE-02 is mandatory"),
+        custom(
+            function = "datatype::string::<4, 9>",
+            message = "SE-02 must be 4-9 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -149,14 +357,90 @@ pub struct SE {
 /// ----|----|------|--------|----|------|-------
 /// 01 | 143 | Transaction Set Identifier Code | 1 | M/Z | ID | 3/3
 /// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
-#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplaySegment, ParseSegment)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, Validate, DisplaySegment, ParseSegment)]
+#[serde(rename = "$x12::segment::ST")]
 pub struct ST {
+    #[validate(
+        length(min = 1, message = "ST-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "ST-01 must be 3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "ST-02 is mandatory"),
+        custom(
+            function = "datatype::string::<4, 9>",
+            message = "ST-02 must be 4-9 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
 
+/// TA1 - Interchange Acknowledgment
+///
+/// To report the status of processing a received interchange header and trailer or the non-delivery by a network provider
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | I12 | Interchange Control Number | 1 | M | N0 | 9/9
+/// 02 | I08 | Interchange Date | 1 | M | DT | 6/6
+/// 03 | I09 | Interchange Time | 1 | M | TM | 4/4
+/// 04 | I17 | Interchange Acknowledgment Code | 1 | M | ID | 1/1
+/// 05 | I18 | Interchange Note Code | 1 | M | ID | 3/3
+#[derive(Serialize, Deserialize, Clone, Default, Debug, Validate, DisplaySegment, ParseSegment)]
+#[serde(rename = "$x12::segment::TA1")]
+pub struct TA1 {
+    #[validate(
+        length(min = 1, message = "TA1-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "TA1-01 must be a number of 9 digits"
+        )
+    )]
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[validate(
+        length(min = 1, message = "TA1-02 is mandatory"),
+        custom(
+            function = "datatype::date::<6, 6>",
+            message = "TA1-02 must be a date of 6 digits"
+        )
+    )]
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[validate(
+        length(min = 1, message = "TA1-03 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 4>",
+            message = "TA1-03 must be a time of 4 digits"
+        )
+    )]
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[validate(
+        length(min = 1, message = "TA1-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "TA1-04 must be 1 character"
+        )
+    )]
+    #[serde(rename = "04")]
+    pub _04: String,
+    #[validate(
+        length(min = 1, message = "TA1-05 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "TA1-05 must be 3 characters"
+        )
+    )]
+    #[serde(rename = "05")]
+    pub _05: String,
+}
+
 /// ZD - Transaction Set Deletion - ID, Reason, and Source
 ///
 /// This segment is used to specify the transaction set to be canceled
@@ -171,22 +455,79 @@ pub struct ST {
 /// 06 | 243 | Transaction Reference Date | 1 | O | DT | 6/6
 /// 07 | 202 | Correction Indicator | 1 | M | ID | 2/2
 /// 08 | 140 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
-#[derive(Serialize, Deserialize, Clone, Default, Debug, DisplaySegment, ParseSegment)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug, Validate, DisplaySegment, ParseSegment)]
+#[serde(rename = "$x12::segment::ZD")]
 pub struct ZD {
+    #[validate(
+        length(min = 1, message = "ZD-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "ZD-01 must be 3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "ZD-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(
+        length(min = 1, message = "ZD-03 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 4>",
+            message = "ZD-03 must be 1-4 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(
+        length(min = 1, message = "ZD-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 10>",
+            message = "ZD-04 must be 1-10 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 15>",
+        message = "ZD-05 must be 1-15 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::date::<6, 6>",
+        message = "ZD-06 must be a date of 6 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(
+        length(min = 1, message = "ZD-07 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ZD-07 must be 2 characters"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "ZD-08 must be 2-4 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
 }
+
+crate::validation::impl_segment_syntax! {}
+
+crate::validation::impl_segment_codes! {
+    GS => [7 => "455"],
+    ISA => [14 => "I13"],
+    TA1 => [4 => "I17"],
+}
+
+crate::validation::impl_segment_elements! {
+    GE, GS, IEA, ISA, SE, ST, TA1, ZD,
+}
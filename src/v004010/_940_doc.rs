@@ -10,6 +10,82 @@ pub use segment::*;
 /// 940 - Warehouse Shipping Order
 ///
 /// This Draft Standard for Trial Use contains the format and establishes the data contents of the Warehouse Shipping Order Transaction Set (940) for use within the context of an Electronic Data Interchange (EDI) environment. This transaction set can be used to enable the depositor to advise a warehouse to make a shipment, confirm a shipment, or modify or cancel a previously submitted shipping order, or to report the status of the current shipping order to the depositor.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | W05 | Warehouse Information | M | 1
+/// LOOP ID - N1 | 10
+/// N1 -> 0040 | N1 | Name | O | 1
+/// N1 -> 0050 | N2 | Additional Name Information | O | 2
+/// N1 -> 0060 | N3 | Address Information | O | 2
+/// N1 -> 0070 | N4 | Geographic Location | O | 1
+/// N1 -> 0080 | PER | Administrative Communications Contact | O | 3
+/// 0090 | N9 | Reference Identification | O | 10
+/// 0100 | G61 | Contact | O | 3
+/// 0110 | G62 | Date/Time | O | 2
+/// 0120 | NTE | Note/Special Instruction | O | 10
+/// 0130 | W09 | Equipment Details | O | 1
+/// 0140 | W66 | Warehouse Carrier Information | M | 1
+/// 0150 | W6 | Warehouse Information | O | 1
+/// 0153 | R2 | Equipment Details | O | 12
+/// 0156 | BNX | Vessel Information | O | 1
+/// LOOP ID - LM | 10
+/// LM -> 0160 | LM | Code Source Information | O | 1
+/// LM -> LOOP ID - LQ | 100
+/// LM -> LQ -> 0170 | LQ | Industry Code | M | 1
+/// LOOP ID - LX | 9999
+/// LX -> 0005 | LX | Assigned Number | M | 1
+/// LX -> 0010 | MAN | Marks and Numbers | O | >1
+/// LX -> 0015 | SDQ | Destination Quantity | O | 50
+/// LX -> 0016 | N1 | Name | O | 1
+/// LX -> 0017 | G62 | Date/Time | O | 2
+/// LX -> LOOP ID - W01 | 9999
+/// LX -> W01 -> 0020 | W01 | Warehouse Information | M | 1
+/// LX -> W01 -> 0030 | G69 | Line Item Detail - Description | O | 5
+/// LX -> W01 -> 0040 | N9 | Reference Identification | O | 200
+/// LX -> W01 -> 0045 | NTE | Note/Special Instruction | O | 10
+/// LX -> W01 -> 0050 | W20 | Warehouse Information | O | 1
+/// LX -> W01 -> 0070 | QTY | Quantity | O | 5
+/// LX -> W01 -> 0080 | AMT | Monetary Amount | O | 1
+/// LX -> W01 -> 0090 | G62 | Date/Time | O | 2
+/// LX -> W01 -> 0100 | G66 | Transportation Instructions | O | 1
+/// LX -> W01 -> 0110 | N1 | Name | O | 1
+/// LX -> W01 -> 0112 | PER | Administrative Communications Contact | O | 3
+/// LX -> W01 -> 0114 | LH2 | Hazardous Classification Information | O | 6
+/// LX -> W01 -> 0116 | LHR | Hazardous Material Identifying Reference Numbers | O | 1
+/// LX -> W01 -> 0118 | LH6 | Hazardous Certification | O | 5
+/// LX -> W01 -> LOOP ID - LM | 10
+/// LX -> W01 -> LM -> 0120 | LM | Code Source Information | O | 1
+/// LX -> W01 -> LM -> LOOP ID - LQ | 100
+/// LX -> W01 -> LM -> LQ -> 0130 | LQ | Industry Code | M | 1
+/// LX -> W01 -> LM -> 0135 | LS | Loop Header | O | 1
+/// LX -> W01 -> LM -> LOOP ID - LX | 999
+/// LX -> W01 -> LM -> LX -> 0140 | LX | Assigned Number | O | 1
+/// LX -> W01 -> LM -> LX -> 0150 | N9 | Reference Identification | O | 10
+/// LX -> W01 -> LM -> LX -> 0160 | G62 | Date/Time | O | 2
+/// LX -> W01 -> LM -> LX -> 0170 | N1 | Name | O | 1
+/// LX -> W01 -> LM -> LX -> 0175 | SDQ | Destination Quantity | O | 50
+/// LX -> W01 -> LM -> LX -> LOOP ID - LM | 10
+/// LX -> W01 -> LM -> LX -> LM -> 0180 | LM | Code Source Information | O | 1
+/// LX -> W01 -> LM -> LX -> LM -> LOOP ID - LQ | 100
+/// LX -> W01 -> LM -> LX -> LM -> LQ -> 0190 | LQ | Industry Code | M | 1
+/// LX -> W01 -> LM -> LX -> LM -> LOOP ID - LH1 | 100
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> 0200 | LH1 | Hazardous Identification Information | O | 1
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> 0210 | LH2 | Hazardous Classification Information | O | 4
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> 0220 | LH3 | Hazardous Material Shipping Name | O | 10
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> 0230 | LFH | Freeform Hazardous Material Information | O | 20
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> 0240 | LEP | EPA Required Data | O | 3
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> 0250 | LH4 | Canadian Dangerous Requirements | O | 1
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> 0260 | LHT | Transborder Hazardous Requirements | O | 3
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> 0270 | LHR | Hazardous Material Identifying Reference Numbers | O | 10
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> 0280 | PER | Administrative Communications Contact | O | 5
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> 0285 | LE | Loop Trailer | O | 1
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> LOOP ID - FA1 | >1
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> FA1 -> 0290 | FA1 | Type of Financial Accounting Data | O | 1
+/// LX -> W01 -> LM -> LX -> LM -> LH1 -> FA1 -> 0300 | FA2 | Accounting Data | M | >1
+/// 0010 | W76 | Total Shipping Order | O | 1
+/// 0020 | SE | Transaction Set Trailer | M | 1
 #[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
 pub struct _940 {
     pub _010: ST,
@@ -53,6 +129,15 @@ pub struct _940 {
 
 impl<'a> Parser<&'a str, _940, nom::error::Error<&'a str>> for _940 {
     fn parse(input: &'a str) -> IResult<&'a str, _940> {
+        let _context = crate::error::transaction_set(
+            "_940",
+            &[
+                "AMT", "BNX", "FA1", "FA2", "G61", "G62", "G66", "G69", "LE", "LEP", "LFH", "LH1",
+                "LH2", "LH3", "LH4", "LH6", "LHR", "LHT", "LM", "LQ", "LS", "LX", "MAN", "N1",
+                "N2", "N3", "N4", "N9", "NTE", "PER", "QTY", "R2", "SDQ", "SE", "ST", "W01", "W05",
+                "W09", "W20", "W6", "W66", "W76",
+            ],
+        );
         let (mut rest, st) = ST::parse(input)?;
         let (rest_w05, w05) = W05::parse(rest)?;
         let (rest_loop100, loop100) = many0(_940Loop100::parse).parse(rest_w05)?;
@@ -114,6 +199,7 @@ pub struct _940Loop100 {
 
 impl<'a> Parser<&'a str, _940Loop100, nom::error::Error<&'a str>> for _940Loop100 {
     fn parse(input: &'a str) -> IResult<&'a str, _940Loop100> {
+        let _context = crate::error::context("_940Loop100");
         let (mut rest, n1) = N1::parse(input)?;
         let (rest_n2, n2) = many0(N2::parse).parse(rest)?;
         let (rest_n3, n3) = many0(N3::parse).parse(rest_n2)?;
@@ -141,6 +227,7 @@ pub struct _940Loop200 {
 
 impl<'a> Parser<&'a str, _940Loop200, nom::error::Error<&'a str>> for _940Loop200 {
     fn parse(input: &'a str) -> IResult<&'a str, _940Loop200> {
+        let _context = crate::error::context("_940Loop200");
         let (rest_lm, lm) = LM::parse(input)?;
         let (rest_lq, lq) = many0(LQ::parse).parse(rest_lm)?;
         Ok((rest_lq, _940Loop200 { _160: lm, _170: lq }))
@@ -169,6 +256,7 @@ pub struct _940Loop300 {
 
 impl<'a> Parser<&'a str, _940Loop300, nom::error::Error<&'a str>> for _940Loop300 {
     fn parse(input: &'a str) -> IResult<&'a str, _940Loop300> {
+        let _context = crate::error::context("_940Loop300");
         let (mut rest, lx) = LX::parse(input)?;
         let (rest_man, man) = many0(MAN::parse).parse(rest)?;
         let (rest_sdq, sdq) = many0(SDQ::parse).parse(rest_man)?;
@@ -240,6 +328,7 @@ pub struct _940Loop310 {
 
 impl<'a> Parser<&'a str, _940Loop310, nom::error::Error<&'a str>> for _940Loop310 {
     fn parse(input: &'a str) -> IResult<&'a str, _940Loop310> {
+        let _context = crate::error::context("_940Loop310");
         let (mut rest, w01) = W01::parse(input)?;
         let (rest_g69, g69) = many0(G69::parse).parse(rest)?;
         let (rest_n9, n9) = many0(N9::parse).parse(rest_g69)?;
@@ -294,6 +383,7 @@ pub struct _940Loop320 {
 
 impl<'a> Parser<&'a str, _940Loop320, nom::error::Error<&'a str>> for _940Loop320 {
     fn parse(input: &'a str) -> IResult<&'a str, _940Loop320> {
+        let _context = crate::error::context("_940Loop320");
         let (mut rest, lm) = LM::parse(input)?;
         let (rest_lq, lq) = many0(LQ::parse).parse(rest)?;
         let (rest_ls, ls) = opt(LS::parse).parse(rest_lq)?;
@@ -333,6 +423,7 @@ pub struct _940Loop330 {
 
 impl<'a> Parser<&'a str, _940Loop330, nom::error::Error<&'a str>> for _940Loop330 {
     fn parse(input: &'a str) -> IResult<&'a str, _940Loop330> {
+        let _context = crate::error::context("_940Loop330");
         let (mut rest, lx) = LX::parse(input)?;
         let (rest_n9, n9) = many0(N9::parse).parse(rest)?;
         let (rest_g62, g62) = many0(G62::parse).parse(rest_n9)?;
@@ -367,6 +458,7 @@ pub struct _940Loop331 {
 
 impl<'a> Parser<&'a str, _940Loop331, nom::error::Error<&'a str>> for _940Loop331 {
     fn parse(input: &'a str) -> IResult<&'a str, _940Loop331> {
+        let _context = crate::error::context("_940Loop331");
         let (mut rest, lm) = LM::parse(input)?;
         let (rest_lq, lq) = many0(LQ::parse).parse(rest)?;
         let (rest_loop, loop332) = many0(_940Loop332::parse).parse(rest_lq)?;
@@ -418,6 +510,7 @@ pub struct _940Loop332 {
 
 impl<'a> Parser<&'a str, _940Loop332, nom::error::Error<&'a str>> for _940Loop332 {
     fn parse(input: &'a str) -> IResult<&'a str, _940Loop332> {
+        let _context = crate::error::context("_940Loop332");
         let (mut rest, lh1) = LH1::parse(input)?;
         let (rest_lh2, lh2) = many0(LH2::parse).parse(rest)?;
         let (rest_lh3, lh3) = many0(LH3::parse).parse(rest_lh2)?;
@@ -457,6 +550,7 @@ pub struct _940Loop340 {
 
 impl<'a> Parser<&'a str, _940Loop340, nom::error::Error<&'a str>> for _940Loop340 {
     fn parse(input: &'a str) -> IResult<&'a str, _940Loop340> {
+        let _context = crate::error::context("_940Loop340");
         let (mut rest, fa1) = FA1::parse(input)?;
         let (rest_fa2, fa2) = many0(FA2::parse).parse(rest)?;
         rest = rest_fa2;
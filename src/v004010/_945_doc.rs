@@ -10,6 +10,67 @@ use x12_types_macros::DisplayX12;
 /// 945 - Warehouse Shipping Advice
 ///
 /// This X12 Transaction Set contains the format and establishes the data contents of the Warehouse Shipping Advice Transaction Set (945) for use within the context of an Electronic Data Interchange (EDI) environment. The transaction set can be used by the warehouse to advise the depositor that shipment was made. It is used to reconcile order quantities with shipment quantities.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | W06 | Warehouse Shipment Identification | M | 1
+/// LOOP ID - N1 | 10
+/// N1 -> 0040 | N1 | Name | O | 1
+/// N1 -> 0050 | N2 | Additional Name Information | O | 2
+/// N1 -> 0060 | N3 | Address Information | O | 2
+/// N1 -> 0070 | N4 | Geographic Location | O | 1
+/// N1 -> 0080 | PER | Administrative Communications Contact | O | 3
+/// 0090 | N9 | Reference Identification | O | 30
+/// 0100 | G61 | Contact | O | 3
+/// 0110 | G62 | Date/Time | O | 10
+/// 0120 | NTE | Note/Special Instruction | O | 10
+/// 0130 | W27 | Carrier Detail | M | 1
+/// 0140 | W6 | Warehouse Information | O | 1
+/// 0150 | W28 | Consolidation Information | O | 1
+/// 0160 | W10 | Warehouse Additional Carrier Information | O | 10
+/// 0170 | G72 | Allowance or Charge | O | 10
+/// LOOP ID - LM | 10
+/// LM -> 0180 | LM | Code Source Information | O | 1
+/// LM -> LOOP ID - LQ | 100
+/// LM -> LQ -> 0190 | LQ | Industry Code | M | 1
+/// LOOP ID - LX | 9999
+/// LX -> 0010 | LX | Assigned Number | M | 1
+/// LX -> 0020 | MAN | Marks and Numbers | O | 10
+/// LX -> 0030 | PAL | Pallet Information | O | 1
+/// LX -> 0040 | N9 | Reference Identification | O | 10
+/// LX -> LOOP ID - W12 | 9999
+/// LX -> W12 -> 0050 | W12 | Warehouse Item Detail | M | 1
+/// LX -> W12 -> 0060 | G69 | Line Item Detail - Description | O | 5
+/// LX -> W12 -> 0070 | N9 | Reference Identification | O | 20
+/// LX -> W12 -> 0080 | G62 | Date/Time | O | 2
+/// LX -> W12 -> 0090 | QTY | Quantity | O | 5
+/// LX -> W12 -> 0100 | MEA | Measurements | O | 5
+/// LX -> W12 -> 0110 | AMT | Monetary Amount | O | 1
+/// LX -> W12 -> 0120 | R4 | Port or Terminal | O | 5
+/// LX -> W12 -> 0130 | W27 | Carrier Detail | O | 1
+/// LX -> W12 -> 0140 | N1 | Name | O | 10
+/// LX -> W12 -> 0150 | G72 | Allowance or Charge | O | 10
+/// LX -> W12 -> LOOP ID - LM | 10
+/// LX -> W12 -> LM -> 0160 | LM | Code Source Information | O | 1
+/// LX -> W12 -> LM -> LOOP ID - LQ | 100
+/// LX -> W12 -> LM -> LQ -> 0170 | LQ | Industry Code | M | 1
+/// LX -> W12 -> 0180 | LS | Loop Header | O | 1
+/// LX -> W12 -> LOOP ID - LX | 999
+/// LX -> W12 -> LX -> 0190 | LX | Assigned Number | O | 1
+/// LX -> W12 -> LX -> 0200 | N9 | Reference Identification | O | 10
+/// LX -> W12 -> LX -> 0210 | G62 | Date/Time | O | 2
+/// LX -> W12 -> LX -> 0220 | N1 | Name | O | 1
+/// LX -> W12 -> LX -> LOOP ID - LM | 10
+/// LX -> W12 -> LX -> LM -> 0230 | LM | Code Source Information | O | 1
+/// LX -> W12 -> LX -> LM -> LOOP ID - LQ | 100
+/// LX -> W12 -> LX -> LM -> LQ -> 0240 | LQ | Industry Code | M | 1
+/// LX -> W12 -> 0250 | LE | Loop Trailer | O | 1
+/// LX -> W12 -> LOOP ID - FA1 | >1
+/// LX -> W12 -> FA1 -> 0260 | FA1 | Type of Financial Accounting Data | O | 1
+/// LX -> W12 -> FA1 -> 0270 | FA2 | Accounting Data | M | >1
+/// 0010 | W03 | Total Shipment Information | M | 1
+/// 0020 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _945 {
     pub st: ST,
@@ -54,6 +115,14 @@ pub struct _945 {
 
 impl<'a> Parser<&'a str, _945, nom::error::Error<&'a str>> for _945 {
     fn parse(input: &'a str) -> IResult<&'a str, _945> {
+        let _context = crate::error::transaction_set(
+            "_945",
+            &[
+                "AMT", "FA1", "FA2", "G61", "G62", "G69", "G72", "LE", "LM", "LQ", "LS", "LX",
+                "MAN", "MEA", "N1", "N2", "N3", "N4", "N9", "NTE", "PAL", "PER", "QTY", "R4", "SE",
+                "ST", "W03", "W06", "W10", "W12", "W27", "W28", "W6",
+            ],
+        );
         let mut output = _945::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -372,6 +372,7 @@ fn full_transmission_945() {
             _01: "1".to_string(),
             _02: "000000001".to_string(),
         },
+        ..Default::default()
     };
 
     let serialized = format!("{obj}");
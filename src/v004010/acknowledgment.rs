@@ -0,0 +1,203 @@
+//! 997 functional acknowledgments built from the findings of the transaction sets of a functional group.
+
+use super::{_997LoopAK2, _997LoopAK3, _997, AK1, AK2, AK3, AK4, AK5, AK9, SE, ST};
+use crate::envelope::{self, finalize_transaction_set, TransactionSet, Version};
+use crate::raw::RawInterchange;
+use crate::util::charset::is_x12_character;
+use crate::util::Parser;
+use crate::validation::{ElementSyntaxError, GroupReport, SegmentSyntaxError, SetReport};
+use serde::Serialize;
+
+/// AK3 loops of a transaction set with more AK4 are cut off
+const MAX_AK4: usize = 99;
+/// AK404 - Copy of Bad Data Element, 1/99
+const MAX_COPY: usize = 99;
+
+impl _997 {
+    /// Acknowledges the functional group of `report`.
+    ///
+    /// Each transaction set gets an AK2 loop, with an AK3 for each segment with findings and an AK4 for
+    /// each finding of its elements. A transaction set with findings is rejected, the functional group
+    /// is accepted (`A`), partially accepted (`P`) or rejected (`R`), a group with errors of its own,
+    /// reported in AK905 to AK909, is rejected. The 997 has the control number `0001` and its SE is filled, numbering and enveloping is
+    /// up to the caller, e.g. with [`EnvelopeBuilder`](crate::envelope::EnvelopeBuilder).
+    pub fn from_report(report: &GroupReport) -> _997 {
+        let received = report.sets.len();
+        let accepted = report.accepted();
+        let ak901 = match (report.errors.is_empty(), accepted) {
+            (false, _) => "R",
+            (true, 0) if received > 0 => "R",
+            (true, accepted) if accepted == received => "A",
+            (true, _) => "P",
+        };
+        let mut codes = report.errors.iter().map(|error| error.code().to_string());
+        let mut ack = _997 {
+            st: ST {
+                _01: "997".to_string(),
+                _02: "0001".to_string(),
+            },
+            ak1: AK1 {
+                _01: report.functional_id.clone(),
+                _02: report.control_number.clone(),
+            },
+            loop_ak2: report.sets.iter().map(set_response).collect(),
+            ak9: AK9 {
+                _01: ak901.to_string(),
+                _02: report.included.to_string(),
+                _03: received.to_string(),
+                _04: accepted.to_string(),
+                _05: codes.next(),
+                _06: codes.next(),
+                _07: codes.next(),
+                _08: codes.next(),
+                _09: codes.next(),
+            },
+            se: SE::default(),
+        };
+        finalize_transaction_set(&mut ack);
+        ack
+    }
+}
+
+/// The AK2 loop acknowledging the transaction set of `report`
+fn set_response(report: &SetReport) -> _997LoopAK2 {
+    let mut codes = report.errors.iter().map(|error| error.code().to_string());
+    let ak501 = match report.is_accepted() {
+        true => "A",
+        false => "R",
+    };
+    _997LoopAK2 {
+        ak2: AK2 {
+            _01: report.transaction_set_id.clone(),
+            _02: report.control_number.clone(),
+        },
+        loop_ak3: segment_responses(report),
+        ak5: AK5 {
+            _01: ak501.to_string(),
+            _02: codes.next(),
+            _03: codes.next(),
+            _04: codes.next(),
+            _05: codes.next(),
+            _06: codes.next(),
+        },
+    }
+}
+
+/// The AK3 loops of the segments with findings, ordered by their position
+fn segment_responses(report: &SetReport) -> Vec<_997LoopAK3> {
+    let mut loops: Vec<(usize, _997LoopAK3)> = report
+        .structure
+        .iter()
+        .map(|error| {
+            let ak3 = ak3(
+                &error.segment,
+                error.segment_position,
+                error.loop_id.clone(),
+                error.error,
+            );
+            (error.segment_position, _997LoopAK3 { ak3, ak4: vec![] })
+        })
+        .collect();
+    let syntax = report.syntax.iter().map(|error| {
+        let element = (error.element_position, error.error, "");
+        (&error.segment, error.segment_position, element)
+    });
+    let codes = report.codes.iter().map(|error| {
+        let element = (
+            error.element_position,
+            ElementSyntaxError::InvalidCode,
+            error.value.as_str(),
+        );
+        (&error.segment, error.segment_position, element)
+    });
+    let elements = report.elements.iter().map(|error| {
+        let element = (error.element_position, error.error, error.value.as_str());
+        (&error.segment, error.segment_position, element)
+    });
+    let mut element_errors: Vec<_> = syntax.chain(codes).chain(elements).collect();
+    element_errors.sort_by_key(|(_, segment_position, (element_position, _, _))| {
+        (*segment_position, *element_position)
+    });
+    for (segment, segment_position, (element_position, error, value)) in element_errors {
+        let ak4 = AK4 {
+            _01: element_position.to_string(),
+            _02: None,
+            _03: error.code().to_string(),
+            _04: Some(value)
+                .filter(|value| !value.is_empty() && value.chars().all(is_x12_character))
+                .map(|value| value.chars().take(MAX_COPY).collect()),
+        };
+        match loops.last_mut() {
+            Some((position, segment_loop))
+                if *position == segment_position
+                    && segment_loop.ak3._04.as_deref()
+                        == Some(SegmentSyntaxError::ElementErrors.code()) =>
+            {
+                if segment_loop.ak4.len() < MAX_AK4 {
+                    segment_loop.ak4.push(ak4);
+                }
+            }
+            _ => loops.push((
+                segment_position,
+                _997LoopAK3 {
+                    ak3: ak3(
+                        segment,
+                        segment_position,
+                        None,
+                        SegmentSyntaxError::ElementErrors,
+                    ),
+                    ak4: vec![ak4],
+                },
+            )),
+        }
+    }
+    // the segment errors come first at each position
+    loops.sort_by_key(|(position, _)| *position);
+    loops
+        .into_iter()
+        .map(|(_, segment_loop)| segment_loop)
+        .collect()
+}
+
+fn ak3(
+    segment: &str,
+    segment_position: usize,
+    loop_id: Option<String>,
+    error: SegmentSyntaxError,
+) -> AK3 {
+    AK3 {
+        _01: segment.to_string(),
+        _02: segment_position.to_string(),
+        _03: loop_id,
+        _04: Some(error.code().to_string()),
+    }
+}
+
+/// Validates each functional group of `transmission` against version `V` and acknowledges it with a 997.
+pub fn functional_acknowledgments<V, T>(transmission: &envelope::Transmission<V, T>) -> Vec<_997>
+where
+    V: Version,
+    T: TransactionSet + Serialize,
+{
+    transmission
+        .functional_group
+        .iter()
+        .map(|group| _997::from_report(&GroupReport::validate(group)))
+        .collect()
+}
+
+/// Acknowledges each functional group of an interchange which failed to parse into typed transaction sets.
+///
+/// Each transaction set is parsed into `T` on its own, a set which fails to parse is rejected,
+/// see [`GroupReport::validate_raw`].
+pub fn raw_functional_acknowledgments<V, T>(interchange: &RawInterchange) -> Vec<_997>
+where
+    V: Version,
+    T: TransactionSet + Serialize + for<'a> Parser<&'a str, T, nom::error::Error<&'a str>>,
+{
+    interchange
+        .groups
+        .iter()
+        .map(|group| _997::from_report(&GroupReport::validate_raw::<V, T>(group)))
+        .collect()
+}
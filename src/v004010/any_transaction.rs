@@ -0,0 +1,39 @@
+use crate::envelope;
+use crate::v004010::*;
+
+envelope::any_transaction! {
+    /// Any transaction set of 004010
+    ///
+    /// Parsing dispatches on the Transaction Set Identifier Code (ST01).
+    /// Transaction sets without a typed binding are kept as [`RawTransaction`](crate::raw::RawTransaction).
+    AnyTransaction {
+        /// 204 - Motor Carrier Load Tender
+        "204" => _204,
+        /// 214 - Transportation Carrier Shipment Status Message
+        "214" => _214,
+        /// 301 - Confirmation (Ocean)
+        "301" => _301,
+        /// 309 - U.S. Customs Manifest
+        "309" => _309,
+        /// 310 - Freight Receipt and Invoice (Ocean)
+        "310" => _310,
+        /// 315 - Status Details (Ocean)
+        "315" => _315,
+        /// 322 - Terminal Operations and Intermodal Ramp Activity
+        "322" => _322,
+        /// 404 - Rail Carrier Shipment Information
+        "404" => _404,
+        /// 810 - Invoice
+        "810" => _810,
+        /// 856 - Ship Notice/Manifest
+        "856" => _856,
+        /// 940 - Warehouse Shipping Order
+        "940" => _940,
+        /// 945 - Warehouse Shipping Advice
+        "945" => _945,
+        /// 997 - Functional Acknowledgment
+        "997" => _997,
+        /// 998 - Set Cancellation
+        "998" => _998,
+    }
+}
@@ -1,6 +1,7 @@
 //! v004010 repesents all entities of the 004010 specification.
 
-use crate::util::Parser;
+use crate::envelope;
+use crate::util::{reader, Parser};
 use nom::combinator::opt;
 use nom::combinator::peek;
 use nom::multi::many0;
@@ -8,12 +9,16 @@ use nom::IResult;
 use nom::Parser as _;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
-use std::fmt::Display;
-use validator::Validate;
 use x12_types_macros::DisplayX12;
 
 pub mod segment;
 
+mod acknowledgment;
+pub use acknowledgment::*;
+
+mod any_transaction;
+pub use any_transaction::*;
+
 mod _940_doc;
 pub use _940_doc::*;
 
@@ -51,65 +56,42 @@ mod test_997;
 mod test_998;
 #[cfg(test)]
 mod test_segments;
+#[cfg(test)]
+mod test_transmission;
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Validate)]
-pub struct Transmission<T> {
-    #[validate(nested)]
-    pub isa: ISA,
-    pub functional_group: Vec<FunctionalGroup<T>>,
-    #[validate(nested)]
-    pub iea: IEA,
-}
-
-impl<'a, T: Default + Parser<&'a str, T, nom::error::Error<&'a str>>>
-    Parser<&'a str, Transmission<T>, nom::error::Error<&'a str>> for Transmission<T>
-{
-    fn parse(input: &'a str) -> IResult<&'a str, Transmission<T>> {
-        let mut output = Transmission::default();
-        let (input, obj) = ISA::parse(input)?;
-        output.isa = obj;
-        // functional group
-        let (input, gs) = GS::parse(input)?;
-        let (input, t_obj) = many0(T::parse).parse(input)?;
-        // let (input, t_obj) = T::parse(input)?;
-        let (input, ge) = GE::parse(input)?;
-        let fg = FunctionalGroup {
-            gs,
-            segments: t_obj,
-            // segments: vec![t_obj],
-            ge,
-        };
-        output.functional_group.push(fg);
-        let (input, obj) = IEA::parse(input)?;
-        output.iea = obj;
-        Ok((input, output))
-    }
-}
+/// The envelope segments of 004010
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct V004010;
 
-impl<T: Display> Display for Transmission<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut lines = vec![];
-        lines.push(format!("{}", self.isa));
-        for fg in &self.functional_group {
-            lines.push(format!("{}", fg.gs));
-            for segment in &fg.segments {
-                lines.push(format!("{segment}"));
-            }
-            lines.push(format!("{}", fg.ge));
-        }
-        lines.push(format!("{}", self.iea));
-        let all = lines.join("");
-        write!(f, "{all}")
-    }
-}
+envelope::impl_version!(V004010, "00401");
+
+/// An interchange of 004010, from ISA to IEA
+pub type Transmission<T> = envelope::Transmission<V004010, T>;
+
+/// A functional group of 004010, from GS to GE
+pub type FunctionalGroup<T> = envelope::FunctionalGroup<V004010, T>;
+
+/// Streaming reader yielding the envelope segments and one transaction set at a time
+pub type TransmissionReader<R, T> = reader::TransmissionReader<R, ISA, GS, T, GE, IEA>;
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, Validate)]
-pub struct FunctionalGroup<T> {
-    #[validate(nested)]
-    pub gs: GS,
-    pub segments: Vec<T>,
-    #[validate(nested)]
-    pub ge: GE,
+/// Item of a [`TransmissionReader`]
+pub type Event<T> = reader::Event<ISA, GS, T, GE, IEA>;
+
+envelope::impl_transaction_set! {
+    _204 => st, se;
+    _214 => st, se;
+    _301 => st, se;
+    _309 => st, se;
+    _310 => st, se;
+    _315 => st, se;
+    _322 => st, se;
+    _404 => st, se;
+    _810 => st, se;
+    _856 => st, se;
+    _940 => _010, _0500;
+    _945 => st, se;
+    _997 => st, se;
+    _998 => st, se;
 }
 
 /// 204 - Motor Carrier Load Tender
@@ -233,6 +215,14 @@ pub struct _204 {
 
 impl<'a> Parser<&'a str, _204, nom::error::Error<&'a str>> for _204 {
     fn parse(input: &'a str) -> IResult<&'a str, _204> {
+        let _context = crate::error::transaction_set(
+            "_204",
+            &[
+                "AT5", "AT8", "B2", "B2A", "G61", "G62", "L11", "L3", "L5", "LAD", "LEP", "LFH",
+                "LH1", "LH2", "LH3", "LH4", "LH6", "LHT", "M7", "MEA", "MS3", "N1", "N2", "N3",
+                "N4", "N7", "N7A", "N7B", "NTE", "PLD", "S5", "SE", "ST",
+            ],
+        );
         let mut output = _204::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -258,6 +248,7 @@ impl<'a> Parser<&'a str, _204, nom::error::Error<&'a str>> for _204 {
         let mut loop_100 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_204Loop100");
             let (rest, n1) = opt(N1::parse).parse(loop_rest)?;
             let (rest, n2) = opt(N2::parse).parse(rest)?;
             let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -280,6 +271,7 @@ impl<'a> Parser<&'a str, _204, nom::error::Error<&'a str>> for _204 {
         let mut loop_200 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N7::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_204Loop200");
             let (rest, n7) = opt(N7::parse).parse(loop_rest)?;
             let (rest, n7a) = opt(N7A::parse).parse(rest)?;
             let (rest, n7b) = opt(N7B::parse).parse(rest)?;
@@ -300,6 +292,7 @@ impl<'a> Parser<&'a str, _204, nom::error::Error<&'a str>> for _204 {
         let mut loop_300 = vec![];
         let mut loop_rest = rest;
         while peek(opt(S5::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_204Loop300");
             let (rest, s5) = S5::parse(loop_rest)?;
             let (rest, l11) = many0(L11::parse).parse(rest)?;
             let (rest, g62) = many0(G62::parse).parse(rest)?;
@@ -312,6 +305,7 @@ impl<'a> Parser<&'a str, _204, nom::error::Error<&'a str>> for _204 {
             // loop 310
             let mut loop_310 = vec![];
             while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_204Loop310");
                 let (rest, n1) = opt(N1::parse).parse(loop_rest)?;
                 let (rest, n2) = opt(N2::parse).parse(rest)?;
                 let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -331,6 +325,7 @@ impl<'a> Parser<&'a str, _204, nom::error::Error<&'a str>> for _204 {
             while peek(opt(L5::parse)).parse(loop_rest)?.1.is_some()
                 || peek(opt(LH1::parse)).parse(loop_rest)?.1.is_some()
             {
+                let _context = crate::error::context("_204Loop320");
                 let (rest, l5) = opt(L5::parse).parse(loop_rest)?;
                 let (rest, at8) = opt(AT8::parse).parse(rest)?;
                 loop_rest = rest;
@@ -339,6 +334,7 @@ impl<'a> Parser<&'a str, _204, nom::error::Error<&'a str>> for _204 {
                 while peek(opt(G61::parse)).parse(loop_rest)?.1.is_some()
                     || peek(opt(LH1::parse)).parse(loop_rest)?.1.is_some()
                 {
+                    let _context = crate::error::context("_204Loop325");
                     let (rest, g61) = opt(G61::parse).parse(loop_rest)?;
                     let (rest, l11) = many0(L11::parse).parse(rest)?;
                     let (rest, lh6) = opt(LH6::parse).parse(rest)?;
@@ -346,6 +342,7 @@ impl<'a> Parser<&'a str, _204, nom::error::Error<&'a str>> for _204 {
                     // loop 330
                     let mut loop_330 = vec![];
                     while peek(opt(LH1::parse)).parse(loop_rest)?.1.is_some() {
+                        let _context = crate::error::context("_204Loop330");
                         let (rest, lh1) = opt(LH1::parse).parse(loop_rest)?;
                         let (rest, lh2) = many0(LH2::parse).parse(rest)?;
                         let (rest, lh3) = many0(LH3::parse).parse(rest)?;
@@ -376,6 +373,7 @@ impl<'a> Parser<&'a str, _204, nom::error::Error<&'a str>> for _204 {
             // loop 380
             let mut loop_380 = vec![];
             while peek(opt(N7::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_204Loop380");
                 let (rest, n7) = opt(N7::parse).parse(loop_rest)?;
                 let (rest, n7a) = opt(N7A::parse).parse(rest)?;
                 let (rest, n7b) = opt(N7B::parse).parse(rest)?;
@@ -614,6 +612,13 @@ pub struct _214 {
 
 impl<'a> Parser<&'a str, _214, nom::error::Error<&'a str>> for _214 {
     fn parse(input: &'a str) -> IResult<&'a str, _214> {
+        let _context = crate::error::transaction_set(
+            "_214",
+            &[
+                "AT5", "AT7", "AT8", "B10", "G61", "G62", "K1", "L11", "LX", "MAN", "MS1", "MS2",
+                "MS3", "N1", "N2", "N3", "N4", "Q7", "SE", "ST",
+            ],
+        );
         let mut output = _214::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -629,6 +634,7 @@ impl<'a> Parser<&'a str, _214, nom::error::Error<&'a str>> for _214 {
         let mut loop_100 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_214Loop0100");
             let (rest, n1) = opt(N1::parse).parse(loop_rest)?;
             let (rest, n2) = opt(N2::parse).parse(rest)?;
             let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -655,11 +661,13 @@ impl<'a> Parser<&'a str, _214, nom::error::Error<&'a str>> for _214 {
         let mut loop_200 = vec![];
         loop_rest = rest;
         while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_214Loop0200");
             let (rest, lx) = LX::parse(loop_rest)?;
             // loop 0205
             let mut loop_0205 = vec![];
             loop_rest = rest;
             while peek(opt(AT7::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_214Loop0205");
                 let (rest, at7) = AT7::parse(loop_rest)?;
                 let (rest, ms1) = opt(MS1::parse).parse(rest)?;
                 let (rest, ms2) = opt(MS2::parse).parse(rest)?;
@@ -805,7 +813,45 @@ pub struct _214Loop0260 {
     pub bin: BIN,
 }
 
-/// 301 Confirmation (Ocean)
+/// 301 - Confirmation (Ocean)
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | B1 | Beginning Segment for Booking or Pick-up/Delivery | M | 1
+/// 0030 | Y3 | Equipment Details | M | 1
+/// LOOP ID - Y4 | 99
+/// Y4 -> 0040 | Y4 | Equipment Details | O | 1
+/// Y4 -> 0045 | W09 | Equipment Details | O | 1
+/// 0050 | N9 | Reference Identification | O | 30
+/// 0060 | R2A | Route Information | O | 25
+/// LOOP ID - N1 | 20
+/// N1 -> 0070 | N1 | Name | O | 1
+/// N1 -> 0080 | N2 | Additional Name Information | O | 1
+/// N1 -> 0090 | N3 | Address Information | O | 2
+/// N1 -> 0100 | N4 | Geographic Location | O | 1
+/// N1 -> 0110 | G61 | Contact | O | 3
+/// LOOP ID - R4 | 20
+/// R4 -> 0120 | R4 | Port or Terminal | M | 1
+/// R4 -> 0130 | DTM | Date/Time Reference | O | 15
+/// 0140 | W09 | Equipment Details | O | 1
+/// 0150 | H3 | Special Handling Instructions | O | 20
+/// 0160 | EA | Equipment Attributes | O | 10
+/// LOOP ID - LX | 999
+/// LX -> 0010 | LX | Assigned Number | M | 1
+/// LX -> 0020 | N7 | Equipment Details | O | 1
+/// LX -> 0025 | W09 | Equipment Details | O | 1
+/// LX -> 0030 | K1 | Remarks | O | 10
+/// LX -> 0040 | L0 | Line Item - Quantity and Weight | O | 1
+/// LX -> 0050 | L5 | Description, Marks and Numbers | O | 1
+/// LX -> 0055 | L4 | Measurement | O | 1
+/// LX -> 0060 | L1 | Rate and Charges | O | 1
+/// LX -> LOOP ID - H1 | 10
+/// LX -> H1 -> 0070 | H1 | Hazardous Material | O | 1
+/// LX -> H1 -> 0080 | H2 | Additional Hazardous Material Description | O | 10
+/// 0010 | V1 | Vessel Identification | O | 2
+/// 0020 | V9 | Event Detail | O | 10
+/// 0030 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12)]
 pub struct _301 {
     pub st: ST,
@@ -827,6 +873,14 @@ pub struct _301 {
 
 impl<'a> Parser<&'a str, _301, nom::error::Error<&'a str>> for _301 {
     fn parse(input: &'a str) -> IResult<&'a str, _301> {
+        let _context = crate::error::transaction_set(
+            "_301",
+            &[
+                "B1", "DTM", "EA", "G61", "H1", "H2", "H3", "K1", "L0", "L1", "L4", "L5", "LX",
+                "N1", "N2", "N3", "N4", "N7", "N9", "R2A", "R4", "SE", "ST", "V1", "V9", "W09",
+                "Y3", "Y4",
+            ],
+        );
         let mut output = _301::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -840,6 +894,7 @@ impl<'a> Parser<&'a str, _301, nom::error::Error<&'a str>> for _301 {
         while peek(opt(Y4::parse)).parse(loop_rest)?.1.is_some()
             || peek(opt(W09::parse)).parse(loop_rest)?.1.is_some()
         {
+            let _context = crate::error::context("_301LoopY4");
             let (rest, y4) = opt(Y4::parse).parse(loop_rest)?;
             let (rest, w09) = opt(W09::parse).parse(rest)?;
             loop_rest = rest;
@@ -855,6 +910,7 @@ impl<'a> Parser<&'a str, _301, nom::error::Error<&'a str>> for _301 {
         let mut loop_n1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_301LoopN1");
             println!("n1");
             let (rest, n1) = opt(N1::parse).parse(loop_rest)?;
             let (rest, n2) = opt(N2::parse).parse(rest)?;
@@ -876,6 +932,7 @@ impl<'a> Parser<&'a str, _301, nom::error::Error<&'a str>> for _301 {
         let mut loop_r4 = vec![];
         let mut loop_rest = rest;
         while peek(opt(R4::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_301LoopR4");
             let (rest, r4) = R4::parse(loop_rest)?;
             let (rest, dtm) = many0(DTM::parse).parse(rest)?;
             loop_rest = rest;
@@ -895,6 +952,7 @@ impl<'a> Parser<&'a str, _301, nom::error::Error<&'a str>> for _301 {
         while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some()
             || peek(opt(W09::parse)).parse(loop_rest)?.1.is_some()
         {
+            let _context = crate::error::context("_301LoopLx");
             let (rest, lx) = LX::parse(loop_rest)?;
             let (rest, n7) = opt(N7::parse).parse(rest)?;
             let (rest, w09) = opt(W09::parse).parse(rest)?;
@@ -907,6 +965,7 @@ impl<'a> Parser<&'a str, _301, nom::error::Error<&'a str>> for _301 {
             // loop h1
             let mut loop_h1 = vec![];
             while peek(opt(H1::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_301LoopLxLoopH1");
                 let (rest, h1) = opt(H1::parse).parse(loop_rest)?;
                 let (rest, h2) = many0(H2::parse).parse(rest)?;
                 loop_rest = rest;
@@ -1020,6 +1079,13 @@ pub struct _309 {
 
 impl<'a> Parser<&'a str, _309, nom::error::Error<&'a str>> for _309 {
     fn parse(input: &'a str) -> IResult<&'a str, _309> {
+        let _context = crate::error::transaction_set(
+            "_309",
+            &[
+                "DTM", "H1", "H2", "LX", "M10", "M11", "M12", "M13", "M7", "N1", "N10", "N3", "N4",
+                "N9", "P4", "PER", "R4", "SE", "ST", "VC", "VID",
+            ],
+        );
         let mut output = _309::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -1029,11 +1095,13 @@ impl<'a> Parser<&'a str, _309, nom::error::Error<&'a str>> for _309 {
         let mut loop_p4 = vec![];
         let mut loop_rest = rest;
         while peek(opt(P4::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_309LoopP4");
             let (rest, p4) = P4::parse(loop_rest)?;
             loop_rest = rest;
             // loop lx
             let mut loop_lx = vec![];
             while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_309LoopLX");
                 let (rest, lx) = LX::parse(loop_rest)?;
                 let (rest, m13) = opt(M13::parse).parse(rest)?;
                 let (rest, m11) = opt(M11::parse).parse(rest)?;
@@ -1042,6 +1110,7 @@ impl<'a> Parser<&'a str, _309, nom::error::Error<&'a str>> for _309 {
                 // loop n1
                 let mut loop_n1 = vec![];
                 while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_309LoopN1");
                     let (rest, n1) = opt(N1::parse).parse(loop_rest)?;
                     let (rest, n3) = opt(N3::parse).parse(rest)?;
                     let (rest, n4) = opt(N4::parse).parse(rest)?;
@@ -1059,6 +1128,7 @@ impl<'a> Parser<&'a str, _309, nom::error::Error<&'a str>> for _309 {
                 // loop m12
                 let mut loop_m12 = vec![];
                 while peek(opt(M12::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_309LoopM12");
                     let (rest, m12) = opt(M12::parse).parse(loop_rest)?;
                     let (rest, r4) = many0(R4::parse).parse(rest)?;
                     loop_rest = rest;
@@ -1067,18 +1137,21 @@ impl<'a> Parser<&'a str, _309, nom::error::Error<&'a str>> for _309 {
                 // loop vid
                 let mut loop_vid = vec![];
                 while peek(opt(VID::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_309LoopVID");
                     let (rest, vid) = opt(VID::parse).parse(loop_rest)?;
                     let (rest, m7) = many0(M7::parse).parse(rest)?;
                     loop_rest = rest;
                     // loop n10
                     let mut loop_n10 = vec![];
                     while peek(opt(N10::parse)).parse(loop_rest)?.1.is_some() {
+                        let _context = crate::error::context("_309LoopN10");
                         let (rest, n10) = opt(N10::parse).parse(loop_rest)?;
                         let (rest, vc) = many0(VC::parse).parse(rest)?;
                         loop_rest = rest;
                         // loop h1
                         let mut loop_h1 = vec![];
                         while peek(opt(H1::parse)).parse(loop_rest)?.1.is_some() {
+                            let _context = crate::error::context("_309LoopH1");
                             let (rest, h1) = opt(H1::parse).parse(loop_rest)?;
                             let (rest, h2) = many0(H2::parse).parse(rest)?;
                             loop_rest = rest;
@@ -1217,7 +1290,7 @@ pub struct _309LoopH1 {
 /// LX -> LOOP ID - L0 | 120
 /// LX -> L0 -> 150 | L0 | Line Item - Quantity and Weight | O | 1 |
 /// LX -> L0 -> 160 | L5 | Description, Marks and Numbers | O | 999 |
-/// LX -> L0 ->  |  | LOOP ID - L1 | 20
+/// LX -> L0 -> LOOP ID - L1 | 20
 /// LX -> L0 -> L1 -> 170 | L1 | Rate and Charges | O | 1 |
 /// LX -> L0 -> L1 -> 180 | C3 | Currency | O | 1 |
 /// LX -> L0 -> 190 | L7 | Tariff Reference | O | 1 |
@@ -1225,7 +1298,7 @@ pub struct _309LoopH1 {
 /// LX -> L0 -> 210 | X2 | Import License | O | 1 |
 /// LX -> L0 -> LOOP ID - C8 | 20
 /// LX -> L0 -> C8 -> 220 | C8 | Certifications and Clauses | O | 1 |
-/// LX -> L0 -> C8C -> 221 | C8C | Certifications Clauses Continuation | O | 5 |
+/// LX -> L0 -> C8 -> 221 | C8C | Certifications Clauses Continuation | O | 5 |
 /// LX -> L0 -> LOOP ID - H1 | 10
 /// LX -> L0 -> H1 -> 230 | H1 | Hazardous Material | O | 1 |
 /// LX -> L0 -> H1 -> 240 | H2 | Additional Hazardous Material Description | O | 10
@@ -1293,6 +1366,15 @@ pub struct _310 {
 
 impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
     fn parse(input: &'a str) -> IResult<&'a str, _310> {
+        let _context = crate::error::transaction_set(
+            "_310",
+            &[
+                "B2A", "B3", "C2", "C3", "C8", "C8C", "DTM", "G3", "G61", "H1", "H2", "H3", "K1",
+                "L0", "L1", "L11", "L3", "L5", "L7", "LX", "M0", "M1", "M7", "N1", "N12", "N2",
+                "N3", "N4", "N7", "N9", "PWK", "QTY", "R2", "R2A", "R4", "SE", "ST", "V1", "V4",
+                "V9", "W09", "X1", "X2", "Y2", "Y6",
+            ],
+        );
         let (rest, st) = ST::parse(input)?;
         let (rest, b3) = B3::parse(rest)?;
         let (rest, b2a) = opt(B2A::parse).parse(rest)?;
@@ -1309,6 +1391,7 @@ impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
         let mut loop_n1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_310LoopN1");
             let (rest, n1) = N1::parse(loop_rest)?;
             let (rest, n2) = opt(N2::parse).parse(rest)?;
             let (rest, n3) = opt(N3::parse).parse(rest)?;
@@ -1322,6 +1405,7 @@ impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
         let mut loop_r4 = vec![];
         let mut loop_rest = rest;
         while peek(opt(R4::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_310LoopR4");
             let (rest, r4) = R4::parse(loop_rest)?;
             let (rest, dtm) = opt(DTM::parse).parse(rest)?;
             loop_rest = rest;
@@ -1337,6 +1421,7 @@ impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
         let mut loop_c8 = vec![];
         let mut loop_rest = rest;
         while peek(opt(C8::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_310LoopC8");
             let (rest, c8) = opt(C8::parse).parse(loop_rest)?;
             let (rest, c8c) = many0(C8C::parse).parse(rest)?;
             loop_rest = rest;
@@ -1347,6 +1432,7 @@ impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
         let mut loop_lx = vec![];
         let mut loop_rest = rest;
         while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_310LoopLX");
             let (rest, lx) = LX::parse(loop_rest)?;
             loop_rest = rest;
             // loop n7
@@ -1354,6 +1440,7 @@ impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
             while peek(opt(N7::parse)).parse(loop_rest)?.1.is_some()
                 || peek(opt(L1::parse)).parse(loop_rest)?.1.is_some()
             {
+                let _context = crate::error::context("_310LoopN7");
                 let (rest, n7) = opt(N7::parse).parse(loop_rest)?;
                 let (rest, qty) = opt(QTY::parse).parse(rest)?;
                 let (rest, v4) = opt(V4::parse).parse(rest)?;
@@ -1364,6 +1451,7 @@ impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
                 let mut loop_l1 = vec![];
                 loop_rest = rest;
                 while peek(opt(L1::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_310LoopL1");
                     let (rest, l1) = opt(L1::parse).parse(loop_rest)?;
                     let (rest, c3) = opt(C3::parse).parse(rest)?;
                     loop_rest = rest;
@@ -1392,12 +1480,14 @@ impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
             // loop l0
             let mut loop_l0 = vec![];
             while peek(opt(L0::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_310LoopL0");
                 let (rest, l0) = opt(L0::parse).parse(loop_rest)?;
                 let (rest, l5) = many0(L5::parse).parse(rest)?;
                 loop_rest = rest;
                 // loop l1
                 let mut loop_l1 = vec![];
                 while peek(opt(L1::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_310LoopL1");
                     let (rest, l1) = opt(L1::parse).parse(loop_rest)?;
                     let (rest, c3) = opt(C3::parse).parse(rest)?;
                     loop_rest = rest;
@@ -1406,6 +1496,7 @@ impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
                 // loop c8
                 let mut loop_c8 = vec![];
                 while peek(opt(C8::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_310LoopC8");
                     let (rest, c8) = opt(C8::parse).parse(loop_rest)?;
                     let (rest, c8c) = many0(C8C::parse).parse(rest)?;
                     loop_rest = rest;
@@ -1414,6 +1505,7 @@ impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
                 // loop h1
                 let mut loop_h1 = vec![];
                 while peek(opt(H1::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_310LoopH1");
                     let (rest, h1) = opt(H1::parse).parse(loop_rest)?;
                     let (rest, h2) = many0(H2::parse).parse(rest)?;
                     loop_rest = rest;
@@ -1443,6 +1535,7 @@ impl<'a> Parser<&'a str, _310, nom::error::Error<&'a str>> for _310 {
         let mut loop_l1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(L1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_310LoopL1");
             let (rest, l1) = opt(L1::parse).parse(loop_rest)?;
             let (rest, c3) = opt(C3::parse).parse(rest)?;
             loop_rest = rest;
@@ -1598,6 +1691,10 @@ pub struct _315 {
 
 impl<'a> Parser<&'a str, _315, nom::error::Error<&'a str>> for _315 {
     fn parse(input: &'a str) -> IResult<&'a str, _315> {
+        let _context = crate::error::transaction_set(
+            "_315",
+            &["B4", "DTM", "N9", "Q2", "R4", "SE", "SG", "ST", "V9"],
+        );
         let mut output = _315::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -1613,6 +1710,7 @@ impl<'a> Parser<&'a str, _315, nom::error::Error<&'a str>> for _315 {
         let mut loop_r4 = vec![];
         let mut loop_rest = rest;
         while peek(opt(R4::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_315LoopR4");
             let (rest, r4) = R4::parse(loop_rest)?;
             let (rest, dtm) = many0(DTM::parse).parse(rest)?;
             loop_rest = rest;
@@ -1683,6 +1781,13 @@ pub struct _322 {
 
 impl<'a> Parser<&'a str, _322, nom::error::Error<&'a str>> for _322 {
     fn parse(input: &'a str) -> IResult<&'a str, _322> {
+        let _context = crate::error::transaction_set(
+            "_322",
+            &[
+                "DTM", "GR5", "H3", "K1", "L3", "M7", "N1", "N3", "N4", "N7", "N9", "NA", "Q5",
+                "R4", "SE", "ST", "V1", "V4", "W09", "W2", "Y7", "ZC1",
+            ],
+        );
         let mut output = _322::default();
         println!("before ST");
         let (rest, obj) = ST::parse(input)?;
@@ -1698,6 +1803,7 @@ impl<'a> Parser<&'a str, _322, nom::error::Error<&'a str>> for _322 {
         let mut loop_n7 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N7::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_322LoopN7");
             println!("loop n7");
             let (rest, n7) = N7::parse(loop_rest)?;
             let (rest, v4) = opt(V4::parse).parse(rest)?;
@@ -1713,6 +1819,7 @@ impl<'a> Parser<&'a str, _322, nom::error::Error<&'a str>> for _322 {
             // loop r4
             let mut loop_r4 = vec![];
             while peek(opt(R4::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_322LoopR4");
                 let (rest, r4) = R4::parse(loop_rest)?;
                 let (rest, dtm) = many0(DTM::parse).parse(rest)?;
                 loop_rest = rest;
@@ -1724,6 +1831,7 @@ impl<'a> Parser<&'a str, _322, nom::error::Error<&'a str>> for _322 {
             // loop n1
             let mut loop_n1 = vec![];
             while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_322LoopN1");
                 let (rest, n1) = N1::parse(loop_rest)?;
                 let (rest, n3) = many0(N3::parse).parse(rest)?;
                 let (rest, n4) = opt(N4::parse).parse(rest)?;
@@ -1951,6 +2059,16 @@ pub struct _404 {
 
 impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
     fn parse(input: &'a str) -> IResult<&'a str, _404> {
+        let _context = crate::error::transaction_set(
+            "_404",
+            &[
+                "BL", "BNX", "BX", "CM", "D9", "DTM", "EM", "F9", "GA", "H3", "IC", "IM", "L0",
+                "L1", "L3", "L5", "LE", "LEP", "LFH", "LH1", "LH2", "LH3", "LH4", "LH6", "LHR",
+                "LHT", "LS", "LX", "M1", "M12", "M3", "M7", "MEA", "N1", "N2", "N3", "N4", "N5",
+                "N7", "N9", "NA", "PER", "PI", "PS", "R2", "R9", "REF", "SE", "ST", "X1", "X7",
+                "XH", "ZC1",
+            ],
+        );
         let mut output = _404::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -1974,6 +2092,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
         let mut loop_n7 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N7::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_404LoopN7");
             let (rest, n7) = N7::parse(loop_rest)?;
             let (rest, em) = opt(EM::parse).parse(rest)?;
             let (rest, m7) = opt(M7::parse).parse(rest)?;
@@ -2009,6 +2128,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
         let mut loop_n1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_404LoopN1");
             let (rest, n1) = N1::parse(loop_rest)?;
             let (rest, n2) = opt(N2::parse).parse(rest)?;
             let (rest, n3) = opt(N3::parse).parse(rest)?;
@@ -2041,6 +2161,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
         let mut loop_lx = vec![];
         let mut loop_rest = rest;
         while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_404LoopLX");
             let (rest, lx) = LX::parse(loop_rest)?;
             let (rest, l5) = L5::parse(rest)?;
             let (rest, x1) = opt(X1::parse).parse(rest)?;
@@ -2048,6 +2169,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
             // loop l0
             let mut loop_l0 = vec![];
             while peek(opt(L0::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_404LoopL0");
                 let (rest, l0) = opt(L0::parse).parse(loop_rest)?;
                 let (rest, mea) = opt(MEA::parse).parse(rest)?;
                 let (rest, l1) = opt(L1::parse).parse(rest)?;
@@ -2072,6 +2194,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
         let mut loop_lh1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(LH1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_404LoopLH1");
             let (rest, lh1) = opt(LH1::parse).parse(loop_rest)?;
             let (rest, lh2) = many0(LH2::parse).parse(rest)?;
             let (rest, lh3) = many0(LH3::parse).parse(rest)?;
@@ -2237,6 +2360,116 @@ pub struct _404LoopRefN1 {
 }
 
 /// 810 - Invoice
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0010 | ST | Transaction Set Header | M | 1
+/// 0020 | BIG | Beginning Segment for Invoice | M | 1
+/// 0030 | NTE | Note/Special Instruction | O | 100
+/// 0040 | CUR | Currency | O | 1
+/// 0050 | REF | Reference Information | O | 12
+/// 0060 | YNQ | Yes/No Question | O | 10
+/// 0065 | PER | Administrative Communications Contact | O | 3
+/// LOOP ID - N1 | 200
+/// N1 -> 0070 | N1 | Name | O | 1
+/// N1 -> 0080 | N2 | Additional Name Information | O | 2
+/// N1 -> 0090 | N3 | Address Information | O | 2
+/// N1 -> 0100 | N4 | Geographic Location | O | 1
+/// N1 -> 0110 | REF | Reference Information | O | 12
+/// N1 -> 0120 | PER | Administrative Communications Contact | O | 3
+/// N1 -> 0125 | DMG | Demographic Information | O | 1
+/// 0130 | ITD | Terms of Sale/Deferred Terms of Sale | O | >1
+/// 0140 | DTM | Date/Time Reference | O | 10
+/// 0150 | FOB | F.O.B. Related Instructions | O | 1
+/// 0160 | PID | Product/Item Description | O | 200
+/// 0170 | MEA | Measurements | O | 40
+/// 0180 | PWK | Paperwork | O | 25
+/// 0190 | PKG | Marking, Packaging, Loading | O | 25
+/// 0200 | L7 | Tariff Reference | O | 1
+/// 0212 | BAL | Balance Detail | O | >1
+/// 0220 | INC | Installment Information | O | 1
+/// 0230 | PAM | Period Amount | O | >1
+/// LOOP ID - LM | 10
+/// LM -> 0240 | LM | Code Source Information | O | 1
+/// LM -> LOOP ID - LQ | 100
+/// LM -> LQ -> 0250 | LQ | Industry Code | M | 1
+/// LOOP ID - N9 | 1
+/// N9 -> 0260 | N9 | Reference Identification | O | 1
+/// N9 -> 0270 | MSG | Message Text | O | 10
+/// LOOP ID - V1 | >1
+/// V1 -> 0280 | V1 | Vessel Identification | O | 1
+/// V1 -> 0290 | R4 | Port or Terminal | O | >1
+/// V1 -> 0300 | DTM | Date/Time Reference | O | >1
+/// LOOP ID - FA1 | >1
+/// FA1 -> 0310 | FA1 | Type of Financial Accounting Data | O | 1
+/// FA1 -> 0320 | FA2 | Accounting Data | M | >1
+/// LOOP ID - IT1 | 200000
+/// IT1 -> 0010 | IT1 | Baseline Item Data (Invoice) | O | 1
+/// IT1 -> 0012 | CRC | Conditions Indicator | O | 1
+/// IT1 -> 0015 | QTY | Quantity | O | 5
+/// IT1 -> 0020 | CUR | Currency | O | 1
+/// IT1 -> 0030 | IT3 | Additional Item Data | O | 5
+/// IT1 -> 0040 | TXI | Tax Information | O | 10
+/// IT1 -> 0050 | CTP | Pricing Information | O | 25
+/// IT1 -> 0059 | PAM | Period Amount | O | 10
+/// IT1 -> 0060 | MEA | Measurements | O | 40
+/// IT1 -> LOOP ID - PID | 1000
+/// IT1 -> PID -> 0070 | PID | Product/Item Description | O | 1
+/// IT1 -> PID -> 0080 | MEA | Measurements | O | 10
+/// IT1 -> 0090 | PWK | Paperwork | O | 25
+/// IT1 -> 0100 | PKG | Marking, Packaging, Loading | O | 25
+/// IT1 -> 0110 | PO4 | Item Physical Details | O | 1
+/// IT1 -> 0120 | ITD | Terms of Sale/Deferred Terms of Sale | O | 2
+/// IT1 -> 0130 | REF | Reference Information | O | >1
+/// IT1 -> 0131 | YNQ | Yes/No Question | O | 10
+/// IT1 -> 0140 | PER | Administrative Communications Contact | O | 5
+/// IT1 -> 0150 | SDQ | Destination Quantity | O | 500
+/// IT1 -> 0160 | DTM | Date/Time Reference | O | 10
+/// IT1 -> 0170 | CAD | Carrier Detail | O | >1
+/// IT1 -> 0180 | L7 | Tariff Reference | O | >1
+/// IT1 -> 0190 | SR | Service, Promotion, Allowance, or Charge Information | O | 1
+/// IT1 -> LOOP ID - SAC | 25
+/// IT1 -> SAC -> 0200 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// IT1 -> SAC -> 0210 | TXI | Tax Information | O | 10
+/// IT1 -> LOOP ID - SLN | 1000
+/// IT1 -> SLN -> 0220 | SLN | Subline Item Detail | O | 1
+/// IT1 -> SLN -> 0230 | DTM | Date/Time Reference | O | 1
+/// IT1 -> SLN -> 0240 | REF | Reference Information | O | >1
+/// IT1 -> SLN -> 0250 | PID | Product/Item Description | O | 1000
+/// IT1 -> SLN -> 0260 | SAC | Service, Promotion, Allowance, or Charge Information | O | 25
+/// IT1 -> SLN -> 0270 | TC2 | Commodity | O | >1
+/// IT1 -> SLN -> 0280 | TXI | Tax Information | O | 10
+/// IT1 -> LOOP ID - N1 | 200
+/// IT1 -> N1 -> 0290 | N1 | Name | O | 1
+/// IT1 -> N1 -> 0300 | N2 | Additional Name Information | O | 2
+/// IT1 -> N1 -> 0310 | N3 | Address Information | O | 2
+/// IT1 -> N1 -> 0320 | N4 | Geographic Location | O | 1
+/// IT1 -> N1 -> 0330 | REF | Reference Information | O | 12
+/// IT1 -> N1 -> 0340 | PER | Administrative Communications Contact | O | 3
+/// IT1 -> N1 -> 0350 | DMG | Demographic Information | O | 1
+/// IT1 -> LOOP ID - LM | 10
+/// IT1 -> LM -> 0360 | LM | Code Source Information | O | 1
+/// IT1 -> LM -> LOOP ID - LQ | 100
+/// IT1 -> LM -> LQ -> 0370 | LQ | Industry Code | M | 1
+/// IT1 -> LOOP ID - V1 | >1
+/// IT1 -> V1 -> 0380 | V1 | Vessel Identification | O | 1
+/// IT1 -> V1 -> 0390 | R4 | Port or Terminal | O | >1
+/// IT1 -> V1 -> 0400 | DTM | Date/Time Reference | O | >1
+/// IT1 -> LOOP ID - FA1 | >1
+/// IT1 -> FA1 -> 0410 | FA1 | Type of Financial Accounting Data | O | 1
+/// IT1 -> FA1 -> 0420 | FA2 | Accounting Data | M | >1
+/// 0010 | TDS | Total Monetary Value Summary | M | 1
+/// 0020 | TXI | Tax Information | O | 10
+/// 0030 | CAD | Carrier Detail | O | 1
+/// 0035 | AMT | Monetary Amount | O | >1
+/// LOOP ID - SAC | 25
+/// SAC -> 0040 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// SAC -> 0050 | TXI | Tax Information | O | 10
+/// LOOP ID - ISS | >1
+/// ISS -> 0060 | ISS | Invoice Shipment Summary | O | 1
+/// ISS -> 0070 | PID | Product/Item Description | O | 1
+/// 0080 | CTT | Transaction Totals | O | 1
+/// 0090 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12)]
 pub struct _810 {
     pub st: ST,
@@ -2275,6 +2508,15 @@ pub struct _810 {
 
 impl<'a> Parser<&'a str, _810, nom::error::Error<&'a str>> for _810 {
     fn parse(input: &'a str) -> IResult<&'a str, _810> {
+        let _context = crate::error::transaction_set(
+            "_810",
+            &[
+                "AMT", "BAL", "BIG", "CAD", "CRC", "CTP", "CTT", "CUR", "DMG", "DTM", "FA1", "FA2",
+                "FOB", "INC", "IT1", "IT3", "ITD", "L7", "LM", "LQ", "MEA", "MSG", "N1", "N2",
+                "N3", "N4", "N9", "NTE", "PAM", "PER", "PID", "PKG", "PO4", "PWK", "QTY", "R4",
+                "REF", "SAC", "SDQ", "SE", "SLN", "SR", "ST", "TC2", "TDS", "TXI", "V1", "YNQ",
+            ],
+        );
         let mut output = _810::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -2294,6 +2536,7 @@ impl<'a> Parser<&'a str, _810, nom::error::Error<&'a str>> for _810 {
         let mut loop_n1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_810LoopN1");
             let (rest, n1) = N1::parse(loop_rest)?;
             let (rest, n2) = many0(N2::parse).parse(rest)?;
             let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -2340,6 +2583,7 @@ impl<'a> Parser<&'a str, _810, nom::error::Error<&'a str>> for _810 {
         let mut loop_lm = vec![];
         loop_rest = rest;
         while peek(opt(LM::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_810LoopLM");
             let (rest, lm) = LM::parse(loop_rest)?;
             let (rest, lq) = LQ::parse(rest)?;
             loop_rest = rest;
@@ -2351,6 +2595,7 @@ impl<'a> Parser<&'a str, _810, nom::error::Error<&'a str>> for _810 {
         let mut loop_n9 = vec![];
         loop_rest = rest;
         while peek(opt(N9::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_810LoopN9");
             let (rest, n9) = N9::parse(loop_rest)?;
             let (rest, msg) = many0(MSG::parse).parse(rest)?;
             loop_rest = rest;
@@ -2362,6 +2607,7 @@ impl<'a> Parser<&'a str, _810, nom::error::Error<&'a str>> for _810 {
         let mut loop_v1 = vec![];
         loop_rest = rest;
         while peek(opt(V1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_810LoopV1");
             let (rest, v1) = V1::parse(loop_rest)?;
             let (rest, r4) = many0(R4::parse).parse(rest)?;
             let (rest, dtm) = many0(DTM::parse).parse(rest)?;
@@ -2374,6 +2620,7 @@ impl<'a> Parser<&'a str, _810, nom::error::Error<&'a str>> for _810 {
         let mut loop_fa1 = vec![];
         loop_rest = rest;
         while peek(opt(FA1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_810LoopFA1");
             let (rest, fa1) = FA1::parse(loop_rest)?;
             let (rest, fa2) = many0(FA2::parse).parse(rest)?;
             loop_rest = rest;
@@ -2385,6 +2632,7 @@ impl<'a> Parser<&'a str, _810, nom::error::Error<&'a str>> for _810 {
         let mut loop_it1 = vec![];
         loop_rest = rest;
         while peek(opt(IT1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_810LoopIT1");
             let (rest, it1) = IT1::parse(loop_rest)?;
             let (rest, crc) = opt(CRC::parse).parse(rest)?;
             let (rest, qty) = many0(QTY::parse).parse(rest)?;
@@ -2398,6 +2646,7 @@ impl<'a> Parser<&'a str, _810, nom::error::Error<&'a str>> for _810 {
             let mut loop_pid = vec![];
             loop_rest = rest;
             while peek(opt(PID::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_810LoopPID");
                 let (rest, pid) = PID::parse(loop_rest)?;
                 let (rest, mea) = many0(MEA::parse).parse(rest)?;
                 loop_rest = rest;
@@ -2420,6 +2669,7 @@ impl<'a> Parser<&'a str, _810, nom::error::Error<&'a str>> for _810 {
             let mut loop_sln = vec![];
             loop_rest = rest;
             while peek(opt(SLN::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_810LoopSLN");
                 let (rest, sln) = SLN::parse(loop_rest)?;
                 let (rest, dtm) = opt(DTM::parse).parse(rest)?;
                 let (rest, r#ref) = many0(REF::parse).parse(rest)?;
@@ -2615,6 +2865,10 @@ pub struct _997 {
 
 impl<'a> Parser<&'a str, _997, nom::error::Error<&'a str>> for _997 {
     fn parse(input: &'a str) -> IResult<&'a str, _997> {
+        let _context = crate::error::transaction_set(
+            "_997",
+            &["AK1", "AK2", "AK3", "AK4", "AK5", "AK9", "SE", "ST"],
+        );
         let mut output = _997::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -2624,11 +2878,13 @@ impl<'a> Parser<&'a str, _997, nom::error::Error<&'a str>> for _997 {
         let mut loop_ak2 = vec![];
         let mut loop_rest = rest;
         while peek(opt(AK2::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_997LoopAK2");
             let (rest, ak2) = AK2::parse(loop_rest)?;
             // loop ak3
             loop_rest = rest;
             let mut loop_ak3 = vec![];
             while peek(opt(AK3::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_997LoopAK3");
                 let (rest, ak3) = AK3::parse(loop_rest)?;
                 let (rest, ak4) = many0(AK4::parse).parse(rest)?;
                 loop_rest = rest;
@@ -2679,6 +2935,7 @@ pub struct _998 {
 
 impl<'a> Parser<&'a str, _998, nom::error::Error<&'a str>> for _998 {
     fn parse(input: &'a str) -> IResult<&'a str, _998> {
+        let _context = crate::error::transaction_set("_998", &["SE", "ST", "ZD"]);
         let mut output = _998::default();
         let (input, obj) = ST::parse(input)?;
         output.st = obj;
@@ -2698,44 +2955,67 @@ impl<'a> Parser<&'a str, _998, nom::error::Error<&'a str>> for _998 {
 /// ----|----|------|-----|-----|-------
 /// 0010 | ST | Transaction Set Header | M | 1
 /// 0020 | BSN | Beginning Segment for Ship Notice | M | 1
-/// 0030 | DTM | Date/Time Reference | O | 10
+/// 0040 | DTM | Date/Time Reference | O | 10
 /// LOOP ID - HL | 200000
-/// HL -> 0040 | HL | Hierarchical Level | M | 1
-/// HL -> 0050 | TD1 | Carrier Details (Routing Sequence/Transit Time) | O | 20
-/// HL -> 0060 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
-/// HL -> 0070 | TD3 | Carrier Details (Equipment) | O | 12
-/// HL -> 0080 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
-/// HL -> 0090 | REF | Reference Identification | O | 200
-/// HL -> 0100 | DTM | Date/Time Reference | O | 10
-/// HL -> 0110 | FOB | F.O.B. Related Instructions | O | 1
-/// HL -> 0120 | PKG | Marking, Packaging, Loading | O | 25
-/// HL -> 0130 | L5 | Description, Marks and Numbers | O | 999
-/// HL -> 0140 | H1 | Hardship Exemption | O | 1
-/// HL -> 0150 | H2 | Additional Reference Information | O | 6
-/// HL -> 0160 | H3 | Special Handling Instructions | O | 6
-/// HL -> 0170 | L0 | Line Item - Quantity and Weight | O | 1
-/// HL -> 0180 | L1 | Rate and Charges | O | 1
-/// HL -> 0190 | L4 | Description, Marks and Numbers | O | 1
-/// HL -> 0200 | L7 | Tariff Reference | O | 1
-/// HL -> 0210 | L9 | Charge Detail | O | 1
-/// HL -> 0220 | LH1 | Hazardous Identification Information | O | 1
-/// HL -> 0230 | LH2 | Hazardous Classification Information | O | 4
-/// HL -> 0240 | LH3 | Hazardous Material Shipping Name | O | 10
-/// HL -> 0250 | LFH | Free Form Hazardous Material Information | O | 20
-/// HL -> 0260 | LEP | Hazardous Material Packing Group | O | 3
-/// HL -> 0270 | LH4 | Hazardous Material Shipment Information | O | 1
-/// HL -> 0280 | LHT | Hazardous Material Identifying Reference Numbers | O | 3
-/// HL -> 0290 | LHR | Hazardous Material Reportable Quantity | O | 1
-/// HL -> 0300 | PER | Administrative Communications Contact | O | 3
-/// HL -> 0310 | N1 | Name | O | 200
-/// HL -> 0320 | N2 | Additional Name Information | O | 2
-/// HL -> 0330 | N3 | Address Information | O | 2
-/// HL -> 0340 | N4 | Geographic Location | O | 1
-/// HL -> 0350 | REF | Reference Identification | O | 12
-/// HL -> 0360 | PER | Administrative Communications Contact | O | 3
-/// HL -> 0370 | FOB | F.O.B. Related Instructions | O | 1
-/// HL -> 0380 | CTT | Transaction Totals | O | 1
-/// 0390 | SE | Transaction Set Trailer | M | 1
+/// HL -> 0010 | HL | Hierarchical Level | M | 1
+/// HL -> 0020 | LIN | Item Identification | O | 1
+/// HL -> 0030 | SN1 | Shipment Detail | O | 1
+/// HL -> 0040 | SLN | Subline Item Detail | O | 1000
+/// HL -> 0050 | PRF | Purchase Order Reference | O | 1
+/// HL -> 0060 | PO4 | Item Physical Details | O | 1
+/// HL -> 0070 | PID | Product/Item Description | O | 200
+/// HL -> 0080 | MEA | Measurements | O | 40
+/// HL -> 0090 | PWK | Paperwork | O | 25
+/// HL -> 0100 | PKG | Marking, Packaging, Loading | O | 25
+/// HL -> 0110 | TD1 | Carrier Details (Equipment) | O | 20
+/// HL -> 0120 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// HL -> 0130 | TD3 | Carrier Details (Routing Sequence/Transit Time) | O | 12
+/// HL -> 0140 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// HL -> 0141 | TSD | Tariff Service Description | O | 1
+/// HL -> 0150 | REF | Reference Information | O | 200
+/// HL -> 0151 | PER | Administrative Communications Contact | O | 3
+/// HL -> LOOP ID - LH1 | 100
+/// HL -> LH1 -> 0152 | LH1 | Hazardous Identification Information | O | 1
+/// HL -> LH1 -> 0153 | LH2 | Hazardous Classification Information | O | 4
+/// HL -> LH1 -> 0154 | LH3 | Hazardous Material Shipping Name | O | 10
+/// HL -> LH1 -> 0155 | LFH | Freeform Hazardous Material Information | O | 20
+/// HL -> LH1 -> 0156 | LEP | EPA Required Data | O | 3
+/// HL -> LH1 -> 0157 | LH4 | Canadian Dangerous Requirements | O | 1
+/// HL -> LH1 -> 0158 | LHT | Transborder Hazardous Requirements | O | 3
+/// HL -> LH1 -> 0159 | LHR | Hazardous Material Identifying Reference Numbers | O | 10
+/// HL -> LH1 -> 0160 | PER | Administrative Communications Contact | O | 5
+/// HL -> LOOP ID - CLD | 200
+/// HL -> CLD -> 0170 | CLD | Carrier Load Details | O | 1
+/// HL -> CLD -> 0180 | REF | Reference Information | O | 200
+/// HL -> 0190 | MAN | Marks and Numbers | O | >1
+/// HL -> 0200 | DTM | Date/Time Reference | O | 10
+/// HL -> 0210 | FOB | F.O.B. Related Instructions | O | 1
+/// HL -> LOOP ID - N1 | 200
+/// HL -> N1 -> 0220 | N1 | Name | O | 1
+/// HL -> N1 -> 0230 | N2 | Additional Name Information | O | 2
+/// HL -> N1 -> 0240 | N3 | Address Information | O | 2
+/// HL -> N1 -> 0250 | N4 | Geographic Location | O | 1
+/// HL -> N1 -> 0260 | REF | Reference Information | O | 12
+/// HL -> N1 -> 0270 | PER | Administrative Communications Contact | O | 3
+/// HL -> N1 -> 0280 | FOB | F.O.B. Related Instructions | O | 1
+/// HL -> 0290 | SDQ | Destination Quantity | O | 50
+/// HL -> 0300 | ETD | Excess Transportation Detail | O | 1
+/// HL -> 0310 | CUR | Currency | O | 1
+/// HL -> LOOP ID - SAC | 25
+/// HL -> SAC -> 0320 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// HL -> SAC -> 0325 | CUR | Currency | O | 1
+/// HL -> 0330 | GF | General Order Identification | O | 1
+/// HL -> 0335 | YNQ | Yes/No Question | O | 10
+/// HL -> LOOP ID - LM | 10
+/// HL -> LM -> 0340 | LM | Code Source Information | O | 1
+/// HL -> LM -> LOOP ID - LQ | 100
+/// HL -> LM -> LQ -> 0350 | LQ | Industry Code | M | 1
+/// HL -> LOOP ID - V1 | >1
+/// HL -> V1 -> 0360 | V1 | Vessel Identification | O | 1
+/// HL -> V1 -> 0370 | R4 | Port or Terminal | O | >1
+/// HL -> V1 -> 0380 | DTM | Date/Time Reference | O | >1
+/// 0010 | CTT | Transaction Totals | O | 1
+/// 0020 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, DisplayX12)]
 pub struct _856 {
     pub st: ST,
@@ -2747,6 +3027,13 @@ pub struct _856 {
 
 impl<'a> Parser<&'a str, _856, nom::error::Error<&'a str>> for _856 {
     fn parse(input: &'a str) -> IResult<&'a str, _856> {
+        let _context = crate::error::transaction_set(
+            "_856",
+            &[
+                "BSN", "CLD", "CTT", "DTM", "HL", "LIN", "MAN", "MEA", "N1", "N2", "N3", "N4",
+                "PER", "PRF", "REF", "SE", "SN1", "ST", "TD1", "TD3", "TD5",
+            ],
+        );
         let mut output = _856::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -2759,6 +3046,7 @@ impl<'a> Parser<&'a str, _856, nom::error::Error<&'a str>> for _856 {
         let mut loop_hl = vec![];
         let mut loop_rest = rest;
         while peek(opt(HL::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_856LoopHL");
             let (rest, hl) = HL::parse(loop_rest)?;
             let (rest, td1) = many0(TD1::parse).parse(rest)?;
             let (rest, td5) = many0(TD5::parse).parse(rest)?;
@@ -2829,3 +3117,1350 @@ pub struct _856LoopHL {
     pub sn1: Vec<SN1>,
     pub ctt: Option<CTT>,
 }
+
+crate::validation::impl_transaction_structure! {
+    _204 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "B2", M, 1),
+        segment("0030", "B2A", M, 1),
+        segment("0080", "L11", O, 50),
+        segment("0090", "G62", O, 1),
+        segment("0100", "MS3", O, 1),
+        segment("0110", "AT5", O, 6),
+        segment("0120", "PLD", O, 1),
+        segment("0125", "LH6", O, 6),
+        segment("0130", "NTE", O, 10),
+        segment_loop(
+            "0100",
+            5,
+            &[
+                segment("0140", "N1", O, 1),
+                segment("0150", "N2", O, 1),
+                segment("0160", "N3", O, 2),
+                segment("0170", "N4", O, 1),
+                segment("0180", "L11", O, 1),
+                segment("0190", "G61", O, 3),
+            ],
+        ),
+        segment_loop(
+            "0200",
+            10,
+            &[
+                segment("0200", "N7", O, 1),
+                segment("0203", "N7A", O, 1),
+                segment("0205", "N7B", O, 1),
+                segment("0208", "MEA", O, 1),
+                segment("0210", "M7", O, 2),
+            ],
+        ),
+        segment_loop(
+            "0300",
+            999,
+            &[
+                segment("0010", "S5", M, 1),
+                segment("0020", "L11", O, 50),
+                segment("0030", "G62", O, 2),
+                segment("0040", "AT8", O, 1),
+                segment("0050", "LAD", O, 999),
+                segment("0060", "AT5", O, 6),
+                segment("0063", "PLD", O, 1),
+                segment("0065", "NTE", O, 20),
+                segment_loop(
+                    "0310",
+                    1,
+                    &[
+                        segment("0070", "N1", O, 1),
+                        segment("0080", "N2", O, 1),
+                        segment("0090", "N3", O, 2),
+                        segment("0100", "N4", O, 1),
+                        segment("0120", "G61", O, 3),
+                    ],
+                ),
+                segment_loop(
+                    "0320",
+                    99,
+                    &[
+                        segment("0130", "L5", O, 1),
+                        segment("0135", "AT8", O, 1),
+                        segment_loop(
+                            "0325",
+                            99,
+                            &[
+                                segment("0140", "G61", O, 1),
+                                segment("0141", "L11", O, 5),
+                                segment("0142", "LH6", O, 6),
+                                segment_loop(
+                                    "0330",
+                                    25,
+                                    &[
+                                        segment("0143", "LH1", O, 1),
+                                        segment("0144", "LH2", O, 4),
+                                        segment("0145", "LH3", O, 10),
+                                        segment("0146", "LFH", O, 20),
+                                        segment("0147", "LEP", O, 3),
+                                        segment("0148", "LH4", O, 1),
+                                        segment("0149", "LHT", O, 3),
+                                    ],
+                                ),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "0350",
+                    999,
+                    &[
+                        segment("0150", "OID", O, 1),
+                        segment("0160", "G62", O, 2),
+                        segment("0180", "LAD", O, 999),
+                        segment_loop(
+                            "0360",
+                            99,
+                            &[
+                                segment("0190", "L5", O, 1),
+                                segment("0195", "AT8", O, 1),
+                                segment_loop(
+                                    "0365",
+                                    99,
+                                    &[
+                                        segment("0200", "G61", O, 1),
+                                        segment("0201", "L11", O, 5),
+                                        segment("0202", "LH6", O, 6),
+                                        segment_loop(
+                                            "0370",
+                                            25,
+                                            &[
+                                                segment("0203", "LH1", O, 1),
+                                                segment("0204", "LH2", O, 4),
+                                                segment("0205", "LH3", O, 10),
+                                                segment("0206", "LFH", O, 20),
+                                                segment("0207", "LEP", O, 3),
+                                                segment("0208", "LH4", O, 1),
+                                                segment("0209", "LHT", O, 3),
+                                            ],
+                                        ),
+                                    ],
+                                ),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "0380",
+                    10,
+                    &[
+                        segment("0210", "N7", O, 1),
+                        segment("0220", "N7A", O, 1),
+                        segment("0230", "N7B", O, 1),
+                        segment("0240", "MEA", O, 1),
+                        segment("0250", "M7", O, 2),
+                    ],
+                ),
+            ],
+        ),
+        segment("9010", "L3", O, 1),
+        segment("9020", "SE", M, 1),
+    ],
+    _214 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "B10", M, 1),
+        segment("0030", "L11", O, 300),
+        segment("0035", "MAN", O, 9999),
+        segment("0040", "K1", O, 10),
+        segment_loop(
+            "0100",
+            10,
+            &[
+                segment("0050", "N1", O, 1),
+                segment("0060", "N2", O, 1),
+                segment("0070", "N3", O, 2),
+                segment("0080", "N4", O, 1),
+                segment("0090", "G61", O, 1),
+                segment("0100", "G62", O, 1),
+                segment("0110", "L11", O, 10),
+            ],
+        ),
+        segment("0120", "MS3", O, 12),
+        segment_loop(
+            "0200",
+            999999,
+            &[
+                segment("0130", "LX", O, 1),
+                segment_loop(
+                    "0205",
+                    10,
+                    &[
+                        segment("0140", "AT7", O, 1),
+                        segment("0143", "MS1", O, 1),
+                        segment("0146", "MS2", O, 1),
+                    ],
+                ),
+                segment("0150", "L11", O, 10),
+                segment("0155", "MAN", O, 9999),
+                segment("0160", "Q7", O, 10),
+                segment("0170", "K1", O, 10),
+                segment("0180", "AT5", O, 10),
+                segment("0200", "AT8", O, 10),
+                segment_loop(
+                    "0210",
+                    999999,
+                    &[
+                        segment("0210", "CD3", O, 1),
+                        segment("0220", "L11", O, 20),
+                        segment_loop(
+                            "0215",
+                            10,
+                            &[
+                                segment("0230", "AT7", O, 1),
+                                segment("0233", "MS1", O, 1),
+                                segment("0236", "MS2", O, 1),
+                            ],
+                        ),
+                        segment("0240", "NM1", O, 1),
+                        segment("0250", "Q7", O, 10),
+                        segment("0260", "AT8", O, 1),
+                        segment("0265", "MAN", O, 9999),
+                        segment_loop(
+                            "0220",
+                            999999,
+                            &[
+                                segment("0270", "N1", O, 1),
+                                segment("0280", "N2", O, 1),
+                                segment("0290", "N3", O, 3),
+                                segment("0300", "N4", O, 1),
+                                segment("0310", "L11", O, 10),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "0230",
+                    999999,
+                    &[
+                        segment("0320", "PRF", O, 1),
+                        segment_loop(
+                            "0231",
+                            999999,
+                            &[
+                                segment("0330", "N1", O, 1),
+                                segment("0340", "N2", O, 1),
+                                segment("0350", "N3", O, 2),
+                                segment("0360", "N4", O, 1),
+                                segment("0370", "L11", O, 10),
+                            ],
+                        ),
+                        segment_loop(
+                            "0233",
+                            999999,
+                            &[
+                                segment("0380", "CD3", O, 1),
+                                segment("0390", "L11", O, 20),
+                                segment_loop(
+                                    "0240",
+                                    10,
+                                    &[
+                                        segment("0400", "AT7", O, 1),
+                                        segment("0402", "MS1", O, 1),
+                                        segment("0404", "MS2", O, 1),
+                                    ],
+                                ),
+                                segment("0405", "MAN", O, 9999),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "0250",
+                    999999,
+                    &[
+                        segment("0410", "SPO", O, 1),
+                        segment("0420", "SDQ", O, 10),
+                    ],
+                ),
+                segment_loop(
+                    "0260",
+                    usize::MAX,
+                    &[
+                        segment("0423", "EFI", O, 1),
+                        segment("0426", "BIN", M, 1),
+                    ],
+                ),
+            ],
+        ),
+        segment("0610", "SE", M, 1),
+    ],
+    _301 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "B1", M, 1),
+        segment("0030", "Y3", M, 1),
+        segment_loop(
+            "Y4",
+            99,
+            &[
+                segment("0040", "Y4", O, 1),
+                segment("0045", "W09", O, 1),
+            ],
+        ),
+        segment("0050", "N9", O, 30),
+        segment("0060", "R2A", O, 25),
+        segment_loop(
+            "N1",
+            20,
+            &[
+                segment("0070", "N1", O, 1),
+                segment("0080", "N2", O, 1),
+                segment("0090", "N3", O, 2),
+                segment("0100", "N4", O, 1),
+                segment("0110", "G61", O, 3),
+            ],
+        ),
+        segment_loop(
+            "R4",
+            20,
+            &[
+                segment("0120", "R4", M, 1),
+                segment("0130", "DTM", O, 15),
+            ],
+        ),
+        segment("0140", "W09", O, 1),
+        segment("0150", "H3", O, 20),
+        segment("0160", "EA", O, 10),
+        segment_loop(
+            "LX",
+            999,
+            &[
+                segment("0010", "LX", M, 1),
+                segment("0020", "N7", O, 1),
+                segment("0025", "W09", O, 1),
+                segment("0030", "K1", O, 10),
+                segment("0040", "L0", O, 1),
+                segment("0050", "L5", O, 1),
+                segment("0055", "L4", O, 1),
+                segment("0060", "L1", O, 1),
+                segment_loop(
+                    "H1",
+                    10,
+                    &[
+                        segment("0070", "H1", O, 1),
+                        segment("0080", "H2", O, 10),
+                    ],
+                ),
+            ],
+        ),
+        segment("0010", "V1", O, 2),
+        segment("0020", "V9", O, 10),
+        segment("0030", "SE", M, 1),
+    ],
+    _309 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "M10", M, 1),
+        segment_loop(
+            "P4",
+            20,
+            &[
+                segment("0040", "P4", M, 1),
+                segment_loop(
+                    "LX",
+                    9999,
+                    &[
+                        segment("0060", "LX", M, 1),
+                        segment("0070", "M13", O, 1),
+                        segment("0080", "M11", O, 1),
+                        segment("0085", "N9", O, 999),
+                        segment_loop(
+                            "N1",
+                            5,
+                            &[
+                                segment("0100", "N1", O, 1),
+                                segment("0110", "N3", O, 2),
+                                segment("0120", "N4", O, 1),
+                                segment("0123", "DTM", O, 1),
+                                segment("0125", "PER", O, 1),
+                            ],
+                        ),
+                        segment_loop(
+                            "M12",
+                            1,
+                            &[
+                                segment("0130", "M12", O, 1),
+                                segment("0135", "P5", O, 5),
+                            ],
+                        ),
+                        segment_loop(
+                            "VID",
+                            999,
+                            &[
+                                segment("0150", "VID", O, 1),
+                                segment("0155", "VC", O, 21),
+                                segment_loop(
+                                    "N10",
+                                    999,
+                                    &[
+                                        segment("0160", "N10", O, 1),
+                                        segment_loop(
+                                            "H1",
+                                            10,
+                                            &[
+                                                segment("0165", "H1", O, 1),
+                                                segment("0166", "H2", O, 99),
+                                            ],
+                                        ),
+                                    ],
+                                ),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("0200", "SE", M, 1),
+    ],
+    _310 => [
+        segment("010", "ST", M, 1),
+        segment("020", "B3", M, 1),
+        segment("030", "B2A", O, 1),
+        segment("040", "Y6", O, 2),
+        segment("050", "G3", O, 1),
+        segment("060", "N9", O, 15),
+        segment("070", "V1", M, 2),
+        segment("080", "M0", O, 1),
+        segment("090", "M1", O, 5),
+        segment("100", "C2", O, 1),
+        segment("110", "C3", O, 1),
+        segment("120", "Y2", O, 10),
+        segment_loop(
+            "N1",
+            10,
+            &[
+                segment("130", "N1", M, 1),
+                segment("140", "N2", O, 1),
+                segment("150", "N3", O, 2),
+                segment("160", "N4", O, 1),
+            ],
+        ),
+        segment("170", "G61", O, 3),
+        segment_loop(
+            "R4",
+            20,
+            &[
+                segment("180", "R4", M, 1),
+                segment("190", "DTM", O, 15),
+            ],
+        ),
+        segment("199", "R2A", O, 25),
+        segment("200", "R2", O, 13),
+        segment("210", "K1", O, 12),
+        segment("220", "H3", O, 6),
+        segment("230", "L5", O, 1),
+        segment_loop(
+            "C8",
+            20,
+            &[
+                segment("240", "C8", O, 1),
+                segment("250", "C8C", O, 5),
+            ],
+        ),
+        segment_loop(
+            "LX",
+            999,
+            &[
+                segment("010", "LX", M, 1),
+                segment_loop(
+                    "N7",
+                    999,
+                    &[
+                        segment("020", "N7", O, 1),
+                        segment("025", "QTY", O, 1),
+                        segment("030", "V4", O, 1),
+                        segment("040", "N12", O, 1),
+                        segment("050", "M7", O, 5),
+                        segment("060", "W09", O, 1),
+                        segment_loop(
+                            "L1",
+                            20,
+                            &[
+                                segment("070", "L1", O, 1),
+                                segment("080", "C3", O, 1),
+                            ],
+                        ),
+                        segment("090", "L7", O, 1),
+                        segment("100", "X1", O, 1),
+                        segment("110", "X2", O, 1),
+                        segment("120", "N9", O, 3),
+                        segment_loop(
+                            "H1",
+                            10,
+                            &[
+                                segment("130", "H1", O, 1),
+                                segment("140", "H2", O, 10),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "L0",
+                    120,
+                    &[
+                        segment("150", "L0", O, 1),
+                        segment("160", "L5", O, 999),
+                        segment_loop(
+                            "L1",
+                            20,
+                            &[
+                                segment("170", "L1", O, 1),
+                                segment("180", "C3", O, 1),
+                            ],
+                        ),
+                        segment("190", "L7", O, 1),
+                        segment("200", "X1", O, 1),
+                        segment("210", "X2", O, 1),
+                        segment_loop(
+                            "C8",
+                            20,
+                            &[
+                                segment("220", "C8", O, 1),
+                                segment("221", "C8C", O, 5),
+                            ],
+                        ),
+                        segment_loop(
+                            "H1",
+                            10,
+                            &[
+                                segment("230", "H1", O, 1),
+                                segment("240", "H2", O, 10),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("010", "L3", M, 1),
+        segment("020", "PWK", O, 25),
+        segment_loop(
+            "L1",
+            20,
+            &[
+                segment("030", "L1", O, 1),
+                segment("040", "C3", O, 1),
+            ],
+        ),
+        segment("050", "V9", O, 10),
+        segment("055", "C8", O, 20),
+        segment("060", "K1", O, 999),
+        segment("070", "L11", O, 1),
+        segment("080", "SE", M, 1),
+    ],
+    _315 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "B4", M, 1),
+        segment("0030", "N9", O, 30),
+        segment("0040", "Q2", O, 1),
+        segment("0050", "SG", O, 15),
+        segment_loop(
+            "R4",
+            20,
+            &[
+                segment("0060", "R4", M, 1),
+                segment("0070", "DTM", O, 15),
+            ],
+        ),
+        segment("0080", "V9", O, 10),
+        segment("0090", "SE", M, 1),
+    ],
+    _322 => [
+        segment("0010", "ST", M, 1),
+        segment("0015", "ZC1", O, 1),
+        segment("0016", "Q5", M, 1),
+        segment_loop(
+            "N7",
+            1000,
+            &[
+                segment("0020", "N7", M, 1),
+                segment("0030", "V4", O, 1),
+                segment("0040", "DTM", O, 2),
+                segment("0050", "M7", O, 5),
+                segment("0060", "W09", O, 1),
+                segment("0070", "W2", O, 1),
+                segment("0080", "NA", O, 30),
+                segment("0085", "GR5", O, 10),
+                segment("0100", "Y7", O, 1),
+                segment("0110", "V1", O, 1),
+                segment_loop(
+                    "R4",
+                    20,
+                    &[
+                        segment("0120", "R4", M, 1),
+                        segment("0130", "DTM", O, 15),
+                    ],
+                ),
+                segment("0140", "H3", O, 6),
+                segment_loop(
+                    "N1",
+                    10,
+                    &[
+                        segment("0150", "N1", O, 1),
+                        segment("0153", "N3", O, 2),
+                        segment("0156", "N4", O, 1),
+                    ],
+                ),
+                segment("0160", "K1", O, 2),
+                segment("0170", "N9", O, 10),
+                segment_loop(
+                    "L0",
+                    999,
+                    &[
+                        segment("0180", "L0", O, 1),
+                        segment("0190", "L5", O, 1),
+                        segment("0200", "H1", O, 3),
+                    ],
+                ),
+                segment("0210", "L3", O, 2),
+            ],
+        ),
+        segment("0220", "SE", M, 1),
+    ],
+    _404 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "ZC1", O, 1),
+        segment("0030", "BX", O, 1),
+        segment("0040", "BNX", O, 1),
+        segment("0050", "M3", M, 1),
+        segment("0060", "N9", M, 30),
+        segment("0070", "CM", O, 2),
+        segment("0080", "M1", O, 1),
+        segment("0090", "DTM", O, 5),
+        segment_loop(
+            "N7",
+            500,
+            &[
+                segment("0100", "N7", M, 1),
+                segment("0101", "EM", O, 1),
+                segment_loop(
+                    "VC",
+                    21,
+                    &[
+                        segment("0110", "VC", O, 1),
+                        segment_loop(
+                            "N1",
+                            2,
+                            &[
+                                segment("0112", "N1", O, 1),
+                                segment("0114", "N3", O, 2),
+                                segment("0116", "N4", O, 1),
+                                segment("0118", "H3", O, 1),
+                            ],
+                        ),
+                    ],
+                ),
+                segment("0130", "M7", O, 5),
+                segment("0140", "N5", O, 1),
+                segment("0150", "IC", O, 1),
+                segment("0160", "IM", O, 1),
+                segment("0170", "M12", O, 2),
+                segment_loop(
+                    "E1",
+                    2,
+                    &[
+                        segment("0171", "E1", O, 1),
+                        segment("0172", "E4", O, 1),
+                        segment("0173", "E5", O, 13),
+                        segment("0174", "PI", O, 1),
+                    ],
+                ),
+                segment("0175", "GA", O, 15),
+                segment_loop(
+                    "REF",
+                    99,
+                    &[
+                        segment("0177", "REF", O, 1),
+                        segment("0178", "N10", O, 15),
+                        segment_loop(
+                            "N1",
+                            5,
+                            &[
+                                segment("0179", "N1", O, 1),
+                                segment("0180", "N3", O, 1),
+                                segment("0182", "N4", O, 1),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("0185", "NA", O, 10),
+        segment("0190", "F9", M, 1),
+        segment("0200", "D9", M, 1),
+        segment_loop(
+            "N1",
+            10,
+            &[
+                segment("0210", "N1", M, 1),
+                segment("0215", "N2", O, 2),
+                segment("0220", "N3", O, 2),
+                segment("0230", "N4", O, 1),
+                segment("0235", "REF", O, 2),
+                segment("0240", "PER", O, 2),
+                segment("0252", "BL", O, 12),
+            ],
+        ),
+        segment_loop(
+            "S1",
+            12,
+            &[
+                segment("0430", "S1", O, 1),
+                segment("0440", "S2", O, 2),
+                segment("0448", "S9", O, 1),
+                segment("0449", "N1", O, 1),
+                segment("0450", "N2", O, 1),
+                segment("0451", "N3", O, 1),
+                segment("0452", "N4", O, 1),
+                segment("0453", "PER", O, 1),
+            ],
+        ),
+        segment("0460", "R2", O, 13),
+        segment("0480", "R9", O, 1),
+        segment_loop(
+            "E1",
+            2,
+            &[
+                segment("0490", "E1", O, 1),
+                segment("0500", "E4", O, 1),
+                segment("0510", "E5", O, 13),
+                segment("0511", "PI", O, 1),
+            ],
+        ),
+        segment("0520", "H3", O, 20),
+        segment("0530", "PS", O, 5),
+        segment_loop(
+            "LX",
+            25,
+            &[
+                segment("0540", "LX", M, 1),
+                segment("0550", "L5", M, 15),
+                segment_loop(
+                    "L0",
+                    25,
+                    &[
+                        segment("0570", "L0", O, 1),
+                        segment("0575", "MEA", O, 3),
+                        segment("0580", "L1", O, 10),
+                        segment("0590", "PI", O, 30),
+                    ],
+                ),
+                segment("0600", "X1", O, 6),
+            ],
+        ),
+        segment_loop(
+            "T1",
+            64,
+            &[
+                segment("0610", "T1", O, 1),
+                segment("0620", "T2", O, 30),
+                segment("0630", "T3", O, 12),
+                segment("0640", "T6", O, 1),
+                segment("0650", "T8", O, 99),
+            ],
+        ),
+        segment("0660", "L3", O, 1),
+        segment("0670", "LS", O, 1),
+        segment_loop(
+            "LH1",
+            100,
+            &[
+                segment("0680", "LH1", O, 1),
+                segment("0690", "LH2", O, 4),
+                segment("0700", "LH3", O, 10),
+                segment("0710", "LFH", O, 20),
+                segment("0720", "LEP", O, 3),
+                segment("0730", "LH4", O, 1),
+                segment("0740", "LHT", O, 3),
+                segment("0750", "LHR", O, 5),
+                segment("0755", "PER", O, 5),
+            ],
+        ),
+        segment("0760", "LE", O, 1),
+        segment("0770", "PER", O, 5),
+        segment("0780", "LH2", O, 6),
+        segment("0790", "LHR", O, 1),
+        segment("0800", "LH6", O, 5),
+        segment("0810", "XH", O, 1),
+        segment("0820", "X7", O, 10),
+        segment("0840", "SE", M, 1),
+    ],
+    _810 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "BIG", M, 1),
+        segment("0030", "NTE", O, 100),
+        segment("0040", "CUR", O, 1),
+        segment("0050", "REF", O, 12),
+        segment("0060", "YNQ", O, 10),
+        segment("0065", "PER", O, 3),
+        segment_loop(
+            "N1",
+            200,
+            &[
+                segment("0070", "N1", O, 1),
+                segment("0080", "N2", O, 2),
+                segment("0090", "N3", O, 2),
+                segment("0100", "N4", O, 1),
+                segment("0110", "REF", O, 12),
+                segment("0120", "PER", O, 3),
+                segment("0125", "DMG", O, 1),
+            ],
+        ),
+        segment("0130", "ITD", O, usize::MAX),
+        segment("0140", "DTM", O, 10),
+        segment("0150", "FOB", O, 1),
+        segment("0160", "PID", O, 200),
+        segment("0170", "MEA", O, 40),
+        segment("0180", "PWK", O, 25),
+        segment("0190", "PKG", O, 25),
+        segment("0200", "L7", O, 1),
+        segment("0212", "BAL", O, usize::MAX),
+        segment("0220", "INC", O, 1),
+        segment("0230", "PAM", O, usize::MAX),
+        segment_loop(
+            "LM",
+            10,
+            &[
+                segment("0240", "LM", O, 1),
+                segment_loop(
+                    "LQ",
+                    100,
+                    &[
+                        segment("0250", "LQ", M, 1),
+                    ],
+                ),
+            ],
+        ),
+        segment_loop(
+            "N9",
+            1,
+            &[
+                segment("0260", "N9", O, 1),
+                segment("0270", "MSG", O, 10),
+            ],
+        ),
+        segment_loop(
+            "V1",
+            usize::MAX,
+            &[
+                segment("0280", "V1", O, 1),
+                segment("0290", "R4", O, usize::MAX),
+                segment("0300", "DTM", O, usize::MAX),
+            ],
+        ),
+        segment_loop(
+            "FA1",
+            usize::MAX,
+            &[
+                segment("0310", "FA1", O, 1),
+                segment("0320", "FA2", M, usize::MAX),
+            ],
+        ),
+        segment_loop(
+            "IT1",
+            200000,
+            &[
+                segment("0010", "IT1", O, 1),
+                segment("0012", "CRC", O, 1),
+                segment("0015", "QTY", O, 5),
+                segment("0020", "CUR", O, 1),
+                segment("0030", "IT3", O, 5),
+                segment("0040", "TXI", O, 10),
+                segment("0050", "CTP", O, 25),
+                segment("0059", "PAM", O, 10),
+                segment("0060", "MEA", O, 40),
+                segment_loop(
+                    "PID",
+                    1000,
+                    &[
+                        segment("0070", "PID", O, 1),
+                        segment("0080", "MEA", O, 10),
+                    ],
+                ),
+                segment("0090", "PWK", O, 25),
+                segment("0100", "PKG", O, 25),
+                segment("0110", "PO4", O, 1),
+                segment("0120", "ITD", O, 2),
+                segment("0130", "REF", O, usize::MAX),
+                segment("0131", "YNQ", O, 10),
+                segment("0140", "PER", O, 5),
+                segment("0150", "SDQ", O, 500),
+                segment("0160", "DTM", O, 10),
+                segment("0170", "CAD", O, usize::MAX),
+                segment("0180", "L7", O, usize::MAX),
+                segment("0190", "SR", O, 1),
+                segment_loop(
+                    "SAC",
+                    25,
+                    &[
+                        segment("0200", "SAC", O, 1),
+                        segment("0210", "TXI", O, 10),
+                    ],
+                ),
+                segment_loop(
+                    "SLN",
+                    1000,
+                    &[
+                        segment("0220", "SLN", O, 1),
+                        segment("0230", "DTM", O, 1),
+                        segment("0240", "REF", O, usize::MAX),
+                        segment("0250", "PID", O, 1000),
+                        segment("0260", "SAC", O, 25),
+                        segment("0270", "TC2", O, usize::MAX),
+                        segment("0280", "TXI", O, 10),
+                    ],
+                ),
+                segment_loop(
+                    "N1",
+                    200,
+                    &[
+                        segment("0290", "N1", O, 1),
+                        segment("0300", "N2", O, 2),
+                        segment("0310", "N3", O, 2),
+                        segment("0320", "N4", O, 1),
+                        segment("0330", "REF", O, 12),
+                        segment("0340", "PER", O, 3),
+                        segment("0350", "DMG", O, 1),
+                    ],
+                ),
+                segment_loop(
+                    "LM",
+                    10,
+                    &[
+                        segment("0360", "LM", O, 1),
+                        segment_loop(
+                            "LQ",
+                            100,
+                            &[
+                                segment("0370", "LQ", M, 1),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "V1",
+                    usize::MAX,
+                    &[
+                        segment("0380", "V1", O, 1),
+                        segment("0390", "R4", O, usize::MAX),
+                        segment("0400", "DTM", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "FA1",
+                    usize::MAX,
+                    &[
+                        segment("0410", "FA1", O, 1),
+                        segment("0420", "FA2", M, usize::MAX),
+                    ],
+                ),
+            ],
+        ),
+        segment("0010", "TDS", M, 1),
+        segment("0020", "TXI", O, 10),
+        segment("0030", "CAD", O, 1),
+        segment("0035", "AMT", O, usize::MAX),
+        segment_loop(
+            "SAC",
+            25,
+            &[
+                segment("0040", "SAC", O, 1),
+                segment("0050", "TXI", O, 10),
+            ],
+        ),
+        segment_loop(
+            "ISS",
+            usize::MAX,
+            &[
+                segment("0060", "ISS", O, 1),
+                segment("0070", "PID", O, 1),
+            ],
+        ),
+        segment("0080", "CTT", O, 1),
+        segment("0090", "SE", M, 1),
+    ],
+    _856 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "BSN", M, 1),
+        segment("0040", "DTM", O, 10),
+        segment_loop(
+            "HL",
+            200000,
+            &[
+                segment("0010", "HL", M, 1),
+                segment("0020", "LIN", O, 1),
+                segment("0030", "SN1", O, 1),
+                segment("0040", "SLN", O, 1000),
+                segment("0050", "PRF", O, 1),
+                segment("0060", "PO4", O, 1),
+                segment("0070", "PID", O, 200),
+                segment("0080", "MEA", O, 40),
+                segment("0090", "PWK", O, 25),
+                segment("0100", "PKG", O, 25),
+                segment("0110", "TD1", O, 20),
+                segment("0120", "TD5", O, 12),
+                segment("0130", "TD3", O, 12),
+                segment("0140", "TD4", O, 5),
+                segment("0141", "TSD", O, 1),
+                segment("0150", "REF", O, 200),
+                segment("0151", "PER", O, 3),
+                segment_loop(
+                    "LH1",
+                    100,
+                    &[
+                        segment("0152", "LH1", O, 1),
+                        segment("0153", "LH2", O, 4),
+                        segment("0154", "LH3", O, 10),
+                        segment("0155", "LFH", O, 20),
+                        segment("0156", "LEP", O, 3),
+                        segment("0157", "LH4", O, 1),
+                        segment("0158", "LHT", O, 3),
+                        segment("0159", "LHR", O, 10),
+                        segment("0160", "PER", O, 5),
+                    ],
+                ),
+                segment_loop(
+                    "CLD",
+                    200,
+                    &[
+                        segment("0170", "CLD", O, 1),
+                        segment("0180", "REF", O, 200),
+                    ],
+                ),
+                segment("0190", "MAN", O, usize::MAX),
+                segment("0200", "DTM", O, 10),
+                segment("0210", "FOB", O, 1),
+                segment_loop(
+                    "N1",
+                    200,
+                    &[
+                        segment("0220", "N1", O, 1),
+                        segment("0230", "N2", O, 2),
+                        segment("0240", "N3", O, 2),
+                        segment("0250", "N4", O, 1),
+                        segment("0260", "REF", O, 12),
+                        segment("0270", "PER", O, 3),
+                        segment("0280", "FOB", O, 1),
+                    ],
+                ),
+                segment("0290", "SDQ", O, 50),
+                segment("0300", "ETD", O, 1),
+                segment("0310", "CUR", O, 1),
+                segment_loop(
+                    "SAC",
+                    25,
+                    &[
+                        segment("0320", "SAC", O, 1),
+                        segment("0325", "CUR", O, 1),
+                    ],
+                ),
+                segment("0330", "GF", O, 1),
+                segment("0335", "YNQ", O, 10),
+                segment_loop(
+                    "LM",
+                    10,
+                    &[
+                        segment("0340", "LM", O, 1),
+                        segment_loop(
+                            "LQ",
+                            100,
+                            &[
+                                segment("0350", "LQ", M, 1),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "V1",
+                    usize::MAX,
+                    &[
+                        segment("0360", "V1", O, 1),
+                        segment("0370", "R4", O, usize::MAX),
+                        segment("0380", "DTM", O, usize::MAX),
+                    ],
+                ),
+            ],
+        ),
+        segment("0010", "CTT", O, 1),
+        segment("0020", "SE", M, 1),
+    ],
+    _940 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "W05", M, 1),
+        segment_loop(
+            "N1",
+            10,
+            &[
+                segment("0040", "N1", O, 1),
+                segment("0050", "N2", O, 2),
+                segment("0060", "N3", O, 2),
+                segment("0070", "N4", O, 1),
+                segment("0080", "PER", O, 3),
+            ],
+        ),
+        segment("0090", "N9", O, 10),
+        segment("0100", "G61", O, 3),
+        segment("0110", "G62", O, 2),
+        segment("0120", "NTE", O, 10),
+        segment("0130", "W09", O, 1),
+        segment("0140", "W66", M, 1),
+        segment("0150", "W6", O, 1),
+        segment("0153", "R2", O, 12),
+        segment("0156", "BNX", O, 1),
+        segment_loop(
+            "LM",
+            10,
+            &[
+                segment("0160", "LM", O, 1),
+                segment_loop(
+                    "LQ",
+                    100,
+                    &[
+                        segment("0170", "LQ", M, 1),
+                    ],
+                ),
+            ],
+        ),
+        segment_loop(
+            "LX",
+            9999,
+            &[
+                segment("0005", "LX", M, 1),
+                segment("0010", "MAN", O, usize::MAX),
+                segment("0015", "SDQ", O, 50),
+                segment("0016", "N1", O, 1),
+                segment("0017", "G62", O, 2),
+                segment_loop(
+                    "W01",
+                    9999,
+                    &[
+                        segment("0020", "W01", M, 1),
+                        segment("0030", "G69", O, 5),
+                        segment("0040", "N9", O, 200),
+                        segment("0045", "NTE", O, 10),
+                        segment("0050", "W20", O, 1),
+                        segment("0070", "QTY", O, 5),
+                        segment("0080", "AMT", O, 1),
+                        segment("0090", "G62", O, 2),
+                        segment("0100", "G66", O, 1),
+                        segment("0110", "N1", O, 1),
+                        segment("0112", "PER", O, 3),
+                        segment("0114", "LH2", O, 6),
+                        segment("0116", "LHR", O, 1),
+                        segment("0118", "LH6", O, 5),
+                        segment_loop(
+                            "LM",
+                            10,
+                            &[
+                                segment("0120", "LM", O, 1),
+                                segment_loop(
+                                    "LQ",
+                                    100,
+                                    &[
+                                        segment("0130", "LQ", M, 1),
+                                    ],
+                                ),
+                                segment("0135", "LS", O, 1),
+                                segment_loop(
+                                    "LX",
+                                    999,
+                                    &[
+                                        segment("0140", "LX", O, 1),
+                                        segment("0150", "N9", O, 10),
+                                        segment("0160", "G62", O, 2),
+                                        segment("0170", "N1", O, 1),
+                                        segment("0175", "SDQ", O, 50),
+                                        segment_loop(
+                                            "LM",
+                                            10,
+                                            &[
+                                                segment("0180", "LM", O, 1),
+                                                segment_loop(
+                                                    "LQ",
+                                                    100,
+                                                    &[
+                                                        segment("0190", "LQ", M, 1),
+                                                    ],
+                                                ),
+                                                segment_loop(
+                                                    "LH1",
+                                                    100,
+                                                    &[
+                                                        segment("0200", "LH1", O, 1),
+                                                        segment("0210", "LH2", O, 4),
+                                                        segment("0220", "LH3", O, 10),
+                                                        segment("0230", "LFH", O, 20),
+                                                        segment("0240", "LEP", O, 3),
+                                                        segment("0250", "LH4", O, 1),
+                                                        segment("0260", "LHT", O, 3),
+                                                        segment("0270", "LHR", O, 10),
+                                                        segment("0280", "PER", O, 5),
+                                                        segment("0285", "LE", O, 1),
+                                                        segment_loop(
+                                                            "FA1",
+                                                            usize::MAX,
+                                                            &[
+                                                                segment("0290", "FA1", O, 1),
+                                                                segment("0300", "FA2", M, usize::MAX),
+                                                            ],
+                                                        ),
+                                                    ],
+                                                ),
+                                            ],
+                                        ),
+                                    ],
+                                ),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("0010", "W76", O, 1),
+        segment("0020", "SE", M, 1),
+    ],
+    _945 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "W06", M, 1),
+        segment_loop(
+            "N1",
+            10,
+            &[
+                segment("0040", "N1", O, 1),
+                segment("0050", "N2", O, 2),
+                segment("0060", "N3", O, 2),
+                segment("0070", "N4", O, 1),
+                segment("0080", "PER", O, 3),
+            ],
+        ),
+        segment("0090", "N9", O, 30),
+        segment("0100", "G61", O, 3),
+        segment("0110", "G62", O, 10),
+        segment("0120", "NTE", O, 10),
+        segment("0130", "W27", M, 1),
+        segment("0140", "W6", O, 1),
+        segment("0150", "W28", O, 1),
+        segment("0160", "W10", O, 10),
+        segment("0170", "G72", O, 10),
+        segment_loop(
+            "LM",
+            10,
+            &[
+                segment("0180", "LM", O, 1),
+                segment_loop(
+                    "LQ",
+                    100,
+                    &[
+                        segment("0190", "LQ", M, 1),
+                    ],
+                ),
+            ],
+        ),
+        segment_loop(
+            "LX",
+            9999,
+            &[
+                segment("0010", "LX", M, 1),
+                segment("0020", "MAN", O, 10),
+                segment("0030", "PAL", O, 1),
+                segment("0040", "N9", O, 10),
+                segment_loop(
+                    "W12",
+                    9999,
+                    &[
+                        segment("0050", "W12", M, 1),
+                        segment("0060", "G69", O, 5),
+                        segment("0070", "N9", O, 20),
+                        segment("0080", "G62", O, 2),
+                        segment("0090", "QTY", O, 5),
+                        segment("0100", "MEA", O, 5),
+                        segment("0110", "AMT", O, 1),
+                        segment("0120", "R4", O, 5),
+                        segment("0130", "W27", O, 1),
+                        segment("0140", "N1", O, 10),
+                        segment("0150", "G72", O, 10),
+                        segment_loop(
+                            "LM",
+                            10,
+                            &[
+                                segment("0160", "LM", O, 1),
+                                segment_loop(
+                                    "LQ",
+                                    100,
+                                    &[
+                                        segment("0170", "LQ", M, 1),
+                                    ],
+                                ),
+                            ],
+                        ),
+                        segment("0180", "LS", O, 1),
+                        segment_loop(
+                            "LX",
+                            999,
+                            &[
+                                segment("0190", "LX", O, 1),
+                                segment("0200", "N9", O, 10),
+                                segment("0210", "G62", O, 2),
+                                segment("0220", "N1", O, 1),
+                                segment_loop(
+                                    "LM",
+                                    10,
+                                    &[
+                                        segment("0230", "LM", O, 1),
+                                        segment_loop(
+                                            "LQ",
+                                            100,
+                                            &[
+                                                segment("0240", "LQ", M, 1),
+                                            ],
+                                        ),
+                                    ],
+                                ),
+                            ],
+                        ),
+                        segment("0250", "LE", O, 1),
+                        segment_loop(
+                            "FA1",
+                            usize::MAX,
+                            &[
+                                segment("0260", "FA1", O, 1),
+                                segment("0270", "FA2", M, usize::MAX),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("0010", "W03", M, 1),
+        segment("0020", "SE", M, 1),
+    ],
+    _997 => [
+        segment("0010", "ST", M, 1),
+        segment("0020", "AK1", M, 1),
+        segment_loop(
+            "AK2",
+            999999,
+            &[
+                segment("0030", "AK2", O, 1),
+                segment_loop(
+                    "AK3",
+                    999999,
+                    &[
+                        segment("0040", "AK3", O, 1),
+                        segment("0050", "AK4", O, 99),
+                    ],
+                ),
+                segment("0060", "AK5", M, 1),
+            ],
+        ),
+        segment("0070", "AK9", M, 1),
+        segment("0080", "SE", M, 1),
+    ],
+    _998 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "ZD", M, 1),
+        segment("0300", "SE", M, 1),
+    ],
+}
@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// AK1 - Functional Group Response Trailer
@@ -10,11 +12,35 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 01 | 479 | Functional Identifier Code | 1 | M | ID | 2/2
 /// 02 | 28 | Group Control Number | 1 | M | N0 | 1/9
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AK1")]
 pub struct AK1 {
+    #[validate(
+        length(min = 1, message = "AK1-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "AK1-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "AK1-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 9>",
+            message = "AK1-02 must be a number of 1-9 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -28,11 +54,35 @@ pub struct AK1 {
 /// 01 | 143 | Transaction Set Identifier Code | 1 | M | ID | 3/3
 /// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AK2")]
 pub struct AK2 {
+    #[validate(
+        length(min = 1, message = "AK2-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "AK2-01 must be 3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "AK2-02 is mandatory"),
+        custom(
+            function = "datatype::string::<4, 9>",
+            message = "AK2-02 must be 4-9 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -48,15 +98,47 @@ pub struct AK2 {
 /// 03 | 447 | Loop Identifier Code | 1 | O | AN | 1/6
 /// 04 | 720 | Segment Syntax Error Code | 1 | O | ID | 1/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AK3")]
 pub struct AK3 {
+    #[validate(
+        length(min = 1, message = "AK3-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "AK3-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "AK3-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 6>",
+            message = "AK3-02 must be a number of 1-6 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 6>",
+        message = "AK3-03 must be 1-6 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK3-04 must be 1-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -72,15 +154,33 @@ pub struct AK3 {
 /// 03 | 724 | Data Element Syntax Error Code | 1 | O | ID | 1/3
 /// 04 | 725 | Copy of Bad Data Element | 1 | O | AN | 1/99
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AK4")]
 pub struct AK4 {
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 4>",
+        message = "AK4-02 must be a number of 1-4 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 99>",
+        message = "AK4-04 must be 1-99 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -98,19 +198,56 @@ pub struct AK4 {
 /// 05 | 718 | Transaction Set Syntax Error Code | 1 | O | ID | 1/3
 /// 06 | 718 | Transaction Set Syntax Error Code | 1 | O | ID | 1/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AK5")]
 pub struct AK5 {
+    #[validate(
+        length(min = 1, message = "AK5-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "AK5-01 must be 1 character"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK5-02 must be 1-3 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK5-03 must be 1-3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK5-04 must be 1-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK5-05 must be 1-3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK5-06 must be 1-3 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
 }
@@ -131,25 +268,83 @@ pub struct AK5 {
 /// 08 | 716 | Functional Group Syntax Error Code | 1 | O | ID | 1/3
 /// 09 | 716 | Functional Group Syntax Error Code | 1 | O | ID | 1/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AK9")]
 pub struct AK9 {
+    #[validate(
+        length(min = 1, message = "AK9-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "AK9-01 must be 1 character"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "AK9-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 6>",
+            message = "AK9-02 must be a number of 1-6 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "AK9-03 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 9>",
+            message = "AK9-03 must be a number of 1-9 digits"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(
+        length(min = 1, message = "AK9-04 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 9>",
+            message = "AK9-04 must be a number of 1-9 digits"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK9-05 must be 1-3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK9-06 must be 1-3 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK9-07 must be 1-3 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK9-08 must be 1-3 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "AK9-09 must be 1-3 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
 }
@@ -164,13 +359,41 @@ pub struct AK9 {
 /// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
 /// 03 | 478 | Credit/Debit Flag Code | 1 | O | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AMT")]
 pub struct AMT {
+    #[validate(
+        length(min = 1, message = "AMT-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 3>",
+            message = "AMT-01 must be 1-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "AMT-02 is mandatory"),
+        custom(
+            function = "datatype::decimal::<1, 18>",
+            message = "AMT-02 must be a decimal number of 1-18 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "AMT-03 must be 1 character"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
 }
@@ -185,13 +408,35 @@ pub struct AMT {
 /// 02 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 03 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AT5")]
 pub struct AT5 {
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT5-01 must be 1-30 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT5-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT5-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
 }
@@ -210,15 +455,41 @@ pub struct AT5 {
 /// 06 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 07 | 623 | Time Code | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AT7")]
 pub struct AT7 {
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT7-01 must be 1-30 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT7-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT7-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT7-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
     /// 373 - Date
@@ -227,8 +498,16 @@ pub struct AT7 {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "AT7-05 must be a date of 8 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT7-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
     /// 623 - Time Code
@@ -237,6 +516,10 @@ pub struct AT7 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "AT7-07 must be 2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -255,21 +538,59 @@ pub struct AT7 {
 /// 06 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 07 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AT8")]
 pub struct AT8 {
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT8-01 must be 1-30 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT8-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT8-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT8-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT8-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT8-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "AT8-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// B1 - Beginning Segment for Booking or Pick-up/Delivery
@@ -12,15 +14,47 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 03 | 373 | Date | 1 | O | DT | 8/8
 /// 04 | 284 | Reservation Action Code | 1 | M | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::B1")]
 pub struct B1 {
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "B1-01 must be 2-4 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(
+        length(min = 1, message = "B1-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "B1-02 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "B1-03 must be a date of 8 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(
+        length(min = 1, message = "B1-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "B1-04 must be 1 character"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
 }
@@ -44,31 +78,92 @@ pub struct B1 {
 /// 11 | 284 | Shipment Identification Number | 1 | O | AN | 1/30
 /// 12 | 284 | Shipment Identification Number | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::B2")]
 pub struct B2 {
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "B2-01 must be 2-4 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B2-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B2-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "B2-04 must be a date of 8 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B2-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(
+        length(min = 1, message = "B2-06 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "B2-06 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "06")]
     pub _06: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B2-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B2-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B2-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B2-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B2-11 must be 1-30 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B2-12 must be 1-30 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
 }
@@ -82,11 +177,32 @@ pub struct B2 {
 /// 01 | 353 | Transaction Set Purpose Code | 1 | M | ID | 2/2
 /// 02 | 786 | Application Type | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::B2A")]
 pub struct B2A {
+    #[validate(
+        length(min = 1, message = "B2A-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "B2A-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "B2A-02 must be 2 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
 }
@@ -112,35 +228,116 @@ pub struct B2A {
 /// 13 | 284 | Shipment Identification Number | 1 | O | AN | 1/30
 /// 14 | 284 | Shipment Identification Number | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::B3")]
 pub struct B3 {
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "B3-01 must be 2-4 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(
+        length(min = 1, message = "B3-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "B3-02 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B3-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(
+        length(min = 1, message = "B3-04 is mandatory"),
+        custom(
+            function = "datatype::date::<8, 8>",
+            message = "B3-04 must be a date of 8 digits"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B3-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(
+        length(min = 1, message = "B3-06 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "B3-06 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "06")]
     pub _06: String,
+    #[validate(
+        length(min = 1, message = "B3-07 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "B3-07 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B3-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B3-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B3-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(
+        length(min = 1, message = "B3-11 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "B3-11 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "11")]
     pub _11: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B3-12 must be 1-30 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B3-13 must be 1-30 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B3-14 must be 1-30 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
 }
@@ -165,8 +362,18 @@ pub struct B3 {
 /// 12 | 284 | Shipment Identification Number | 1 | O | AN | 1/30
 /// 13 | 284 | Shipment Identification Number | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::B4")]
 pub struct B4 {
     /// 152 - Special Handling Code
     ///
@@ -174,10 +381,22 @@ pub struct B4 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "B4-01 must be 2-3 characters"
+    ))]
     #[serde(rename = "01", skip_serializing_if = "Option::is_none")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02", skip_serializing_if = "Option::is_none")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03", skip_serializing_if = "Option::is_none")]
     pub _03: Option<String>,
     /// 373 - Date
@@ -186,24 +405,64 @@ pub struct B4 {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "B4-04 must be a date of 8 digits"
+    ))]
     #[serde(rename = "04", skip_serializing_if = "Option::is_none")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05", skip_serializing_if = "Option::is_none")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06", skip_serializing_if = "Option::is_none")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07", skip_serializing_if = "Option::is_none")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08", skip_serializing_if = "Option::is_none")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09", skip_serializing_if = "Option::is_none")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10", skip_serializing_if = "Option::is_none")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-11 must be 1-30 characters"
+    ))]
     #[serde(rename = "11", skip_serializing_if = "Option::is_none")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-12 must be 1-30 characters"
+    ))]
     #[serde(rename = "12", skip_serializing_if = "Option::is_none")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B4-13 must be 1-30 characters"
+    ))]
     #[serde(rename = "13", skip_serializing_if = "Option::is_none")]
     pub _13: Option<String>,
 }
@@ -222,21 +481,62 @@ pub struct B4 {
 /// 06 | 284 | Shipment Identification Number | 1 | O | AN | 1/30
 /// 07 | 284 | Shipment Identification Number | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::B10")]
 pub struct B10 {
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "B10-01 must be 2-4 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B10-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(
+        length(min = 1, message = "B10-03 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "B10-03 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B10-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B10-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B10-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "B10-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -251,13 +551,44 @@ pub struct B10 {
 /// 02 | 782 | Monetary Amount | 1 | M | R | 1/18
 /// 03 | 478 | Credit/Debit Flag Code | 1 | M | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BAL")]
 pub struct BAL {
+    #[validate(
+        length(min = 1, message = "BAL-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "BAL-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "BAL-02 is mandatory"),
+        custom(
+            function = "datatype::decimal::<1, 18>",
+            message = "BAL-02 must be a decimal number of 1-18 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "BAL-03 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "BAL-03 must be 1 character"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
 }
@@ -280,29 +611,89 @@ pub struct BAL {
 /// 10 | 324 | Action Code | 1 | O | ID | 1/2
 /// 11 | 324 | Invoice Number | 1 | O | AN | 1/22
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BIG")]
 pub struct BIG {
+    #[validate(
+        length(min = 1, message = "BIG-01 is mandatory"),
+        custom(
+            function = "datatype::date::<8, 8>",
+            message = "BIG-01 must be a date of 8 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "BIG-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 22>",
+            message = "BIG-02 must be 1-22 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "BIG-03 must be a date of 8 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 22>",
+        message = "BIG-04 must be 1-22 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "BIG-05 must be a date of 8 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BIG-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 8>",
+        message = "BIG-07 must be 1-8 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "BIG-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "BIG-09 must be 2 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "BIG-10 must be 1-2 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 22>",
+        message = "BIG-11 must be 1-22 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
 }
@@ -316,9 +707,26 @@ pub struct BIG {
 /// 01 | 1000 | Binary Data | 1 | M | B | 1/99999
 /// 02 | 1001 | Binary Data | 1 | O | B | 1/99999
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BIN")]
 pub struct BIN {
+    #[validate(
+        length(min = 1, message = "BIN-01 is mandatory"),
+        custom(
+            function = "datatype::length::<1, 99999>",
+            message = "BIN-01 must be 1-99999 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     #[serde(rename = "02")]
@@ -349,15 +757,44 @@ pub struct BIN {
 /// 16 | 284 | Shipment Identification Number | 1 | O | AN | 1/30
 /// 17 | 284 | Shipment Identification Number | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BL")]
 pub struct BL {
+    #[validate(
+        length(min = 1, message = "BL-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "BL-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BL-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BL-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BL-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
     /// 19 - City Name
@@ -366,8 +803,16 @@ pub struct BL {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "BL-05 must be 2-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "BL-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
     /// 26 - Country Code
@@ -376,8 +821,16 @@ pub struct BL {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "BL-07 must be 2-3 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BL-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
     /// 19 - City Name
@@ -386,8 +839,16 @@ pub struct BL {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "BL-09 must be 2-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "BL-10 must be 2 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
     /// 26 - Country Code
@@ -396,18 +857,46 @@ pub struct BL {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "BL-11 must be 2-3 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BL-12 must be 1-30 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BL-13 must be 1-30 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BL-14 must be 1-30 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BL-15 must be 1-30 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BL-16 must be 1-30 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BL-17 must be 1-30 characters"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
 }
@@ -423,15 +912,41 @@ pub struct BL {
 /// 03 | 284 | Vessel Code | 1 | O | AN | 1/30
 /// 04 | 284 | Vessel Name | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BNX")]
 pub struct BNX {
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BNX-01 must be 1-30 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BNX-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BNX-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BNX-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -457,35 +972,110 @@ pub struct BNX {
 /// 13 | 284 | Vessel Code | 1 | O | AN | 1/30
 /// 14 | 284 | Vessel Name | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BX")]
 pub struct BX {
+    #[validate(
+        length(min = 1, message = "BX-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "BX-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "BX-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "BX-02 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "BX-03 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "BX-03 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-11 must be 1-30 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-12 must be 1-30 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-13 must be 1-30 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "BX-14 must be 1-30 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
 }
@@ -502,17 +1092,56 @@ pub struct BX {
 /// 04 | 1000 | Hierarchical Structure Code | 1 | O | ID | 4/4
 /// 05 | 1001 | Transaction Set Purpose Code | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BSN")]
 pub struct BSN {
+    #[validate(
+        length(min = 1, message = "BSN-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 30>",
+            message = "BSN-01 must be 2-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "BSN-02 is mandatory"),
+        custom(
+            function = "datatype::date::<8, 8>",
+            message = "BSN-02 must be a date of 8 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "BSN-03 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 8>",
+            message = "BSN-03 must be a time of 4-8 digits"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::string::<4, 4>",
+        message = "BSN-04 must be 4 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "BSN-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
 }
@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// C2 - Currency
@@ -15,21 +17,54 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 06 | 100 | Exchange Rate | 1 | O | R | 1/15
 /// 07 | 100 | Currency Code | 1 | O | ID | 3/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::C2")]
 pub struct C2 {
+    #[validate(
+        length(min = 1, message = "C2-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "C2-01 must be 3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     #[serde(rename = "02")]
     pub _02: String,
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "C2-04 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "C2-05 must be 3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "C2-06 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "C2-07 must be 3 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -45,15 +80,44 @@ pub struct C2 {
 /// 03 | 100 | Currency Code | 1 | O | ID | 3/3
 /// 04 | 100 | Exchange Rate | 1 | O | R | 1/15
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::C3")]
 pub struct C3 {
+    #[validate(
+        length(min = 1, message = "C3-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "C3-01 must be 3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "C3-02 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "C3-03 must be 3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "C3-04 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -69,15 +133,41 @@ pub struct C3 {
 /// 03 | 100 | Certificate Number | 1 | O | AN | 1/30
 /// 04 | 100 | Certificate Number | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::C8")]
 pub struct C8 {
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "C8-01 must be 2 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "C8-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "C8-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "C8-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -92,13 +182,38 @@ pub struct C8 {
 /// 02 | 100 | Certificate Number | 1 | O | AN | 1/30
 /// 03 | 100 | Certificate Number | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::C8C")]
 pub struct C8C {
+    #[validate(
+        length(min = 1, message = "C8C-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "C8C-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "C8C-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "C8C-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
 }
@@ -119,25 +234,74 @@ pub struct C8C {
 /// 08 | 100 | Reference Identification | 1 | O | AN | 1/30
 /// 09 | 100 | Service Level Code | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CAD")]
 pub struct CAD {
+    #[validate(
+        length(min = 1, message = "CAD-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 2>",
+            message = "CAD-01 must be 1-2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "CAD-02 must be 1-4 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CAD-03 must be 1-10 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "CAD-04 must be 2-4 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 35>",
+        message = "CAD-05 must be 1-35 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "CAD-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CAD-07 must be 2-3 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CAD-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "CAD-09 must be 2 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
 }
@@ -163,33 +327,95 @@ pub struct CAD {
 /// 13 | 100 | Equipment Number | 1 | O | AN | 1/10
 /// 14 | 100 | Country Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CD3")]
 pub struct CD3 {
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "CD3-01 must be 1-4 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-02 must be 1-10 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<4, 4>",
+        message = "CD3-03 must be 4 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-04 must be 1-10 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-05 must be 1-10 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-06 must be 1-10 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-07 must be 1-10 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-08 must be 1-10 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-09 must be 1-10 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-10 must be 1-10 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-11 must be 1-10 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-12 must be 1-10 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CD3-13 must be 1-10 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
     /// 26 - Country Code
@@ -198,6 +424,10 @@ pub struct CD3 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CD3-14 must be 2-3 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
 }
@@ -226,13 +456,35 @@ pub struct CD3 {
 /// 16 | 100 | Equipment Number | 1 | O | AN | 1/10
 /// 17 | 100 | Equipment Number | 1 | O | AN | 1/10
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CM")]
 pub struct CM {
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "CM-01 must be 1-4 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-02 must be 1-10 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<4, 4>",
+        message = "CM-03 must be 4 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
     /// 373 - Date
@@ -241,12 +493,28 @@ pub struct CM {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "CM-04 must be a date of 8 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-05 must be 1-10 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-06 must be 1-10 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-07 must be 1-10 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
     /// 373 - Date
@@ -255,16 +523,40 @@ pub struct CM {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "CM-08 must be a date of 8 digits"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-09 must be 1-10 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-10 must be 1-10 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-11 must be 1-10 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-12 must be 1-10 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-13 must be 1-10 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
     /// 26 - Country Code
@@ -273,12 +565,28 @@ pub struct CM {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CM-14 must be 2-3 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-15 must be 1-10 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-16 must be 1-10 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "CM-17 must be 1-10 characters"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
 }
@@ -297,21 +605,68 @@ pub struct CM {
 /// 06 | 100 | Condition Indicator | 1 | O | ID | 2/3
 /// 07 | 100 | Condition Indicator | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CRC")]
 pub struct CRC {
+    #[validate(
+        length(min = 1, message = "CRC-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "CRC-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "CRC-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "CRC-02 must be 1 character"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "CRC-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "CRC-03 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CRC-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CRC-05 must be 2-3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CRC-06 must be 2-3 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CRC-07 must be 2-3 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -334,29 +689,83 @@ pub struct CRC {
 /// 10 | 100 | Condition Value | 1 | O | R | 1/10
 /// 11 | 100 | Multiple Price Quantity | 1 | O | N0 | 1/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CTP")]
 pub struct CTP {
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "CTP-01 must be 2 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "CTP-02 must be 3 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 17>",
+        message = "CTP-03 must be a decimal number of 1-17 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "CTP-04 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "CTP-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "CTP-06 must be 3 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "CTP-07 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 18>",
+        message = "CTP-08 must be a decimal number of 1-18 digits"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "CTP-09 must be 2 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "CTP-10 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 2>",
+        message = "CTP-11 must be a number of 1-2 digits"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
 }
@@ -375,21 +784,62 @@ pub struct CTP {
 /// 06 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
 /// 07 | 352 | Description | 1 | O | AN | 1/80
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CTT")]
 pub struct CTT {
+    #[validate(
+        length(min = 1, message = "CTT-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 6>",
+            message = "CTT-01 must be a number of 1-6 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "CTT-02 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "CTT-03 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "CTT-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "CTT-05 must be a decimal number of 1-8 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "CTT-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "CTT-07 must be 1-80 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -422,49 +872,149 @@ pub struct CTT {
 /// 20 | 100 | Currency Code | 1 | O | ID | 3/3
 /// 21 | 280 | Exchange Rate | 1 | O | R | 1/15
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CUR")]
 pub struct CUR {
+    #[validate(
+        length(min = 1, message = "CUR-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "CUR-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "CUR-02 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "CUR-02 must be 3 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "CUR-03 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CUR-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "CUR-05 must be 3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "CUR-06 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CUR-07 must be 2-3 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "CUR-08 must be 3 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "CUR-09 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CUR-10 must be 2-3 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "CUR-11 must be 3 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "CUR-12 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CUR-13 must be 2-3 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "CUR-14 must be 3 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "CUR-15 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CUR-16 must be 2-3 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "CUR-17 must be 3 characters"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "CUR-18 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "CUR-19 must be 2-3 characters"
+    ))]
     #[serde(rename = "19")]
     pub _19: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "CUR-20 must be 3 characters"
+    ))]
     #[serde(rename = "20")]
     pub _20: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "CUR-21 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "21")]
     pub _21: Option<String>,
 }
@@ -496,47 +1046,143 @@ pub struct CUR {
 /// 19 | 100 | Commodity Code | 1 | O | AN | 1/30
 /// 20 | 100 | Commodity Code | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CLD")]
 pub struct CLD {
+    #[validate(
+        length(min = 1, message = "CLD-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 10>",
+            message = "CLD-01 must be a number of 1-10 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "CLD-02 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "CLD-02 must be 3 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 7>",
+        message = "CLD-03 must be a number of 1-7 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "CLD-04 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "CLD-05 must be 1 character"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "CLD-06 must be a decimal number of 1-8 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "CLD-07 must be 1 character"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "CLD-08 must be 1-80 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 6>",
+        message = "CLD-09 must be a number of 1-6 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-11 must be 1-30 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-12 must be 1-30 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-13 must be 1-30 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-14 must be 1-30 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-15 must be 1-30 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-16 must be 1-30 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-17 must be 1-30 characters"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-18 must be 1-30 characters"
+    ))]
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-19 must be 1-30 characters"
+    ))]
     #[serde(rename = "19")]
     pub _19: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "CLD-20 must be 1-30 characters"
+    ))]
     #[serde(rename = "20")]
     pub _20: Option<String>,
 }
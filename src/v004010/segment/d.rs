@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// D9 - Route Carrier
@@ -20,9 +22,23 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 11 | 100 | Standard Carrier Alpha Code | 1 | O | ID | 2/4
 /// 12 | 26 | Country Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::D9")]
 pub struct D9 {
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "D9-01 must be 2-4 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
     /// 19 - City Name
@@ -31,8 +47,19 @@ pub struct D9 {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "D9-02 must be 2-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(
+        length(min = 1, message = "D9-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "D9-03 must be 2 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
     /// 26 - Country Code
@@ -41,8 +68,16 @@ pub struct D9 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "D9-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "D9-05 must be 2-4 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
     /// 19 - City Name
@@ -51,16 +86,40 @@ pub struct D9 {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "D9-06 must be 2-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "D9-07 must be 2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "D9-08 must be 2-4 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "D9-09 must be 2-4 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "D9-10 must be 2-4 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "D9-11 must be 2-4 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
     /// 26 - Country Code
@@ -69,6 +128,10 @@ pub struct D9 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "D9-12 must be 2-3 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
 }
@@ -89,25 +152,71 @@ pub struct D9 {
 /// 08 | 659 | Basis of Verification Code | 1 | O | ID | 1/2
 /// 09 | 380 | Quantity | 1 | O | R | 1/15
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DMG")]
 pub struct DMG {
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "DMG-01 must be 2-3 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 35>",
+        message = "DMG-02 must be 1-35 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "DMG-03 must be 1 character"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "DMG-04 must be 1 character"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "DMG-05 must be 1 character"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "DMG-06 must be 1 character"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "DMG-07 must be 2-3 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "DMG-08 must be 1-2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "DMG-09 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
 }
@@ -125,8 +234,18 @@ pub struct DMG {
 /// 05 | 1250 | Date Time Period Format Qualifier | 1 | O | ID | 2/3
 /// 06 | 1251 | Date Time Period | 1 | O | AN | 1/35
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DTM")]
 pub struct DTM {
     /// 374 - Date/Time Qualifier
     ///
@@ -134,6 +253,13 @@ pub struct DTM {
     /// - TYPE=ID
     /// - MIN=3
     /// - MAX=3
+    #[validate(
+        length(min = 1, message = "DTM-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "DTM-01 must be 3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 373 - Date
@@ -142,6 +268,10 @@ pub struct DTM {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "DTM-02 must be a date of 8 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
     /// 337 - Time
@@ -150,6 +280,10 @@ pub struct DTM {
     /// - TYPE=TM
     /// - MIN=4
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::time::<4, 8>",
+        message = "DTM-03 must be a time of 4-8 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
     /// 623 - Time Code
@@ -158,10 +292,22 @@ pub struct DTM {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "DTM-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "DTM-05 must be 2-3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 35>",
+        message = "DTM-06 must be 1-35 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
 }
@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// E1 - Expiration
@@ -11,13 +13,38 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 02 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 /// 03 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::E1")]
 pub struct E1 {
+    #[validate(
+        length(min = 1, message = "E1-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "E1-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "E1-02 must be 2-3 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "E1-03 must be 2-3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
 }
@@ -33,8 +60,18 @@ pub struct E1 {
 /// 03 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 /// 04 | 26 | Country Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::E4")]
 pub struct E4 {
     /// 19 - City Name
     ///
@@ -42,10 +79,28 @@ pub struct E4 {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(
+        length(min = 1, message = "E4-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 30>",
+            message = "E4-01 must be 2-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "E4-02 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "E4-02 must be 2 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "E4-03 must be 2-3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
     /// 26 - Country Code
@@ -54,6 +109,10 @@ pub struct E4 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "E4-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -69,11 +128,35 @@ pub struct E4 {
 /// 03 | 19 | City Name | 1 | O | AN | 2/30
 /// 04 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::E5")]
 pub struct E5 {
+    #[validate(
+        length(min = 1, message = "E5-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "E5-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "E5-02 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "E5-02 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
     /// 19 - City Name
@@ -82,8 +165,16 @@ pub struct E5 {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "E5-03 must be 2-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "E5-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -98,13 +189,38 @@ pub struct E5 {
 /// 02 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 /// 03 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::EA")]
 pub struct EA {
+    #[validate(
+        length(min = 1, message = "EA-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "EA-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EA-02 must be 2-3 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EA-03 must be 2-3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
 }
@@ -132,39 +248,116 @@ pub struct EA {
 /// 15 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 /// 16 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::EFI")]
 pub struct EFI {
+    #[validate(
+        length(min = 1, message = "EFI-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "EFI-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-02 must be 2-3 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-03 must be 2-3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-05 must be 2-3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-06 must be 2-3 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-07 must be 2-3 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-08 must be 2-3 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-09 must be 2-3 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-10 must be 2-3 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-11 must be 2-3 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-12 must be 2-3 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-13 must be 2-3 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-14 must be 2-3 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-15 must be 2-3 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EFI-16 must be 2-3 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
 }
@@ -183,15 +376,41 @@ pub struct EFI {
 /// 06 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 /// 07 | 373 | Date | 1 | O | DT | 8/8
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::EM")]
 pub struct EM {
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EM-01 must be 2-3 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EM-02 must be 2-3 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EM-03 must be 2-3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EM-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
     /// 26 - Country Code
@@ -200,8 +419,16 @@ pub struct EM {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EM-05 must be 2-3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "EM-06 must be 2-3 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
     /// 373 - Date
@@ -210,6 +437,10 @@ pub struct EM {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "EM-07 must be a date of 8 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -231,27 +462,77 @@ pub struct EM {
 /// 09 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 /// 10 | 100 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ETD")]
 pub struct ETD {
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "ETD-01 must be 2-3 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "ETD-02 must be 2-3 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "ETD-03 must be 2-3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "ETD-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "ETD-05 must be 2-3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "ETD-06 must be 2-3 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "ETD-07 must be 2-3 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "ETD-08 must be 2-3 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "ETD-09 must be 2-3 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "ETD-10 must be 2-3 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -1,12 +1,24 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// F9 - Origin Station
 ///
 /// To identify the station where the shipment originates
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::F9")]
 pub struct F9 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -16,6 +28,10 @@ pub struct F9 {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "F9-02 must be 2-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
     #[serde(rename = "03")]
@@ -26,6 +42,10 @@ pub struct F9 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "F9-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
     #[serde(rename = "05")]
@@ -36,6 +56,10 @@ pub struct F9 {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "F9-06 must be 2-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
     #[serde(rename = "07")]
@@ -54,6 +78,10 @@ pub struct F9 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "F9-12 must be 2-3 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
 }
@@ -64,6 +92,7 @@ pub struct F9 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::FA1")]
 pub struct FA1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -79,6 +108,7 @@ pub struct FA1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::FA2")]
 pub struct FA2 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -92,6 +122,7 @@ pub struct FA2 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::FOB")]
 pub struct FOB {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1,3 +1,4 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
@@ -15,19 +16,56 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 05 | 782 | Monetary Amount | O |  | R 1/18
 /// 06 | 73 | Compensation Qualifier | O |  | ID 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::G3")]
 pub struct G3 {
+    #[validate(custom(
+        function = "datatype::decimal::<2, 5>",
+        message = "G3-01 must be a decimal number of 2-5 digits"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(
+        length(min = 1, message = "G3-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<3, 10>",
+            message = "G3-02 must be a number of 3-10 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 60>",
+        message = "G3-03 must be 1-60 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "G3-04 must be 1-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 18>",
+        message = "G3-05 must be a decimal number of 1-18 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "G3-06 must be 1 character"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
 }
@@ -44,9 +82,26 @@ pub struct G3 {
 /// 04 | 364 | Communication Number | 1 | X | AN | 1/80
 /// 05 | 443 | Contact Inquiry Reference | 1 | O | AN | 1/20
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::G61")]
 pub struct G61 {
+    #[validate(
+        length(min = 1, message = "G61-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "G61-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 93 - Name
@@ -55,12 +110,31 @@ pub struct G61 {
     /// - TYPE=AN
     /// - MIN=1
     /// - MAX=60
+    #[validate(
+        length(min = 1, message = "G61-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 60>",
+            message = "G61-02 must be 1-60 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "G61-03 must be 2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "G61-04 must be 1-80 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 20>",
+        message = "G61-05 must be 1-20 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
 }
@@ -77,9 +151,23 @@ pub struct G61 {
 /// 04 | 337 | Time | 1 | X | TM | 4/8
 /// 05 | 623 | Time Code | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::G62")]
 pub struct G62 {
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "G62-01 must be 2 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
     /// 373 - Date
@@ -88,10 +176,22 @@ pub struct G62 {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "G62-02 must be a date of 8 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "G62-03 must be 1-2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::time::<4, 8>",
+        message = "G62-04 must be a time of 4-8 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
     /// 623 - Time Code
@@ -100,6 +200,10 @@ pub struct G62 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "G62-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
 }
@@ -128,21 +232,59 @@ pub struct G62 {
 /// 16 | 1004 | Percent Qualifier | 1 | X | ID | 1/2
 /// 17 | 954 | Percent | 1 | X | R | 1/10
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::GA")]
 pub struct GA {
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "GA-01 must be 1 character"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "GA-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "GA-03 must be 1-2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "GA-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "GA-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<4, 4>",
+        message = "GA-06 must be a number of 4 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 4>",
+        message = "GA-07 must be 3-4 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
     /// 373 - Date
@@ -151,24 +293,64 @@ pub struct GA {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "GA-08 must be a date of 8 digits"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 9>",
+        message = "GA-09 must be a number of 1-9 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "GA-10 must be 2 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "GA-11 must be 1 character"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "GA-12 must be 1 character"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "GA-13 must be 1 character"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "GA-14 must be 1-30 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "GA-15 must be 2 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "GA-16 must be 1-2 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "GA-17 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
 }
@@ -193,6 +375,7 @@ pub struct GA {
     ParseSegment,
     Validate,
 )]
+#[serde(rename = "$x12::segment::GE")]
 pub struct GE {
     /// 97 - Number of Transaction Sets Included
     ///
@@ -200,7 +383,13 @@ pub struct GE {
     /// - TYPE=N0
     /// - MIN=1
     /// - MAX=6
-    #[validate(length(min = 1, max = 6, message = "GE01 must be 1-6 characters long"))]
+    #[validate(
+        length(min = 1, message = "GE-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 6>",
+            message = "GE-01 must be a number of 1-6 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 28 - Group Control Number
@@ -209,7 +398,13 @@ pub struct GE {
     /// - TYPE=N0
     /// - MIN=1
     /// - MAX=9
-    #[validate(length(min = 1, max = 9, message = "GE02 must be 1-9 characters long"))]
+    #[validate(
+        length(min = 1, message = "GE-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 9>",
+            message = "GE-02 must be a number of 1-9 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -226,17 +421,50 @@ pub struct GE {
 /// 04 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
 /// 05 | 641 | Status Reason Code | 1 | O | ID | 3/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::GR5")]
 pub struct GR5 {
+    #[validate(
+        length(min = 1, message = "GR5-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "GR5-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "GR5-02 must be 2 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 20>",
+        message = "GR5-03 must be a decimal number of 1-20 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "GR5-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "GR5-05 must be 3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
 }
@@ -267,11 +495,33 @@ pub struct GR5 {
     ParseSegment,
     Validate,
 )]
+#[serde(rename = "$x12::segment::GS")]
 pub struct GS {
+    #[validate(
+        length(min = 1, message = "GS-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "GS-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "GS-02 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 15>",
+            message = "GS-02 must be 2-15 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "GS-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 15>",
+            message = "GS-03 must be 2-15 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
     /// 373 - Date
@@ -280,6 +530,13 @@ pub struct GS {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(
+        length(min = 1, message = "GS-04 is mandatory"),
+        custom(
+            function = "datatype::date::<8, 8>",
+            message = "GS-04 must be a date of 8 digits"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
     /// 337 - Time
@@ -288,12 +545,40 @@ pub struct GS {
     /// - TYPE=TM
     /// - MIN=4
     /// - MAX=8
+    #[validate(
+        length(min = 1, message = "GS-05 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 8>",
+            message = "GS-05 must be a time of 4-8 digits"
+        )
+    )]
     #[serde(rename = "05")]
     pub _05: String,
+    #[validate(
+        length(min = 1, message = "GS-06 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 9>",
+            message = "GS-06 must be a number of 1-9 digits"
+        )
+    )]
     #[serde(rename = "06")]
     pub _06: String,
+    #[validate(
+        length(min = 1, message = "GS-07 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 2>",
+            message = "GS-07 must be 1-2 characters"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
+    #[validate(
+        length(min = 1, message = "GS-08 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 12>",
+            message = "GS-08 must be 1-12 characters"
+        )
+    )]
     #[serde(rename = "08")]
     pub _08: String,
 }
@@ -304,6 +589,7 @@ pub struct GS {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::GF")]
 pub struct GF {
     #[serde(rename = "01")]
     pub _01: String,
@@ -393,6 +679,7 @@ pub struct GF {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::G66")]
 pub struct G66 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -416,6 +703,7 @@ pub struct G66 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::G69")]
 pub struct G69 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -427,6 +715,7 @@ pub struct G69 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::G72")]
 pub struct G72 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// H1 - Hazardous Material
@@ -17,25 +19,74 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 08 | 355 | Unit or Basis for Measurement Code | 1 | X | ID | 2/2
 /// 09 | 254 | Packing Group Code | 1 | O | ID | 1/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::H1")]
 pub struct H1 {
+    #[validate(
+        length(min = 1, message = "H1-01 is mandatory"),
+        custom(
+            function = "datatype::string::<4, 10>",
+            message = "H1-01 must be 4-10 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "H1-02 must be 1-4 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "H1-03 must be 1 character"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "H1-04 must be 2-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 24>",
+        message = "H1-05 must be 1-24 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 6>",
+        message = "H1-06 must be 1-6 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 3>",
+        message = "H1-07 must be a number of 1-3 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "H1-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "H1-09 must be 1-3 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
 }
@@ -49,11 +100,32 @@ pub struct H1 {
 /// 01 | 64 | Hazardous Material Description | M |  | AN 2/30
 /// 02 | 274 | Hazardous Material Classification | O |  | AN 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::H2")]
 pub struct H2 {
+    #[validate(
+        length(min = 1, message = "H2-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 30>",
+            message = "H2-01 must be 2-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "H2-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
 }
@@ -70,17 +142,47 @@ pub struct H2 {
 /// 04 | 242 | Vent Instruction Code | 1 | O | ID | 1/7
 /// 05 | 257 | Tariff Application Code | 1 | O | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::H3")]
 pub struct H3 {
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "H3-01 must be 2-3 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "H3-02 must be 2-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "H3-03 must be 1-4 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 7>",
+        message = "H3-04 must be 1-7 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "H3-05 must be 1 character"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
 }
@@ -96,15 +198,47 @@ pub struct H3 {
 /// 03 | 735 | Hierarchical Level Code | 1 | M | ID | 1/2
 /// 04 | 736 | Hierarchical Child Code | 1 | O | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::HL")]
 pub struct HL {
+    #[validate(
+        length(min = 1, message = "HL-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 12>",
+            message = "HL-01 must be 1-12 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 12>",
+        message = "HL-02 must be 1-12 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(
+        length(min = 1, message = "HL-03 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 2>",
+            message = "HL-03 must be 1-2 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "HL-04 must be 1 character"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
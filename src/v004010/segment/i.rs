@@ -1,3 +1,4 @@
+use crate::util::datatype;
 use crate::util::Parser;
 
 use nom::error::ErrorKind;
@@ -63,23 +64,71 @@ impl std::str::FromStr for UsageIndicator {
 /// 07 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
 /// 08 | 845 | Chassis Type | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IC")]
 pub struct IC {
+    #[validate(
+        length(min = 1, message = "IC-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 4>",
+            message = "IC-01 must be 1-4 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "IC-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 10>",
+            message = "IC-02 must be 1-10 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::numeric::<3, 8>",
+        message = "IC-03 must be a number of 3-8 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "IC-04 must be 1 character"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "IC-05 must be 2-4 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<4, 5>",
+        message = "IC-06 must be a number of 4-5 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "IC-07 must be 2-4 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "IC-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
 }
@@ -94,13 +143,35 @@ pub struct IC {
 /// 02 | 152 | Special Handling Code | 1 | O | ID | 2/3
 /// 03 | 534 | Inland Transportation Code | 1 | O/Z | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IM")]
 pub struct IM {
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "IM-01 must be 1 character"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "IM-02 must be 2-3 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "IM-03 must be 2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
 }
@@ -111,6 +182,7 @@ pub struct IM {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::INC")]
 pub struct INC {
     #[serde(rename = "01")]
     pub _01: String,
@@ -146,6 +218,7 @@ pub struct INC {
     ParseSegment,
     Validate,
 )]
+#[serde(rename = "$x12::segment::IEA")]
 pub struct IEA {
     /// I16 - Number of Included Functional Groups
     ///
@@ -153,7 +226,13 @@ pub struct IEA {
     /// - TYPE=N0
     /// - MIN=1
     /// - MAX=5
-    #[validate(length(min = 1, max = 5, message = "IEA 01 (I16) must be 1-5 characters long"))]
+    #[validate(
+        length(min = 1, message = "IEA-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 5>",
+            message = "IEA-01 must be a number of 1-5 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// I12 - Interchange Control Number
@@ -162,7 +241,13 @@ pub struct IEA {
     /// - TYPE=N0
     /// - MIN=9
     /// - MAX=9
-    #[validate(length(min = 9, max = 9, message = "IEA 02 (I12) must be 9 characters long"))]
+    #[validate(
+        length(min = 1, message = "IEA-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "IEA-02 must be a number of 9 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -192,6 +277,7 @@ pub struct IEA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, Validate,
 )]
+#[serde(rename = "$x12::segment::ISA")]
 pub struct ISA {
     /// I01 - Authorization Information Qualifier
     ///
@@ -199,7 +285,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
-    #[validate(length(equal = 2, message = "ISA 01 (I01) must be 2 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// I02 - Authorization Information
@@ -208,7 +300,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=10
     /// - MAX=10
-    #[validate(length(equal = 10, message = "ISA 02 (I02) must be 10 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-02 is mandatory"),
+        custom(
+            function = "datatype::string::<10, 10>",
+            message = "ISA-02 must be 10 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
     /// I03 - Security Information Qualifier
@@ -217,7 +315,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
-    #[validate(length(equal = 2, message = "ISA 03 (I03) must be 2 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-03 must be 2 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
     /// I04 - Security Information
@@ -226,7 +330,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=10
     /// - MAX=10
-    #[validate(length(equal = 10, message = "ISA 04 (I04) must be 10 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-04 is mandatory"),
+        custom(
+            function = "datatype::string::<10, 10>",
+            message = "ISA-04 must be 10 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
     /// I05 - Interchange ID Qualifier
@@ -235,7 +345,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
-    #[validate(length(equal = 2, message = "ISA 05 (I05) must be 2 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-05 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-05 must be 2 characters"
+        )
+    )]
     #[serde(rename = "05")]
     pub _05: String,
     /// I06 - Interchange Sender ID
@@ -244,7 +360,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=15
     /// - MAX=15
-    #[validate(length(equal = 15, message = "ISA 06 (I06) must be 15 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-06 is mandatory"),
+        custom(
+            function = "datatype::string::<15, 15>",
+            message = "ISA-06 must be 15 characters"
+        )
+    )]
     #[serde(rename = "06")]
     pub _06: String,
     /// I05 - Interchange ID Qualifier
@@ -253,7 +375,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
-    #[validate(length(equal = 2, message = "ISA 07 (I05) must be 2 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-07 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-07 must be 2 characters"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
     /// I07 - Interchange Receiver ID
@@ -262,7 +390,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=15
     /// - MAX=15
-    #[validate(length(equal = 15, message = "ISA 08 (I07) must be 15 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-08 is mandatory"),
+        custom(
+            function = "datatype::string::<15, 15>",
+            message = "ISA-08 must be 15 characters"
+        )
+    )]
     #[serde(rename = "08")]
     pub _08: String,
     /// I08 - Interchange Date
@@ -271,7 +405,13 @@ pub struct ISA {
     /// - TYPE=DT
     /// - MIN=6
     /// - MAX=6
-    #[validate(length(equal = 6, message = "ISA 09 (I08) must be 6 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-09 is mandatory"),
+        custom(
+            function = "datatype::date::<6, 6>",
+            message = "ISA-09 must be a date of 6 digits"
+        )
+    )]
     #[serde(rename = "09")]
     pub _09: String,
     /// I09 - Interchange Time
@@ -280,7 +420,13 @@ pub struct ISA {
     /// - TYPE=TM
     /// - MIN=4
     /// - MAX=4
-    #[validate(length(equal = 4, message = "ISA 10 (I09) must be 4 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-10 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 4>",
+            message = "ISA-10 must be a time of 4 digits"
+        )
+    )]
     #[serde(rename = "10")]
     pub _10: String,
     /// I10 - Interchange Control Standards Identifier
@@ -289,7 +435,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=1
     /// - MAX=1
-    #[validate(length(equal = 1, message = "ISA 11 (I10) must be 1 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-11 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-11 must be 1 character"
+        )
+    )]
     #[serde(rename = "11")]
     pub _11: String,
     /// I11 - Interchange Control Version Number
@@ -298,7 +450,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=5
     /// - MAX=5
-    #[validate(length(equal = 5, message = "ISA 12 (I11) must be 5 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-12 is mandatory"),
+        custom(
+            function = "datatype::string::<5, 5>",
+            message = "ISA-12 must be 5 characters"
+        )
+    )]
     #[serde(rename = "12")]
     pub _12: String,
     /// I12 - Interchange Control Number
@@ -307,7 +465,13 @@ pub struct ISA {
     /// - TYPE=N0
     /// - MIN=9
     /// - MAX=9
-    #[validate(length(equal = 9, message = "ISA 13 (I12) must be 9 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-13 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "ISA-13 must be a number of 9 digits"
+        )
+    )]
     #[serde(rename = "13")]
     pub _13: String,
     /// I13 - Acknowledgment Requested
@@ -316,7 +480,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=1
     /// - MAX=1
-    #[validate(length(equal = 1, message = "ISA 14 (I13) must be 1 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-14 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-14 must be 1 character"
+        )
+    )]
     #[serde(rename = "14")]
     pub _14: String,
     /// I14 - Usage Indicator
@@ -333,7 +503,13 @@ pub struct ISA {
     /// - TYPE=
     /// - MIN=1
     /// - MAX=1
-    #[validate(length(equal = 1, message = "ISA 16 (I15) must be 1 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-16 is mandatory"),
+        custom(
+            function = "datatype::length::<1, 1>",
+            message = "ISA-16 must be 1 character"
+        )
+    )]
     #[serde(rename = "16")]
     pub _16: String,
 }
@@ -458,6 +634,7 @@ impl<'a> Parser<&'a str, ISA, nom::error::Error<&'a str>> for ISA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ISS")]
 pub struct ISS {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -483,6 +660,7 @@ pub struct ISS {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IT1")]
 pub struct IT1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -542,6 +720,7 @@ pub struct IT1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IT3")]
 pub struct IT3 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -561,6 +740,7 @@ pub struct IT3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ITD")]
 pub struct ITD {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// K1 - Remarks
@@ -10,11 +12,32 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 01 | 61 | Free-Form Message | 1 | M | AN | 1/30
 /// 02 | 61 | Free-Form Message | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::K1")]
 pub struct K1 {
+    #[validate(
+        length(min = 1, message = "K1-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "K1-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "K1-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
 }
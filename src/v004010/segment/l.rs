@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// L0 - Line Item - Quantity and Weight
@@ -23,37 +25,107 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 14 | 211 | Packaging Form Code | 1 | O | ID | 3/3
 /// 15 | 1073 | Yes/No Condition or Response Code | 1 | X/Z | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L0")]
 pub struct L0 {
+    #[validate(custom(
+        function = "datatype::numeric::<1, 3>",
+        message = "L0-01 must be a number of 1-3 digits"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 11>",
+        message = "L0-02 must be a decimal number of 1-11 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "L0-03 must be 2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "L0-04 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "L0-05 must be 1-2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "L0-06 must be a decimal number of 1-8 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L0-07 must be 1 character"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 7>",
+        message = "L0-08 must be a number of 1-7 digits"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "L0-09 must be 3 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 25>",
+        message = "L0-10 must be 2-25 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L0-11 must be 1 character"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "L0-12 must be 2 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "L0-13 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "L0-14 must be 3 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L0-15 must be 1 character"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
 }
@@ -86,49 +158,143 @@ pub struct L0 {
 /// 20 | 100 | Currency Code | 1 | O/Z | ID | 3/3
 /// 21 | 610 | Amount | 1 | O/Z | N2 | 1/15
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L1")]
 pub struct L1 {
+    #[validate(custom(
+        function = "datatype::numeric::<1, 3>",
+        message = "L1-01 must be a number of 1-3 digits"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 9>",
+        message = "L1-02 must be a decimal number of 1-9 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "L1-03 must be 2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 12>",
+        message = "L1-04 must be a number of 1-12 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 9>",
+        message = "L1-05 must be a number of 1-9 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 9>",
+        message = "L1-06 must be a number of 1-9 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 9>",
+        message = "L1-07 must be 3-9 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "L1-08 must be 3 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "L1-09 must be 1-3 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L1-10 must be 1 character"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L1-11 must be 1 character"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 25>",
+        message = "L1-12 must be 2-25 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L1-13 must be 1 character"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<2, 12>",
+        message = "L1-14 must be a number of 2-12 digits"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "L1-15 must be 2 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L1-16 must be 1 character"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 11>",
+        message = "L1-17 must be a decimal number of 1-11 digits"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "L1-18 must be 2 characters"
+    ))]
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "L1-19 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "19")]
     pub _19: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "L1-20 must be 3 characters"
+    ))]
     #[serde(rename = "20")]
     pub _20: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 15>",
+        message = "L1-21 must be a number of 1-15 digits"
+    ))]
     #[serde(rename = "21")]
     pub _21: Option<String>,
 }
@@ -155,37 +321,107 @@ pub struct L1 {
 /// 14 | 74 | Declared Value | 1 | X | N2 | 2/12
 /// 15 | 122 | Rate/Value Qualifier | 1 | X | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L3")]
 pub struct L3 {
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "L3-01 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "L3-02 must be 1-2 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 9>",
+        message = "L3-03 must be a decimal number of 1-9 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "L3-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 12>",
+        message = "L3-05 must be a number of 1-12 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 9>",
+        message = "L3-06 must be a number of 1-9 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 9>",
+        message = "L3-07 must be a number of 1-9 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "L3-08 must be 3 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "L3-09 must be a decimal number of 1-8 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L3-10 must be 1 character"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 7>",
+        message = "L3-11 must be a number of 1-7 digits"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L3-12 must be 1 character"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 7>",
+        message = "L3-13 must be 1-7 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<2, 12>",
+        message = "L3-14 must be a number of 2-12 digits"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "L3-15 must be 2 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
 }
@@ -196,6 +432,7 @@ pub struct L3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L4")]
 pub struct L4 {
     /// 82 - Length
     #[serde(rename = "01")]
@@ -234,27 +471,77 @@ pub struct L4 {
 /// 09 | 22 | Commodity Code | 1 | X | AN | 1/30
 /// 10 | 595 | Compartment ID Code | 1 | O | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L5")]
 pub struct L5 {
+    #[validate(custom(
+        function = "datatype::numeric::<1, 3>",
+        message = "L5-01 must be a number of 1-3 digits"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 50>",
+        message = "L5-02 must be 1-50 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "L5-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L5-04 must be 1 character"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 5>",
+        message = "L5-05 must be 3-5 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "L5-06 must be 1-48 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "L5-07 must be 1-2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L5-08 must be 1 character"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "L5-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L5-10 must be 1 character"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -282,39 +569,113 @@ pub struct L5 {
 /// 15 | 19 | City Name | O |  | AN 2/30
 /// 16 | 156 | State or Province Code | O |  | ID 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L7")]
 pub struct L7 {
+    #[validate(custom(
+        function = "datatype::numeric::<1, 3>",
+        message = "L7-01 must be a number of 1-3 digits"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "L7-02 must be 1-4 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 7>",
+        message = "L7-03 must be 1-7 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "L7-04 must be 1-2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 16>",
+        message = "L7-05 must be 1-16 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 2>",
+        message = "L7-06 must be a number of 1-2 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 5>",
+        message = "L7-07 must be 2-5 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "L7-08 must be 1-4 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<4, 4>",
+        message = "L7-09 must be 4 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "L7-10 must be a date of 8 digits"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 6>",
+        message = "L7-11 must be 1-6 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "L7-12 must be 1-2 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 5>",
+        message = "L7-13 must be a number of 1-5 digits"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "L7-14 must be 1 character"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "L7-15 must be 2-30 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "L7-16 must be 2 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
 }
@@ -329,13 +690,35 @@ pub struct L7 {
 /// 02 | 128 | Reference Identification Qualifier | 1 | X | ID | 2/3
 /// 03 | 352 | Description | 1 | X | AN | 1/80
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L11")]
 pub struct L11 {
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "L11-01 must be 1-30 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "L11-02 must be 2-3 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "L11-03 must be 1-80 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
 }
@@ -360,33 +743,95 @@ pub struct L11 {
 /// 12 | 234 | Product/Service ID | 1 | X | AN | 1/48
 /// 13 | 79 | Lading Description | 1 | O | AN | 1/50
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LAD")]
 pub struct LAD {
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "LAD-01 must be 3 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 7>",
+        message = "LAD-02 must be a number of 1-7 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "LAD-03 must be 1 character"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "LAD-04 must be a decimal number of 1-8 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "LAD-05 must be 1 character"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "LAD-06 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LAD-07 must be 2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LAD-08 must be 1-48 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LAD-09 must be 2 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LAD-10 must be 1-48 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LAD-11 must be 2 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LAD-12 must be 1-48 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 50>",
+        message = "LAD-13 must be 1-50 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
 }
@@ -399,9 +844,26 @@ pub struct LAD {
 /// ----|----|-------|--------|----|------|-------
 /// 01 | 447 | Loop Identifier Code | 1 | M | AN | 1/6
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LE")]
 pub struct LE {
+    #[validate(
+        length(min = 1, message = "LE-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 6>",
+            message = "LE-01 must be 1-6 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
 }
@@ -417,15 +879,41 @@ pub struct LE {
 /// 03 | 156 | State or Province Code NEW | 1 | X/Z | ID | 2/2
 /// 04 | 127 | Reference Identification NEW | 1 | X/Z | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LEP")]
 pub struct LEP {
+    #[validate(custom(
+        function = "datatype::string::<4, 6>",
+        message = "LEP-01 must be 4-6 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<12, 16>",
+        message = "LEP-02 must be 12-16 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LEP-03 must be 2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "LEP-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -444,21 +932,65 @@ pub struct LEP {
 /// 06 | 380 | Quantity NEW | 1 | X/Z | R | 1/15
 /// 07 | 380 | Quantity NEW | 1 | O/Z | R | 1/15
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LFH")]
 pub struct LFH {
+    #[validate(
+        length(min = 1, message = "LFH-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "LFH-01 must be 3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "LFH-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 25>",
+            message = "LFH-02 must be 1-25 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 25>",
+        message = "LFH-03 must be 1-25 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "LFH-04 must be 1 character"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LFH-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "LFH-06 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "LFH-07 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -481,29 +1013,89 @@ pub struct LFH {
 /// 10 | 254 | Packing Group Code | 1 | O | ID | 1/3
 /// 11 | 1375 | Interim Hazardous Material Regulatory Number | 1 | O | AN | 1/5
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LH1")]
 pub struct LH1 {
+    #[validate(
+        length(min = 1, message = "LH1-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "LH1-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "LH1-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 7>",
+            message = "LH1-02 must be a number of 1-7 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<6, 6>",
+        message = "LH1-03 must be 6 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 6>",
+        message = "LH1-04 must be 1-6 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "LH1-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LH1-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "LH1-07 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "LH1-08 must be 1 character"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "LH1-09 must be 1 character"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "LH1-10 must be 1-3 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 5>",
+        message = "LH1-11 must be 1-5 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
 }
@@ -526,29 +1118,83 @@ pub struct LH1 {
 /// 10 | 355 | Unit or Basis for Measurement Code NEW | 1 | X/Z | ID | 2/2
 /// 11 | 408 | Temperature NEW | 1 | X | R | 1/4
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LH2")]
 pub struct LH2 {
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "LH2-01 must be 1-30 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "LH2-02 must be 1 character"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<14, 40>",
+        message = "LH2-03 must be 14-40 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<4, 25>",
+        message = "LH2-04 must be 4-25 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LH2-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LH2-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 4>",
+        message = "LH2-07 must be a decimal number of 1-4 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LH2-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 4>",
+        message = "LH2-09 must be a decimal number of 1-4 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LH2-10 must be 2 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 4>",
+        message = "LH2-11 must be a decimal number of 1-4 digits"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
 }
@@ -564,15 +1210,41 @@ pub struct LH2 {
 /// 03 | 985 | N.O.S. Indicator Code | 1 | O | ID | 3/3
 /// 04 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LH3")]
 pub struct LH3 {
+    #[validate(custom(
+        function = "datatype::string::<1, 25>",
+        message = "LH3-01 must be 1-25 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "LH3-02 must be 1 character"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "LH3-03 must be 3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "LH3-04 must be 1 character"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -595,30 +1267,79 @@ pub struct LH3 {
 /// 10 | 986 | Special Commodity Indicator Code | 1 | O | ID | 1/1
 /// 11 | 364 | Communication Number | 1 | O/Z | AN | 1/80
 /// 12 | 355 | Unit or Basis for Measurement Code NEW | 1 | X | ID | 2/2
-#[derive(Debug, Default, Clone, Serialize, Deserialize, DisplaySegment, ParseSegment)]
+#[derive(Debug, Validate, Default, Clone, Serialize, Deserialize, DisplaySegment, ParseSegment)]
+#[serde(rename = "$x12::segment::LH4")]
 pub struct LH4 {
+    #[validate(custom(
+        function = "datatype::string::<1, 12>",
+        message = "LH4-01 must be 1-12 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "LH4-02 must be 1-80 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "LH4-03 must be 1-3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "LH4-04 must be 1-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "LH4-05 must be 1-3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "LH4-06 must be 1-3 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "LH4-07 must be 1-2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 6>",
+        message = "LH4-08 must be a number of 1-6 digits"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 25>",
+        message = "LH4-09 must be 1-25 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "LH4-10 must be 1 character"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "LH4-11 must be 1-80 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LH4-12 must be 2 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
 }
@@ -634,8 +1355,18 @@ pub struct LH4 {
 /// 03 | 273 | Hazardous Certification Declaration | 1 | X | AN | 1/25
 /// 04 | 273 | Hazardous Certification Declaration | 1 | O | AN | 1/25
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LH6")]
 pub struct LH6 {
     /// 93 - Name
     ///
@@ -643,12 +1374,28 @@ pub struct LH6 {
     /// - TYPE=AN
     /// - MIN=1
     /// - MAX=60
+    #[validate(custom(
+        function = "datatype::string::<1, 60>",
+        message = "LH6-01 must be 1-60 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "LH6-02 must be 1 character"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 25>",
+        message = "LH6-03 must be 1-25 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 25>",
+        message = "LH6-04 must be 1-25 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -663,11 +1410,35 @@ pub struct LH6 {
 /// 02 | 127 | Reference Identification | 1 | M | AN | 1/30
 /// 03 | 373 | Date NEW | 1 | O | DT | 8/8
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LHR")]
 pub struct LHR {
+    #[validate(
+        length(min = 1, message = "LHR-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "LHR-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "LHR-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "LHR-02 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
     /// 373 - Date
@@ -676,6 +1447,10 @@ pub struct LHR {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "LHR-03 must be a date of 8 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
 }
@@ -690,13 +1465,35 @@ pub struct LHR {
 /// 02 | 218 | Hazardous Placard Notation | 1 | O | ID | 14/40
 /// 03 | 222 | Hazardous Endorsement | 1 | O | ID | 4/25
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LHT")]
 pub struct LHT {
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "LHT-01 must be 1-30 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<14, 40>",
+        message = "LHT-02 must be 14-40 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<4, 25>",
+        message = "LHT-03 must be 4-25 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
 }
@@ -707,6 +1504,7 @@ pub struct LHT {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LM")]
 pub struct LM {
     #[serde(rename = "01")]
     pub _01: String,
@@ -720,6 +1518,7 @@ pub struct LM {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LQ")]
 pub struct LQ {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -735,9 +1534,26 @@ pub struct LQ {
 /// ----|----|-------|--------|----|------|-------
 /// 01 | 447 | Loop Identifier Code | 1 | M | AN | 1/6
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LS")]
 pub struct LS {
+    #[validate(
+        length(min = 1, message = "LS-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 6>",
+            message = "LS-01 must be 1-6 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
 }
@@ -750,9 +1566,26 @@ pub struct LS {
 /// ----|----|-------|--------|----|------|-------
 /// 01 | 554 | Assigned Number | 1 | M | N0 | 1/6
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LX")]
 pub struct LX {
+    #[validate(
+        length(min = 1, message = "LX-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 6>",
+            message = "LX-01 must be a number of 1-6 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
 }
@@ -795,69 +1628,209 @@ pub struct LX {
 /// 30 | 235 | Product/Service ID Qualifier | 1 | O | ID | 2/2
 /// 31 | 234 | Product/Service ID | 1 | O | AN | 1/48
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LIN")]
 pub struct LIN {
+    #[validate(custom(
+        function = "datatype::string::<1, 20>",
+        message = "LIN-01 must be 1-20 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(
+        length(min = 1, message = "LIN-02 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "LIN-02 must be 2 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "LIN-03 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 48>",
+            message = "LIN-03 must be 1-48 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-05 must be 1-48 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-07 must be 1-48 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-09 must be 1-48 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-10 must be 2 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-11 must be 1-48 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-12 must be 2 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-13 must be 1-48 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-14 must be 2 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-15 must be 1-48 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-16 must be 2 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-17 must be 1-48 characters"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-18 must be 2 characters"
+    ))]
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-19 must be 1-48 characters"
+    ))]
     #[serde(rename = "19")]
     pub _19: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-20 must be 2 characters"
+    ))]
     #[serde(rename = "20")]
     pub _20: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-21 must be 1-48 characters"
+    ))]
     #[serde(rename = "21")]
     pub _21: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-22 must be 2 characters"
+    ))]
     #[serde(rename = "22")]
     pub _22: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-23 must be 1-48 characters"
+    ))]
     #[serde(rename = "23")]
     pub _23: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-24 must be 2 characters"
+    ))]
     #[serde(rename = "24")]
     pub _24: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-25 must be 1-48 characters"
+    ))]
     #[serde(rename = "25")]
     pub _25: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-26 must be 2 characters"
+    ))]
     #[serde(rename = "26")]
     pub _26: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-27 must be 1-48 characters"
+    ))]
     #[serde(rename = "27")]
     pub _27: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-28 must be 2 characters"
+    ))]
     #[serde(rename = "28")]
     pub _28: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-29 must be 1-48 characters"
+    ))]
     #[serde(rename = "29")]
     pub _29: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "LIN-30 must be 2 characters"
+    ))]
     #[serde(rename = "30")]
     pub _30: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "LIN-31 must be 1-48 characters"
+    ))]
     #[serde(rename = "31")]
     pub _31: Option<String>,
 }
@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// M0 - Letter of Credit Reference
@@ -12,15 +14,44 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 03 | 373 | Date | O |  | DT 8/8
 /// 04 | 373 | Date | O |  | DT 8/8
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M0")]
 pub struct M0 {
+    #[validate(
+        length(min = 1, message = "M0-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 40>",
+            message = "M0-01 must be 2-40 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "M0-02 must be a date of 8 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "M0-03 must be a date of 8 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "M0-04 must be a date of 8 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -44,8 +75,18 @@ pub struct M0 {
 /// 11 | 1004 | Percent Qualifier | 1 | X | ID | 1/2
 /// 12 | 954 | Percent | 1 | X | R | 1/10
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M1")]
 pub struct M1 {
     /// 26 - Country Code
     ///
@@ -53,28 +94,76 @@ pub struct M1 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "M1-01 must be 2-3 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<2, 8>",
+        message = "M1-02 must be a number of 2-8 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<2, 12>",
+        message = "M1-03 must be a number of 2-12 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "M1-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "M1-05 must be 2-3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "M1-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "M1-07 must be 2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 18>",
+        message = "M1-08 must be a decimal number of 1-18 digits"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "M1-09 must be 1-2 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "M1-10 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "M1-11 must be 1-2 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "M1-12 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
 }
@@ -90,9 +179,23 @@ pub struct M1 {
 /// 03 | 337 | Time | 1 | X | TM | 4/8
 /// 04 | 623 | Time Code | 1 | O/Z | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M3")]
 pub struct M3 {
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "M3-01 must be 1 character"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
     /// 373 - Date
@@ -101,8 +204,16 @@ pub struct M3 {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "M3-02 must be a date of 8 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::time::<4, 8>",
+        message = "M3-03 must be a time of 4-8 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
     /// 623 - Time Code
@@ -111,6 +222,10 @@ pub struct M3 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "M3-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -127,17 +242,50 @@ pub struct M3 {
 /// 04 | 225 | Seal Number | 1 | O | AN | 2/15
 /// 05 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M7")]
 pub struct M7 {
+    #[validate(
+        length(min = 1, message = "M7-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 15>",
+            message = "M7-01 must be 2-15 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 15>",
+        message = "M7-02 must be 2-15 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 15>",
+        message = "M7-03 must be 2-15 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 15>",
+        message = "M7-04 must be 2-15 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "M7-05 must be 2-3 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
 }
@@ -161,31 +309,92 @@ pub struct M7 {
 /// 11 | 1073 | Yes/No Condition or Response Code O ID 1/1
 /// 12 | 127 | Reference Identification O AN 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M10")]
 pub struct M10 {
+    #[validate(
+        length(min = 1, message = "M10-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 4>",
+            message = "M10-01 must be 2-4 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "M10-02 must be 1-2 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "M10-03 must be 2-3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 8>",
+        message = "M10-04 must be 1-8 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 28>",
+        message = "M10-05 must be 2-28 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 10>",
+        message = "M10-06 must be 2-10 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "M10-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "M10-08 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "M10-09 must be 1 character"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "M10-10 must be 1 character"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "M10-11 must be 1 character"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "M10-12 must be 1-30 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
 }
@@ -216,45 +425,152 @@ pub struct M10 {
 /// 18 | 140 | Standard Carrier Alpha Code | X |  | ID 2/4
 /// 19 | 140 | Standard Carrier Alpha Code | O |  | ID 2/4
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M11")]
 pub struct M11 {
+    #[validate(
+        length(min = 1, message = "M11-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 12>",
+            message = "M11-01 must be 1-12 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "M11-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "M11-02 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "M11-03 is mandatory"),
+        custom(
+            function = "datatype::decimal::<1, 15>",
+            message = "M11-03 must be a decimal number of 1-15 digits"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(
+        length(min = 1, message = "M11-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 3>",
+            message = "M11-04 must be 1-3 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
+    #[validate(
+        length(min = 1, message = "M11-05 is mandatory"),
+        custom(
+            function = "datatype::decimal::<1, 10>",
+            message = "M11-05 must be a decimal number of 1-10 digits"
+        )
+    )]
     #[serde(rename = "05")]
     pub _05: String,
+    #[validate(
+        length(min = 1, message = "M11-06 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "M11-06 must be 1 character"
+        )
+    )]
     #[serde(rename = "06")]
     pub _06: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "M11-07 must be a decimal number of 1-8 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "M11-08 must be 1 character"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "M11-09 must be 2 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 17>",
+        message = "M11-10 must be 1-17 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 12>",
+        message = "M11-11 must be 1-12 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(
+        length(min = 1, message = "M11-12 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 4>",
+            message = "M11-12 must be 2-4 characters"
+        )
+    )]
     #[serde(rename = "12")]
     pub _12: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "M11-13 must be 2-4 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "M11-14 must be 2-4 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "M11-15 must be 2-4 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "M11-16 must be 1-2 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "M11-17 must be 2 characters"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "M11-18 must be 2-4 characters"
+    ))]
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "M11-19 must be 2-4 characters"
+    ))]
     #[serde(rename = "19")]
     pub _19: Option<String>,
 }
@@ -277,29 +593,86 @@ pub struct M11 {
 /// 10 | 91 | Transportation Method/Type Code | 1 | X | ID | 1/2
 /// 11 | 182 | Vessel Name | 1 | X | AN | 2/28
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M12")]
 pub struct M12 {
+    #[validate(
+        length(min = 1, message = "M12-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "M12-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 15>",
+        message = "M12-02 must be 1-15 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "M12-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "M12-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 8>",
+        message = "M12-05 must be 2-8 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 25>",
+        message = "M12-06 must be 1-25 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "M12-07 must be 2-4 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "M12-08 must be 2-3 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "M12-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "M12-10 must be 1-2 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 28>",
+        message = "M12-11 must be 2-28 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
 }
@@ -321,27 +694,89 @@ pub struct M12 {
 /// 09 | 140 | Standard Carrier Alpha Code | M |  | ID 2/4
 /// 10 | 140 | Standard Carrier Alpha Code | X |  | ID 2/4
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M13")]
 pub struct M13 {
+    #[validate(
+        length(min = 1, message = "M13-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 4>",
+            message = "M13-01 must be 2-4 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "M13-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "M13-02 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "M13-03 must be 1 character"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(
+        length(min = 1, message = "M13-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 12>",
+            message = "M13-04 must be 1-12 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "M13-05 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "M13-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "M13-07 must be 1-2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 12>",
+        message = "M13-08 must be 1-12 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(
+        length(min = 1, message = "M13-09 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 4>",
+            message = "M13-09 must be 2-4 characters"
+        )
+    )]
     #[serde(rename = "09")]
     pub _09: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "M13-10 must be 2-4 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -359,19 +794,53 @@ pub struct M13 {
 /// 05 | 87 | Marks and Numbers | 1 | X/Z | AN | 1/48
 /// 06 | 87 | Marks and Numbers | 1 | O | AN | 1/48
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MAN")]
 pub struct MAN {
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "MAN-01 must be 1-2 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "MAN-02 must be 1-48 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "MAN-03 must be 1-48 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "MAN-04 must be 1-2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "MAN-05 must be 1-48 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "MAN-06 must be 1-48 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
 }
@@ -393,27 +862,73 @@ pub struct MAN {
 /// 09 | 752 | Surface/Layer/Position Code | 1 | O | ID | 2/2
 /// 10 | 1373 | Measurement Method or Device | 1 | O | ID | 2/4
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MEA")]
 pub struct MEA {
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "MEA-01 must be 2 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "MEA-02 must be 1-3 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 20>",
+        message = "MEA-03 must be a decimal number of 1-20 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 20>",
+        message = "MEA-05 must be a decimal number of 1-20 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 20>",
+        message = "MEA-06 must be a decimal number of 1-20 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "MEA-07 must be 2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "MEA-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "MEA-09 must be 2 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "MEA-10 must be 2-4 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -432,8 +947,18 @@ pub struct MEA {
 /// 06 | 1280 | Direction Identifier Code NEW | 1 | O/Z | ID | 1/1
 /// 07 | 1280 | Direction Identifier Code NEW | 1 | O/Z | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MS1")]
 pub struct MS1 {
     /// 19 - City Name
     ///
@@ -441,8 +966,16 @@ pub struct MS1 {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "MS1-01 must be 2-30 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "MS1-02 must be 2 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
     /// 26 - Country Code
@@ -451,14 +984,34 @@ pub struct MS1 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "MS1-03 must be 2-3 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<7, 7>",
+        message = "MS1-04 must be 7 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<7, 7>",
+        message = "MS1-05 must be 7 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "MS1-06 must be 1 character"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "MS1-07 must be 1 character"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -474,15 +1027,41 @@ pub struct MS1 {
 /// 03 | 40 | Equipment Description Code | 1 | O | ID | 2/2
 /// 04 | 761 | Equipment Number Check Digit | 1 | O | N0 | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MS2")]
 pub struct MS2 {
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "MS2-01 must be 2-4 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "MS2-02 must be 1-10 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "MS2-03 must be 2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "MS2-04 must be a number of 1 digit"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -499,11 +1078,35 @@ pub struct MS2 {
 /// 04 | 91 | Transportation Method/Type Code | 1 | O | ID | 1/2
 /// 05 | 156 | State or Province Code NEW | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MS3")]
 pub struct MS3 {
+    #[validate(
+        length(min = 1, message = "MS3-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 4>",
+            message = "MS3-01 must be 2-4 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "MS3-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 2>",
+            message = "MS3-02 must be 1-2 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
     /// 19 - City Name
@@ -512,10 +1115,22 @@ pub struct MS3 {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "MS3-03 must be 2-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "MS3-04 must be 1-2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "MS3-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
 }
@@ -526,6 +1141,7 @@ pub struct MS3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MSG")]
 pub struct MSG {
     #[serde(rename = "01")]
     pub _01: String,
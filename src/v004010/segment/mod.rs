@@ -61,3 +61,51 @@ pub use z::*;
 pub use f::{FA1, FA2};
 pub use g::G72;
 pub use w::{W03, W06, W10, W12, W27, W28};
+
+crate::validation::impl_segment_syntax! {
+    N1 => ["R0203", "P0304"],
+    N4 => ["C0605"],
+    REF => ["R0203"],
+    PER => ["P0304", "P0506", "P0708"],
+    DTM => ["R020305", "C0403", "P0506"],
+    N9 => ["R0203", "C0605"],
+    QTY => ["R0204", "E0204"],
+    MEA => ["R03050608", "C0504", "C0604", "L07030506", "E0803"],
+    TD5 => ["R0204050612", "C0203", "C0708"],
+    G62 => ["R0103", "P0102", "P0304"],
+    LIN => ["P0405", "P0607", "P0809", "P1011", "P1213", "P1415", "P1617", "P1819", "P2021", "P2223", "P2425", "P2627", "P2829", "P3031"],
+    CTT => ["P0304", "P0506"],
+    DMG => ["P0102"],
+    NM1 => ["P0809", "C1110"],
+}
+
+crate::validation::impl_segment_codes! {
+    AK5 => [1 => "717"],
+    AK9 => [1 => "715"],
+    AMT => [3 => "478"],
+    B2A => [1 => "353"],
+    BIG => [8 => "353"],
+    BSN => [1 => "353"],
+    CRC => [2 => "1073"],
+    CUR => [1 => "98", 4 => "98", 7 => "98", 10 => "98", 13 => "98", 16 => "98", 19 => "98"],
+    GS => [7 => "455"],
+    HL => [4 => "736"],
+    ISA => [14 => "I13", 15 => "I14"],
+    M7 => [5 => "98"],
+    N1 => [1 => "98", 6 => "98"],
+    NM1 => [1 => "98", 2 => "1065", 11 => "98"],
+    PWK => [4 => "98"],
+    TA1 => [4 => "I17"],
+    YNQ => [2 => "1073"],
+}
+
+crate::validation::impl_segment_elements! {
+    AK1, AK2, AK3, AK4, AK5, AK9, AMT, AT5, AT7, AT8, B1, B10, B2, B2A, B3, B4, BAL, BIG, BIN, BL,
+    BNX, BSN, BX, C2, C3, C8, C8C, CAD, CD3, CLD, CM, CRC, CTP, CTT, CUR, D9, DMG, DTM, E1, E4, E5,
+    EA, EFI, EM, ETD, F9, G3, G61, G62, GA, GE, GR5, GS, H1, H2, H3, HL, IC, IEA, IM, K1, L0, L1,
+    L11, L3, L5, L7, LAD, LE, LEP, LFH, LH1, LH2, LH3, LH4, LH6, LHR, LHT, LIN, LS, LX, M0, M1, M10,
+    M11, M12, M13, M3, M7, MAN, MEA, MS1, MS2, MS3, N1, N10, N12, N2, N3, N4, N5, N7, N7A, N7B, N9,
+    NA, NM1, NTE, OID, P4, P5, PER, PI, PLD, PRF, PS, PWK, Q2, Q5, Q7, QTY, R2, R2A, R4, R9, REF,
+    S1, S2, S5, S9, SAC, SDQ, SE, SG, SLN, SN1, SPO, SR, ST, T1, T2, T3, T6, T8, TA1, TC2, TD1, TD3,
+    TD5, TDS, TXI, V1, V4, V9, VC, VID, W09, W2, X1, X2, X7, XH, Y2, Y3, Y4, Y6, Y7, YNQ, ZC1, ZD,
+}
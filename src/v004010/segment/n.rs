@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// N1 - Name
@@ -14,9 +16,26 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 05 | 706 | Entity Relationship Code | 1 | O | ID | 2/2
 /// 06 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N1")]
 pub struct N1 {
+    #[validate(
+        length(min = 1, message = "N1-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "N1-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 93 - Name
@@ -25,14 +44,34 @@ pub struct N1 {
     /// - TYPE=AN
     /// - MIN=1
     /// - MAX=60
+    #[validate(custom(
+        function = "datatype::string::<1, 60>",
+        message = "N1-02 must be 1-60 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "N1-03 must be 1-2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 80>",
+        message = "N1-04 must be 2-80 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "N1-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "N1-06 must be 2-3 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
 }
@@ -46,8 +85,18 @@ pub struct N1 {
 /// 01 | 93 | Name | 1 | M | AN | 1/60
 /// 02 | 93 | Name | 1 | O | AN | 1/60
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N2")]
 pub struct N2 {
     /// 93 - Name
     ///
@@ -55,6 +104,13 @@ pub struct N2 {
     /// - TYPE=AN
     /// - MIN=1
     /// - MAX=60
+    #[validate(
+        length(min = 1, message = "N2-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 60>",
+            message = "N2-01 must be 1-60 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 93 - Name
@@ -63,6 +119,10 @@ pub struct N2 {
     /// - TYPE=AN
     /// - MIN=1
     /// - MAX=60
+    #[validate(custom(
+        function = "datatype::string::<1, 60>",
+        message = "N2-02 must be 1-60 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
 }
@@ -76,11 +136,32 @@ pub struct N2 {
 /// 01 | 166 | Address Information | 1 | M | AN | 1/55
 /// 02 | 166 | Address Information | 1 | O | AN | 1/55
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N3")]
 pub struct N3 {
+    #[validate(
+        length(min = 1, message = "N3-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 55>",
+            message = "N3-01 must be 1-55 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 55>",
+        message = "N3-02 must be 1-55 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
 }
@@ -98,8 +179,18 @@ pub struct N3 {
 /// 05 | 309 | Location Qualifier | 1 | X | ID | 1/2
 /// 06 | 310 | Location Identifier | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N4")]
 pub struct N4 {
     /// 19 - City Name
     ///
@@ -107,8 +198,20 @@ pub struct N4 {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "N4-01 must be 2-30 characters"
+    ))]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "N4-02 must be 2 characters"
+    ))]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 15>",
+        message = "N4-03 must be 3-15 characters"
+    ))]
     pub _03: Option<String>,
     /// 26 - Country Code
     ///
@@ -116,8 +219,20 @@ pub struct N4 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "N4-04 must be 2-3 characters"
+    ))]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "N4-05 must be 1-2 characters"
+    ))]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "N4-06 must be 1-30 characters"
+    ))]
     pub _06: Option<String>,
 }
 
@@ -137,17 +252,63 @@ pub struct N4 {
 /// 08 | 644 | Lading Percent Qualifier | 1 | X | ID | 1/1
 /// 09 | 40 | Equipment Description Code | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N5")]
 pub struct N5 {
+    #[validate(custom(
+        function = "datatype::numeric::<4, 5>",
+        message = "N5-01 must be a number of 4-5 digits"
+    ))]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<2, 3>",
+        message = "N5-02 must be a number of 2-3 digits"
+    ))]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<2, 4>",
+        message = "N5-03 must be a number of 2-4 digits"
+    ))]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "N5-04 must be 1-4 characters"
+    ))]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "N5-05 must be 1 character"
+    ))]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "N5-06 must be a decimal number of 1-8 digits"
+    ))]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<2, 4>",
+        message = "N5-07 must be a number of 2-4 digits"
+    ))]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "N5-08 must be 1 character"
+    ))]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "N5-09 must be 2 characters"
+    ))]
     pub _09: Option<String>,
 }
 
@@ -182,32 +343,141 @@ pub struct N5 {
 /// 23 | 140 | Standard Carrier Alpha Code | 1 | O/Z | ID | 2/4
 /// 24 | 301 | Car Type Code | 1 | O | ID | 1/4
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N7")]
 pub struct N7 {
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "N7-01 must be 1-4 characters"
+    ))]
     pub _01: Option<String>,
+    #[validate(
+        length(min = 1, message = "N7-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 10>",
+            message = "N7-02 must be 1-10 characters"
+        )
+    )]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "N7-03 must be a decimal number of 1-10 digits"
+    ))]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "N7-04 must be 1-2 characters"
+    ))]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<3, 8>",
+        message = "N7-05 must be a number of 3-8 digits"
+    ))]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<2, 6>",
+        message = "N7-06 must be a number of 2-6 digits"
+    ))]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 6>",
+        message = "N7-07 must be a number of 1-6 digits"
+    ))]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "N7-08 must be a decimal number of 1-8 digits"
+    ))]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "N7-09 must be 1 character"
+    ))]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "N7-10 must be 1 character"
+    ))]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "N7-11 must be 2 characters"
+    ))]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "N7-12 must be 2-4 characters"
+    ))]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 6>",
+        message = "N7-13 must be 3-6 characters"
+    ))]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "N7-14 must be 1-3 characters"
+    ))]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<4, 5>",
+        message = "N7-15 must be a number of 4-5 digits"
+    ))]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "N7-16 must be 1 character"
+    ))]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "N7-17 must be 1 character"
+    ))]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "N7-18 must be a number of 1 digit"
+    ))]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "N7-19 must be 2 characters"
+    ))]
     pub _19: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "N7-20 must be a decimal number of 1-8 digits"
+    ))]
     pub _20: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "N7-21 must be a decimal number of 1-8 digits"
+    ))]
     pub _21: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<4, 4>",
+        message = "N7-22 must be 4 characters"
+    ))]
     pub _22: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "N7-23 must be 2-4 characters"
+    ))]
     pub _23: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "N7-24 must be 1-4 characters"
+    ))]
     pub _24: Option<String>,
 }
 
@@ -227,17 +497,63 @@ pub struct N7 {
 /// 08 | 1046 | Inlet or Outlet Fitting Type Code | 1 | O | ID | 2/2
 /// 09 | 1047 | Miscellaneous Equipment Code | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N7A")]
 pub struct N7A {
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "N7A-01 must be 2 characters"
+    ))]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "N7A-02 must be a decimal number of 1-8 digits"
+    ))]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 2>",
+        message = "N7A-03 must be a decimal number of 1-2 digits"
+    ))]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "N7A-04 must be 3 characters"
+    ))]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 2>",
+        message = "N7A-05 must be a decimal number of 1-2 digits"
+    ))]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 2>",
+        message = "N7A-06 must be a decimal number of 1-2 digits"
+    ))]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "N7A-07 must be 2 characters"
+    ))]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "N7A-08 must be 2 characters"
+    ))]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "N7A-09 must be 2 characters"
+    ))]
     pub _09: Option<String>,
 }
 
@@ -254,14 +570,48 @@ pub struct N7A {
 /// 05 | 1031 | Trailer Lining Type Code | 1 | O | ID | 3/3
 /// 06 | 127 | Reference Identification | 1 | O/Z | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N7B")]
 pub struct N7B {
+    #[validate(custom(
+        function = "datatype::numeric::<1, 2>",
+        message = "N7B-01 must be a number of 1-2 digits"
+    ))]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "N7B-02 must be 1 character"
+    ))]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "N7B-03 must be 3 characters"
+    ))]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "N7B-04 must be 3 characters"
+    ))]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "N7B-05 must be 3 characters"
+    ))]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "N7B-06 must be 1-30 characters"
+    ))]
     pub _06: Option<String>,
 }
 
@@ -278,13 +628,34 @@ pub struct N7B {
 /// 06 | 623 | Time Code | 1 | O/Z | ID | 2/2
 /// 07 | C040 | Reference Identifier | 1 | O/Z
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N9")]
 pub struct N9 {
+    #[validate(
+        length(min = 1, message = "N9-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "N9-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 45>",
+        message = "N9-03 must be 1-45 characters"
+    ))]
     #[serde(rename = "03")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub _03: Option<String>,
@@ -294,6 +665,10 @@ pub struct N9 {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "N9-04 must be a date of 8 digits"
+    ))]
     #[serde(rename = "04")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub _04: Option<String>,
@@ -303,6 +678,10 @@ pub struct N9 {
     /// - TYPE=TM
     /// - MIN=4
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::time::<4, 8>",
+        message = "N9-05 must be a time of 4-8 digits"
+    ))]
     #[serde(rename = "05")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub _05: Option<String>,
@@ -312,6 +691,10 @@ pub struct N9 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "N9-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub _06: Option<String>,
@@ -340,18 +723,68 @@ pub struct N9 {
 /// 12 | 26 | Country Code NEW | 1 | O/Z | ID | 2/3
 /// 13 | 100 | Currency Code NEW | 1 | X/Z | ID | 3/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N10")]
 pub struct N10 {
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "N10-01 must be a decimal number of 1-15 digits"
+    ))]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 45>",
+        message = "N10-02 must be 1-45 characters"
+    ))]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "N10-03 must be 1-48 characters"
+    ))]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "N10-04 must be 1 character"
+    ))]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "N10-05 must be 1-30 characters"
+    ))]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 8>",
+        message = "N10-06 must be 2-8 characters"
+    ))]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "N10-07 must be 1 character"
+    ))]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "N10-08 must be a decimal number of 1-10 digits"
+    ))]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "N10-09 must be 1-30 characters"
+    ))]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "N10-10 must be 1-3 characters"
+    ))]
     pub _10: Option<String>,
     /// 26 - Country Code
     ///
@@ -359,6 +792,10 @@ pub struct N10 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "N10-11 must be 2-3 characters"
+    ))]
     pub _11: Option<String>,
     /// 26 - Country Code
     ///
@@ -366,7 +803,15 @@ pub struct N10 {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "N10-12 must be 2-3 characters"
+    ))]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "N10-13 must be 3 characters"
+    ))]
     pub _13: Option<String>,
 }
 
@@ -379,9 +824,26 @@ pub struct N10 {
 /// 01 | 829 | Fuel Type | M |  | ID 1/1
 /// 02 | C001 | Composite Unit of Measure | M
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N12")]
 pub struct N12 {
+    #[validate(
+        length(min = 1, message = "N12-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "N12-01 must be 1 character"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     #[serde(rename = "02")]
@@ -406,19 +868,79 @@ pub struct N12 {
 /// 10 | 845 | Chassis Type | 1 | O | ID | 2/2
 /// 11 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::NA")]
 pub struct NA {
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "NA-01 must be 2-3 characters"
+    ))]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "NA-02 must be 1-30 characters"
+    ))]
     pub _02: Option<String>,
+    #[validate(
+        length(min = 1, message = "NA-03 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 4>",
+            message = "NA-03 must be 1-4 characters"
+        )
+    )]
     pub _03: String,
+    #[validate(
+        length(min = 1, message = "NA-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 10>",
+            message = "NA-04 must be 1-10 characters"
+        )
+    )]
     pub _04: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "NA-05 must be 1 character"
+    ))]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "NA-06 must be 1-3 characters"
+    ))]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "NA-07 must be 2-4 characters"
+    ))]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<4, 5>",
+        message = "NA-08 must be a number of 4-5 digits"
+    ))]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "NA-09 must be 2-4 characters"
+    ))]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "NA-10 must be 2 characters"
+    ))]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "NA-11 must be 1 character"
+    ))]
     pub _11: Option<String>,
 }
 
@@ -440,19 +962,76 @@ pub struct NA {
 /// 10 | 706 | Entity Relationship Code | 1 | X | ID | 2/2
 /// 11 | 98 | Entity Identifier Code | 1 | O | ID | 2/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::NM1")]
 pub struct NM1 {
+    #[validate(
+        length(min = 1, message = "NM1-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "NM1-01 must be 2-3 characters"
+        )
+    )]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "NM1-02 must be 1 character"
+    ))]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 35>",
+        message = "NM1-03 must be 1-35 characters"
+    ))]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 25>",
+        message = "NM1-04 must be 1-25 characters"
+    ))]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 25>",
+        message = "NM1-05 must be 1-25 characters"
+    ))]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "NM1-06 must be 1-10 characters"
+    ))]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "NM1-07 must be 1-10 characters"
+    ))]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "NM1-08 must be 1-2 characters"
+    ))]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 80>",
+        message = "NM1-09 must be 2-80 characters"
+    ))]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "NM1-10 must be 2 characters"
+    ))]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "NM1-11 must be 2-3 characters"
+    ))]
     pub _11: Option<String>,
 }
 
@@ -465,11 +1044,32 @@ pub struct NM1 {
 /// 01 | 363 | Note Reference Code | 1 | O | ID | 3/3
 /// 02 | 352 | Description | 1 | M | AN | 1/80
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::NTE")]
 pub struct NTE {
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "NTE-01 must be 3 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(
+        length(min = 1, message = "NTE-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 80>",
+            message = "NTE-02 must be 1-80 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
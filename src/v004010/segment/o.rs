@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// OID - Order Identification Detail NEW
@@ -17,25 +19,71 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 08 | 184 | Volume Unit Qualifier | 1 | X | ID | 1/1
 /// 09 | 183 | Volume | 1 | X | R | 1/8
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::OID")]
 pub struct OID {
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "OID-01 must be 1-30 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 22>",
+        message = "OID-02 must be 1-22 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "OID-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "OID-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "OID-05 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "OID-06 must be 1 character"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "OID-07 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "OID-08 must be 1 character"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "OID-09 must be a decimal number of 1-8 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
 }
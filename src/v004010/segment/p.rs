@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// P4 - U.S. Port Information
@@ -13,17 +15,53 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 04 | 310 | Location Identifier O AN 1/30
 /// 05 | 337 | Time O TM 4/8
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::P4")]
 pub struct P4 {
+    #[validate(
+        length(min = 1, message = "P4-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "P4-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "P4-02 is mandatory"),
+        custom(
+            function = "datatype::date::<8, 8>",
+            message = "P4-02 must be a date of 8 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "P4-03 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "P4-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::time::<4, 8>",
+        message = "P4-05 must be a time of 4-8 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
 }
@@ -38,13 +76,44 @@ pub struct P4 {
 /// 02 | 309 | Location Qualifier | M |  | ID 1/2
 /// 03 | 310 | Location Identifier | M |  | AN 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::P5")]
 pub struct P5 {
+    #[validate(
+        length(min = 1, message = "P5-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "P5-01 must be 1 character"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "P5-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 2>",
+            message = "P5-02 must be 1-2 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "P5-03 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "P5-03 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
 }
@@ -55,6 +124,7 @@ pub struct P5 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PAM")]
 pub struct PAM {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -104,9 +174,26 @@ pub struct PAM {
 /// 08 | 364 | Communication Number | 1 | X | AN | 1/80
 /// 09 | 443 | Contact Inquiry Reference | 1 | O | AN | 1/20
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PER")]
 pub struct PER {
+    #[validate(
+        length(min = 1, message = "PER-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "PER-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 93 - Name
@@ -115,20 +202,52 @@ pub struct PER {
     /// - TYPE=AN
     /// - MIN=1
     /// - MAX=60
+    #[validate(custom(
+        function = "datatype::string::<1, 60>",
+        message = "PER-02 must be 1-60 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "PER-03 must be 2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "PER-04 must be 1-80 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "PER-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "PER-06 must be 1-80 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "PER-07 must be 2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "PER-08 must be 1-80 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 20>",
+        message = "PER-09 must be 1-20 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
 }
@@ -155,29 +274,89 @@ pub struct PER {
 /// 14 | 629 | Alternation Precedence Code | 1 | O | ID | 1/1
 /// 15 | 629 | Alternation Precedence Code | 1 | O | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PI")]
 pub struct PI {
+    #[validate(
+        length(min = 1, message = "PI-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "PI-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "PI-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "PI-02 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "PI-03 must be 2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 5>",
+        message = "PI-04 must be 3-5 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "PI-05 must be 1-4 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "PI-06 must be 1-10 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "PI-07 must be 1-2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 16>",
+        message = "PI-08 must be 1-16 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "PI-09 must be 1-4 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "PI-10 must be 1-2 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "PI-11 must be 1-2 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
     /// 373 - Date
@@ -186,6 +365,10 @@ pub struct PI {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "PI-12 must be a date of 8 digits"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
     /// 373 - Date
@@ -194,10 +377,22 @@ pub struct PI {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "PI-13 must be a date of 8 digits"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "PI-14 must be 1 character"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "PI-15 must be 1 character"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
 }
@@ -208,6 +403,7 @@ pub struct PI {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PID")]
 pub struct PID {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -237,6 +433,7 @@ pub struct PID {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PKG")]
 pub struct PKG {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -265,15 +462,44 @@ pub struct PKG {
 /// 03 | 188 | Weight Unit Code | 1 | X | ID | 1/1
 /// 04 | 81 | Weight | 1 | X | R | 1/10
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PLD")]
 pub struct PLD {
+    #[validate(
+        length(min = 1, message = "PLD-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 3>",
+            message = "PLD-01 must be a number of 1-3 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "PLD-02 must be 1 character"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "PLD-03 must be 1 character"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "PLD-04 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
 }
@@ -284,6 +510,7 @@ pub struct PLD {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PO4")]
 pub struct PO4 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -337,13 +564,38 @@ pub struct PO4 {
 /// 06 | 367 | Contract Number | 1 | O | AN | 1/30
 /// 07 | 92 | Purchase Order Type Code | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PRF")]
 pub struct PRF {
+    #[validate(
+        length(min = 1, message = "PRF-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 22>",
+            message = "PRF-01 must be 1-22 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "PRF-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 8>",
+        message = "PRF-03 must be 1-8 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
     /// 373 - Date
@@ -352,12 +604,28 @@ pub struct PRF {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "PRF-04 must be a date of 8 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 20>",
+        message = "PRF-05 must be 1-20 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "PRF-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "PRF-07 must be 2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -383,19 +651,59 @@ pub struct PRF {
 /// 13 | 1073 | Yes/No Condition or Response Code | 1 | O/Z | ID | 1/1
 /// 14 | 408 | Temperature | 1 | X/Z | R | 1/4
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PS")]
 pub struct PS {
+    #[validate(
+        length(min = 1, message = "PS-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 9>",
+            message = "PS-01 must be 3-9 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "PS-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 4>",
+            message = "PS-02 must be 1-4 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "PS-03 must be 2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 4>",
+        message = "PS-04 must be a decimal number of 1-4 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "PS-05 must be 2-4 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 5>",
+        message = "PS-06 must be 1-5 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
     /// 19 - City Name
@@ -404,20 +712,52 @@ pub struct PS {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "PS-07 must be 2-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "PS-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "PS-09 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "PS-10 must be 1 character"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "PS-11 must be 1 character"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "PS-12 must be 1 character"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "PS-13 must be 1 character"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 4>",
+        message = "PS-14 must be a decimal number of 1-4 digits"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
 }
@@ -438,25 +778,70 @@ pub struct PS {
 /// 08 | C002 | Actions Indicated | O |  |
 /// 09 | 1525 | Request Category Code | O |  | ID 1/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PWK")]
 pub struct PWK {
+    #[validate(
+        length(min = 1, message = "PWK-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "PWK-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "PWK-02 must be 1-2 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 2>",
+        message = "PWK-03 must be a number of 1-2 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 3>",
+        message = "PWK-04 must be 2-3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "PWK-05 must be 1-2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 80>",
+        message = "PWK-06 must be 2-80 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "PWK-07 must be 1-80 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "PWK-09 must be 1-2 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
 }
@@ -467,6 +852,7 @@ pub struct PWK {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PAL")]
 pub struct PAL {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// Q2 - Status Details (Ocean)
@@ -24,39 +26,116 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 15 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 16 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::Q2")]
 pub struct Q2 {
+    #[validate(
+        length(min = 1, message = "Q2-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "Q2-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-11 must be 1-30 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-12 must be 1-30 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-13 must be 1-30 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-14 must be 1-30 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-15 must be 1-30 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q2-16 must be 1-30 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
 }
@@ -78,27 +157,80 @@ pub struct Q2 {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::Q5")]
 pub struct Q5 {
+    #[validate(
+        length(min = 1, message = "Q5-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "Q5-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q5-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q5-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q5-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q5-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q5-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q5-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q5-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q5-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q5-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -120,27 +252,80 @@ pub struct Q5 {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::Q7")]
 pub struct Q7 {
+    #[validate(
+        length(min = 1, message = "Q7-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "Q7-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q7-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q7-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q7-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q7-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q7-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q7-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q7-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q7-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "Q7-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -167,37 +352,113 @@ pub struct Q7 {
 /// 14 | 373 | Date | 1 | O | DT | 8/8
 /// 15 | 337 | Time | 1 | O | TM | 4/8
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::QTY")]
 pub struct QTY {
+    #[validate(
+        length(min = 1, message = "QTY-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "QTY-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "QTY-02 is mandatory"),
+        custom(
+            function = "datatype::decimal::<1, 15>",
+            message = "QTY-02 must be a decimal number of 1-15 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "QTY-03 must be 2 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "QTY-04 must be 3 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "QTY-05 must be a date of 8 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::time::<4, 8>",
+        message = "QTY-06 must be a time of 4-8 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "QTY-07 must be 3 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "QTY-08 must be a date of 8 digits"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::time::<4, 8>",
+        message = "QTY-09 must be a time of 4-8 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "QTY-10 must be 3 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "QTY-11 must be a date of 8 digits"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::time::<4, 8>",
+        message = "QTY-12 must be a time of 4-8 digits"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<3, 3>",
+        message = "QTY-13 must be 3 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "QTY-14 must be a date of 8 digits"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::time::<4, 8>",
+        message = "QTY-15 must be a time of 4-8 digits"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
 }
@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// SE - Transaction Set Trailer
@@ -10,11 +12,35 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 01 | 96 | Number of Included Segments | 1 | M | N0 | 1/10
 /// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SE")]
 pub struct SE {
+    #[validate(
+        length(min = 1, message = "SE-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 10>",
+            message = "SE-01 must be a number of 1-10 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "SE-02 is mandatory"),
+        custom(
+            function = "datatype::string::<4, 9>",
+            message = "SE-02 must be 4-9 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -36,27 +62,83 @@ pub struct SE {
 /// 09 | 499 | Relationship Code | 1 | O | ID | 1/1
 /// 10 | 289 | Unit Price | 1 | O | R | 1/17
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SN1")]
 pub struct SN1 {
+    #[validate(custom(
+        function = "datatype::string::<1, 20>",
+        message = "SN1-01 must be 1-20 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(
+        length(min = 1, message = "SN1-02 is mandatory"),
+        custom(
+            function = "datatype::decimal::<1, 10>",
+            message = "SN1-02 must be a decimal number of 1-10 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "SN1-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "SN1-03 must be 2 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "SN1-04 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "SN1-05 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SN1-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "SN1-07 must be 1 character"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SN1-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "SN1-09 must be 1 character"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 17>",
+        message = "SN1-10 must be a decimal number of 1-17 digits"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -93,57 +175,173 @@ pub struct SN1 {
 /// 24 | 380 | Amount | 1 | O | R | 1/15
 /// 25 | 380 | Amount | 1 | O | R | 1/15
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SR")]
 pub struct SR {
+    #[validate(
+        length(min = 1, message = "SR-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "SR-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "SR-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 4>",
+            message = "SR-02 must be 1-4 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "SR-03 must be 1-4 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "SR-04 must be 1-4 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "SR-05 must be 1-4 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "SR-06 must be 1-4 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "SR-07 must be 1-4 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "SR-08 must be 1-4 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "SR-09 must be 1-4 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "SR-10 must be 1-4 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "SR-11 must be 1-4 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "SR-12 must be 1-80 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SR-13 must be 2 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 4>",
+        message = "SR-14 must be 1-4 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-15 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-16 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-17 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-18 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-19 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "19")]
     pub _19: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-20 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "20")]
     pub _20: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-21 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "21")]
     pub _21: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-22 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "22")]
     pub _22: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-23 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "23")]
     pub _23: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-24 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "24")]
     pub _24: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SR-25 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "25")]
     pub _25: Option<String>,
 }
@@ -161,19 +359,59 @@ pub struct SR {
 /// 05 | 1250 | Shipment Status Code | 1 | O | ID | 2/2
 /// 06 | 1251 | Shipment Status or Appointments Reason Code | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SG")]
 pub struct SG {
+    #[validate(
+        length(min = 1, message = "SG-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "SG-01 must be 3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "SG-02 is mandatory"),
+        custom(
+            function = "datatype::date::<8, 8>",
+            message = "SG-02 must be a date of 8 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::time::<4, 8>",
+        message = "SG-03 must be a time of 4-8 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SG-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SG-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SG-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
 }
@@ -210,57 +448,176 @@ pub struct SG {
 /// 24 | 1270 | Shipment Status or Appointments Reason Code | 1 | O | ID | 2/2
 /// 25 | 1271 | Shipment Status or Appointments Reason Code | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SDQ")]
 pub struct SDQ {
+    #[validate(
+        length(min = 1, message = "SDQ-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 20>",
+            message = "SDQ-01 must be 1-20 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "SDQ-02 is mandatory"),
+        custom(
+            function = "datatype::decimal::<1, 15>",
+            message = "SDQ-02 must be a decimal number of 1-15 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "SDQ-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "SDQ-03 must be 2 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-07 must be 2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-09 must be 2 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-10 must be 2 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-11 must be 2 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-12 must be 2 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-13 must be 2 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-14 must be 2 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-15 must be 2 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-16 must be 2 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-17 must be 2 characters"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-18 must be 2 characters"
+    ))]
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-19 must be 2 characters"
+    ))]
     #[serde(rename = "19")]
     pub _19: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-20 must be 2 characters"
+    ))]
     #[serde(rename = "20")]
     pub _20: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-21 must be 2 characters"
+    ))]
     #[serde(rename = "21")]
     pub _21: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-22 must be 2 characters"
+    ))]
     #[serde(rename = "22")]
     pub _22: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-23 must be 2 characters"
+    ))]
     #[serde(rename = "23")]
     pub _23: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-24 must be 2 characters"
+    ))]
     #[serde(rename = "24")]
     pub _24: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SDQ-25 must be 2 characters"
+    ))]
     #[serde(rename = "25")]
     pub _25: Option<String>,
 }
@@ -274,13 +631,37 @@ pub struct SDQ {
 /// 01 | 143 | Transaction Set Identifier Code | 1 | M/Z | ID | 3/3
 /// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ST")]
 pub struct ST {
     /// 143 - Transaction Set Identifier Code 3/3
+    #[validate(
+        length(min = 1, message = "ST-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "ST-01 must be 3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 329 - Transaction Set Control Number 4/9
+    #[validate(
+        length(min = 1, message = "ST-02 is mandatory"),
+        custom(
+            function = "datatype::string::<4, 9>",
+            message = "ST-02 must be 4-9 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -302,27 +683,80 @@ pub struct ST {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::S1")]
 pub struct S1 {
+    #[validate(
+        length(min = 1, message = "S1-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "S1-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S1-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S1-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S1-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S1-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S1-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S1-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S1-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S1-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S1-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -344,27 +778,80 @@ pub struct S1 {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::S2")]
 pub struct S2 {
+    #[validate(
+        length(min = 1, message = "S2-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "S2-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S2-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S2-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S2-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S2-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S2-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S2-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S2-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S2-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S2-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -386,27 +873,76 @@ pub struct S2 {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::S5")]
 pub struct S5 {
+    #[validate(
+        length(min = 1, message = "S5-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "S5-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S5-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S5-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S5-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S5-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S5-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S5-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S5-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S5-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
     #[serde(rename = "11")]
@@ -430,27 +966,80 @@ pub struct S5 {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::S9")]
 pub struct S9 {
+    #[validate(
+        length(min = 1, message = "S9-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "S9-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S9-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S9-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S9-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S9-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S9-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S9-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S9-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S9-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "S9-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -487,57 +1076,173 @@ pub struct S9 {
 /// 24 | 380 | Quantity | 1 | O | R | 1/15
 /// 25 | 380 | Quantity | 1 | O | R | 1/15
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SAC")]
 pub struct SAC {
+    #[validate(
+        length(min = 1, message = "SAC-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "SAC-01 must be 1 character"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "SAC-02 is mandatory"),
+        custom(
+            function = "datatype::string::<4, 4>",
+            message = "SAC-02 must be 4 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 18>",
+        message = "SAC-03 must be a decimal number of 1-18 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SAC-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "SAC-05 must be 1-10 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "SAC-06 must be 1 character"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 6>",
+        message = "SAC-07 must be a decimal number of 1-6 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-08 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-09 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 9>",
+        message = "SAC-10 must be a decimal number of 1-9 digits"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-11 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SAC-12 must be 2 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-13 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-14 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-15 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-16 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-17 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-18 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-19 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "19")]
     pub _19: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-20 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "20")]
     pub _20: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-21 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "21")]
     pub _21: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-22 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "22")]
     pub _22: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-23 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "23")]
     pub _23: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-24 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "24")]
     pub _24: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 15>",
+        message = "SAC-25 must be a decimal number of 1-15 digits"
+    ))]
     #[serde(rename = "25")]
     pub _25: Option<String>,
 }
@@ -579,67 +1284,206 @@ pub struct SAC {
 /// 29 | 235 | Product/Service ID Qualifier | 1 | O | ID | 2/2
 /// 30 | 234 | Product/Service ID | 1 | O | AN | 1/48
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SLN")]
 pub struct SLN {
+    #[validate(
+        length(min = 1, message = "SLN-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 20>",
+            message = "SLN-01 must be 1-20 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 20>",
+        message = "SLN-02 must be 1-20 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(
+        length(min = 1, message = "SLN-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "SLN-03 must be 2 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(
+        length(min = 1, message = "SLN-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 48>",
+            message = "SLN-04 must be 1-48 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-05 must be 2 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-06 must be 1-48 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-07 must be 2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-08 must be 1-48 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-09 must be 2 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-10 must be 1-48 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-11 must be 2 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-12 must be 1-48 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-13 must be 2 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-14 must be 1-48 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-15 must be 2 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-16 must be 1-48 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-17 must be 2 characters"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-18 must be 1-48 characters"
+    ))]
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-19 must be 2 characters"
+    ))]
     #[serde(rename = "19")]
     pub _19: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-20 must be 1-48 characters"
+    ))]
     #[serde(rename = "20")]
     pub _20: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-21 must be 2 characters"
+    ))]
     #[serde(rename = "21")]
     pub _21: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-22 must be 1-48 characters"
+    ))]
     #[serde(rename = "22")]
     pub _22: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-23 must be 2 characters"
+    ))]
     #[serde(rename = "23")]
     pub _23: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-24 must be 1-48 characters"
+    ))]
     #[serde(rename = "24")]
     pub _24: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-25 must be 2 characters"
+    ))]
     #[serde(rename = "25")]
     pub _25: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-26 must be 1-48 characters"
+    ))]
     #[serde(rename = "26")]
     pub _26: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-27 must be 2 characters"
+    ))]
     #[serde(rename = "27")]
     pub _27: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-28 must be 1-48 characters"
+    ))]
     #[serde(rename = "28")]
     pub _28: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "SLN-29 must be 2 characters"
+    ))]
     #[serde(rename = "29")]
     pub _29: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 48>",
+        message = "SLN-30 must be 1-48 characters"
+    ))]
     #[serde(rename = "30")]
     pub _30: Option<String>,
 }
@@ -661,27 +1505,80 @@ pub struct SLN {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SPO")]
 pub struct SPO {
+    #[validate(
+        length(min = 1, message = "SPO-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "SPO-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "SPO-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "SPO-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "SPO-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "SPO-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "SPO-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "SPO-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "SPO-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "SPO-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "SPO-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
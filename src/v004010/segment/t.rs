@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// T1 - Transit Inbound Origin
@@ -17,11 +19,32 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 08 | 229 | Transit Registration Number | 1 | O | AN | 1/6
 /// 09 | 461 | Transit Level Code | 1 | O | ID | 1/3
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::T1")]
 pub struct T1 {
+    #[validate(
+        length(min = 1, message = "T1-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 6>",
+            message = "T1-01 must be a number of 1-6 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 6>",
+        message = "T1-02 must be a number of 1-6 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
     /// 373 - Date
@@ -30,8 +53,16 @@ pub struct T1 {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "T1-03 must be a date of 8 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 4>",
+        message = "T1-04 must be 2-4 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
     /// 19 - City Name
@@ -40,14 +71,34 @@ pub struct T1 {
     /// - TYPE=AN
     /// - MIN=2
     /// - MAX=30
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "T1-05 must be 2-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "T1-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<6, 9>",
+        message = "T1-07 must be 6-9 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 6>",
+        message = "T1-08 must be 1-6 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 3>",
+        message = "T1-09 must be 1-3 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
 }
@@ -71,35 +122,198 @@ pub struct T1 {
 /// 11 | 462 | Through Surcharge Percent | 1 | O | N2 | 2/4
 /// 12 | 463 | Paid-In Surcharge Percent | 1 | O | N2 | 2/4
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::T2")]
 pub struct T2 {
+    #[validate(
+        length(min = 1, message = "T2-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 6>",
+            message = "T2-01 must be a number of 1-6 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 50>",
+        message = "T2-02 must be 1-50 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "T2-03 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "T2-04 must be 1-2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 9>",
+        message = "T2-05 must be a decimal number of 1-9 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "T2-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 9>",
+        message = "T2-07 must be a decimal number of 1-9 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "T2-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "T2-09 must be 2-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 30>",
+        message = "T2-10 must be 2-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<2, 4>",
+        message = "T2-11 must be a number of 2-4 digits"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<2, 4>",
+        message = "T2-12 must be a number of 2-4 digits"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
 }
 
+/// TA1 - Interchange Acknowledgment
+///
+/// To report the status of processing a received interchange header and trailer or the non-delivery by a network provider
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | I12 | Interchange Control Number | 1 | M | N0 | 9/9
+/// 02 | I08 | Interchange Date | 1 | M | DT | 6/6
+/// 03 | I09 | Interchange Time | 1 | M | TM | 4/4
+/// 04 | I17 | Interchange Acknowledgment Code | 1 | M | ID | 1/1
+/// 05 | I18 | Interchange Note Code | 1 | M | ID | 3/3
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+#[serde(rename = "$x12::segment::TA1")]
+pub struct TA1 {
+    /// I12 - Interchange Control Number
+    ///
+    /// A control number assigned by the interchange sender
+    /// - TYPE=N0
+    /// - MIN=9
+    /// - MAX=9
+    #[validate(
+        length(min = 1, message = "TA1-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "TA1-01 must be a number of 9 digits"
+        )
+    )]
+    #[serde(rename = "01")]
+    pub _01: String,
+    /// I08 - Interchange Date
+    ///
+    /// Date of the interchange
+    /// - TYPE=DT
+    /// - MIN=6
+    /// - MAX=6
+    #[validate(
+        length(min = 1, message = "TA1-02 is mandatory"),
+        custom(
+            function = "datatype::date::<6, 6>",
+            message = "TA1-02 must be a date of 6 digits"
+        )
+    )]
+    #[serde(rename = "02")]
+    pub _02: String,
+    /// I09 - Interchange Time
+    ///
+    /// Time of the interchange
+    /// - TYPE=TM
+    /// - MIN=4
+    /// - MAX=4
+    #[validate(
+        length(min = 1, message = "TA1-03 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 4>",
+            message = "TA1-03 must be a time of 4 digits"
+        )
+    )]
+    #[serde(rename = "03")]
+    pub _03: String,
+    /// I17 - Interchange Acknowledgment Code
+    ///
+    /// This indicates the status of the receipt of the interchange control structure
+    /// - TYPE=ID
+    /// - MIN=1
+    /// - MAX=1
+    #[validate(
+        length(min = 1, message = "TA1-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "TA1-04 must be 1 character"
+        )
+    )]
+    #[serde(rename = "04")]
+    pub _04: String,
+    /// I18 - Interchange Note Code
+    ///
+    /// This numeric code indicates the error found processing the interchange control structure
+    /// - TYPE=ID
+    /// - MIN=3
+    /// - MAX=3
+    #[validate(
+        length(min = 1, message = "TA1-05 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "TA1-05 must be 3 characters"
+        )
+    )]
+    #[serde(rename = "05")]
+    pub _05: String,
+}
+
 /// TC2 - Commodity
 ///
 /// To specify commodity details
@@ -117,27 +331,80 @@ pub struct T2 {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TC2")]
 pub struct TC2 {
+    #[validate(
+        length(min = 1, message = "TC2-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "TC2-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "TC2-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "TC2-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "TC2-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "TC2-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "TC2-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "TC2-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "TC2-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "TC2-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "TC2-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -159,27 +426,77 @@ pub struct TC2 {
 /// 09 | 183 | Volume | 1 | O | R | 1/8
 /// 10 | 355 | Unit or Basis for Measurement Code | 1 | O | ID | 2/2
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TD1")]
 pub struct TD1 {
+    #[validate(custom(
+        function = "datatype::string::<3, 5>",
+        message = "TD1-01 must be 3-5 characters"
+    ))]
     #[serde(rename = "01")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 7>",
+        message = "TD1-02 must be a decimal number of 1-7 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "TD1-03 must be 1 character"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "TD1-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 50>",
+        message = "TD1-05 must be 1-50 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "TD1-06 must be 1-2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "TD1-07 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "TD1-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "TD1-09 must be a decimal number of 1-8 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "TD1-10 must be 2 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -211,47 +528,146 @@ pub struct TD1 {
 /// 19 | 387 | Equipment Number Check Digit | 1 | O | N0 | 1/1
 /// 20 | 387 | Equipment Number Check Digit | 1 | O | N0 | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TD3")]
 pub struct TD3 {
+    #[validate(
+        length(min = 1, message = "TD3-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "TD3-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "TD3-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 4>",
+            message = "TD3-02 must be 1-4 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "TD3-03 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 10>",
+            message = "TD3-03 must be 1-10 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-04 must be a number of 1 digit"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 10>",
+        message = "TD3-05 must be a decimal number of 1-10 digits"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "TD3-06 must be 2 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 8>",
+        message = "TD3-07 must be a decimal number of 1-8 digits"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "TD3-08 must be 2 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-09 must be a number of 1 digit"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-10 must be a number of 1 digit"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-11 must be a number of 1 digit"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-12 must be a number of 1 digit"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-13 must be a number of 1 digit"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-14 must be a number of 1 digit"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-15 must be a number of 1 digit"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-16 must be a number of 1 digit"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-17 must be a number of 1 digit"
+    ))]
     #[serde(rename = "17")]
     pub _17: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-18 must be a number of 1 digit"
+    ))]
     #[serde(rename = "18")]
     pub _18: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-19 must be a number of 1 digit"
+    ))]
     #[serde(rename = "19")]
     pub _19: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD3-20 must be a number of 1 digit"
+    ))]
     #[serde(rename = "20")]
     pub _20: Option<String>,
 }
@@ -273,27 +689,86 @@ pub struct TD3 {
 /// 09 | 387 | Equipment Number Check Digit | 1 | O | N0 | 1/1
 /// 10 | 387 | Equipment Number Check Digit | 1 | O | N0 | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TD5")]
 pub struct TD5 {
+    #[validate(
+        length(min = 1, message = "TD5-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 2>",
+            message = "TD5-01 must be 1-2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "TD5-02 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "TD5-02 must be 2 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "TD5-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 80>",
+            message = "TD5-03 must be 2-80 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 2>",
+        message = "TD5-04 must be 1-2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD5-05 must be a number of 1 digit"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD5-06 must be a number of 1 digit"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD5-07 must be a number of 1 digit"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD5-08 must be a number of 1 digit"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD5-09 must be a number of 1 digit"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::numeric::<1, 1>",
+        message = "TD5-10 must be a number of 1 digit"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -315,27 +790,80 @@ pub struct TD5 {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::T3")]
 pub struct T3 {
+    #[validate(
+        length(min = 1, message = "T3-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "T3-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T3-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T3-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T3-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T3-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T3-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T3-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T3-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T3-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T3-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -357,27 +885,80 @@ pub struct T3 {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::T6")]
 pub struct T6 {
+    #[validate(
+        length(min = 1, message = "T6-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "T6-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T6-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T6-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T6-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T6-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T6-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T6-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T6-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T6-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T6-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -399,27 +980,80 @@ pub struct T6 {
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 10 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::T8")]
 pub struct T8 {
+    #[validate(
+        length(min = 1, message = "T8-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "T8-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T8-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T8-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T8-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T8-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T8-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T8-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T8-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T8-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "T8-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
@@ -432,9 +1066,26 @@ pub struct T8 {
 /// ----|----|-------|--------|----|------|-------
 /// 01 | 610 | Total Amount | 1 | M | R | 1/15
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TDS")]
 pub struct TDS {
+    #[validate(
+        length(min = 1, message = "TDS-01 is mandatory"),
+        custom(
+            function = "datatype::decimal::<1, 15>",
+            message = "TDS-01 must be a decimal number of 1-15 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
 }
@@ -461,37 +1112,110 @@ pub struct TDS {
 /// 14 | 956 | Tax Jurisdiction Code | 1 | O | AN | 1/10
 /// 15 | 441 | Tax Exempt Code | 1 | O | ID | 1/1
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TXI")]
 pub struct TXI {
+    #[validate(
+        length(min = 1, message = "TXI-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "TXI-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 18>",
+        message = "TXI-02 must be a decimal number of 1-18 digits"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::decimal::<1, 6>",
+        message = "TXI-03 must be a decimal number of 1-6 digits"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "TXI-04 must be 2 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "TXI-05 must be 1-10 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "TXI-06 must be 1 character"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "TXI-07 must be 2 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "TXI-08 must be 1-10 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "TXI-09 must be 1 character"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "TXI-10 must be 2 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "TXI-11 must be 1-10 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "TXI-12 must be 1 character"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<2, 2>",
+        message = "TXI-13 must be 2 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 10>",
+        message = "TXI-14 must be 1-10 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 1>",
+        message = "TXI-15 must be 1 character"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
 }
@@ -502,6 +1226,7 @@ pub struct TXI {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TD4")]
 pub struct TD4 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -591,6 +1316,7 @@ pub struct TD4 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TSD")]
 pub struct TSD {
     #[serde(rename = "01")]
     pub _01: String,
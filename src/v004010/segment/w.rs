@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// W03 - Total Shipment Information
@@ -7,6 +9,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W03")]
 pub struct W03 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -30,6 +33,7 @@ pub struct W03 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W06")]
 pub struct W06 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -57,6 +61,7 @@ pub struct W06 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W10")]
 pub struct W10 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -84,6 +89,7 @@ pub struct W10 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W12")]
 pub struct W12 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -137,6 +143,7 @@ pub struct W12 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W27")]
 pub struct W27 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -166,6 +173,7 @@ pub struct W27 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W28")]
 pub struct W28 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -206,23 +214,77 @@ pub struct W28 {
 /// 15 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 16 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W2")]
 pub struct W2 {
+    #[validate(
+        length(min = 1, message = "W2-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "W2-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "W2-02 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "W2-02 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(
+        length(min = 1, message = "W2-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "W2-04 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
+    #[validate(
+        length(min = 1, message = "W2-05 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "W2-05 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "05")]
     pub _05: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
     /// 373 - Date
@@ -231,20 +293,52 @@ pub struct W2 {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "W2-09 must be a date of 8 digits"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-10 must be 1-30 characters"
+    ))]
     #[serde(rename = "10")]
     pub _10: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-11 must be 1-30 characters"
+    ))]
     #[serde(rename = "11")]
     pub _11: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-12 must be 1-30 characters"
+    ))]
     #[serde(rename = "12")]
     pub _12: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-13 must be 1-30 characters"
+    ))]
     #[serde(rename = "13")]
     pub _13: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-14 must be 1-30 characters"
+    ))]
     #[serde(rename = "14")]
     pub _14: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-15 must be 1-30 characters"
+    ))]
     #[serde(rename = "15")]
     pub _15: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W2-16 must be 1-30 characters"
+    ))]
     #[serde(rename = "16")]
     pub _16: Option<String>,
 }
@@ -265,25 +359,74 @@ pub struct W2 {
 /// 08 | 127 | Reference Identification | 1 | O | AN | 1/30
 /// 09 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W09")]
 pub struct W09 {
+    #[validate(
+        length(min = 1, message = "W09-01 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "W09-01 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W09-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W09-03 must be 1-30 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W09-04 must be 1-30 characters"
+    ))]
     #[serde(rename = "04")]
     pub _04: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W09-05 must be 1-30 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W09-06 must be 1-30 characters"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W09-07 must be 1-30 characters"
+    ))]
     #[serde(rename = "07")]
     pub _07: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W09-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "W09-09 must be 1-30 characters"
+    ))]
     #[serde(rename = "09")]
     pub _09: Option<String>,
 }
@@ -294,6 +437,7 @@ pub struct W09 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W01")]
 pub struct W01 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -323,6 +467,7 @@ pub struct W01 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W05")]
 pub struct W05 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -346,6 +491,7 @@ pub struct W05 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W6")]
 pub struct W6 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -367,6 +513,7 @@ pub struct W6 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W20")]
 pub struct W20 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -388,6 +535,7 @@ pub struct W20 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W66")]
 pub struct W66 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -417,6 +565,7 @@ pub struct W66 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::W76")]
 pub struct W76 {
     #[serde(rename = "01")]
     pub _01: String,
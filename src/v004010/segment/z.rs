@@ -1,4 +1,6 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
 /// ZC1 - Contract Information
@@ -16,15 +18,47 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 /// 07 | 127 | Reference Identification | 1 | M | AN | 1/30
 /// 08 | 127 | Reference Identification | 1 | O | AN | 1/30
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ZC1")]
 pub struct ZC1 {
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "ZC1-01 must be 1-30 characters"
+    ))]
     #[serde(rename = "01", skip_serializing_if = "Option::is_none")]
     pub _01: Option<String>,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "ZC1-02 must be 1-30 characters"
+    ))]
     #[serde(rename = "02", skip_serializing_if = "Option::is_none")]
     pub _02: Option<String>,
+    #[validate(
+        length(min = 1, message = "ZC1-03 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "ZC1-03 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(
+        length(min = 1, message = "ZC1-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "ZC1-04 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
     /// 243 - Transaction Reference Date
@@ -33,12 +67,37 @@ pub struct ZC1 {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(
+        length(min = 1, message = "ZC1-05 is mandatory"),
+        custom(
+            function = "datatype::date::<8, 8>",
+            message = "ZC1-05 must be a date of 8 digits"
+        )
+    )]
     #[serde(rename = "05")]
     pub _05: String,
+    #[validate(
+        length(min = 1, message = "ZC1-06 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "ZC1-06 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "06")]
     pub _06: String,
+    #[validate(
+        length(min = 1, message = "ZC1-07 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 30>",
+            message = "ZC1-07 must be 1-30 characters"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
+    #[validate(custom(
+        function = "datatype::string::<1, 30>",
+        message = "ZC1-08 must be 1-30 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
 }
@@ -58,17 +117,56 @@ pub struct ZC1 {
 /// 07 | 96 | Number of Included Segments | 1 | M | N0 | 1/10
 /// 08 | 329 | Transaction Set Control Number | 1 | O | AN | 4/9
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ZD")]
 pub struct ZD {
+    #[validate(
+        length(min = 1, message = "ZD-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 10>",
+            message = "ZD-01 must be a number of 1-10 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(custom(
+        function = "datatype::string::<4, 9>",
+        message = "ZD-02 must be 4-9 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
+    #[validate(
+        length(min = 1, message = "ZD-03 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 10>",
+            message = "ZD-03 must be a number of 1-10 digits"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
+    #[validate(
+        length(min = 1, message = "ZD-04 is mandatory"),
+        custom(
+            function = "datatype::string::<4, 9>",
+            message = "ZD-04 must be 4-9 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
+    #[validate(custom(
+        function = "datatype::string::<4, 9>",
+        message = "ZD-05 must be 4-9 characters"
+    ))]
     #[serde(rename = "05")]
     pub _05: Option<String>,
     /// 243 - Transaction Reference Date
@@ -77,10 +175,25 @@ pub struct ZD {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(custom(
+        function = "datatype::date::<8, 8>",
+        message = "ZD-06 must be a date of 8 digits"
+    ))]
     #[serde(rename = "06")]
     pub _06: Option<String>,
+    #[validate(
+        length(min = 1, message = "ZD-07 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 10>",
+            message = "ZD-07 must be a number of 1-10 digits"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
+    #[validate(custom(
+        function = "datatype::string::<4, 9>",
+        message = "ZD-08 must be 4-9 characters"
+    ))]
     #[serde(rename = "08")]
     pub _08: Option<String>,
 }
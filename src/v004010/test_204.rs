@@ -266,3 +266,35 @@ fn test_3() {
     println!("{obj:?}");
     assert!(rest.is_empty());
 }
+
+#[test]
+fn validate_204_structure() {
+    use crate::envelope::Version;
+    use crate::validation::{validate_structure, SegmentSyntaxError, TransactionStructure};
+    let str = r#"ISA*00*          *00*          *ZZ*XXXXXXXXX      *ZZ*XXXXXX         *230517*1710*U*00401*000022310*0*P*+~GS*SM*XXXXXXXXX*SUDU*20230517*1710*22310*X*004010~ST*204*22310~B2**SUDU**3PHLT0XXXX**DE~B2A*00~L11*3PHLT0XXXX*9R~S5*1*LD~N1*PW*UNION PACIFIC ICTF RAMP~S5*2*DT~SE*7*22310~GE*1*22310~IEA*1*000022310~"#;
+    let (_, obj) = Transmission::<_204>::parse(str).unwrap();
+    let set = &obj.functional_group[0].segments[0];
+    assert_eq!(set.validate_structure().unwrap(), vec![]);
+
+    let lh6 = "LH6*HAZARDOUS CARGO~".repeat(7);
+    let n1 = "N1*SH*KAWASAKI MOTORS MFG CORP~".repeat(6);
+    let str = format!("ISA*00*          *00*          *ZZ*XXXXXXXXX      *ZZ*XXXXXX         *230517*1710*U*00401*000022310*0*P*+~GS*SM*XXXXXXXXX*SUDU*20230517*1710*22310*X*004010~ST*204*22310~B2**SUDU**3PHLT0XXXX**DE~B2A*00~L11*3PHLT0XXXX*9R~{lh6}{n1}SE*18*22310~GE*1*22310~IEA*1*000022310~");
+    let (_, obj) = Transmission::<_204>::parse(&str).unwrap();
+    let set = &obj.functional_group[0].segments[0];
+    let errors = validate_structure(set, V004010::transaction_structure("204")).unwrap();
+    assert_eq!(errors, set.validate_structure().unwrap());
+    let errors: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        errors,
+        vec![
+            "LH6 at 11: Segment Exceeds Maximum Use",
+            "N1 at 17 in loop 0100: Loop Occurs Over Maximum Times",
+            "S5 at 18 in loop 0300: Mandatory segment missing",
+        ]
+    );
+    assert_eq!(
+        set.validate_structure().unwrap()[2].error,
+        SegmentSyntaxError::MandatoryMissing
+    );
+    assert!(V004010::transaction_structure("999").is_empty());
+}
@@ -116,6 +116,7 @@ fn parse_315() {
             _01: "1".to_string(),
             _02: "000000001".to_string(),
         },
+        ..Default::default()
     };
     let str = format!("{x}");
     println!("{str}");
@@ -267,6 +268,7 @@ fn test_315() {
             _01: "1".to_string(),
             _02: "000000001".to_string(),
         },
+        ..Default::default()
     };
     let serialized = format!("{x}");
     let original = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
@@ -403,6 +405,7 @@ fn test_315_defaults() {
             _01: "1".to_string(),
             _02: "000000001".to_string(),
         },
+        ..Default::default()
     };
     let serialized = format!("{x}");
     let original = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
@@ -36,7 +36,7 @@ N9*PC*JAN0100~
 W76*56*500*LB*24*CF~
 SE*29*0001~
 GE*1*575103~
-IEA*1*3317     ~
+IEA*1*000003438~
 "#;
     let (rest, obj) = Transmission::<_940>::parse(str).unwrap();
     println!("{obj:?}");
@@ -520,6 +520,23 @@ IEA*1*999999999~"#;
     assert_eq!(obj.isa._16, "~"); // Component separator should be the tilde character
 }
 
+#[test]
+fn test_940_invalid_interchange_date_and_time() {
+    let str = r#"ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *1112O1*11:8*U*00401*000000001*0*P*>~
+GS*OW*SENDER*RECEIVER*20111201*1108*1*X*004010~
+ST*940*0001~
+W05*N*TEST*TEST~
+SE*2*0001~
+GE*1*1~
+IEA*1*000000001~"#;
+
+    let (rest, obj) = Transmission::<_940>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let err = obj.validate().unwrap_err().to_string();
+    assert!(err.contains("ISA-09 must be a date of 6 digits"));
+    assert!(err.contains("ISA-10 must be a time of 4 digits"));
+}
+
 #[test]
 fn test_940_usage_indicator_enum_valid_values() {
     // Test all valid usage indicator values
@@ -680,14 +697,13 @@ fn test_940_dynamic_segment_terminator_detection() {
 
     for (comp_sep, seg_term, description) in test_cases {
         let test_str = format!(
-            r#"ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *111201*1108*U*00401*000000001*0*P*{}{}
-GS*OW*SENDER*RECEIVER*20111201*1108*1*T*004010UCS~
-ST*940*0001~
-W05*N*TEST*TEST~
-SE*2*0001~
-GE*1*1~
-IEA*1*000000001~"#,
-            comp_sep, seg_term
+            r#"ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *111201*1108*U*00401*000000001*0*P*{comp_sep}{seg_term}
+GS*OW*SENDER*RECEIVER*20111201*1108*1*T*004010UCS{seg_term}
+ST*940*0001{seg_term}
+W05*N*TEST*TEST{seg_term}
+SE*2*0001{seg_term}
+GE*1*1{seg_term}
+IEA*1*000000001{seg_term}"#
         );
 
         let (rest, obj) = Transmission::<_940>::parse(&test_str).unwrap();
@@ -707,12 +723,13 @@ IEA*1*000000001~"#,
 fn test_940_newline_as_segment_terminator() {
     // Test when segment terminator is a newline character
     let test_str = r#"ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *111201*1108*U*00401*000000001*0*P*:
-GS*OW*SENDER*RECEIVER*20111201*1108*1*T*004010UCS~
-ST*940*0001~
-W05*N*TEST*TEST~
-SE*2*0001~
-GE*1*1~
-IEA*1*000000001~"#;
+GS*OW*SENDER*RECEIVER*20111201*1108*1*T*004010UCS
+ST*940*0001
+W05*N*TEST*TEST
+SE*2*0001
+GE*1*1
+IEA*1*000000001
+"#;
 
     let (rest, obj) = Transmission::<_940>::parse(test_str).unwrap();
     obj.validate().unwrap();
@@ -735,14 +752,13 @@ fn test_940_unicode_component_separator() {
 
     for (comp_sep, seg_term, description) in test_cases {
         let test_str = format!(
-            r#"ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *111201*1108*U*00401*000000001*0*P*{}{}
-GS*OW*SENDER*RECEIVER*20111201*1108*1*T*004010UCS~
-ST*940*0001~
-W05*N*TEST*TEST~
-SE*2*0001~
-GE*1*1~
-IEA*1*000000001~"#,
-            comp_sep, seg_term
+            r#"ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *111201*1108*U*00401*000000001*0*P*{comp_sep}{seg_term}
+GS*OW*SENDER*RECEIVER*20111201*1108*1*T*004010UCS{seg_term}
+ST*940*0001{seg_term}
+W05*N*TEST*TEST{seg_term}
+SE*2*0001{seg_term}
+GE*1*1{seg_term}
+IEA*1*000000001{seg_term}"#
         );
 
         let (rest, obj) = Transmission::<_940>::parse(&test_str).unwrap();
@@ -763,12 +779,12 @@ fn test_940_same_component_and_segment_separator() {
     // Test edge case where component separator and segment terminator are the same
     // This should still work because they are positionally different
     let test_str = r#"ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *111201*1108*U*00401*000000001*0*P*##
-GS*OW*SENDER*RECEIVER*20111201*1108*1*T*004010UCS~
-ST*940*0001~
-W05*N*TEST*TEST~
-SE*2*0001~
-GE*1*1~
-IEA*1*000000001~"#;
+GS*OW*SENDER*RECEIVER*20111201*1108*1*T*004010UCS#
+ST*940*0001#
+W05*N*TEST*TEST#
+SE*2*0001#
+GE*1*1#
+IEA*1*000000001#"#;
 
     let (rest, obj) = Transmission::<_940>::parse(test_str).unwrap();
     obj.validate().unwrap();
@@ -796,14 +812,13 @@ fn test_940_special_characters_as_separators() {
 
     for (comp_sep, seg_term, description) in test_cases {
         let test_str = format!(
-            r#"ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *111201*1108*U*00401*000000001*0*P*{}{}
-GS*OW*SENDER*RECEIVER*20111201*1108*1*T*004010UCS~
-ST*940*0001~
-W05*N*TEST*TEST~
-SE*2*0001~
-GE*1*1~
-IEA*1*000000001~"#,
-            comp_sep, seg_term
+            r#"ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *111201*1108*U*00401*000000001*0*P*{comp_sep}{seg_term}
+GS*OW*SENDER*RECEIVER*20111201*1108*1*T*004010UCS{seg_term}
+ST*940*0001{seg_term}
+W05*N*TEST*TEST{seg_term}
+SE*2*0001{seg_term}
+GE*1*1{seg_term}
+IEA*1*000000001{seg_term}"#
         );
 
         let (rest, obj) = Transmission::<_940>::parse(&test_str).unwrap();
@@ -75,3 +75,202 @@ SE*6*0001~"#;
     println!("{obj:?}");
     assert!(rest.is_empty());
 }
+
+#[test]
+fn acknowledge_functional_group() {
+    let s = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*7*X*004010~
+ST*997*0001~
+AK1*IM*1~
+AK9*A*1*1*1~
+SE*4*0001~
+ST*997*0002~
+AK1*IM*1~
+AK9*Z*1*1*1~
+SE*4*0002~
+ST*997*0003~
+AK1*IM*ABC~
+AK9*A*1*1*1~
+SE*5*0003~
+GE*3*7~
+IEA*1*000000001~
+"#;
+    let (rest, obj) = crate::v004010::Transmission::<_997>::parse(s).unwrap();
+    assert!(rest.is_empty());
+    let acks = crate::v004010::functional_acknowledgments(&obj);
+    assert_eq!(acks.len(), 1);
+    assert_eq!(
+        acks[0].to_string(),
+        r#"ST*997*0001~
+AK1*FA*7~
+AK2*997*0001~
+AK5*A~
+AK2*997*0002~
+AK3*AK9*3**8~
+AK4*1**7*Z~
+AK5*R*5~
+AK2*997*0003~
+AK3*AK1*2**8~
+AK4*2**6*ABC~
+AK5*R*4*5~
+AK9*P*3*3*1~
+SE*14*0001~
+"#
+    );
+}
+
+#[test]
+fn acknowledge_unparsed_functional_group() {
+    let s = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*7*X*004010~
+ST*997*0001~
+AK9*A*1*1*1~
+SE*3*0001~
+GE*2*7~
+IEA*1*000000001~
+"#;
+    assert!(crate::v004010::Transmission::<_997>::parse(s).is_err());
+    let (rest, obj) = crate::raw::RawInterchange::parse(s).unwrap();
+    assert!(rest.is_empty());
+    let acks =
+        crate::v004010::raw_functional_acknowledgments::<crate::v004010::V004010, _997>(&obj);
+    assert_eq!(acks.len(), 1);
+    assert_eq!(
+        acks[0].to_string(),
+        r#"ST*997*0001~
+AK1*FA*7~
+AK2*997*0001~
+AK3*AK1*2**3~
+AK5*R*5~
+AK9*R*2*1*0*5~
+SE*7*0001~
+"#
+    );
+}
+
+#[test]
+fn acknowledge_834() {
+    //source: https://github.com/EdiFabric/X12.NET/blob/master/Files/HIPAA/BenefitEnrollment.txt
+    let s = r#"ISA*00*          *00*          *ZZ*386028429      *30*382328142      *050221*0602*U*00501*000012345*0*P*:~
+GS*BE*386028429*382328142*20050221*0602*000012345*X*005010X220A1~
+ST*834*12345*005010X220A1~
+BGN*00*12456*19980520*1200****2~
+N1*P5**FI*999888777~
+N1*IN**FI*654456654~
+INS*Y*18*021*20*A***FT~
+REF*0F*123456789~
+REF*1L*123456001~
+DTP*356*D8*19960523~
+NM1*IL*1*DOE*JOHN*P***34*123456789~
+PER*IP**HP*7172343334*WP*7172341240~
+N3*100 MARKET ST*APT 3G~
+N4*CAMP HILL*PA*17011**CY*CUMBERLAND~
+DMG*D8*19400816*M~
+HD*021**HLT~
+DTP*348*D8*19960601~
+COB*P*890111*5~
+HD*021**DEN~
+DTP*348*D8*19960601~
+HD*021**VIS~
+DTP*348*D8*19960601~
+SE*21*12345~
+GE*1*000012345~
+IEA*1*000012345~"#;
+    use crate::v005010::{Transmission, _834, V005010};
+    let (_, obj) = Transmission::<_834>::parse(s).unwrap();
+    let acks = crate::v004010::functional_acknowledgments::<V005010, _834>(&obj);
+    assert_eq!(
+        acks[0].to_string(),
+        r#"ST*997*0001~
+AK1*BE*000012345~
+AK2*834*12345~
+AK5*A~
+AK9*A*1*1*1~
+SE*6*0001~
+"#
+    );
+}
+
+#[test]
+fn acknowledge_missing_mandatory_element() {
+    let s = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *230517*1710*U*00401*000022310*0*P*>~
+GS*SM*SOURCE*TARGET*20230517*1710*22310*X*004010~
+ST*204*22310~
+B2**SUDU~
+B2A*00~
+L11*3PHLT0XXXX*9R~
+S5*1*LD~
+N1*PW*UNION PACIFIC ICTF RAMP~
+S5*2*DT~
+SE*8*22310~
+GE*1*22310~
+IEA*1*000022310~
+"#;
+    let (_, obj) = crate::raw::RawInterchange::parse(s).unwrap();
+    let acks = crate::v004010::raw_functional_acknowledgments::<
+        crate::v004010::V004010,
+        crate::v004010::_204,
+    >(&obj);
+    assert_eq!(
+        acks[0].to_string(),
+        r#"ST*997*0001~
+AK1*SM*22310~
+AK2*204*22310~
+AK3*B2*2**8~
+AK4*6**1~
+AK5*R*5~
+AK9*R*1*1*0~
+SE*8*0001~
+"#
+    );
+
+    // the group is rejected for its own errors, even if all its transaction sets are accepted
+    let s = s
+        .replace("B2**SUDU~", "B2**SUDU****DE~")
+        .replace("GE*1*", "GE*2*");
+    let (_, obj) = crate::raw::RawInterchange::parse(&s).unwrap();
+    let acks = crate::v004010::raw_functional_acknowledgments::<
+        crate::v004010::V004010,
+        crate::v004010::_204,
+    >(&obj);
+    assert_eq!(
+        acks[0].to_string(),
+        r#"ST*997*0001~
+AK1*SM*22310~
+AK2*204*22310~
+AK5*A~
+AK9*R*2*1*1*5~
+SE*6*0001~
+"#
+    );
+}
+
+#[test]
+fn acknowledge_unparsed_transaction_set() {
+    let s = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *230517*1710*U*00401*000022310*0*P*>~
+GS*IN*SOURCE*TARGET*20230517*1710*5*X*004010~
+ST*810*0001~
+BIG*20230517*INV1**PO1~
+XYZ*1~
+TDS*100~
+SE*5*0001~
+GE*1*5~
+IEA*1*000022310~
+"#;
+    let (_, obj) = crate::raw::RawInterchange::parse(s).unwrap();
+    let acks = crate::v004010::raw_functional_acknowledgments::<
+        crate::v004010::V004010,
+        crate::v004010::_810,
+    >(&obj);
+    assert_eq!(
+        acks[0].to_string(),
+        r#"ST*997*0001~
+AK1*IN*5~
+AK2*810*0001~
+AK3*XYZ*3**6~
+AK5*R*5~
+AK9*R*1*1*0~
+SE*7*0001~
+"#
+    );
+}
@@ -313,6 +313,71 @@ fn test_m12() {
     assert_eq!(result.1._03, Some("LOC1".to_string()));
 }
 
+#[test]
+fn validate_element_types() {
+    use validator::Validate;
+    let (_, obj) = B2::parse("B2*A*SCAC**2024023**SHIP123~").unwrap();
+    let err = obj.validate().unwrap_err().to_string();
+    assert!(err.contains("B2-01 must be 2-4 characters"));
+    assert!(err.contains("B2-04 must be a date of 8 digits"));
+    assert!(!err.contains("B2-06"));
+    let (_, obj) = B2::parse("B2**SCAC**20240229**SHIP123~").unwrap();
+    obj.validate().unwrap();
+
+    let (_, obj) = L3::parse("L3*-1200*G*1200.50*FR*1500~").unwrap();
+    obj.validate().unwrap();
+    let (_, obj) = L3::parse("L3*12A*G*12.5.0*FR*15.00~").unwrap();
+    let err = obj.validate().unwrap_err().to_string();
+    assert!(err.contains("L3-01 must be a decimal number"));
+    assert!(err.contains("L3-03 must be a decimal number"));
+    assert!(err.contains("L3-05 must be a number of 1-12 digits"));
+}
+
+#[test]
+fn validate_syntax_notes() {
+    use crate::validation::{ElementSyntaxError, SegmentSyntax, SyntaxNote};
+    let (_, obj) = N1::parse("N1*ST*ACME*92~").unwrap();
+    let errors = obj.validate_syntax().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "N1-04 violates P0304: Conditional required data element missing"
+    );
+    let (_, obj) = N1::parse("N1*ST~").unwrap();
+    assert_eq!(obj.validate_syntax().unwrap()[0].note, "R0203");
+    let (_, obj) = QTY::parse("QTY*01*5**FREE~").unwrap();
+    let errors = obj.validate_syntax().unwrap();
+    assert_eq!(errors[0].element_position, 4);
+    assert_eq!(errors[0].error, ElementSyntaxError::ExclusionViolated);
+    assert_eq!(errors[0].error.code(), "10");
+    let (_, obj) = MEA::parse("MEA*PD*G**LB~").unwrap();
+    let notes: Vec<String> = obj
+        .validate_syntax()
+        .unwrap()
+        .into_iter()
+        .map(|e| e.note)
+        .collect();
+    assert_eq!(notes, vec!["R03050608"]);
+    assert!(syntax_notes("ST").is_empty());
+
+    let note: SyntaxNote = "L07030506".parse().unwrap();
+    assert_eq!(note, SyntaxNote::ListConditional(7, vec![3, 5, 6]));
+    assert_eq!(note.to_string(), "L07030506");
+    assert!("X0102".parse::<SyntaxNote>().is_err());
+    assert!("P01".parse::<SyntaxNote>().is_err());
+    for note in [
+        "P0102", "C010203", "R0203", "P0100", "P010", "X0102", "P01a2", "",
+    ] {
+        assert_eq!(
+            crate::validation::is_syntax_note(note),
+            note.parse::<SyntaxNote>().is_ok(),
+            "{note}"
+        );
+    }
+    // a value without X12 representation is not reported as valid
+    assert!(crate::validation::validate_syntax(&true, syntax_notes).is_err());
+}
+
 // #[test]
 // fn test_parse_301_nom() {
 //     let str = r#"ST*301*33233~
@@ -336,3 +401,57 @@ fn test_m12() {
 //     let obj = parse_301(&str);
 //     println!("{:?}", obj);
 // }
+
+#[test]
+fn validate_code_lists() {
+    use crate::validation::{CodeList, SegmentCodes};
+    let (_, obj) = B2A::parse("B2A*00~").unwrap();
+    assert_eq!(obj.validate_codes().unwrap(), vec![]);
+    assert_eq!(
+        B2A::code_list(1).unwrap().description(&obj._01),
+        Some("Original")
+    );
+    let (_, obj) = B2A::parse("B2A*99~").unwrap();
+    let errors: Vec<String> = obj
+        .validate_codes()
+        .unwrap()
+        .iter()
+        .map(|e| e.to_string())
+        .collect();
+    assert_eq!(errors, vec!["B2A-01 '99' is not a code of element 353"]);
+    assert!(B2A::code_list(1).unwrap().complete);
+    let (_, obj) = AK5::parse("AK5*Z~").unwrap();
+    let errors = obj.validate_codes().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].element, "717");
+    assert_eq!(errors[0].value, "Z");
+    let (_, obj) = NM1::parse("NM1*IL*3*DOE*JOHN~").unwrap();
+    let errors: Vec<String> = obj
+        .validate_codes()
+        .unwrap()
+        .iter()
+        .map(|e| e.to_string())
+        .collect();
+    assert_eq!(errors, vec!["NM1-02 '3' is not a code of element 1065"]);
+    assert_eq!(code_lists("N1"), &[(1, "98"), (6, "98")]);
+    assert!(code_lists("ST").is_empty());
+    let list = CodeList::get("98").unwrap();
+    assert_eq!(list.name, "Entity Identifier Code");
+    assert_eq!(list.description("SH"), Some("Shipper"));
+    assert!(!list.contains("ZZZ"));
+    // the code list of 98 is partial, so codes missing from it are not reported
+    let (_, obj) = N1::parse("N1*ZZZ*NAME~").unwrap();
+    assert_eq!(obj.validate_codes().unwrap(), vec![]);
+    let obj = ISA {
+        _14: "2".to_string(),
+        ..Default::default()
+    };
+    let errors: Vec<String> = obj
+        .validate_codes()
+        .unwrap()
+        .iter()
+        .map(|e| e.to_string())
+        .collect();
+    assert_eq!(errors, vec!["ISA-14 '2' is not a code of element I13"]);
+    assert!(CodeList::get("1").is_none());
+}
@@ -0,0 +1,618 @@
+use crate::envelope::{
+    ControlNumberAllocator, ControlNumberKind, EnvelopeBuilder, FileAllocator, InMemoryAllocator,
+    InterchangeNote,
+};
+use crate::util::Parser;
+use crate::v004010::*;
+
+#[test]
+fn parse_multiple_functional_groups() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~
+ST*997*0001~
+AK1*IM*1~
+AK9*A*1*1*1~
+SE*4*0001~
+GE*1*1~
+GS*FA*SOURCE*TARGET*20220524*1600*2*X*004010~
+ST*997*0002~
+AK1*QM*2~
+AK9*A*1*1*1~
+SE*4*0002~
+ST*997*0003~
+AK1*QM*3~
+AK9*A*1*1*1~
+SE*4*0003~
+GE*2*2~
+IEA*2*000000001~
+"#;
+    let (rest, obj) = Transmission::<_997>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.functional_group.len(), 2);
+    assert_eq!(obj.functional_group[0].segments.len(), 1);
+    assert_eq!(obj.functional_group[0].segments[0].ak1._01, "IM");
+    assert_eq!(obj.functional_group[1].gs._06, "2");
+    assert_eq!(obj.functional_group[1].segments.len(), 2);
+    assert_eq!(obj.functional_group[1].segments[1].ak1._01, "QM");
+    assert_eq!(obj.iea._01, "2");
+    let s = format!("{obj}");
+    assert_eq!(s, str);
+}
+
+#[test]
+fn parse_declared_delimiters() {
+    let str = "ISA|00|          |00|          |ZZ|SOURCE         |ZZ|TARGET         |220524|1120|U|00401|000000001|0|P|:\nGS|FA|SOURCE|TARGET|20220524|1600|1|X|004010\nST|997|0001\nAK1|IM|1\nAK9|A|1|1|1\nSE|4|0001\nGE|1|1\nIEA|1|000000001\n";
+    let (rest, obj) = Transmission::<_997>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.delimiters.element, '|');
+    assert_eq!(obj.delimiters.segment, '\n');
+    assert_eq!(obj.isa._16, ":");
+    assert_eq!(obj.functional_group[0].gs._01, "FA");
+    assert_eq!(obj.functional_group[0].segments[0].ak9._04, "1");
+    // rendering uses the same delimiters
+    let s = format!("{obj}");
+    assert_eq!(s, str);
+    // the delimiters are only in effect while parsing the transmission
+    assert!(ST::parse("ST|997|0001\n").is_err());
+}
+
+#[test]
+fn parse_mixed_transaction_sets() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~
+ST*997*0001~
+AK1*IM*1~
+AK9*A*1*1*1~
+SE*4*0001~
+ST*990*0002~
+B1*SCAC*12345*20220524*A~
+N9*CN*987>1~
+SE*4*0002~
+ST*998*0003~
+ZD*404*3PHLT00XXX*TRHU*653199*3PHLT00XXX**CA~
+SE*3*0003~
+GE*3*1~
+IEA*1*000000001~
+"#;
+    let (rest, obj) = Transmission::<AnyTransaction>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let sets = &obj.functional_group[0].segments;
+    assert_eq!(sets.len(), 3);
+    assert!(matches!(&sets[0], AnyTransaction::_997(t) if t.ak1._01 == "IM"));
+    let AnyTransaction::Raw(raw) = &sets[1] else {
+        panic!("expected a raw transaction set, got {:?}", sets[1]);
+    };
+    assert_eq!(raw.id(), Some("990"));
+    assert_eq!(raw.segments.len(), 4);
+    assert_eq!(raw.segments[2].elements[1], vec!["987", "1"]);
+    assert_eq!(sets[2].id(), "998");
+    let s = format!("{obj}");
+    assert_eq!(s, str);
+}
+
+#[test]
+fn read_transmission_streaming() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~
+ST*997*0001~
+AK1*IM*1~
+AK9*A*1*1*1~
+SE*4*0001~
+ST*997*0002~
+AK1*QM*2~
+AK9*A*1*1*1~
+SE*4*0002~
+GE*2*1~
+IEA*1*000000001~
+"#;
+    let reader = TransmissionReader::<_, _997>::new(str.as_bytes());
+    let events = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(events.len(), 6);
+    assert!(matches!(&events[0], Event::Interchange(isa) if isa._13 == "000000001"));
+    assert!(matches!(&events[1], Event::FunctionalGroup(gs) if gs._06 == "1"));
+    assert!(matches!(&events[2], Event::Transaction(t) if t.ak1._01 == "IM"));
+    assert!(matches!(&events[3], Event::Transaction(t) if t.ak1._01 == "QM"));
+    assert!(matches!(&events[4], Event::FunctionalGroupEnd(ge) if ge._01 == "2"));
+    assert!(matches!(&events[5], Event::InterchangeEnd(iea) if iea._02 == "000000001"));
+}
+
+#[test]
+fn read_transmission_streaming_declared_delimiters() {
+    let str = "ISA|00|          |00|          |ZZ|SOURCE         |ZZ|TARGET         |220524|1120|U|00401|000000001|0|P|:\nGS|FA|SOURCE|TARGET|20220524|1600|1|X|004010\nST|997|0001\nAK1|IM|1\nAK9|A|1|1|1\nSE|4|0001\nGE|1|1\nIEA|1|000000001\n";
+    let mut reader =
+        TransmissionReader::<_, AnyTransaction>::new(std::io::BufReader::new(str.as_bytes()));
+    assert!(matches!(reader.next(), Some(Ok(Event::Interchange(_)))));
+    assert_eq!(reader.delimiters().unwrap().segment, '\n');
+    assert!(matches!(reader.next(), Some(Ok(Event::FunctionalGroup(_)))));
+    assert!(matches!(
+        reader.next(),
+        Some(Ok(Event::Transaction(AnyTransaction::_997(_))))
+    ));
+    assert!(matches!(
+        reader.next(),
+        Some(Ok(Event::FunctionalGroupEnd(_)))
+    ));
+    assert!(matches!(reader.next(), Some(Ok(Event::InterchangeEnd(_)))));
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn read_transmission_streaming_truncated() {
+    let str = "ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~ST*997*0001~AK1*IM*1~";
+    let reader = TransmissionReader::<_, _997>::new(str.as_bytes());
+    let events = reader.collect::<Vec<_>>();
+    assert_eq!(events.len(), 3);
+    assert!(events[2].is_err());
+}
+
+#[test]
+fn read_transmission_streaming_error_position() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~
+ST*997*0001~
+AK1*IM*1~
+AK9*A*1*1*1~
+SE*4*0001~
+ST*997*0002~
+AK1*QM*2~
+XYZ*1~
+SE*4*0002~
+GE*2*1~
+IEA*1*000000001~
+"#;
+    let reader = TransmissionReader::<_, _997>::new(str.as_bytes());
+    let err = reader.filter_map(Result::err).next().unwrap();
+    let err = err
+        .get_ref()
+        .and_then(|err| err.downcast_ref::<crate::error::ParseError>())
+        .unwrap();
+    assert_eq!(err.found.as_deref(), Some("XYZ"));
+    assert_eq!(err.segment_index, 8);
+    assert_eq!(err.line, 9);
+    assert_eq!(err.offset, str.find("XYZ").unwrap());
+    assert_eq!(err.control_number.as_deref(), Some("0002"));
+    assert_eq!(err.loop_name.as_deref(), Some("_997"));
+    assert!(err.expected.contains(&"AK9".to_string()));
+}
+
+#[test]
+fn parse_lenient_unknown_segments() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~
+ST*997*0001~
+AK1*IM*1~
+NTE*GEN*PARTNER NOTE~
+AK2*856*0001~
+AK5*A~
+AK2*856*0002~
+REF*ZZ*1~
+AK5*A~
+AK9*A*2*2*2~
+SE*8*0001~
+GE*1*1~
+IEA*1*000000001~
+"#;
+    assert!(crate::error::parse::<Transmission<_997>>(str).is_err());
+    let result = crate::error::parse_lenient::<Transmission<_997>>(str).unwrap();
+    let obj = result.value;
+    assert_eq!(obj.functional_group[0].segments[0].loop_ak2.len(), 2);
+    assert_eq!(obj.functional_group[0].segments[0].ak9._04, "2");
+    let unknown = &result.unknown_segments;
+    assert_eq!(unknown.len(), 2);
+    assert_eq!(unknown[0].segment.id, "NTE");
+    assert_eq!(unknown[0].segment.element(2), Some("PARTNER NOTE"));
+    assert_eq!(unknown[0].warning.segment_index, 4);
+    assert_eq!(unknown[0].warning.line, 5);
+    assert_eq!(unknown[0].warning.loop_name.as_deref(), Some("_997"));
+    assert_eq!(unknown[1].segment.id, "REF");
+    assert_eq!(unknown[1].warning.segment_index, 8);
+    assert_eq!(unknown[1].warning.control_number.as_deref(), Some("0001"));
+    assert_eq!(unknown[1].warning.loop_name.as_deref(), Some("_997LoopAK2"));
+    assert!(unknown[1].warning.expected.contains(&"AK5".to_string()));
+    // the first transaction set, the second AK2 loop
+    assert_eq!(unknown[0].loop_index, Some(0));
+    assert_eq!(unknown[1].loop_index, Some(1));
+}
+
+#[test]
+fn parse_lenient_many_unknown_segments() {
+    let mut str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~
+ST*997*0001~
+AK1*IM*1~
+"#
+    .to_string();
+    for ak2 in 0..500 {
+        str.push_str(&format!("AK2*856*{ak2:04}~\nNTE*GEN*NOTE {ak2}~\nAK5*A~\n"));
+    }
+    str.push_str("AK1*IM*2~\nAK9*A*500*500*500~\nSE*1503*0001~\nGE*1*1~\nIEA*1*000000001~\n");
+    let result = crate::error::parse_lenient::<Transmission<_997>>(&str).unwrap();
+    assert_eq!(
+        result.value.functional_group[0].segments[0].loop_ak2.len(),
+        500
+    );
+    let unknown = &result.unknown_segments;
+    assert_eq!(unknown.len(), 501);
+    assert_eq!(unknown[0].segment.element(2), Some("NOTE 0"));
+    assert_eq!(unknown[499].segment.element(2), Some("NOTE 499"));
+    assert_eq!(unknown[499].warning.segment_index, 4 + 499 * 3 + 1);
+    assert_eq!(
+        unknown[499].warning.loop_name.as_deref(),
+        Some("_997LoopAK2")
+    );
+    assert_eq!(unknown[499].loop_index, Some(499));
+    // a segment of the transaction set out of order
+    assert_eq!(unknown[500].segment.id, "AK1");
+    assert_eq!(unknown[500].warning.loop_name.as_deref(), Some("_997"));
+    assert!(unknown[500].warning.expected.contains(&"AK9".to_string()));
+}
+
+#[test]
+fn parse_lenient_out_of_order_in_several_sets() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~
+ST*997*0001~
+AK1*IM*1~
+AK9*A*0*0*0~
+SE*4*0001~
+ST*997*0002~
+AK1*IM*2~
+AK2*856*0001~
+AK5*A~
+AK1*IM*3~
+AK9*A*1*1*1~
+SE*7*0002~
+ST*997*0003~
+AK1*IM*4~
+AK9*A*0*0*0~
+AK1*IM*5~
+SE*5*0003~
+GE*3*1~
+IEA*1*000000001~
+"#;
+    let result = crate::error::parse_lenient::<Transmission<_997>>(str).unwrap();
+    let sets = &result.value.functional_group[0].segments;
+    assert_eq!(sets.len(), 3);
+    assert_eq!(sets[1].ak1._02, "2");
+    assert_eq!(sets[1].loop_ak2.len(), 1);
+    assert_eq!(sets[2].ak1._02, "4");
+    let unknown = &result.unknown_segments;
+    assert_eq!(unknown.len(), 2);
+    assert_eq!(unknown[0].segment.element(2), Some("3"));
+    assert_eq!(unknown[0].warning.control_number.as_deref(), Some("0002"));
+    assert_eq!(unknown[0].warning.segment_index, 10);
+    assert_eq!(unknown[1].segment.element(2), Some("5"));
+    assert_eq!(unknown[1].warning.control_number.as_deref(), Some("0003"));
+    assert_eq!(unknown[1].warning.loop_name.as_deref(), Some("_997"));
+    // the repeat of the transaction set is kept when parsing it again
+    assert_eq!(unknown[0].loop_index, Some(1));
+    assert_eq!(unknown[1].loop_index, Some(2));
+}
+
+#[test]
+fn parse_lenient_missing_segment() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~
+ST*997*0001~
+AK1*IM*1~
+SE*3*0001~
+GE*1*1~
+IEA*1*000000001~
+"#;
+    let err = crate::error::parse_lenient::<Transmission<_997>>(str).unwrap_err();
+    assert_eq!(err.found.as_deref(), Some("SE"));
+    assert!(err.expected.contains(&"AK9".to_string()));
+}
+
+#[test]
+fn validate_envelope() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~
+ST*997*0001~
+AK1*IM*1~
+AK9*A*1*1*1~
+SE*4*0001~
+GE*1*1~
+GS*FA*SOURCE*TARGET*20220524*1600*2*X*004010~
+ST*997*0002~
+AK1*QM*2~
+AK9*A*1*1*1~
+SE*5*0003~
+ST*997*0003~
+AK1*QM*3~
+AK9*A*1*1*1~
+SE*4*0003~
+GE*1*3~
+IEA*3*000000002~
+"#;
+    let (_, obj) = Transmission::<_997>::parse(str).unwrap();
+    assert!(obj.functional_group[0].validate_envelope().is_empty());
+    let findings = obj.validate_envelope();
+    let elements: Vec<_> = findings.iter().map(|f| f.element).collect();
+    assert_eq!(elements, ["SE01", "SE02", "GE01", "GE02", "IEA01", "IEA02"]);
+    assert_eq!(findings[0].control_number, "0002");
+    assert_eq!(findings[0].expected, "4");
+    assert_eq!(findings[0].found, "5");
+    assert_eq!(
+        findings[0].to_string(),
+        "SE01 of transaction set 0002 is '5', expected '4'"
+    );
+    assert_eq!(findings[2].expected, "2");
+    assert_eq!(findings[3].expected, "2");
+    assert_eq!(findings[3].found, "3");
+    assert_eq!(findings[4].expected, "2");
+    assert_eq!(findings[5].expected, "000000001");
+}
+
+#[test]
+fn finalize_trailers() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*7*X*004010~
+ST*997*0001~
+AK1*IM*1~
+AK2*856*0001~
+AK5*A~
+AK9*A*1*1*1~
+SE*1*0002~
+ST*990*0002~
+B1*SCAC*12345*20220524*A~
+SE*9*9~
+GE*1*1~
+IEA*5*000000002~
+"#;
+    let (_, obj) = Transmission::<AnyTransaction>::parse(str).unwrap();
+    assert_eq!(obj.validate_envelope().len(), 8);
+    let obj = obj.finalize();
+    assert!(obj.validate_envelope().is_empty());
+    let expected = str
+        .replace("SE*1*0002~", "SE*6*0001~")
+        .replace("SE*9*9~", "SE*3*0002~")
+        .replace("GE*1*1~", "GE*2*7~")
+        .replace("IEA*5*000000002~", "IEA*1*000000001~");
+    assert_eq!(format!("{obj}"), expected);
+}
+
+#[test]
+fn finalize_default_trailers() {
+    let group = FunctionalGroup {
+        gs: GS {
+            _06: "12".to_string(),
+            ..Default::default()
+        },
+        segments: vec![_998 {
+            st: ST {
+                _01: "998".to_string(),
+                _02: "0001".to_string(),
+            },
+            zd: ZD {
+                _01: "404".to_string(),
+                ..Default::default()
+            },
+            se: SE::default(),
+        }],
+        ge: GE::default(),
+    };
+    let obj = Transmission {
+        functional_group: vec![group],
+        ..Default::default()
+    }
+    .finalize();
+    assert_eq!(obj.functional_group[0].segments[0].se._01, "3");
+    assert_eq!(obj.functional_group[0].segments[0].se._02, "0001");
+    assert_eq!(obj.functional_group[0].ge._01, "1");
+    assert_eq!(obj.functional_group[0].ge._02, "12");
+    assert_eq!(obj.iea._01, "1");
+}
+
+#[test]
+fn finalize_with_control_numbers() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*7*X*004010~
+ST*997*0001~
+AK1*IM*1~
+AK9*A*1*1*1~
+SE*4*0001~
+ST*997*0002~
+AK1*IM*2~
+AK9*A*1*1*1~
+SE*4*0002~
+GE*2*7~
+IEA*1*000000001~
+"#;
+    let (_, obj) = Transmission::<_997>::parse(str).unwrap();
+    let mut allocator = InMemoryAllocator::new();
+    allocator.set_last("TARGET", ControlNumberKind::Interchange, 999_999_999);
+    allocator.set_last("TARGET", ControlNumberKind::TransactionSet, 41);
+    let obj = obj.finalize_with(&mut allocator).unwrap();
+    assert!(obj.validate_envelope().is_empty());
+    let expected = str
+        .replace("*1600*7*", "*1600*1*")
+        .replace("ST*997*0001~", "ST*997*0042~")
+        .replace("SE*4*0001~", "SE*4*0042~")
+        .replace("ST*997*0002~", "ST*997*0043~")
+        .replace("SE*4*0002~", "SE*4*0043~")
+        .replace("GE*2*7~", "GE*2*1~");
+    assert_eq!(obj.to_string(), expected);
+    let obj = obj.finalize_with(&mut allocator).unwrap();
+    assert_eq!(obj.isa._13, "000000002");
+    assert_eq!(obj.iea._02, "000000002");
+    assert_eq!(obj.functional_group[0].gs._06, "2");
+    assert_eq!(obj.functional_group[0].segments[1].st._02, "0045");
+}
+
+#[test]
+fn file_allocator_continues_across_instances() {
+    let path = std::env::temp_dir().join(format!("x12-control-numbers-{}", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let mut allocator = FileAllocator::new(&path);
+    assert_eq!(
+        allocator
+            .next("A PARTNER", ControlNumberKind::Interchange)
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        allocator
+            .next("A PARTNER", ControlNumberKind::Interchange)
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        allocator
+            .next("OTHER", ControlNumberKind::Interchange)
+            .unwrap(),
+        1
+    );
+    let mut allocator = FileAllocator::new(&path);
+    assert_eq!(
+        allocator
+            .next("A PARTNER", ControlNumberKind::Interchange)
+            .unwrap(),
+        3
+    );
+    assert_eq!(
+        allocator
+            .next("A PARTNER", ControlNumberKind::Group)
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "A PARTNER GS06 1\nA PARTNER ISA13 3\nOTHER ISA13 1\n"
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn file_allocators_sharing_a_file_hand_out_distinct_numbers() {
+    let path =
+        std::env::temp_dir().join(format!("x12-shared-control-numbers-{}", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let path = path.clone();
+            std::thread::spawn(move || {
+                let mut allocator = FileAllocator::new(path);
+                (0..25)
+                    .map(|_| allocator.next("TARGET", ControlNumberKind::Interchange))
+                    .collect::<std::io::Result<Vec<u32>>>()
+                    .unwrap()
+            })
+        })
+        .collect();
+    let mut numbers: Vec<u32> = threads
+        .into_iter()
+        .flat_map(|thread| thread.join().unwrap())
+        .collect();
+    numbers.sort();
+    assert_eq!(numbers, (1..=100).collect::<Vec<u32>>());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn acknowledge_interchange() {
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *220524*1120*U*00401*000000001*0*P*>~
+GS*FA*SOURCE*TARGET*20220524*1600*1*X*004010~
+ST*997*0001~
+AK1*IM*1~
+AK9*A*1*1*1~
+SE*4*0001~
+GE*1*1~
+IEA*1*000000001~
+"#;
+    let (_, obj) = Transmission::<_997>::parse(str).unwrap();
+    let ta1 = obj.acknowledge();
+    assert_eq!(ta1.to_string(), "TA1*000000001*220524*1120*A*000~\n");
+
+    let rejected = |from: &str, to: &str| {
+        let (_, obj) = Transmission::<_997>::parse(&str.replace(from, to)).unwrap();
+        let ta1 = obj.acknowledge();
+        (ta1._04, ta1._05)
+    };
+    let rejected_with = |note: &str| ("R".to_string(), note.to_string());
+    assert_eq!(
+        rejected("IEA*1*000000001", "IEA*1*000000002"),
+        rejected_with("001")
+    );
+    assert_eq!(rejected("*00401*", "*00501*"), rejected_with("003"));
+    assert_eq!(rejected("*220524*", "*221324*"), rejected_with("014"));
+    assert_eq!(rejected("*1120*U*", "*2460*U*"), rejected_with("015"));
+    assert_eq!(rejected("*1120*U*", "*1120*^*"), rejected_with("016"));
+    assert_eq!(rejected("*000000001*0*", "*1*0*"), rejected_with("018"));
+    assert_eq!(rejected("IEA*1*", "IEA*2*"), rejected_with("021"));
+    let duplicate = TA1::new(&obj.isa, InterchangeNote::DuplicateControlNumber);
+    assert_eq!(duplicate._04, "R");
+    assert_eq!(duplicate._05, "025");
+}
+
+#[test]
+fn parse_interchange_acknowledgment() {
+    let str = r#"ISA*00*          *00*          *ZZ*TARGET         *ZZ*SOURCE         *220525*0800*U*00401*000000007*0*P*>~
+TA1*000000001*220524*1120*R*021~
+IEA*0*000000007~
+"#;
+    let (rest, obj) = Transmission::<_997>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert!(obj.functional_group.is_empty());
+    assert_eq!(obj.ta1[0]._01, "000000001");
+    assert_eq!(obj.ta1[0]._05, "021");
+    assert_eq!(obj.to_string(), str);
+}
+
+#[test]
+fn build_envelope() {
+    let builder = EnvelopeBuilder::new()
+        .sender("ZZ", "SOURCE")
+        .receiver("01", "A RECEIVER ID LONGER THAN 15")
+        .functional_id("FA")
+        .version("00401", "004010")
+        .timestamp(
+            chrono::NaiveDate::from_ymd_opt(2022, 5, 24)
+                .unwrap()
+                .and_hms_opt(11, 20, 0)
+                .unwrap(),
+        )
+        .interchange_control_number(1)
+        .usage_indicator('T');
+    let obj = Transmission::<_997> {
+        isa: builder.isa().unwrap(),
+        functional_group: vec![FunctionalGroup {
+            gs: builder.gs().unwrap(),
+            segments: vec![],
+            ge: builder.ge(0).unwrap(),
+        }],
+        iea: builder.iea(1).unwrap(),
+        ..Default::default()
+    };
+    assert_eq!(obj.isa._15, UsageIndicator::Test);
+    assert_eq!(
+        obj.to_string(),
+        "ISA*00*          *00*          *ZZ*SOURCE         *01*A RECEIVER ID L*220524*1120*U*00401*000000001*0*T*:~\nGS*FA*SOURCE*A RECEIVER ID L*20220524*1120*1*X*004010~\nGE*0*1~\nIEA*1*000000001~\n"
+    );
+    assert_eq!(obj.acknowledge()._05, "000");
+}
+
+#[test]
+fn parse_missing_mandatory_element() {
+    use validator::Validate;
+    let str = r#"ISA*00*          *00*          *ZZ*SOURCE         *ZZ*TARGET         *230517*1710*U*00401*000022310*0*P*>~
+GS*SM*SOURCE*TARGET*20230517*1710*22310*X*004010~
+ST*204*22310~
+B2**SUDU~
+B2A*00~
+L11*3PHLT0XXXX*9R~
+S5*1*LD~
+N1*PW*UNION PACIFIC ICTF RAMP~
+S5*2*DT~
+SE*8*22310~
+GE*1*22310~
+IEA*1*000022310~
+"#;
+    // the omitted mandatory B2-06 is left empty instead of failing the parse
+    let obj = crate::error::parse::<Transmission<_204>>(str).unwrap();
+    let b2 = &obj.functional_group[0].segments[0].b2;
+    assert_eq!(b2._02.as_deref(), Some("SUDU"));
+    assert_eq!(b2._06, "");
+    let err = b2.validate().unwrap_err();
+    assert!(err.to_string().contains("B2-06 is mandatory"));
+}
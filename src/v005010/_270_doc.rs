@@ -9,6 +9,37 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 270 - Eligibility, Coverage or Benefit Inquiry
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | BHT | Beginning of Hierarchical Transaction | M | 1
+/// LOOP ID - 2000 | >1
+/// 2000 -> 0100 | HL | Hierarchical Level | M | 1
+/// 2000 -> 0200 | TRN | Trace | O | 9
+/// 2000 -> LOOP ID - 2100 | >1
+/// 2000 -> 2100 -> 0300 | NM1 | Individual or Organizational Name | M | 1
+/// 2000 -> 2100 -> 0400 | REF | Reference Information | O | 9
+/// 2000 -> 2100 -> 0500 | N2 | Additional Name Information | O | 1
+/// 2000 -> 2100 -> 0600 | N3 | Party Location | O | 1
+/// 2000 -> 2100 -> 0700 | N4 | Geographic Location | O | 1
+/// 2000 -> 2100 -> 0800 | PER | Administrative Communications Contact | O | 3
+/// 2000 -> 2100 -> 0900 | PRV | Provider Information | O | 1
+/// 2000 -> 2100 -> 1000 | DMG | Demographic Information | O | 1
+/// 2000 -> 2100 -> 1100 | INS | Insured Benefit | O | 1
+/// 2000 -> 2100 -> 1150 | HI | Health Care Information Codes | O | 1
+/// 2000 -> 2100 -> 1200 | DTP | Date or Time or Period | O | 9
+/// 2000 -> 2100 -> 1250 | MPI | Military Personnel Information | O | 9
+/// 2000 -> 2100 -> LOOP ID - 2110 | 99
+/// 2000 -> 2100 -> 2110 -> 1300 | EQ | Eligibility or Benefit Inquiry | O | 1
+/// 2000 -> 2100 -> 2110 -> 1350 | AMT | Monetary Amount Information | O | 2
+/// 2000 -> 2100 -> 2110 -> 1400 | VEH | Vehicle Information | O | 1
+/// 2000 -> 2100 -> 2110 -> 1500 | PDR | Property Description - Real | O | 1
+/// 2000 -> 2100 -> 2110 -> 1600 | PDP | Property Description - Personal | O | 1
+/// 2000 -> 2100 -> 2110 -> 1700 | III | Information | O | 10
+/// 2000 -> 2100 -> 2110 -> 1800 | REF | Reference Information | O | 1
+/// 2000 -> 2100 -> 2110 -> 1900 | DTP | Date or Time or Period | O | 9
+/// 0100 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _270 {
     pub st: ST,
@@ -19,6 +50,13 @@ pub struct _270 {
 
 impl<'a> Parser<&'a str, _270, nom::error::Error<&'a str>> for _270 {
     fn parse(input: &'a str) -> IResult<&'a str, _270> {
+        let _context = crate::error::transaction_set(
+            "_270",
+            &[
+                "AMT", "BHT", "DMG", "DTP", "EQ", "HI", "HL", "III", "INS", "MPI", "N2", "N3",
+                "N4", "NM1", "PDP", "PDR", "PER", "PRV", "REF", "SE", "ST", "TRN", "VEH",
+            ],
+        );
         let mut output = _270::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -29,6 +67,7 @@ impl<'a> Parser<&'a str, _270, nom::error::Error<&'a str>> for _270 {
         let mut loop_2000 = vec![];
         let mut loop_rest = rest;
         while peek(opt(HL::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_270Loop2000");
             let (rest, hl) = HL::parse(loop_rest)?;
             let (rest, trn) = many0(TRN::parse).parse(rest)?;
 
@@ -36,6 +75,7 @@ impl<'a> Parser<&'a str, _270, nom::error::Error<&'a str>> for _270 {
             let mut loop_2100 = vec![];
             let mut loop_2100_rest = rest;
             while peek(opt(NM1::parse)).parse(loop_2100_rest)?.1.is_some() {
+                let _context = crate::error::context("_270Loop2100");
                 let (rest, nm1) = NM1::parse(loop_2100_rest)?;
                 let (rest, r#ref) = many0(REF::parse).parse(rest)?;
                 let (rest, n2) = opt(N2::parse).parse(rest)?;
@@ -53,6 +93,7 @@ impl<'a> Parser<&'a str, _270, nom::error::Error<&'a str>> for _270 {
                 let mut loop_2110 = vec![];
                 let mut loop_2110_rest = rest;
                 while peek(opt(EQ::parse)).parse(loop_2110_rest)?.1.is_some() {
+                    let _context = crate::error::context("_270Loop2110");
                     let (rest, eq) = EQ::parse(loop_2110_rest)?;
                     let (rest, amt) = many0(AMT::parse).parse(rest)?;
                     let (rest, veh) = opt(VEH::parse).parse(rest)?;
@@ -9,6 +9,64 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 271 - Eligibility, Coverage or Benefit Information
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | BHT | Beginning of Hierarchical Transaction | M | 1
+/// LOOP ID - 2000 | >1
+/// 2000 -> 0100 | HL | Hierarchical Level | M | 1
+/// 2000 -> 0200 | TRN | Trace | O | 9
+/// 2000 -> 0250 | AAA | Request Validation | O | 9
+/// 2000 -> LOOP ID - 2100 | >1
+/// 2000 -> 2100 -> 0300 | NM1 | Individual or Organizational Name | M | 1
+/// 2000 -> 2100 -> 0400 | REF | Reference Information | O | 9
+/// 2000 -> 2100 -> 0500 | N2 | Additional Name Information | O | 1
+/// 2000 -> 2100 -> 0600 | N3 | Party Location | O | 1
+/// 2000 -> 2100 -> 0700 | N4 | Geographic Location | O | 1
+/// 2000 -> 2100 -> 0800 | PER | Administrative Communications Contact | O | 3
+/// 2000 -> 2100 -> 0850 | AAA | Request Validation | O | 9
+/// 2000 -> 2100 -> 0900 | PRV | Provider Information | O | 1
+/// 2000 -> 2100 -> 1000 | DMG | Demographic Information | O | 1
+/// 2000 -> 2100 -> 1100 | INS | Insured Benefit | O | 1
+/// 2000 -> 2100 -> 1150 | HI | Health Care Information Codes | O | 1
+/// 2000 -> 2100 -> 1200 | DTP | Date or Time or Period | O | 9
+/// 2000 -> 2100 -> 1220 | LUI | Language Use | O | 9
+/// 2000 -> 2100 -> 1250 | MPI | Military Personnel Information | O | 9
+/// 2000 -> 2100 -> LOOP ID - 2110 | >1
+/// 2000 -> 2100 -> 2110 -> 1300 | EB | Eligibility or Benefit Information | O | 1
+/// 2000 -> 2100 -> 2110 -> 1350 | HSD | Health Care Services Delivery | O | 9
+/// 2000 -> 2100 -> 2110 -> 1400 | REF | Reference Information | O | 9
+/// 2000 -> 2100 -> 2110 -> 1500 | DTP | Date or Time or Period | O | 20
+/// 2000 -> 2100 -> 2110 -> 1600 | AAA | Request Validation | O | 9
+/// 2000 -> 2100 -> 2110 -> 1700 | VEH | Vehicle Information | O | 1
+/// 2000 -> 2100 -> 2110 -> 1750 | PID | Product/Item Description | O | 1
+/// 2000 -> 2100 -> 2110 -> 1800 | PDR | Property Description - Real | O | 1
+/// 2000 -> 2100 -> 2110 -> 1900 | PDP | Property Description - Personal | O | 1
+/// 2000 -> 2100 -> 2110 -> 2000 | LIN | Item Identification | O | 1
+/// 2000 -> 2100 -> 2110 -> 2100 | EM | Equipment Characteristics | O | 1
+/// 2000 -> 2100 -> 2110 -> 2200 | SD1 | Safety Data | O | 1
+/// 2000 -> 2100 -> 2110 -> 2300 | PKD | Packaging Description | O | 1
+/// 2000 -> 2100 -> 2110 -> 2500 | MSG | Message Text | O | 10
+/// 2000 -> 2100 -> 2110 -> LOOP ID - 2115 | 10
+/// 2000 -> 2100 -> 2110 -> 2115 -> 2600 | III | Information | O | 1
+/// 2000 -> 2100 -> 2110 -> 2115 -> 2700 | DTP | Date or Time or Period | O | 1
+/// 2000 -> 2100 -> 2110 -> 2115 -> 2800 | AMT | Monetary Amount Information | O | 1
+/// 2000 -> 2100 -> 2110 -> 2115 -> 2900 | PCT | Percent Amounts | O | 1
+/// 2000 -> 2100 -> 2110 -> 2115 -> LOOP ID - 2117 | >1
+/// 2000 -> 2100 -> 2110 -> 2115 -> 2117 -> 3000 | LQ | Industry Code Identification | O | 1
+/// 2000 -> 2100 -> 2110 -> 2115 -> 2117 -> 3100 | AMT | Monetary Amount Information | O | 1
+/// 2000 -> 2100 -> 2110 -> 2115 -> 2117 -> 3150 | PCT | Percent Amounts | O | 1
+/// 2000 -> 2100 -> 2110 -> 3200 | LS | Loop Header | O | 1
+/// 2000 -> 2100 -> 2110 -> LOOP ID - 2120 | 23
+/// 2000 -> 2100 -> 2110 -> 2120 -> 3250 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2100 -> 2110 -> 2120 -> 3300 | N2 | Additional Name Information | O | 1
+/// 2000 -> 2100 -> 2110 -> 2120 -> 3400 | N3 | Party Location | O | 1
+/// 2000 -> 2100 -> 2110 -> 2120 -> 3500 | N4 | Geographic Location | O | 1
+/// 2000 -> 2100 -> 2110 -> 2120 -> 3600 | PER | Administrative Communications Contact | O | 3
+/// 2000 -> 2100 -> 2110 -> 2120 -> 3700 | PRV | Provider Information | O | 1
+/// 2000 -> 2100 -> 2110 -> 3800 | LE | Loop Trailer | O | 1
+/// 0100 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _271 {
     pub st: ST,
@@ -19,6 +77,14 @@ pub struct _271 {
 
 impl<'a> Parser<&'a str, _271, nom::error::Error<&'a str>> for _271 {
     fn parse(input: &'a str) -> IResult<&'a str, _271> {
+        let _context = crate::error::transaction_set(
+            "_271",
+            &[
+                "AAA", "AMT", "BHT", "DMG", "DTP", "EB", "EM", "HI", "HL", "HSD", "III", "INS",
+                "LE", "LIN", "LQ", "LS", "LUI", "MPI", "MSG", "N2", "N3", "N4", "NM1", "PCT",
+                "PDP", "PDR", "PER", "PID", "PKD", "PRV", "REF", "SD1", "SE", "ST", "TRN", "VEH",
+            ],
+        );
         let mut output = _271::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -29,6 +95,7 @@ impl<'a> Parser<&'a str, _271, nom::error::Error<&'a str>> for _271 {
         let mut loop_2000 = vec![];
         let mut loop_rest = rest;
         while peek(opt(HL::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_271Loop2000");
             let (rest, hl) = HL::parse(loop_rest)?;
             let (rest, trn) = many0(TRN::parse).parse(rest)?;
             let (rest, aaa) = many0(AAA::parse).parse(rest)?;
@@ -37,6 +104,7 @@ impl<'a> Parser<&'a str, _271, nom::error::Error<&'a str>> for _271 {
             let mut loop_2100 = vec![];
             let mut loop_2100_rest = rest;
             while peek(opt(NM1::parse)).parse(loop_2100_rest)?.1.is_some() {
+                let _context = crate::error::context("_271Loop2100");
                 let (rest, nm1) = NM1::parse(loop_2100_rest)?;
                 let (rest, r#ref) = many0(REF::parse).parse(rest)?;
                 let (rest, n2) = opt(N2::parse).parse(rest)?;
@@ -56,6 +124,7 @@ impl<'a> Parser<&'a str, _271, nom::error::Error<&'a str>> for _271 {
                 let mut loop_2110 = vec![];
                 let mut loop_2110_rest = rest;
                 while peek(opt(EB::parse)).parse(loop_2110_rest)?.1.is_some() {
+                    let _context = crate::error::context("_271Loop2110");
                     let (rest, eb) = EB::parse(loop_2110_rest)?;
                     let (rest, hsd) = many0(HSD::parse).parse(rest)?;
                     let (rest, r#ref) = many0(REF::parse).parse(rest)?;
@@ -75,6 +144,7 @@ impl<'a> Parser<&'a str, _271, nom::error::Error<&'a str>> for _271 {
                     let mut loop_2115 = vec![];
                     let mut loop_2115_rest = rest;
                     while peek(opt(III::parse)).parse(loop_2115_rest)?.1.is_some() {
+                        let _context = crate::error::context("_271Loop2115");
                         let (rest, iii) = III::parse(loop_2115_rest)?;
                         let (rest, dtp) = many0(DTP::parse).parse(rest)?;
                         let (rest, amt) = many0(AMT::parse).parse(rest)?;
@@ -84,6 +154,7 @@ impl<'a> Parser<&'a str, _271, nom::error::Error<&'a str>> for _271 {
                         let mut loop_2117 = vec![];
                         let mut loop_2117_rest = rest;
                         while peek(opt(LQ::parse)).parse(loop_2117_rest)?.1.is_some() {
+                            let _context = crate::error::context("_271Loop2117");
                             let (rest, lq) = LQ::parse(loop_2117_rest)?;
                             let (rest, amt) = many0(AMT::parse).parse(rest)?;
                             let (rest, pct) = many0(PCT::parse).parse(rest)?;
@@ -112,6 +183,7 @@ impl<'a> Parser<&'a str, _271, nom::error::Error<&'a str>> for _271 {
                             .1
                             .is_some()
                         {
+                            let _context = crate::error::context("_271Loop2120Content");
                             let (rest, nm1) = NM1::parse(loop_2120_content_rest)?;
                             let (rest, n2) = opt(N2::parse).parse(rest)?;
                             let (rest, n3) = opt(N3::parse).parse(rest)?;
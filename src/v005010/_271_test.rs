@@ -42,3 +42,71 @@ fn parse_271_02() {
     let parsed = Transmission::<_271>::parse(str).unwrap();
     println!("{parsed:?}");
 }
+
+#[test]
+fn parse_271_repeated_elements() {
+    let str = r#"ISA*00*          *00*          *ZZ*1234567        *ZZ*11111          *170508*1141*>*00501*000000101*1*P*:~
+GS*HC*XXXXXXX*XXXXX*20170617*1741*101*X*005010X279A1~
+ST*271*4321*005010X279A1~
+BHT*0022*11*10001234*20060501*1319~
+HL*1**20*1~
+NM1*PR*2*ABC COMPANY*****PI*842610001~
+HL*2*1*21*1~
+NM1*1P*2*BONE AND JOINT CLINIC*****SV*2000035~
+HL*3*2*22*0~
+NM1*IL*1*SMITH*JOHN****MI*123456789~
+EB*1**30**GOLD 123 PLAN~
+EB*1**1>33>35>47~
+SE*11*4321~
+GE*1*101~
+IEA*1*000000101~
+"#;
+    let (_, obj) = Transmission::<_271>::parse(str).unwrap();
+    assert_eq!(obj.delimiters.repetition, Some('>'));
+    let rendered = format!("{obj}");
+    assert_eq!(rendered, str);
+    let (_, eb) = crate::v005010::EB::parse_with("EB*1**1>33>35>47~", &obj.delimiters).unwrap();
+    assert_eq!(eb._03.as_deref().unwrap(), &["1", "33", "35", "47"]);
+    assert_eq!(
+        crate::util::writer::to_string(&eb, obj.delimiters).unwrap(),
+        "EB*1**1>33>35>47~\n"
+    );
+}
+
+#[test]
+fn render_repeated_elements_without_repetition_separator() {
+    let (_, eb) = crate::v005010::EB::parse("EB*1**1^33^35~").unwrap();
+    // without a repetition separator in scope the element is kept as found
+    assert_eq!(eb._03.as_deref().unwrap(), &["1^33^35"]);
+    let eb = crate::v005010::EB {
+        _01: Some("1".to_string()),
+        _03: Some(["1", "33", "35"].map(String::from).into_iter().collect()),
+        ..Default::default()
+    };
+    assert_eq!(eb.to_string(), "EB*1**1^33^35~\n");
+    assert_eq!(
+        crate::util::writer::to_string(&eb, crate::util::Delimiters::default()).unwrap(),
+        "EB*1**1^33^35~\n"
+    );
+}
+
+#[test]
+fn parse_repeated_race_or_ethnicity() {
+    let delimiters = crate::util::Delimiters {
+        repetition: Some('^'),
+        ..Default::default()
+    };
+    let (_, dmg) = crate::v005010::DMG::parse_with(
+        "DMG*D8*19630519*M**7:RET:2106-3^7:RET:2135-2~",
+        &delimiters,
+    )
+    .unwrap();
+    let races = dmg._05.as_deref().unwrap();
+    assert_eq!(races.len(), 2);
+    assert_eq!(races[1]._01.as_deref(), Some("7"));
+    assert_eq!(races[1]._03.as_deref(), Some("2135-2"));
+    assert_eq!(
+        dmg.to_string(),
+        "DMG*D8*19630519*M**7:RET:2106-3^7:RET:2135-2~\n"
+    );
+}
@@ -6,6 +6,31 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 276 - Health Claim Status Request
+///
+/// The positions describe the loops 2000A–2000E as one loop 2000, as the HL segment alone
+/// does not tell them apart.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | BHT | Beginning of Hierarchical Transaction | M | 1
+/// LOOP ID - 2000 | >1
+/// 2000 -> 0100 | HL | Hierarchical Level | M | 1
+/// 2000 -> 0400 | DMG | Demographic Information | O | 1
+/// 2000 -> LOOP ID - 2100 | >1
+/// 2000 -> 2100 -> 0500 | NM1 | Individual or Organizational Name | M | 1
+/// 2000 -> 2100 -> 0800 | PER | Administrative Communications Contact | O | 1
+/// 2000 -> 2100 -> 0810 | DMG | Demographic Information | O | 1
+/// 2000 -> 2100 -> 0820 | REF | Reference Information | O | >1
+/// 2000 -> 2100 -> 0830 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2100 -> 0840 | SVC | Service Information | O | 1
+/// 2000 -> LOOP ID - 2200 | >1
+/// 2000 -> 2200 -> 0900 | TRN | Trace | O | 1
+/// 2000 -> 2200 -> 1000 | REF | Reference Information | O | >1
+/// 2000 -> 2200 -> 1100 | AMT | Monetary Amount Information | O | >1
+/// 2000 -> 2200 -> 1200 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2200 -> 1300 | SVC | Service Information | O | 1
+/// 1600 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _276 {
     pub st: ST,
@@ -144,6 +169,12 @@ pub enum _276Generic2000Loop {
 
 impl<'a> Parser<&'a str, _276, nom::error::Error<&'a str>> for _276 {
     fn parse(input: &'a str) -> IResult<&'a str, _276> {
+        let _context = crate::error::transaction_set(
+            "_276",
+            &[
+                "AMT", "BHT", "DMG", "DTP", "HL", "NM1", "PER", "REF", "SE", "ST", "SVC", "TRN",
+            ],
+        );
         parse_276(input)
     }
 }
@@ -205,6 +236,7 @@ pub fn parse_276(input: &str) -> IResult<&str, _276> {
 
 // Parse Loop 2000A
 fn parse_loop_2000_a(hl: HL, input: &str) -> IResult<&str, _276Generic2000Loop> {
+    let _context = crate::error::context("_276Loop2000A");
     trace!("enter parse_loop_2000A input: \"{input}\"");
     let mut output = _276Loop2000A {
         hl,
@@ -220,6 +252,7 @@ fn parse_loop_2000_a(hl: HL, input: &str) -> IResult<&str, _276Generic2000Loop>
 }
 
 fn parse_loop_2100_a(input: &str) -> IResult<&str, _276Loop2100A> {
+    let _context = crate::error::context("_276Loop2100A");
     trace!("enter parse_loop_2100A input: \"{input}\"");
     let (rest, nm1) = NM1::parse(input)?;
     let (rest, per) = opt(PER::parse).parse(rest)?;
@@ -229,6 +262,7 @@ fn parse_loop_2100_a(input: &str) -> IResult<&str, _276Loop2100A> {
 }
 
 fn parse_loop_2200_a(input: &str) -> IResult<&str, _276Loop2200A> {
+    let _context = crate::error::context("_276Loop2200A");
     trace!("enter parse_loop_2200A input: \"{input}\"");
     let (rest, trn) = TRN::parse(input)?;
     let (rest, rref) = many0(REF::parse).parse(rest)?;
@@ -247,6 +281,7 @@ fn parse_loop_2200_a(input: &str) -> IResult<&str, _276Loop2200A> {
 
 // Parse Loop 2000B
 fn parse_loop_2000_b(hl: HL, input: &str) -> IResult<&str, _276Generic2000Loop> {
+    let _context = crate::error::context("_276Loop2000B");
     trace!("enter parse_loop_2000B input: \"{input}\"");
     let mut output = _276Loop2000B {
         hl,
@@ -262,6 +297,7 @@ fn parse_loop_2000_b(hl: HL, input: &str) -> IResult<&str, _276Generic2000Loop>
 }
 
 fn parse_loop_2100_b(input: &str) -> IResult<&str, _276Loop2100B> {
+    let _context = crate::error::context("_276Loop2100B");
     trace!("enter parse_loop_2100B input: \"{input}\"");
     let (rest, nm1) = NM1::parse(input)?;
     let (rest, per) = opt(PER::parse).parse(rest)?;
@@ -271,6 +307,7 @@ fn parse_loop_2100_b(input: &str) -> IResult<&str, _276Loop2100B> {
 }
 
 fn parse_loop_2200_b(input: &str) -> IResult<&str, _276Loop2200B> {
+    let _context = crate::error::context("_276Loop2200B");
     trace!("enter parse_loop_2200B input: \"{input}\"");
     let (rest, trn) = TRN::parse(input)?;
     let (rest, rref) = many0(REF::parse).parse(rest)?;
@@ -289,6 +326,7 @@ fn parse_loop_2200_b(input: &str) -> IResult<&str, _276Loop2200B> {
 
 // Parse Loop 2000C
 fn parse_loop_2000_c(hl: HL, input: &str) -> IResult<&str, _276Generic2000Loop> {
+    let _context = crate::error::context("_276Loop2000C");
     trace!("enter parse_loop_2000C input: \"{input}\"");
     let mut output = _276Loop2000C {
         hl,
@@ -304,6 +342,7 @@ fn parse_loop_2000_c(hl: HL, input: &str) -> IResult<&str, _276Generic2000Loop>
 }
 
 fn parse_loop_2100_c(input: &str) -> IResult<&str, _276Loop2100C> {
+    let _context = crate::error::context("_276Loop2100C");
     trace!("enter parse_loop_2100C input: \"{input}\"");
     let (rest, nm1) = NM1::parse(input)?;
     let (rest, dmg) = opt(DMG::parse).parse(rest)?;
@@ -321,6 +360,7 @@ fn parse_loop_2100_c(input: &str) -> IResult<&str, _276Loop2100C> {
 }
 
 fn parse_loop_2200_c(input: &str) -> IResult<&str, _276Loop2200C> {
+    let _context = crate::error::context("_276Loop2200C");
     trace!("enter parse_loop_2200C input: \"{input}\"");
     // Only attempt 2200C loop when the next segment is one of TRN, REF, AMT, DTP, or SVC
     if input.starts_with("TRN*")
@@ -355,6 +395,7 @@ fn parse_loop_2200_c(input: &str) -> IResult<&str, _276Loop2200C> {
 
 // Parse Loop 2000D
 fn parse_loop_2000_d(hl: HL, input: &str) -> IResult<&str, _276Generic2000Loop> {
+    let _context = crate::error::context("_276Loop2000D");
     trace!("enter parse_loop_2000D input: \"{input}\"");
     let mut output = _276Loop2000D {
         hl,
@@ -373,6 +414,7 @@ fn parse_loop_2000_d(hl: HL, input: &str) -> IResult<&str, _276Generic2000Loop>
 }
 
 fn parse_loop_2100_d(input: &str) -> IResult<&str, _276Loop2100D> {
+    let _context = crate::error::context("_276Loop2100D");
     trace!("enter parse_loop_2100D input: \"{input}\"");
     let (rest, nm1) = NM1::parse(input)?;
     let (rest, rref) = many0(REF::parse).parse(rest)?;
@@ -382,6 +424,7 @@ fn parse_loop_2100_d(input: &str) -> IResult<&str, _276Loop2100D> {
 }
 
 fn parse_loop_2200_d(input: &str) -> IResult<&str, _276Loop2200D> {
+    let _context = crate::error::context("_276Loop2200D");
     trace!("enter parse_loop_2200D input: \"{input}\"");
     let (rest, trn) = TRN::parse(input)?;
     let (rest, rref) = many0(REF::parse).parse(rest)?;
@@ -403,6 +446,7 @@ fn parse_loop_2200_d(input: &str) -> IResult<&str, _276Loop2200D> {
 
 // Parse Loop 2000E
 fn parse_loop_2000_e(hl: HL, input: &str) -> IResult<&str, _276Generic2000Loop> {
+    let _context = crate::error::context("_276Loop2000E");
     trace!("enter parse_loop_2000E input: \"{input}\"");
     let mut output = _276Loop2000E {
         hl,
@@ -420,6 +464,7 @@ fn parse_loop_2000_e(hl: HL, input: &str) -> IResult<&str, _276Generic2000Loop>
 }
 
 fn parse_loop_2100_e(input: &str) -> IResult<&str, _276Loop2100E> {
+    let _context = crate::error::context("_276Loop2100E");
     trace!("enter parse_loop_2100E input: \"{input}\"");
     let (rest, nm1) = NM1::parse(input)?;
     let (rest, per) = opt(PER::parse).parse(rest)?;
@@ -442,6 +487,7 @@ fn parse_loop_2100_e(input: &str) -> IResult<&str, _276Loop2100E> {
 }
 
 fn parse_loop_2200_e(input: &str) -> IResult<&str, _276Loop2200E> {
+    let _context = crate::error::context("_276Loop2200E");
     trace!("enter parse_loop_2200E input: \"{input}\"");
     let (rest, trn) = TRN::parse(input)?;
     let (rest, rref) = many0(REF::parse).parse(rest)?;
@@ -518,7 +564,8 @@ mod tests {
         // 2000E – Dependent Service Details
         let dep = &doc.loop_2000e[0];
         let svc = dep.loop_2200e[0].svc.as_ref().unwrap();
-        assert_eq!(svc._01, "HC:99203");
+        assert_eq!(svc._01._01, "HC");
+        assert_eq!(svc._01._02, "99203");
     }
 
     #[test]
@@ -557,6 +604,7 @@ mod tests {
         // 2000E – Dependent Service Details
         let dep = &doc.loop_2000e[0];
         let svc = dep.loop_2200e[0].svc.as_ref().unwrap();
-        assert_eq!(svc._01, "HC:99203");
+        assert_eq!(svc._01._01, "HC");
+        assert_eq!(svc._01._02, "99203");
     }
 }
@@ -15,6 +15,35 @@ use x12_types_macros::DisplayX12;
 /// hierarchical (HL) structure. Each loop can contain sub-loops for claim status
 /// detail. Typical segment ordering at the top level: ST, BHT, hierarchical loops,
 /// then SE.
+///
+/// The positions describe the loops 2000A–2000E as one loop 2000, as the HL segment alone
+/// does not tell them apart.
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | BHT | Beginning of Hierarchical Transaction | M | 1
+/// LOOP ID - 2000 | >1
+/// 2000 -> 0100 | HL | Hierarchical Level | M | 1
+/// 2000 -> LOOP ID - 2100 | >1
+/// 2000 -> 2100 -> 0500 | NM1 | Individual or Organizational Name | M | 1
+/// 2000 -> 2100 -> 0600 | N3 | Party Location | O | 1
+/// 2000 -> 2100 -> 0700 | N4 | Geographic Location | O | 1
+/// 2000 -> 2100 -> 0750 | REF | Reference Information | O | >1
+/// 2000 -> 2100 -> 0800 | PER | Administrative Communications Contact | O | >1
+/// 2000 -> LOOP ID - 2200 | >1
+/// 2000 -> 2200 -> 0900 | TRN | Trace | O | 1
+/// 2000 -> 2200 -> 1000 | STC | Claim or Service Line Status information | O | >1
+/// 2000 -> 2200 -> 1100 | REF | Reference Information | O | >1
+/// 2000 -> 2200 -> 1200 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2200 -> 1300 | QTY | Quantity Information | O | >1
+/// 2000 -> 2200 -> 1400 | AMT | Monetary Amount Information | O | >1
+/// 2000 -> 2200 -> LOOP ID - 2220 | >1
+/// 2000 -> 2200 -> 2220 -> 1800 | SVC | Service Information | O | 1
+/// 2000 -> 2200 -> 2220 -> 1900 | STC | Claim or Service Line Status information | O | >1
+/// 2000 -> 2200 -> 2220 -> 2000 | REF | Reference Information | O | >1
+/// 2000 -> 2200 -> 2220 -> 2100 | DTP | Date or Time or Period | O | >1
+/// 2700 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _277 {
     /// ST - Transaction Set Header
@@ -366,6 +395,13 @@ pub struct _277Loop2220E {
 
 impl<'a> Parser<&'a str, _277, nom::error::Error<&'a str>> for _277 {
     fn parse(input: &'a str) -> IResult<&'a str, _277> {
+        let _context = crate::error::transaction_set(
+            "_277",
+            &[
+                "AMT", "BHT", "DTP", "HL", "N3", "N4", "NM1", "PER", "QTY", "REF", "SE", "ST",
+                "STC", "SVC", "TRN",
+            ],
+        );
         parse_277(input)
     }
 }
@@ -474,6 +510,7 @@ fn parse_2000_any(input: &str) -> IResult<&str, _277Generic2000Loop> {
 ///////////////////////////////////////////////////////////
 
 fn parse_loop_2000_a(hl: HL, input: &str) -> IResult<&str, _277Generic2000Loop> {
+    let _context = crate::error::context("_277Loop2000A");
     let mut output = _277Loop2000A::default();
 
     trace!("--> 2000A");
@@ -491,6 +528,7 @@ fn parse_loop_2000_a(hl: HL, input: &str) -> IResult<&str, _277Generic2000Loop>
 }
 
 fn parse_loop_2100_a(input: &str) -> IResult<&str, _277Loop2100A> {
+    let _context = crate::error::context("_277Loop2100A");
     let mut output = _277Loop2100A::default();
     trace!("--> 2100A");
 
@@ -531,6 +569,7 @@ fn parse_loop_2100_a(input: &str) -> IResult<&str, _277Loop2100A> {
 ///////////////////////////////////////////////////////////
 
 fn parse_loop_2000_b(hl: HL, input: &str) -> IResult<&str, _277Generic2000Loop> {
+    let _context = crate::error::context("_277Loop2000B");
     let mut output = _277Loop2000B {
         hl,
         ..Default::default()
@@ -564,6 +603,7 @@ fn parse_loop_2000_b(hl: HL, input: &str) -> IResult<&str, _277Generic2000Loop>
 }
 
 fn parse_loop_2100_b(input: &str) -> IResult<&str, _277Loop2100B> {
+    let _context = crate::error::context("_277Loop2100B");
     let mut output = _277Loop2100B::default();
 
     let (rest, nm1) = NM1::parse(input)?;
@@ -585,6 +625,7 @@ fn parse_loop_2100_b(input: &str) -> IResult<&str, _277Loop2100B> {
 }
 
 fn parse_loop_2200_b(input: &str) -> IResult<&str, _277Loop2200B> {
+    let _context = crate::error::context("_277Loop2200B");
     let mut output = _277Loop2200B::default();
 
     // TRN
@@ -619,6 +660,7 @@ fn parse_loop_2200_b(input: &str) -> IResult<&str, _277Loop2200B> {
 ///////////////////////////////////////////////////////////
 
 fn parse_loop_2000_c(hl: HL, input: &str) -> IResult<&str, _277Generic2000Loop> {
+    let _context = crate::error::context("_277Loop2000C");
     let mut output = _277Loop2000C {
         hl,
         ..Default::default()
@@ -652,6 +694,7 @@ fn parse_loop_2000_c(hl: HL, input: &str) -> IResult<&str, _277Generic2000Loop>
 }
 
 fn parse_loop_2100_c(input: &str) -> IResult<&str, _277Loop2100C> {
+    let _context = crate::error::context("_277Loop2100C");
     trace!("--> 2100C");
     let mut output = _277Loop2100C::default();
 
@@ -697,6 +740,7 @@ fn parse_loop_2100_c(input: &str) -> IResult<&str, _277Loop2100C> {
 }
 
 fn parse_loop_2200_c(input: &str) -> IResult<&str, _277Loop2200C> {
+    let _context = crate::error::context("_277Loop2200C");
     let mut output = _277Loop2200C::default();
 
     trace!("--> 2200C");
@@ -741,6 +785,7 @@ fn parse_loop_2200_c(input: &str) -> IResult<&str, _277Loop2200C> {
 ///////////////////////////////////////////////////////////
 
 fn parse_loop_2000_d(hl: HL, input: &str) -> IResult<&str, _277Generic2000Loop> {
+    let _context = crate::error::context("_277Loop2000D");
     let mut output = _277Loop2000D {
         hl,
         ..Default::default()
@@ -774,6 +819,7 @@ fn parse_loop_2000_d(hl: HL, input: &str) -> IResult<&str, _277Generic2000Loop>
 }
 
 fn parse_loop_2100_d(input: &str) -> IResult<&str, _277Loop2100D> {
+    let _context = crate::error::context("_277Loop2100D");
     let mut output = _277Loop2100D::default();
 
     trace!("--> 2100D");
@@ -804,6 +850,7 @@ fn parse_loop_2100_d(input: &str) -> IResult<&str, _277Loop2100D> {
 }
 
 fn parse_loop_2200_d(input: &str) -> IResult<&str, _277Loop2200D> {
+    let _context = crate::error::context("_277Loop2200D");
     let mut output = _277Loop2200D::default();
     trace!("--> 2200D");
 
@@ -832,6 +879,7 @@ fn parse_loop_2200_d(input: &str) -> IResult<&str, _277Loop2200D> {
 }
 
 fn parse_loop_2220_d(input: &str) -> IResult<&str, _277Loop2220D> {
+    let _context = crate::error::context("_277Loop2220D");
     let mut output = _277Loop2220D::default();
     trace!("--> 2220D");
 
@@ -864,6 +912,7 @@ fn parse_loop_2220_d(input: &str) -> IResult<&str, _277Loop2220D> {
 ///////////////////////////////////////////////////////////
 
 fn parse_loop_2000_e(hl: HL, input: &str) -> IResult<&str, _277Generic2000Loop> {
+    let _context = crate::error::context("_277Loop2000E");
     let mut output = _277Loop2000E {
         hl,
         ..Default::default()
@@ -897,6 +946,7 @@ fn parse_loop_2000_e(hl: HL, input: &str) -> IResult<&str, _277Generic2000Loop>
 }
 
 fn parse_loop_2100_e(input: &str) -> IResult<&str, _277Loop2100E> {
+    let _context = crate::error::context("_277Loop2100E");
     let mut output = _277Loop2100E::default();
 
     let (rest, nm1) = NM1::parse(input)?;
@@ -918,6 +968,7 @@ fn parse_loop_2100_e(input: &str) -> IResult<&str, _277Loop2100E> {
 }
 
 fn parse_loop_2200_e(input: &str) -> IResult<&str, _277Loop2200E> {
+    let _context = crate::error::context("_277Loop2200E");
     let mut output = _277Loop2200E::default();
 
     let (rest, trn) = TRN::parse(input)?;
@@ -939,6 +990,7 @@ fn parse_loop_2200_e(input: &str) -> IResult<&str, _277Loop2200E> {
 }
 
 fn parse_loop_2220_e(input: &str) -> IResult<&str, _277Loop2220E> {
+    let _context = crate::error::context("_277Loop2220E");
     let mut output = _277Loop2220E::default();
 
     // SVC (1+)
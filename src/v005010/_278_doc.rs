@@ -13,6 +13,49 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 278 - Health Care Services Review Information
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | BHT | Beginning of Hierarchical Transaction | M | 1
+/// LOOP ID - 2000 | >1
+/// 2000 -> 0100 | HL | Hierarchical Level | M | 1
+/// 2000 -> 0200 | TRN | Trace | O | 9
+/// 2000 -> 0250 | AAA | Request Validation | O | 9
+/// 2000 -> 0300 | UM | Health Care Services Review Information | O | 1
+/// 2000 -> 0350 | HCR | Health Care Services Review | O | 1
+/// 2000 -> 0400 | REF | Reference Information | O | 9
+/// 2000 -> 0500 | DTP | Date or Time or Period | O | 9
+/// 2000 -> 0600 | HI | Health Care Information Codes | O | 1
+/// 2000 -> 0700 | SV1 | Professional Service | O | 1
+/// 2000 -> 0800 | SV2 | Institutional Service | O | 1
+/// 2000 -> 0900 | SV3 | Dental Service | O | 1
+/// 2000 -> 1000 | TOO | Tooth Identification | O | 10
+/// 2000 -> 1100 | HSD | Health Care Services Delivery | O | 1
+/// 2000 -> 1200 | CRC | Conditions Indicator | O | 9
+/// 2000 -> 1300 | CL1 | Claim Codes | O | 1
+/// 2000 -> 1400 | CR1 | Ambulance Certification | O | 1
+/// 2000 -> 1500 | CR2 | Chiropractic Certification | O | 1
+/// 2000 -> 1600 | CR4 | Enteral or Parenteral Therapy Certification | O | 1
+/// 2000 -> 1700 | CR5 | Oxygen Therapy Certification | O | 1
+/// 2000 -> 1800 | CR6 | Home Health Care Certification | O | 1
+/// 2000 -> 1900 | CR7 | Home Health Treatment Plan Certification | O | 1
+/// 2000 -> 2000 | CR8 | Pacemaker Certification | O | 1
+/// 2000 -> 2100 | PWK | Disability Information | O | 10
+/// 2000 -> 2200 | MSG | Message Text | O | 1
+/// 2000 -> LOOP ID - 2010 | >1
+/// 2000 -> 2010 -> 2300 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2010 -> 2400 | REF | Reference Information | O | 9
+/// 2000 -> 2010 -> 2500 | N2 | Additional Name Information | O | 1
+/// 2000 -> 2010 -> 2600 | N3 | Party Location | O | 1
+/// 2000 -> 2010 -> 2700 | N4 | Geographic Location | O | 1
+/// 2000 -> 2010 -> 2800 | PER | Administrative Communications Contact | O | 3
+/// 2000 -> 2010 -> 2900 | AAA | Request Validation | O | 9
+/// 2000 -> 2010 -> 3000 | PRV | Provider Information | O | 1
+/// 2000 -> 2010 -> 3100 | DMG | Demographic Information | O | 1
+/// 2000 -> 2010 -> 3200 | INS | Insured Benefit | O | 1
+/// 2000 -> 2010 -> 3300 | DTP | Date or Time or Period | O | 9
+/// 0100 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _278 {
     pub st: ST,
@@ -67,6 +110,14 @@ pub struct _278LoopNM1 {
 
 impl<'a> Parser<&'a str, _278, nom::error::Error<&'a str>> for _278 {
     fn parse(input: &'a str) -> IResult<&'a str, _278> {
+        let _context = crate::error::transaction_set(
+            "_278",
+            &[
+                "AAA", "BHT", "CL1", "CR1", "CR2", "CR4", "CR5", "CR6", "CR7", "CR8", "CRC", "DMG",
+                "DTP", "HCR", "HI", "HL", "HSD", "INS", "MSG", "N2", "N3", "N4", "NM1", "PER",
+                "PRV", "PWK", "REF", "SE", "ST", "SV1", "SV2", "SV3", "TOO", "TRN", "UM",
+            ],
+        );
         parse_278(input)
     }
 }
@@ -103,6 +154,7 @@ pub fn parse_278(input: &str) -> IResult<&str, _278> {
 }
 
 fn parse_loop_2000(hl: HL, input: &str) -> IResult<&str, _278Loop2000> {
+    let _context = crate::error::context("_278Loop2000");
     trace!("enter parse_loop_2000");
     let mut rest = input;
     let (r, trn) = many0(TRN::parse).parse(rest)?;
@@ -194,6 +246,7 @@ fn parse_loop_2000(hl: HL, input: &str) -> IResult<&str, _278Loop2000> {
 }
 
 fn parse_loop_nm1(nm1: NM1, input: &str) -> IResult<&str, _278LoopNM1> {
+    let _context = crate::error::context("_278LoopNM1");
     trace!("enter parse_loop_nm1");
     let mut rest = input;
     let (r, rref) = many0(REF::parse).parse(rest)?;
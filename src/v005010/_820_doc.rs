@@ -5,6 +5,256 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 820 - Payment Order/Remittance Advice
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | BPR | Beginning Segment for Payment Order/Remittance Advice | M | 1
+/// 0300 | NTE | Note/Special Instruction | O | >1
+/// 0400 | TRN | Trace | O | 1
+/// 0500 | CUR | Currency | O | 1
+/// 0600 | REF | Reference Information | O | >1
+/// 0700 | DTM | Date/Time Reference | O | >1
+/// LOOP ID - N1 | >1
+/// N1 -> 0800 | N1 | Party Identifier | O | 1
+/// N1 -> 0900 | N2 | Additional Name Information | O | >1
+/// N1 -> 1000 | N3 | Party Location | O | >1
+/// N1 -> 1100 | N4 | Geographic Location | O | 1
+/// N1 -> 1200 | REF | Reference Information | O | >1
+/// N1 -> 1300 | PER | Administrative Communications Contact | O | >1
+/// N1 -> 1400 | RDM | Remittance Delivery Method | O | 1
+/// N1 -> 1500 | DTM | Date/Time Reference | O | 1
+/// LOOP ID - ENT | >1
+/// ENT -> 0050 | ENT | Entity | O | 1
+/// ENT -> LOOP ID - FA1 | >1
+/// ENT -> FA1 -> 0100 | FA1 | Type of Financial Accounting Data | O | 1
+/// ENT -> FA1 -> 0150 | FA2 | Accounting Data | M | >1
+/// ENT -> LOOP ID - NM1 | >1
+/// ENT -> NM1 -> 0200 | NM1 | Individual or Organizational Name | O | 1
+/// ENT -> NM1 -> 0250 | N2 | Additional Name Information | O | >1
+/// ENT -> NM1 -> 0300 | N3 | Party Location | O | >1
+/// ENT -> NM1 -> 0350 | N4 | Geographic Location | O | 1
+/// ENT -> NM1 -> 0400 | REF | Reference Information | O | >1
+/// ENT -> NM1 -> 0450 | PER | Administrative Communications Contact | O | >1
+/// ENT -> LOOP ID - ADX | >1
+/// ENT -> ADX -> 0500 | ADX | Adjustment | O | 1
+/// ENT -> ADX -> 0550 | NTE | Note/Special Instruction | O | >1
+/// ENT -> ADX -> 0600 | PER | Administrative Communications Contact | O | >1
+/// ENT -> ADX -> 0650 | DTM | Date/Time Reference | O | 1
+/// ENT -> ADX -> LOOP ID - REF | >1
+/// ENT -> ADX -> REF -> 0700 | REF | Reference Information | O | 1
+/// ENT -> ADX -> REF -> 0750 | DTM | Date/Time Reference | O | >1
+/// ENT -> ADX -> LOOP ID - IT1 | >1
+/// ENT -> ADX -> IT1 -> 0800 | IT1 | Baseline Item Data (Invoice) | O | 1
+/// ENT -> ADX -> IT1 -> 0850 | RPA | Rate Amounts or Percents | O | 1
+/// ENT -> ADX -> IT1 -> 0900 | QTY | Quantity Information | O | 1
+/// ENT -> ADX -> IT1 -> LOOP ID - REF | >1
+/// ENT -> ADX -> IT1 -> REF -> 0950 | REF | Reference Information | O | 1
+/// ENT -> ADX -> IT1 -> REF -> 1000 | DTM | Date/Time Reference | O | >1
+/// ENT -> ADX -> IT1 -> LOOP ID - SAC | >1
+/// ENT -> ADX -> IT1 -> SAC -> 1050 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// ENT -> ADX -> IT1 -> SAC -> 1100 | TXI | Tax Information | O | >1
+/// ENT -> ADX -> IT1 -> SAC -> 1150 | DTM | Date/Time Reference | O | >1
+/// ENT -> ADX -> IT1 -> LOOP ID - SLN | >1
+/// ENT -> ADX -> IT1 -> SLN -> 1200 | SLN | Subline Item Detail | O | 1
+/// ENT -> ADX -> IT1 -> SLN -> LOOP ID - REF | >1
+/// ENT -> ADX -> IT1 -> SLN -> REF -> 1250 | REF | Reference Information | O | 1
+/// ENT -> ADX -> IT1 -> SLN -> REF -> 1300 | DTM | Date/Time Reference | O | >1
+/// ENT -> ADX -> IT1 -> SLN -> LOOP ID - SAC | >1
+/// ENT -> ADX -> IT1 -> SLN -> SAC -> 1350 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// ENT -> ADX -> IT1 -> SLN -> SAC -> 1400 | TXI | Tax Information | O | >1
+/// ENT -> ADX -> IT1 -> SLN -> SAC -> 1450 | DTM | Date/Time Reference | O | >1
+/// ENT -> ADX -> LOOP ID - FA1 | >1
+/// ENT -> ADX -> FA1 -> 1500 | FA1 | Type of Financial Accounting Data | O | 1
+/// ENT -> ADX -> FA1 -> 1550 | FA2 | Accounting Data | M | >1
+/// ENT -> LOOP ID - RMR | >1
+/// ENT -> RMR -> 1600 | RMR | Remittance Advice Accounts Receivable Open Item Reference | O | 1
+/// ENT -> RMR -> 1650 | NTE | Note/Special Instruction | O | >1
+/// ENT -> RMR -> 1700 | REF | Reference Information | O | >1
+/// ENT -> RMR -> 1750 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> 1800 | VEH | Vehicle Information | O | 1
+/// ENT -> RMR -> LOOP ID - IT1 | >1
+/// ENT -> RMR -> IT1 -> 1850 | IT1 | Baseline Item Data (Invoice) | O | 1
+/// ENT -> RMR -> IT1 -> 1900 | RPA | Rate Amounts or Percents | O | 1
+/// ENT -> RMR -> IT1 -> 1950 | QTY | Quantity Information | O | 1
+/// ENT -> RMR -> IT1 -> LOOP ID - REF | >1
+/// ENT -> RMR -> IT1 -> REF -> 2000 | REF | Reference Information | O | 1
+/// ENT -> RMR -> IT1 -> REF -> 2050 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> IT1 -> LOOP ID - SAC | >1
+/// ENT -> RMR -> IT1 -> SAC -> 2100 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// ENT -> RMR -> IT1 -> SAC -> 2150 | TXI | Tax Information | O | >1
+/// ENT -> RMR -> IT1 -> SAC -> 2200 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> IT1 -> LOOP ID - SLN | >1
+/// ENT -> RMR -> IT1 -> SLN -> 2250 | SLN | Subline Item Detail | O | 1
+/// ENT -> RMR -> IT1 -> SLN -> LOOP ID - REF | >1
+/// ENT -> RMR -> IT1 -> SLN -> REF -> 2300 | REF | Reference Information | O | 1
+/// ENT -> RMR -> IT1 -> SLN -> REF -> 2350 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> IT1 -> SLN -> LOOP ID - SAC | >1
+/// ENT -> RMR -> IT1 -> SLN -> SAC -> 2400 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// ENT -> RMR -> IT1 -> SLN -> SAC -> 2450 | TXI | Tax Information | O | >1
+/// ENT -> RMR -> IT1 -> SLN -> SAC -> 2500 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> LOOP ID - ADX | >1
+/// ENT -> RMR -> ADX -> 2550 | ADX | Adjustment | O | 1
+/// ENT -> RMR -> ADX -> 2600 | NTE | Note/Special Instruction | O | >1
+/// ENT -> RMR -> ADX -> 2650 | PER | Administrative Communications Contact | O | >1
+/// ENT -> RMR -> ADX -> LOOP ID - REF | >1
+/// ENT -> RMR -> ADX -> REF -> 2700 | REF | Reference Information | O | 1
+/// ENT -> RMR -> ADX -> REF -> 2750 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> ADX -> LOOP ID - IT1 | >1
+/// ENT -> RMR -> ADX -> IT1 -> 2800 | IT1 | Baseline Item Data (Invoice) | O | 1
+/// ENT -> RMR -> ADX -> IT1 -> 2850 | RPA | Rate Amounts or Percents | O | 1
+/// ENT -> RMR -> ADX -> IT1 -> 2900 | QTY | Quantity Information | O | 1
+/// ENT -> RMR -> ADX -> IT1 -> LOOP ID - REF | >1
+/// ENT -> RMR -> ADX -> IT1 -> REF -> 2950 | REF | Reference Information | O | 1
+/// ENT -> RMR -> ADX -> IT1 -> REF -> 3000 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> ADX -> IT1 -> LOOP ID - SAC | >1
+/// ENT -> RMR -> ADX -> IT1 -> SAC -> 3050 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// ENT -> RMR -> ADX -> IT1 -> SAC -> 3100 | TXI | Tax Information | O | >1
+/// ENT -> RMR -> ADX -> IT1 -> SAC -> 3150 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> ADX -> IT1 -> LOOP ID - SLN | >1
+/// ENT -> RMR -> ADX -> IT1 -> SLN -> 3200 | SLN | Subline Item Detail | O | 1
+/// ENT -> RMR -> ADX -> IT1 -> SLN -> LOOP ID - REF | >1
+/// ENT -> RMR -> ADX -> IT1 -> SLN -> REF -> 3250 | REF | Reference Information | O | 1
+/// ENT -> RMR -> ADX -> IT1 -> SLN -> REF -> 3300 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> ADX -> IT1 -> SLN -> LOOP ID - SAC | >1
+/// ENT -> RMR -> ADX -> IT1 -> SLN -> SAC -> 3350 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// ENT -> RMR -> ADX -> IT1 -> SLN -> SAC -> 3400 | TXI | Tax Information | O | >1
+/// ENT -> RMR -> ADX -> IT1 -> SLN -> SAC -> 3450 | DTM | Date/Time Reference | O | >1
+/// ENT -> RMR -> LOOP ID - FA1 | >1
+/// ENT -> RMR -> FA1 -> 3500 | FA1 | Type of Financial Accounting Data | O | 1
+/// ENT -> RMR -> FA1 -> 3550 | FA2 | Accounting Data | M | >1
+/// ENT -> LOOP ID - ADX | >1
+/// ENT -> ADX -> 3600 | ADX | Adjustment | O | 1
+/// ENT -> ADX -> 3650 | NTE | Note/Special Instruction | O | >1
+/// ENT -> ADX -> 3700 | PER | Administrative Communications Contact | O | >1
+/// ENT -> ADX -> LOOP ID - REF | >1
+/// ENT -> ADX -> REF -> 3750 | REF | Reference Information | O | 1
+/// ENT -> ADX -> REF -> 3800 | DTM | Date/Time Reference | O | >1
+/// ENT -> ADX -> LOOP ID - IT1 | >1
+/// ENT -> ADX -> IT1 -> 3850 | IT1 | Baseline Item Data (Invoice) | O | 1
+/// ENT -> ADX -> IT1 -> 3900 | RPA | Rate Amounts or Percents | O | 1
+/// ENT -> ADX -> IT1 -> 3950 | QTY | Quantity Information | O | 1
+/// ENT -> ADX -> IT1 -> LOOP ID - REF | >1
+/// ENT -> ADX -> IT1 -> REF -> 4000 | REF | Reference Information | O | 1
+/// ENT -> ADX -> IT1 -> REF -> 4050 | DTM | Date/Time Reference | O | >1
+/// ENT -> ADX -> IT1 -> LOOP ID - SAC | >1
+/// ENT -> ADX -> IT1 -> SAC -> 4100 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// ENT -> ADX -> IT1 -> SAC -> 4150 | TXI | Tax Information | O | >1
+/// ENT -> ADX -> IT1 -> SAC -> 4200 | DTM | Date/Time Reference | O | >1
+/// ENT -> ADX -> IT1 -> LOOP ID - SLN | >1
+/// ENT -> ADX -> IT1 -> SLN -> 4250 | SLN | Subline Item Detail | O | 1
+/// ENT -> ADX -> IT1 -> SLN -> LOOP ID - REF | >1
+/// ENT -> ADX -> IT1 -> SLN -> REF -> 4300 | REF | Reference Information | O | 1
+/// ENT -> ADX -> IT1 -> SLN -> REF -> 4350 | DTM | Date/Time Reference | O | >1
+/// ENT -> ADX -> IT1 -> SLN -> LOOP ID - SAC | >1
+/// ENT -> ADX -> IT1 -> SLN -> SAC -> 4400 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// ENT -> ADX -> IT1 -> SLN -> SAC -> 4450 | TXI | Tax Information | O | >1
+/// ENT -> ADX -> IT1 -> SLN -> SAC -> 4500 | DTM | Date/Time Reference | O | >1
+/// ENT -> LOOP ID - FA1 | >1
+/// ENT -> FA1 -> 4550 | FA1 | Type of Financial Accounting Data | O | 1
+/// ENT -> FA1 -> 4600 | FA2 | Accounting Data | M | >1
+/// LOOP ID - RMR | >1
+/// RMR -> 4650 | RMR | Remittance Advice Accounts Receivable Open Item Reference | O | 1
+/// RMR -> 4700 | NTE | Note/Special Instruction | O | >1
+/// RMR -> 4750 | REF | Reference Information | O | >1
+/// RMR -> 4800 | DTM | Date/Time Reference | O | >1
+/// RMR -> 4850 | VEH | Vehicle Information | O | 1
+/// RMR -> LOOP ID - IT1 | >1
+/// RMR -> IT1 -> 4900 | IT1 | Baseline Item Data (Invoice) | O | 1
+/// RMR -> IT1 -> 4950 | RPA | Rate Amounts or Percents | O | 1
+/// RMR -> IT1 -> 5000 | QTY | Quantity Information | O | 1
+/// RMR -> IT1 -> LOOP ID - REF | >1
+/// RMR -> IT1 -> REF -> 5050 | REF | Reference Information | O | 1
+/// RMR -> IT1 -> REF -> 5100 | DTM | Date/Time Reference | O | >1
+/// RMR -> IT1 -> LOOP ID - SAC | >1
+/// RMR -> IT1 -> SAC -> 5150 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// RMR -> IT1 -> SAC -> 5200 | TXI | Tax Information | O | >1
+/// RMR -> IT1 -> SAC -> 5250 | DTM | Date/Time Reference | O | >1
+/// RMR -> IT1 -> LOOP ID - SLN | >1
+/// RMR -> IT1 -> SLN -> 5300 | SLN | Subline Item Detail | O | 1
+/// RMR -> IT1 -> SLN -> LOOP ID - REF | >1
+/// RMR -> IT1 -> SLN -> REF -> 5350 | REF | Reference Information | O | 1
+/// RMR -> IT1 -> SLN -> REF -> 5400 | DTM | Date/Time Reference | O | >1
+/// RMR -> IT1 -> SLN -> LOOP ID - SAC | >1
+/// RMR -> IT1 -> SLN -> SAC -> 5450 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// RMR -> IT1 -> SLN -> SAC -> 5500 | TXI | Tax Information | O | >1
+/// RMR -> IT1 -> SLN -> SAC -> 5550 | DTM | Date/Time Reference | O | >1
+/// RMR -> LOOP ID - ADX | >1
+/// RMR -> ADX -> 5600 | ADX | Adjustment | O | 1
+/// RMR -> ADX -> 5650 | NTE | Note/Special Instruction | O | >1
+/// RMR -> ADX -> 5700 | PER | Administrative Communications Contact | O | >1
+/// RMR -> ADX -> LOOP ID - REF | >1
+/// RMR -> ADX -> REF -> 5750 | REF | Reference Information | O | 1
+/// RMR -> ADX -> REF -> 5800 | DTM | Date/Time Reference | O | >1
+/// RMR -> ADX -> LOOP ID - IT1 | >1
+/// RMR -> ADX -> IT1 -> 5850 | IT1 | Baseline Item Data (Invoice) | O | 1
+/// RMR -> ADX -> IT1 -> 5900 | RPA | Rate Amounts or Percents | O | 1
+/// RMR -> ADX -> IT1 -> 5950 | QTY | Quantity Information | O | 1
+/// RMR -> ADX -> IT1 -> LOOP ID - REF | >1
+/// RMR -> ADX -> IT1 -> REF -> 6000 | REF | Reference Information | O | 1
+/// RMR -> ADX -> IT1 -> REF -> 6050 | DTM | Date/Time Reference | O | >1
+/// RMR -> ADX -> IT1 -> LOOP ID - SAC | >1
+/// RMR -> ADX -> IT1 -> SAC -> 6100 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// RMR -> ADX -> IT1 -> SAC -> 6150 | TXI | Tax Information | O | >1
+/// RMR -> ADX -> IT1 -> SAC -> 6200 | DTM | Date/Time Reference | O | >1
+/// RMR -> ADX -> IT1 -> LOOP ID - SLN | >1
+/// RMR -> ADX -> IT1 -> SLN -> 6250 | SLN | Subline Item Detail | O | 1
+/// RMR -> ADX -> IT1 -> SLN -> LOOP ID - REF | >1
+/// RMR -> ADX -> IT1 -> SLN -> REF -> 6300 | REF | Reference Information | O | 1
+/// RMR -> ADX -> IT1 -> SLN -> REF -> 6350 | DTM | Date/Time Reference | O | >1
+/// RMR -> ADX -> IT1 -> SLN -> LOOP ID - SAC | >1
+/// RMR -> ADX -> IT1 -> SLN -> SAC -> 6400 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// RMR -> ADX -> IT1 -> SLN -> SAC -> 6450 | TXI | Tax Information | O | >1
+/// RMR -> ADX -> IT1 -> SLN -> SAC -> 6500 | DTM | Date/Time Reference | O | >1
+/// RMR -> LOOP ID - FA1 | >1
+/// RMR -> FA1 -> 6550 | FA1 | Type of Financial Accounting Data | O | 1
+/// RMR -> FA1 -> 6600 | FA2 | Accounting Data | M | >1
+/// LOOP ID - TXP | >1
+/// TXP -> 6650 | TXP | Tax Payment | O | 1
+/// TXP -> 6700 | TXI | Tax Information | O | >1
+/// TXP -> 6750 | REF | Reference Information | O | >1
+/// TXP -> 6800 | DTM | Date/Time Reference | O | >1
+/// LOOP ID - DED | >1
+/// DED -> 6850 | DED | Deductions | O | 1
+/// LOOP ID - LX | >1
+/// LX -> 6900 | LX | Transaction Set Line Number | O | 1
+/// LX -> 6950 | REF | Reference Information | O | >1
+/// LX -> 7000 | TRN | Trace | O | >1
+/// LX -> LOOP ID - NM1 | >1
+/// LX -> NM1 -> 7050 | NM1 | Individual or Organizational Name | O | 1
+/// LX -> NM1 -> 7100 | N2 | Additional Name Information | O | >1
+/// LX -> NM1 -> 7150 | N3 | Party Location | O | >1
+/// LX -> NM1 -> 7200 | N4 | Geographic Location | O | 1
+/// LX -> NM1 -> 7250 | REF | Reference Information | O | >1
+/// LX -> NM1 -> 7300 | PER | Administrative Communications Contact | O | >1
+/// LOOP ID - N9 | 1
+/// N9 -> 0100 | N9 | Extended Reference Information | O | 1
+/// N9 -> 0200 | REF | Reference Information | O | >1
+/// N9 -> LOOP ID - AMT | >1
+/// N9 -> AMT -> 0300 | AMT | Monetary Amount Information | O | 1
+/// N9 -> AMT -> 0400 | REF | Reference Information | O | >1
+/// N9 -> LOOP ID - N1 | >1
+/// N9 -> N1 -> 0500 | N1 | Party Identifier | O | 1
+/// N9 -> N1 -> 0600 | N2 | Additional Name Information | O | >1
+/// N9 -> N1 -> 0700 | N3 | Party Location | O | >1
+/// N9 -> N1 -> 0800 | N4 | Geographic Location | O | 1
+/// N9 -> N1 -> 0900 | REF | Reference Information | O | >1
+/// N9 -> N1 -> 1000 | PER | Administrative Communications Contact | O | >1
+/// N9 -> N1 -> 1100 | RDM | Remittance Delivery Method | O | 1
+/// N9 -> N1 -> 1200 | DTM | Date/Time Reference | O | 1
+/// LOOP ID - RYL | >1
+/// RYL -> 1300 | RYL | Royalty Payment | O | 1
+/// RYL -> LOOP ID - NM1 | >1
+/// RYL -> NM1 -> 1400 | NM1 | Individual or Organizational Name | O | 1
+/// RYL -> NM1 -> 1500 | N2 | Additional Name Information | O | >1
+/// RYL -> NM1 -> 1600 | N3 | Party Location | O | >1
+/// RYL -> NM1 -> 1700 | N4 | Geographic Location | O | 1
+/// RYL -> NM1 -> 1800 | REF | Reference Information | O | >1
+/// RYL -> NM1 -> 1900 | PER | Administrative Communications Contact | O | >1
+/// RYL -> LOOP ID - ASM | 1
+/// RYL -> ASM -> 2000 | ASM | Amount and Settlement Method | O | 1
+/// RYL -> ASM -> 2100 | ADX | Adjustment | O | 1
+/// 2200 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _820 {
     pub st: ST,
@@ -27,6 +277,14 @@ pub struct _820 {
 
 impl<'a> Parser<&'a str, _820, nom::error::Error<&'a str>> for _820 {
     fn parse(input: &'a str) -> IResult<&'a str, _820> {
+        let _context = crate::error::transaction_set(
+            "_820",
+            &[
+                "ADX", "AMT", "ASM", "BPR", "CUR", "DED", "DTM", "ENT", "FA1", "FA2", "IT1", "LX",
+                "N1", "N2", "N3", "N4", "N9", "NM1", "NTE", "PER", "QTY", "RDM", "REF", "RMR",
+                "RPA", "RYL", "SAC", "SE", "SLN", "ST", "TRN", "TXI", "TXP", "VEH",
+            ],
+        );
         let mut output = _820::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -79,6 +337,7 @@ pub struct _820LoopN1 {
 
 impl<'a> Parser<&'a str, _820LoopN1, nom::error::Error<&'a str>> for _820LoopN1 {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopN1> {
+        let _context = crate::error::context("_820LoopN1");
         let mut output = _820LoopN1::default();
         let (rest, obj) = N1::parse(input)?;
         output.n1 = obj;
@@ -114,6 +373,7 @@ pub struct _820LoopENT {
 
 impl<'a> Parser<&'a str, _820LoopENT, nom::error::Error<&'a str>> for _820LoopENT {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopENT> {
+        let _context = crate::error::context("_820LoopENT");
         let mut output = _820LoopENT::default();
         let (rest, obj) = ENT::parse(input)?;
         output.ent = obj;
@@ -142,6 +402,7 @@ pub struct _820LoopFA1 {
 
 impl<'a> Parser<&'a str, _820LoopFA1, nom::error::Error<&'a str>> for _820LoopFA1 {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopFA1> {
+        let _context = crate::error::context("_820LoopFA1");
         let mut output = _820LoopFA1::default();
         let (rest, obj) = FA1::parse(input)?;
         output.fa1 = obj;
@@ -164,6 +425,7 @@ pub struct _820LoopNM1 {
 
 impl<'a> Parser<&'a str, _820LoopNM1, nom::error::Error<&'a str>> for _820LoopNM1 {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopNM1> {
+        let _context = crate::error::context("_820LoopNM1");
         let mut output = _820LoopNM1::default();
         let (rest, obj) = NM1::parse(input)?;
         output.nm1 = obj;
@@ -195,6 +457,7 @@ pub struct _820LoopADX {
 
 impl<'a> Parser<&'a str, _820LoopADX, nom::error::Error<&'a str>> for _820LoopADX {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopADX> {
+        let _context = crate::error::context("_820LoopADX");
         let mut output = _820LoopADX::default();
         let (rest, obj) = ADX::parse(input)?;
         output.adx = obj;
@@ -223,6 +486,7 @@ pub struct _820LoopREF {
 
 impl<'a> Parser<&'a str, _820LoopREF, nom::error::Error<&'a str>> for _820LoopREF {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopREF> {
+        let _context = crate::error::context("_820LoopREF");
         let mut output = _820LoopREF::default();
         let (rest, obj) = REF::parse(input)?;
         output.ref_segment = obj;
@@ -245,6 +509,7 @@ pub struct _820LoopIT1 {
 
 impl<'a> Parser<&'a str, _820LoopIT1, nom::error::Error<&'a str>> for _820LoopIT1 {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopIT1> {
+        let _context = crate::error::context("_820LoopIT1");
         let mut output = _820LoopIT1::default();
         let (rest, obj) = IT1::parse(input)?;
         output.it1 = obj;
@@ -272,6 +537,7 @@ pub struct _820LoopSAC {
 
 impl<'a> Parser<&'a str, _820LoopSAC, nom::error::Error<&'a str>> for _820LoopSAC {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopSAC> {
+        let _context = crate::error::context("_820LoopSAC");
         let mut output = _820LoopSAC::default();
         let (rest, obj) = SAC::parse(input)?;
         output.sac = obj;
@@ -293,6 +559,7 @@ pub struct _820LoopSLN {
 
 impl<'a> Parser<&'a str, _820LoopSLN, nom::error::Error<&'a str>> for _820LoopSLN {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopSLN> {
+        let _context = crate::error::context("_820LoopSLN");
         let mut output = _820LoopSLN::default();
         let (rest, obj) = SLN::parse(input)?;
         output.sln = obj;
@@ -319,6 +586,7 @@ pub struct _820LoopRMR {
 
 impl<'a> Parser<&'a str, _820LoopRMR, nom::error::Error<&'a str>> for _820LoopRMR {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopRMR> {
+        let _context = crate::error::context("_820LoopRMR");
         let mut output = _820LoopRMR::default();
         let (rest, obj) = RMR::parse(input)?;
         output.rmr = obj;
@@ -352,6 +620,7 @@ pub struct _820LoopADXNested {
 
 impl<'a> Parser<&'a str, _820LoopADXNested, nom::error::Error<&'a str>> for _820LoopADXNested {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopADXNested> {
+        let _context = crate::error::context("_820LoopADXNested");
         let mut output = _820LoopADXNested::default();
         let (rest, obj) = ADX::parse(input)?;
         output.adx = obj;
@@ -378,6 +647,7 @@ pub struct _820LoopTXP {
 
 impl<'a> Parser<&'a str, _820LoopTXP, nom::error::Error<&'a str>> for _820LoopTXP {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopTXP> {
+        let _context = crate::error::context("_820LoopTXP");
         let mut output = _820LoopTXP::default();
         let (rest, obj) = TXP::parse(input)?;
         output.txp = obj;
@@ -399,6 +669,7 @@ pub struct _820LoopDED {
 
 impl<'a> Parser<&'a str, _820LoopDED, nom::error::Error<&'a str>> for _820LoopDED {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopDED> {
+        let _context = crate::error::context("_820LoopDED");
         let mut output = _820LoopDED::default();
         let (rest, obj) = DED::parse(input)?;
         output.ded = obj;
@@ -417,6 +688,7 @@ pub struct _820LoopLX {
 
 impl<'a> Parser<&'a str, _820LoopLX, nom::error::Error<&'a str>> for _820LoopLX {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopLX> {
+        let _context = crate::error::context("_820LoopLX");
         let mut output = _820LoopLX::default();
         let (rest, obj) = LX::parse(input)?;
         output.lx = obj;
@@ -441,6 +713,7 @@ pub struct _820LoopN9 {
 
 impl<'a> Parser<&'a str, _820LoopN9, nom::error::Error<&'a str>> for _820LoopN9 {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopN9> {
+        let _context = crate::error::context("_820LoopN9");
         let mut output = _820LoopN9::default();
         let (rest, obj) = N9::parse(input)?;
         output.n9 = obj;
@@ -463,6 +736,7 @@ pub struct _820LoopAMT {
 
 impl<'a> Parser<&'a str, _820LoopAMT, nom::error::Error<&'a str>> for _820LoopAMT {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopAMT> {
+        let _context = crate::error::context("_820LoopAMT");
         let mut output = _820LoopAMT::default();
         let (rest, obj) = AMT::parse(input)?;
         output.amt = obj;
@@ -482,6 +756,7 @@ pub struct _820LoopRYL {
 
 impl<'a> Parser<&'a str, _820LoopRYL, nom::error::Error<&'a str>> for _820LoopRYL {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopRYL> {
+        let _context = crate::error::context("_820LoopRYL");
         let mut output = _820LoopRYL::default();
         let (rest, obj) = RYL::parse(input)?;
         output.ryl = obj;
@@ -502,6 +777,7 @@ pub struct _820LoopASM {
 
 impl<'a> Parser<&'a str, _820LoopASM, nom::error::Error<&'a str>> for _820LoopASM {
     fn parse(input: &'a str) -> IResult<&'a str, _820LoopASM> {
+        let _context = crate::error::context("_820LoopASM");
         let mut output = _820LoopASM::default();
         let (rest, obj) = ASM::parse(input)?;
         output.asm = obj;
@@ -9,6 +9,139 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 834 - Benefit Enrollment and Maintenance
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | BGN | Beginning Segment | M | 1
+/// 0300 | REF | Reference Information | O | >1
+/// 0400 | DTP | Date or Time or Period | O | >1
+/// 0500 | AMT | Monetary Amount Information | O | >1
+/// 0600 | QTY | Quantity Information | O | >1
+/// LOOP ID - 1000 | >1
+/// 1000 -> 0700 | N1 | Party Identifier | O | 1
+/// 1000 -> 0800 | N2 | Additional Name Information | O | >1
+/// 1000 -> 0900 | N3 | Party Location | O | >1
+/// 1000 -> 1000 | N4 | Geographic Location | O | 1
+/// 1000 -> 1100 | PER | Administrative Communications Contact | O | >1
+/// 1000 -> LOOP ID - 1100 | 10
+/// 1000 -> 1100 -> 1200 | ACT | Account Identification | O | 1
+/// 1000 -> 1100 -> 1300 | REF | Reference Information | O | >1
+/// 1000 -> 1100 -> 1400 | N3 | Party Location | O | 1
+/// 1000 -> 1100 -> 1500 | N4 | Geographic Location | O | 1
+/// 1000 -> 1100 -> 1600 | PER | Administrative Communications Contact | O | >1
+/// 1000 -> 1100 -> 1700 | DTP | Date or Time or Period | O | 1
+/// 1000 -> 1100 -> 1800 | AMT | Monetary Amount Information | O | 1
+/// LOOP ID - 2000 | >1
+/// 2000 -> 0100 | INS | Insured Benefit | O | 1
+/// 2000 -> 0200 | REF | Reference Information | O | >1
+/// 2000 -> 0300 | DTP | Date or Time or Period | O | >1
+/// 2000 -> LOOP ID - 2100 | >1
+/// 2000 -> 2100 -> 0400 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2100 -> 0500 | PER | Administrative Communications Contact | O | 1
+/// 2000 -> 2100 -> 0600 | N3 | Party Location | O | 1
+/// 2000 -> 2100 -> 0700 | N4 | Geographic Location | O | 1
+/// 2000 -> 2100 -> 0800 | DMG | Demographic Information | O | 1
+/// 2000 -> 2100 -> 0900 | PM | Electronic Funds Transfer Information | O | 1
+/// 2000 -> 2100 -> 1000 | EC | Employment Class | O | >1
+/// 2000 -> 2100 -> 1100 | ICM | Individual Income | O | 1
+/// 2000 -> 2100 -> 1200 | AMT | Monetary Amount Information | O | >1
+/// 2000 -> 2100 -> 1300 | HLH | Health Information | O | 1
+/// 2000 -> 2100 -> 1400 | HI | Health Care Information Codes | O | >1
+/// 2000 -> 2100 -> 1500 | LUI | Language Use | O | >1
+/// 2000 -> LOOP ID - 2200 | >1
+/// 2000 -> 2200 -> 1600 | DSB | Disability Information | O | 1
+/// 2000 -> 2200 -> 1700 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2200 -> 1800 | AD1 | Adjustment Amount | O | >1
+/// 2000 -> LOOP ID - 2300 | >1
+/// 2000 -> 2300 -> 1900 | HD | Health Coverage | O | 1
+/// 2000 -> 2300 -> 2000 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2300 -> 2100 | AMT | Monetary Amount Information | O | >1
+/// 2000 -> 2300 -> 2200 | REF | Reference Information | O | >1
+/// 2000 -> 2300 -> 2300 | IDC | Health Coverage | O | >1
+/// 2000 -> 2300 -> LOOP ID - 2310 | >1
+/// 2000 -> 2300 -> 2310 -> 2400 | LX | Transaction Set Line Number | O | 1
+/// 2000 -> 2300 -> 2310 -> 2500 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2300 -> 2310 -> 2600 | N1 | Party Identifier | O | >1
+/// 2000 -> 2300 -> 2310 -> 2700 | N2 | Additional Name Information | O | 1
+/// 2000 -> 2300 -> 2310 -> 2800 | N3 | Party Location | O | >1
+/// 2000 -> 2300 -> 2310 -> 2900 | N4 | Geographic Location | O | 1
+/// 2000 -> 2300 -> 2310 -> 3000 | PER | Administrative Communications Contact | O | >1
+/// 2000 -> 2300 -> 2310 -> 3100 | PRV | Provider Information | O | 1
+/// 2000 -> 2300 -> 2310 -> 3200 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2300 -> 2310 -> 3300 | PLA | Place or Location | O | 1
+/// 2000 -> 2300 -> LOOP ID - 2320 | >1
+/// 2000 -> 2300 -> 2320 -> 3400 | COB | Coordination of Benefits | O | 1
+/// 2000 -> 2300 -> 2320 -> 3500 | REF | Reference Information | O | 5
+/// 2000 -> 2300 -> 2320 -> 3600 | DTP | Date or Time or Period | O | 2
+/// 2000 -> 2300 -> 2320 -> LOOP ID - 2330 | >1
+/// 2000 -> 2300 -> 2320 -> 2330 -> 3700 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2300 -> 2320 -> 2330 -> 3800 | N2 | Additional Name Information | O | 1
+/// 2000 -> 2300 -> 2320 -> 2330 -> 3900 | N3 | Party Location | O | >1
+/// 2000 -> 2300 -> 2320 -> 2330 -> 4000 | N4 | Geographic Location | O | 1
+/// 2000 -> 2300 -> 2320 -> 2330 -> 4100 | PER | Administrative Communications Contact | O | 1
+/// 2000 -> LOOP ID - 2400 | >1
+/// 2000 -> 2400 -> 4200 | LC | Life Coverage | O | 1
+/// 2000 -> 2400 -> 4300 | AMT | Monetary Amount Information | O | >1
+/// 2000 -> 2400 -> 4400 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2400 -> 4500 | REF | Reference Information | O | >1
+/// 2000 -> 2400 -> LOOP ID - 2410 | >1
+/// 2000 -> 2400 -> 2410 -> 4600 | BEN | Financial Contribution | O | 1
+/// 2000 -> 2400 -> 2410 -> 4700 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2400 -> 2410 -> 4800 | N1 | Party Identifier | O | 1
+/// 2000 -> 2400 -> 2410 -> 4900 | N2 | Additional Name Information | O | 1
+/// 2000 -> 2400 -> 2410 -> 5000 | N3 | Party Location | O | 1
+/// 2000 -> 2400 -> 2410 -> 5100 | N4 | Geographic Location | O | 1
+/// 2000 -> 2400 -> 2410 -> 5200 | DMG | Demographic Information | O | 1
+/// 2000 -> LOOP ID - 2500 | >1
+/// 2000 -> 2500 -> 5300 | FSA | Flexible Spending Amount | O | 1
+/// 2000 -> 2500 -> 5400 | AMT | Monetary Amount Information | O | >1
+/// 2000 -> 2500 -> 5500 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2500 -> 5600 | REF | Reference Information | O | >1
+/// 2000 -> LOOP ID - 2600 | >1
+/// 2000 -> 2600 -> 5700 | RP | Retirement Product | O | 1
+/// 2000 -> 2600 -> 5800 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2600 -> 5900 | REF | Reference Information | O | >1
+/// 2000 -> 2600 -> 6000 | INV | Incestment Vehicle Selection | O | >1
+/// 2000 -> 2600 -> 6100 | AMT | Monetary Amount Information | O | >1
+/// 2000 -> 2600 -> 6200 | QTY | Quantity Information | O | >1
+/// 2000 -> 2600 -> 6300 | K3 | File Information | O | >1
+/// 2000 -> 2600 -> 6400 | REL | Relationship | O | 1
+/// 2000 -> 2600 -> LOOP ID - 2610 | >1
+/// 2000 -> 2600 -> 2610 -> 6500 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2600 -> 2610 -> 6600 | N2 | Additional Name Information | O | 1
+/// 2000 -> 2600 -> 2610 -> 6700 | DMG | Demographic Information | O | 1
+/// 2000 -> 2600 -> 2610 -> 6800 | BEN | Financial Contribution | O | 1
+/// 2000 -> 2600 -> 2610 -> 6900 | REF | Reference Information | O | >1
+/// 2000 -> 2600 -> 2610 -> LOOP ID - 2620 | >1
+/// 2000 -> 2600 -> 2610 -> 2620 -> 7000 | NX1 | Property or Entity Identification | O | 1
+/// 2000 -> 2600 -> 2610 -> 2620 -> 7100 | N3 | Party Location | O | 1
+/// 2000 -> 2600 -> 2610 -> 2620 -> 7200 | N4 | Geographic Location | O | 1
+/// 2000 -> 2600 -> 2610 -> 2620 -> 7300 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2600 -> LOOP ID - 2630 | >1
+/// 2000 -> 2600 -> 2630 -> 7400 | FC | Financial Contribution | O | 1
+/// 2000 -> 2600 -> 2630 -> 7500 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2600 -> 2630 -> LOOP ID - 2640 | >1
+/// 2000 -> 2600 -> 2630 -> 2640 -> 7600 | INV | Incestment Vehicle Selection | O | 1
+/// 2000 -> 2600 -> 2630 -> 2640 -> 7700 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 2600 -> 2630 -> 2640 -> 7800 | QTY | Quantity Information | O | >1
+/// 2000 -> 2600 -> 2630 -> 2640 -> 7900 | ENT | Entity | O | >1
+/// 2000 -> 2600 -> 2630 -> 2640 -> 8000 | REF | Reference Information | O | >1
+/// 2000 -> 2600 -> 2630 -> 2640 -> 8100 | AMT | Monetary Amount Information | O | >1
+/// 2000 -> 2600 -> 2630 -> 2640 -> 8200 | K3 | File Information | O | >1
+/// 2000 -> 2600 -> LOOP ID - 2650 | >1
+/// 2000 -> 2600 -> 2650 -> 8300 | AIN | Income | O | 1
+/// 2000 -> 2600 -> 2650 -> 8400 | QTY | Quantity Information | O | >1
+/// 2000 -> 2600 -> 2650 -> 8500 | DTP | Date or Time or Period | O | >1
+/// 2000 -> 8600 | LS | Loop Header | O | 1
+/// 2000 -> LOOP ID - 2700 | >1
+/// 2000 -> 2700 -> 8700 | LX | Transaction Set Line Number | O | 1
+/// 2000 -> 2700 -> LOOP ID - 2750 | >1
+/// 2000 -> 2700 -> 2750 -> 8800 | N1 | Party Identifier | O | 1
+/// 2000 -> 2700 -> 2750 -> 8900 | REF | Reference Information | M | 1
+/// 2000 -> 2700 -> 2750 -> 9000 | DTP | Date or Time or Period | O | 1
+/// 2000 -> 9100 | LE | Loop Trailer | O | 1
+/// 0100 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _834 {
     pub st: ST,
@@ -24,6 +157,14 @@ pub struct _834 {
 
 impl<'a> Parser<&'a str, _834, nom::error::Error<&'a str>> for _834 {
     fn parse(input: &'a str) -> IResult<&'a str, _834> {
+        let _context = crate::error::transaction_set(
+            "_834",
+            &[
+                "AMT", "BGN", "COB", "DMG", "DTP", "EC", "HD", "HI", "HLH", "ICM", "IDC", "INS",
+                "INV", "K3", "LE", "LS", "LUI", "LX", "N1", "N2", "N3", "N4", "NM1", "PER", "PLA",
+                "PM", "PRV", "QTY", "REF", "REL", "RP", "SE", "ST",
+            ],
+        );
         let mut output = _834::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -41,6 +182,7 @@ impl<'a> Parser<&'a str, _834, nom::error::Error<&'a str>> for _834 {
         let mut loop_1000 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_834Loop1000");
             let (rest, n1) = N1::parse(loop_rest)?;
             let (rest, n2) = many0(N2::parse).parse(rest)?;
             let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -62,6 +204,7 @@ impl<'a> Parser<&'a str, _834, nom::error::Error<&'a str>> for _834 {
         let mut loop_2000 = vec![];
         let mut loop_rest = rest;
         while peek(opt(INS::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_834Loop2000");
             let (rest, ins) = opt(INS::parse).parse(loop_rest)?;
             let (rest, r#ref) = many0(REF::parse).parse(rest)?;
             let (rest, dtp) = many0(DTP::parse).parse(rest)?;
@@ -69,6 +212,7 @@ impl<'a> Parser<&'a str, _834, nom::error::Error<&'a str>> for _834 {
             // loop 2100
             let mut loop_2100 = vec![];
             while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_834Loop2100");
                 let (rest, nm1) = opt(NM1::parse).parse(loop_rest)?;
                 let (rest, per) = opt(PER::parse).parse(rest)?;
                 let (rest, n3) = opt(N3::parse).parse(rest)?;
@@ -100,6 +244,7 @@ impl<'a> Parser<&'a str, _834, nom::error::Error<&'a str>> for _834 {
             // loop 2300
             let mut loop_2300 = vec![];
             while peek(opt(HD::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_834Loop2300");
                 let (rest, hd) = opt(HD::parse).parse(loop_rest)?;
                 let (rest, dtp) = many0(DTP::parse).parse(rest)?;
                 let (rest, amt) = many0(AMT::parse).parse(rest)?;
@@ -111,6 +256,7 @@ impl<'a> Parser<&'a str, _834, nom::error::Error<&'a str>> for _834 {
                 while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some()
                     || peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some()
                 {
+                    let _context = crate::error::context("_834Loop2310");
                     let (rest, lx) = opt(LX::parse).parse(loop_rest)?;
                     let (rest, nm1) = opt(NM1::parse).parse(rest)?;
                     let (rest, n1) = many0(N1::parse).parse(rest)?;
@@ -141,6 +287,7 @@ impl<'a> Parser<&'a str, _834, nom::error::Error<&'a str>> for _834 {
                     || peek(opt(REF::parse)).parse(loop_rest)?.1.is_some()
                     || peek(opt(DTP::parse)).parse(loop_rest)?.1.is_some()
                 {
+                    let _context = crate::error::context("_834Loop2320");
                     let (rest, cob) = opt(COB::parse).parse(loop_rest)?;
                     let (rest, r#ref) = opt(REF::parse).parse(rest)?;
                     let (rest, dtp) = opt(DTP::parse).parse(rest)?;
@@ -148,6 +295,7 @@ impl<'a> Parser<&'a str, _834, nom::error::Error<&'a str>> for _834 {
                     // loop 2330
                     let mut loop_2330 = vec![];
                     while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
+                        let _context = crate::error::context("_834Loop2330");
                         let (rest, nm1) = opt(NM1::parse).parse(loop_rest)?;
                         let (rest, n2) = opt(N2::parse).parse(rest)?;
                         let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -182,6 +330,7 @@ impl<'a> Parser<&'a str, _834, nom::error::Error<&'a str>> for _834 {
             // loop 2600
             let mut loop_2600 = vec![];
             while peek(opt(RP::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_834Loop2600");
                 let (rest, rp) = opt(RP::parse).parse(loop_rest)?;
                 let (rest, dtp) = many0(DTP::parse).parse(rest)?;
                 let (rest, r#ref) = many0(REF::parse).parse(rest)?;
@@ -211,11 +360,13 @@ impl<'a> Parser<&'a str, _834, nom::error::Error<&'a str>> for _834 {
             // loop 2700
             let mut loop_2700 = vec![];
             while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_834Loop2700");
                 let (rest, lx) = opt(LX::parse).parse(loop_rest)?;
                 loop_rest = rest;
                 // loop 2750
                 let mut loop_2750 = vec![];
                 while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_834Loop2750");
                     let (rest, n1) = N1::parse(loop_rest)?;
                     let (rest, r#ref) = REF::parse(rest)?;
                     let (rest, dtp) = opt(DTP::parse).parse(rest)?;
@@ -1,4 +1,5 @@
 use super::*;
+use crate::util::Parser;
 
 #[test]
 fn parse_834() {
@@ -260,6 +261,7 @@ IEA*1*000012345~
             _01: "1".to_string(),
             _02: "000012345".to_string(),
         },
+        ..Default::default()
     };
     let obj_str = format!("{obj}");
     assert_eq!(str, obj_str);
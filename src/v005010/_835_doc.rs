@@ -1,4 +1,5 @@
 use super::segment::*;
+use super::segment_ref::*;
 use crate::util::Parser;
 use nom::{
     combinator::{opt, peek},
@@ -9,6 +10,50 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 835 - Health Care Claim Payment/Advice
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | BPR | Beginning Segment for Payment Order/Remittance Advice | M | 1
+/// 0300 | NTE | Note/Special Instruction | O | >1
+/// 0400 | TRN | Trace | O | 1
+/// 0500 | CUR | Currency | O | 1
+/// 0600 | REF | Reference Information | O | >1
+/// 0700 | DTM | Date/Time Reference | O | >1
+/// LOOP ID - 1000 | 200
+/// 1000 -> 0800 | N1 | Party Identifier | O | 1
+/// 1000 -> 0900 | N2 | Additional Name Information | O | >1
+/// 1000 -> 1000 | N3 | Party Location | O | >1
+/// 1000 -> 1100 | N4 | Geographic Location | O | 1
+/// 1000 -> 1200 | REF | Reference Information | O | >1
+/// 1000 -> 1300 | PER | Administrative Communications Contact | O | >1
+/// 1000 -> 1400 | RDM | Remittance Delivery Method | O | 1
+/// 1000 -> 1500 | DTM | Date/Time Reference | O | 1
+/// LOOP ID - 2000 | >1
+/// 2000 -> 0030 | LX | Transaction Set Line Number | O | 1
+/// 2000 -> 0050 | TS3 | Transaction Statistics | O | 1
+/// 2000 -> 0070 | TS2 | Transaction Supplemental Statistics | O | 1
+/// 2000 -> LOOP ID - 2100 | >1
+/// 2000 -> 2100 -> 0100 | CLP | Claim Level Data | M | 1
+/// 2000 -> 2100 -> 0200 | CAS | Claims Adjustment | O | 99
+/// 2000 -> 2100 -> 0300 | NM1 | Individual or Organizational Name | M | 9
+/// 2000 -> 2100 -> 0330 | MIA | Medicare Inpatient Adjudication | O | 1
+/// 2000 -> 2100 -> 0350 | MOA | Medicare Outpatient Adjudication | O | 1
+/// 2000 -> 2100 -> 0400 | REF | Reference Information | O | 99
+/// 2000 -> 2100 -> 0500 | DTM | Date/Time Reference | O | 9
+/// 2000 -> 2100 -> 0600 | PER | Administrative Communications Contact | O | 3
+/// 2000 -> 2100 -> 0620 | AMT | Monetary Amount Information | O | 20
+/// 2000 -> 2100 -> 0640 | QTY | Quantity Information | O | 20
+/// 2000 -> 2100 -> LOOP ID - 2110 | 999
+/// 2000 -> 2100 -> 2110 -> 0700 | SVC | Service Information | O | 1
+/// 2000 -> 2100 -> 2110 -> 0800 | DTM | Date/Time Reference | O | 9
+/// 2000 -> 2100 -> 2110 -> 0900 | CAS | Claims Adjustment | O | 99
+/// 2000 -> 2100 -> 2110 -> 1000 | REF | Reference Information | O | 99
+/// 2000 -> 2100 -> 2110 -> 1100 | AMT | Monetary Amount Information | O | 20
+/// 2000 -> 2100 -> 2110 -> 1200 | QTY | Quantity Information | O | 20
+/// 2000 -> 2100 -> 2110 -> 1300 | LQ | Industry Code Identification | O | 99
+/// 0100 | PLB | Provider Level Adjustment | O | >1
+/// 0200 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _835 {
     pub st: ST,
@@ -26,6 +71,14 @@ pub struct _835 {
 
 impl<'a> Parser<&'a str, _835, nom::error::Error<&'a str>> for _835 {
     fn parse(input: &'a str) -> IResult<&'a str, _835> {
+        let _context = crate::error::transaction_set(
+            "_835",
+            &[
+                "AMT", "BPR", "CAS", "CLP", "CUR", "DTM", "LQ", "LX", "MIA", "MOA", "N1", "N2",
+                "N3", "N4", "NM1", "NTE", "PER", "PLB", "QTY", "RDM", "REF", "SE", "ST", "SVC",
+                "TRN", "TS2", "TS3",
+            ],
+        );
         let mut output = _835::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -45,6 +98,7 @@ impl<'a> Parser<&'a str, _835, nom::error::Error<&'a str>> for _835 {
         let mut loop_1000 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_835Loop1000");
             let (rest, n1) = N1::parse(loop_rest)?;
             let (rest, n2) = many0(N2::parse).parse(rest)?;
             let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -69,6 +123,7 @@ impl<'a> Parser<&'a str, _835, nom::error::Error<&'a str>> for _835 {
         // loop 2000
         let mut loop_2000 = vec![];
         while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_835Loop2000");
             let (rest, lx) = LX::parse(loop_rest)?;
             let (rest, ts3) = opt(TS3::parse).parse(rest)?;
             let (rest, ts2) = opt(TS2::parse).parse(rest)?;
@@ -76,6 +131,7 @@ impl<'a> Parser<&'a str, _835, nom::error::Error<&'a str>> for _835 {
             // loop 2100
             let mut loop_2100 = vec![];
             while peek(opt(CLP::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_835Loop2100");
                 let (rest, clp) = CLP::parse(loop_rest)?;
                 let (rest, cas) = many0(CAS::parse).parse(rest)?;
                 let (rest, nm1) = many0(NM1::parse).parse(rest)?;
@@ -90,6 +146,7 @@ impl<'a> Parser<&'a str, _835, nom::error::Error<&'a str>> for _835 {
                 // loop 2110
                 let mut loop_2110 = vec![];
                 while peek(opt(SVC::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_835Loop2110");
                     let (rest, svc) = SVC::parse(loop_rest)?;
                     let (rest, dtm) = many0(DTM::parse).parse(rest)?;
                     let (rest, cas) = many0(CAS::parse).parse(rest)?;
@@ -184,3 +241,277 @@ pub struct _835Loop2110 {
     pub qty: Vec<QTY>,
     pub lq: Vec<LQ>,
 }
+
+/// Borrowed [`_835`], the segments refer to the input instead of copying it
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct _835Ref<'a> {
+    pub st: STRef<'a>,
+    pub bpr: BPRRef<'a>,
+    pub nte: Vec<NTERef<'a>>,
+    pub trn: Option<TRNRef<'a>>,
+    pub cur: Option<CURRef<'a>>,
+    pub r#ref: Vec<REFRef<'a>>,
+    pub dtm: Vec<DTMRef<'a>>,
+    pub loop_1000: Vec<_835Loop1000Ref<'a>>,
+    pub loop_2000: Vec<_835Loop2000Ref<'a>>,
+    pub plb: Vec<PLBRef<'a>>,
+    pub se: SERef<'a>,
+}
+
+impl<'a> _835Ref<'a> {
+    /// Copies the borrowed segments into the owned transaction set
+    pub fn into_owned(self) -> _835 {
+        _835 {
+            st: self.st.into_owned(),
+            bpr: self.bpr.into_owned(),
+            nte: self.nte.into_iter().map(|x| x.into_owned()).collect(),
+            trn: self.trn.map(|x| x.into_owned()),
+            cur: self.cur.map(|x| x.into_owned()),
+            r#ref: self.r#ref.into_iter().map(|x| x.into_owned()).collect(),
+            dtm: self.dtm.into_iter().map(|x| x.into_owned()).collect(),
+            loop_1000: self.loop_1000.into_iter().map(|x| x.into_owned()).collect(),
+            loop_2000: self.loop_2000.into_iter().map(|x| x.into_owned()).collect(),
+            plb: self.plb.into_iter().map(|x| x.into_owned()).collect(),
+            se: self.se.into_owned(),
+        }
+    }
+}
+
+impl<'a> Parser<&'a str, _835Ref<'a>, nom::error::Error<&'a str>> for _835Ref<'a> {
+    fn parse(input: &'a str) -> IResult<&'a str, _835Ref<'a>> {
+        let _context = crate::error::transaction_set(
+            "_835",
+            &[
+                "AMT", "BPR", "CAS", "CLP", "CUR", "DTM", "LQ", "LX", "MIA", "MOA", "N1", "N2",
+                "N3", "N4", "NM1", "NTE", "PER", "PLB", "QTY", "RDM", "REF", "SE", "ST", "SVC",
+                "TRN", "TS2", "TS3",
+            ],
+        );
+        let mut output = _835Ref::default();
+        let (rest, obj) = STRef::parse(input)?;
+        output.st = obj;
+        let (rest, obj) = BPRRef::parse(rest)?;
+        output.bpr = obj;
+        let (rest, obj) = many0(NTERef::parse).parse(rest)?;
+        output.nte = obj;
+        let (rest, obj) = opt(TRNRef::parse).parse(rest)?;
+        output.trn = obj;
+        let (rest, obj) = opt(CURRef::parse).parse(rest)?;
+        output.cur = obj;
+        let (rest, obj) = many0(REFRef::parse).parse(rest)?;
+        output.r#ref = obj;
+        let (rest, obj) = many0(DTMRef::parse).parse(rest)?;
+        output.dtm = obj;
+        // loop 1000
+        let mut loop_1000 = vec![];
+        let mut loop_rest = rest;
+        while peek(opt(N1Ref::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_835Loop1000");
+            let (rest, n1) = N1Ref::parse(loop_rest)?;
+            let (rest, n2) = many0(N2Ref::parse).parse(rest)?;
+            let (rest, n3) = many0(N3Ref::parse).parse(rest)?;
+            let (rest, n4) = opt(N4Ref::parse).parse(rest)?;
+            let (rest, r#ref) = many0(REFRef::parse).parse(rest)?;
+            let (rest, per) = many0(PERRef::parse).parse(rest)?;
+            let (rest, rdm) = opt(RDMRef::parse).parse(rest)?;
+            let (rest, dtm) = opt(DTMRef::parse).parse(rest)?;
+            loop_rest = rest;
+            loop_1000.push(_835Loop1000Ref {
+                n1,
+                n2,
+                n3,
+                n4,
+                r#ref,
+                per,
+                rdm,
+                dtm,
+            });
+        }
+        output.loop_1000 = loop_1000;
+        // loop 2000
+        let mut loop_2000 = vec![];
+        while peek(opt(LXRef::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_835Loop2000");
+            let (rest, lx) = LXRef::parse(loop_rest)?;
+            let (rest, ts3) = opt(TS3Ref::parse).parse(rest)?;
+            let (rest, ts2) = opt(TS2Ref::parse).parse(rest)?;
+            loop_rest = rest;
+            // loop 2100
+            let mut loop_2100 = vec![];
+            while peek(opt(CLPRef::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_835Loop2100");
+                let (rest, clp) = CLPRef::parse(loop_rest)?;
+                let (rest, cas) = many0(CASRef::parse).parse(rest)?;
+                let (rest, nm1) = many0(NM1Ref::parse).parse(rest)?;
+                let (rest, mia) = opt(MIARef::parse).parse(rest)?;
+                let (rest, moa) = opt(MOARef::parse).parse(rest)?;
+                let (rest, r#ref) = many0(REFRef::parse).parse(rest)?;
+                let (rest, dtm) = many0(DTMRef::parse).parse(rest)?;
+                let (rest, per) = many0(PERRef::parse).parse(rest)?;
+                let (rest, amt) = many0(AMTRef::parse).parse(rest)?;
+                let (rest, qty) = many0(QTYRef::parse).parse(rest)?;
+                loop_rest = rest;
+                // loop 2110
+                let mut loop_2110 = vec![];
+                while peek(opt(SVCRef::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_835Loop2110");
+                    let (rest, svc) = SVCRef::parse(loop_rest)?;
+                    let (rest, dtm) = many0(DTMRef::parse).parse(rest)?;
+                    let (rest, cas) = many0(CASRef::parse).parse(rest)?;
+                    let (rest, r#ref) = many0(REFRef::parse).parse(rest)?;
+                    let (rest, amt) = many0(AMTRef::parse).parse(rest)?;
+                    let (rest, qty) = many0(QTYRef::parse).parse(rest)?;
+                    let (rest, lq) = many0(LQRef::parse).parse(rest)?;
+                    loop_rest = rest;
+                    loop_2110.push(_835Loop2110Ref {
+                        svc,
+                        dtm,
+                        cas,
+                        r#ref,
+                        amt,
+                        qty,
+                        lq,
+                    });
+                }
+                loop_2100.push(_835Loop2100Ref {
+                    clp,
+                    cas,
+                    nm1,
+                    mia,
+                    moa,
+                    r#ref,
+                    dtm,
+                    per,
+                    amt,
+                    qty,
+                    loop_2110,
+                });
+            }
+            loop_2000.push(_835Loop2000Ref {
+                lx,
+                ts3,
+                ts2,
+                loop_2100,
+            });
+        }
+        output.loop_2000 = loop_2000;
+        let rest = loop_rest;
+        let (rest, obj) = many0(PLBRef::parse).parse(rest)?;
+        output.plb = obj;
+        let (rest, obj) = SERef::parse(rest)?;
+        output.se = obj;
+        Ok((rest, output))
+    }
+}
+
+/// Borrowed [`_835Loop1000`]
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct _835Loop1000Ref<'a> {
+    pub n1: N1Ref<'a>,
+    pub n2: Vec<N2Ref<'a>>,
+    pub n3: Vec<N3Ref<'a>>,
+    pub n4: Option<N4Ref<'a>>,
+    pub r#ref: Vec<REFRef<'a>>,
+    pub per: Vec<PERRef<'a>>,
+    pub rdm: Option<RDMRef<'a>>,
+    pub dtm: Option<DTMRef<'a>>,
+}
+
+impl<'a> _835Loop1000Ref<'a> {
+    /// Copies the borrowed segments into the owned loop
+    pub fn into_owned(self) -> _835Loop1000 {
+        _835Loop1000 {
+            n1: self.n1.into_owned(),
+            n2: self.n2.into_iter().map(|x| x.into_owned()).collect(),
+            n3: self.n3.into_iter().map(|x| x.into_owned()).collect(),
+            n4: self.n4.map(|x| x.into_owned()),
+            r#ref: self.r#ref.into_iter().map(|x| x.into_owned()).collect(),
+            per: self.per.into_iter().map(|x| x.into_owned()).collect(),
+            rdm: self.rdm.map(|x| x.into_owned()),
+            dtm: self.dtm.map(|x| x.into_owned()),
+        }
+    }
+}
+
+/// Borrowed [`_835Loop2000`]
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct _835Loop2000Ref<'a> {
+    pub lx: LXRef<'a>,
+    pub ts3: Option<TS3Ref<'a>>,
+    pub ts2: Option<TS2Ref<'a>>,
+    pub loop_2100: Vec<_835Loop2100Ref<'a>>,
+}
+
+impl<'a> _835Loop2000Ref<'a> {
+    /// Copies the borrowed segments into the owned loop
+    pub fn into_owned(self) -> _835Loop2000 {
+        _835Loop2000 {
+            lx: self.lx.into_owned(),
+            ts3: self.ts3.map(|x| x.into_owned()),
+            ts2: self.ts2.map(|x| x.into_owned()),
+            loop_2100: self.loop_2100.into_iter().map(|x| x.into_owned()).collect(),
+        }
+    }
+}
+
+/// Borrowed [`_835Loop2100`]
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct _835Loop2100Ref<'a> {
+    pub clp: CLPRef<'a>,
+    pub cas: Vec<CASRef<'a>>,
+    pub nm1: Vec<NM1Ref<'a>>,
+    pub mia: Option<MIARef<'a>>,
+    pub moa: Option<MOARef<'a>>,
+    pub r#ref: Vec<REFRef<'a>>,
+    pub dtm: Vec<DTMRef<'a>>,
+    pub per: Vec<PERRef<'a>>,
+    pub amt: Vec<AMTRef<'a>>,
+    pub qty: Vec<QTYRef<'a>>,
+    pub loop_2110: Vec<_835Loop2110Ref<'a>>,
+}
+
+impl<'a> _835Loop2100Ref<'a> {
+    /// Copies the borrowed segments into the owned loop
+    pub fn into_owned(self) -> _835Loop2100 {
+        _835Loop2100 {
+            clp: self.clp.into_owned(),
+            cas: self.cas.into_iter().map(|x| x.into_owned()).collect(),
+            nm1: self.nm1.into_iter().map(|x| x.into_owned()).collect(),
+            mia: self.mia.map(|x| x.into_owned()),
+            moa: self.moa.map(|x| x.into_owned()),
+            r#ref: self.r#ref.into_iter().map(|x| x.into_owned()).collect(),
+            dtm: self.dtm.into_iter().map(|x| x.into_owned()).collect(),
+            per: self.per.into_iter().map(|x| x.into_owned()).collect(),
+            amt: self.amt.into_iter().map(|x| x.into_owned()).collect(),
+            qty: self.qty.into_iter().map(|x| x.into_owned()).collect(),
+            loop_2110: self.loop_2110.into_iter().map(|x| x.into_owned()).collect(),
+        }
+    }
+}
+
+/// Borrowed [`_835Loop2110`]
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct _835Loop2110Ref<'a> {
+    pub svc: SVCRef<'a>,
+    pub dtm: Vec<DTMRef<'a>>,
+    pub cas: Vec<CASRef<'a>>,
+    pub r#ref: Vec<REFRef<'a>>,
+    pub amt: Vec<AMTRef<'a>>,
+    pub qty: Vec<QTYRef<'a>>,
+    pub lq: Vec<LQRef<'a>>,
+}
+
+impl<'a> _835Loop2110Ref<'a> {
+    /// Copies the borrowed segments into the owned loop
+    pub fn into_owned(self) -> _835Loop2110 {
+        _835Loop2110 {
+            svc: self.svc.into_owned(),
+            dtm: self.dtm.into_iter().map(|x| x.into_owned()).collect(),
+            cas: self.cas.into_iter().map(|x| x.into_owned()).collect(),
+            r#ref: self.r#ref.into_iter().map(|x| x.into_owned()).collect(),
+            amt: self.amt.into_iter().map(|x| x.into_owned()).collect(),
+            qty: self.qty.into_iter().map(|x| x.into_owned()).collect(),
+            lq: self.lq.into_iter().map(|x| x.into_owned()).collect(),
+        }
+    }
+}
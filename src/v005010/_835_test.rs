@@ -1,4 +1,5 @@
 use super::*;
+use crate::util::Parser;
 
 #[test]
 fn parse_835_01() {
@@ -547,3 +548,232 @@ fn parse_835_40() {
     println!("{obj:?}");
     assert!(rest.is_empty());
 }
+
+#[test]
+fn parse_835_latin1_bytes() {
+    let str = r#"ISA*00*          *00*          *ZZ*SUBMITTERS ID  *ZZ*RECEIVERS ID   *200101*1253*^*00501*000000905*0*T*|~
+GS*HP*SENDER CODE*RECEIVER CODE*20200101*0802*1*X*005010X221A1~
+ST*835*10060875~
+BPR*I*150*C*CHK************20190816~
+TRN*1*CK NUMBER 1*1234567890~
+N1*PR*CAISSE MÉDICALE § 12~
+N1*PE*PROVIDER*XX*1123454567~
+LX*1~
+CLP*PATACCT*1*150*150**MC*CLAIMNUMBER*11*1~
+SVC*HC|99213*150*150**1~
+SE*9*10060875~
+GE*1*1~
+IEA*1*000000905~
+"#;
+    // Latin-1 encodes each character as a single byte
+    let latin1: Vec<u8> = str.chars().map(|c| c as u8).collect();
+    assert!(std::str::from_utf8(&latin1).is_err());
+    let obj: Transmission<_835> = crate::error::parse_bytes(&latin1).unwrap();
+    let payer = &obj.functional_group[0].segments[0].loop_1000[0].n1;
+    assert_eq!(payer._02.as_deref(), Some("CAISSE MÉDICALE § 12"));
+    let obj: Transmission<_835> = crate::error::parse_bytes(str.as_bytes()).unwrap();
+    let svc = &obj.functional_group[0].segments[0].loop_2000[0].loop_2100[0].loop_2110[0].svc;
+    assert_eq!(svc._01._02, "99213");
+
+    let mut invalid = latin1.clone();
+    let offset = latin1.windows(8).position(|w| w == b"PROVIDER").unwrap();
+    invalid[offset] = 0x07;
+    let err = crate::error::parse_bytes::<Transmission<_835>>(&invalid).unwrap_err();
+    assert_eq!(err.invalid_character, Some('\u{7}'));
+    assert_eq!(err.offset, offset);
+    assert_eq!(err.segment_index, 6);
+    assert_eq!(err.line, 7);
+    assert_eq!(err.found.as_deref(), Some("N1"));
+    assert_eq!(err.control_number.as_deref(), Some("10060875"));
+    assert_eq!(
+        err.to_string(),
+        format!("invalid character U+0007 in segment N1 at segment 6 (line 7, offset {offset}) in transaction set 10060875")
+    );
+}
+
+#[test]
+fn parse_835_borrowed_transaction() {
+    let str = r#"ISA*00*          *00*          *ZZ*SUBMITTERS ID  *ZZ*RECEIVERS ID   *200101*1253*^*00501*000000905*0*T*|~GS*HP*SENDER CODE*RECEIVER CODE*20200101*0802*1*X*005010X221A1~ST*835*35681~BPR*I*132*C*CHK************20190331~TRN*1*12345*1512345678~REF*EV*CLEARINGHOUSE~N1*PR*DELTA DENTAL OF ABC~N3*225 MAIN STREET~N4*CENTERVILLE*PA*17111~PER*BL*JANE DOE*TE*9005555555~N1*PE*BAN DDS LLC*FI*999994703~LX*1~CLP*7722337*1*226*132**12*119932404007801~NM1*QC*1*DOE*SALLY****MI*SJD11111~AMT*AU*132~SVC*AD|D0120*46*25~DTM*472*20190324~CAS*CO*131*21~AMT*B6*25~SE*19*35681~GE*1*1~IEA*1*000000905~"#;
+    let (_, obj) = crate::raw::RawInterchangeRef::parse(str).unwrap();
+    let transaction = obj.transactions().find(|t| t.id() == Some("835")).unwrap();
+    let svc = transaction.segments.iter().find(|s| s.id == "SVC").unwrap();
+    assert_eq!(svc.components(1).collect::<Vec<_>>(), ["AD", "D0120"]);
+    let typed: _835 = transaction.to_typed().unwrap();
+    let (_, expected) = Transmission::<_835>::parse(str).unwrap();
+    assert_eq!(typed, expected.functional_group[0].segments[0]);
+}
+
+#[test]
+fn validate_835_codes() {
+    use crate::envelope::Version;
+    use crate::validation::{validate_codes, CodeList, SegmentCodes};
+    let str = r#"ISA*00*          *00*          *ZZ*SUBMITTERS ID  *ZZ*RECEIVERS ID   *200101*1253*^*00501*000000905*0*T*|~GS*HP*SENDER CODE*RECEIVER CODE*20200101*0802*1*X*005010X221A1~ST*835*10060875~BPR*I*80.00*C*CHK************20190816~TRN*1*CK NUMBER 1*1234567890~REF*EV*FAC~DTM*405*20190827~N1*PR*ANY PLAN USA~N3*1 WALK THIS WAY~N4*ANYCITY*OH*45209~PER*CX**TE*8661112222~PER*BL*EDI*TE*8002223333*EM*EDI.SUPPORT@ANYPAYER.COM~PER*IC**UR*WWW.ANYPAYER.COM~N1*PE*PROVIDER*XX*1123454567~N3*2255 ANY ROAD~N4*ANY CITY*CA*12211~REF*TJ*123456789~LX*1~CLP*PATACCT*1*400*80**MC*CLAIMNUMBER*11*1~NM1*QC*1*DOE*JOHN*N***MI*ABC123456789~REF*1L*12345F~DTM*050*20190209~PER*CX*G CUSTOMER SERVICE DEPARTMENT*TE*8004074627~AMT*AU*150~SVC*HC|99213*150*80**1~DTM*472*20190101~CAS*CO*45*70~AMT*B6*80~SVC*HC|85003*100*0**1~DTM*472*20190101~CAS*CO*204*100~SVC*HC|36415*150*0**1~DTM*472*20190101~CAS*CO*97*150~SE*33*10060875~GE*1*1~IEA*1*000000905~"#;
+    let (_, obj) = Transmission::<_835>::parse(str).unwrap();
+    let set = &obj.functional_group[0].segments[0];
+    assert_eq!(validate_codes(set, V005010::code_lists).unwrap(), vec![]);
+
+    let claim = str.replacen("CLP*PATACCT*1*", "CLP*PATACCT*9*", 1);
+    let (_, obj) = Transmission::<_835>::parse(&claim).unwrap();
+    let set = &obj.functional_group[0].segments[0];
+    let errors = validate_codes(set, V005010::code_lists).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "CLP-02 '9' is not a code of element 1029"
+    );
+
+    let str = str.replacen("BPR*I*80.00*C*", "BPR*I*80.00*X*", 1);
+    let (_, obj) = Transmission::<_835>::parse(&str).unwrap();
+    let set = &obj.functional_group[0].segments[0];
+    let errors = validate_codes(set, V005010::code_lists).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].segment_position, 2);
+    assert_eq!(
+        errors[0].to_string(),
+        "BPR-03 'X' is not a code of element 478"
+    );
+
+    let errors = set.bpr.validate_codes().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        (errors[0].segment_position, errors[0].element_position),
+        (1, 3)
+    );
+    let list = CLP::code_list(6).unwrap();
+    assert_eq!(list.name, "Claim Filing Indicator Code");
+    assert_eq!(list.description("MC"), Some("Medicaid"));
+    assert_eq!(
+        CodeList::get("1029").unwrap().description("22"),
+        Some("Reversal of Previous Payment")
+    );
+    assert!(CLP::code_list(1).is_none());
+}
+
+#[test]
+fn report_invalid_characters() {
+    use crate::util::charset::is_x12_character;
+    use crate::util::ParseBytes;
+    let str = r#"ISA*00*          *00*          *ZZ*SUBMITTERS ID  *ZZ*RECEIVERS ID   *200101*1253*^*00501*000000905*0*T*|~
+GS*HP*SENDER CODE*RECEIVER CODE*20200101*0802*1*X*005010X221A1~
+ST*835*10060875~
+BPR*I*150*C*CHK************20190816~
+TRN*1*CK NUMBER 1*1234567890~
+N1*PR*CAISSE MÉDICALE § 12~
+N1*PE*PROVIDER*XX*1123454567~
+LX*1~
+CLP*PATACCT*1*150*150**MC*CLAIMNUMBER*11*1~
+SVC*HC|99213*150*150**1~
+SE*9*10060875~
+GE*1*1~
+IEA*1*000000905~
+"#;
+    assert!(is_x12_character('é') && is_x12_character('§'));
+    assert!(!is_x12_character('€') && !is_x12_character('\u{7}'));
+    // the grave accent lies between the accepted `_` and `a`
+    assert!(is_x12_character('_') && is_x12_character('a') && is_x12_character('~'));
+    assert!(!is_x12_character('`') && !is_x12_character('\u{7f}'));
+    let err = crate::error::parse::<Transmission<_835>>(&str.replace("PROVIDER", "PROV`IDER"))
+        .unwrap_err();
+    assert_eq!(err.invalid_character, Some('`'));
+
+    let (rest, obj) = Transmission::<_835>::parse_bytes(str.as_bytes()).unwrap();
+    assert!(rest.is_empty());
+    let latin1: Vec<u8> = str.chars().map(|c| c as u8).collect();
+    let (rest, latin1_obj) = Transmission::<_835>::parse_bytes(&latin1).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(latin1_obj.functional_group, obj.functional_group);
+
+    let control = str.replace("PROVIDER", "PRO\u{7}VIDER");
+    let err = crate::error::parse::<Transmission<_835>>(&control).unwrap_err();
+    assert_eq!(err.invalid_character, Some('\u{7}'));
+    assert_eq!(err.offset, control.find('\u{7}').unwrap());
+    assert_eq!(err.found.as_deref(), Some("N1"));
+    // the segment parsers fail at control characters
+    assert!(Transmission::<_835>::parse(&control).is_err());
+    assert!(Transmission::<_835>::parse_bytes(control.as_bytes()).is_err());
+
+    let euro = str.replace("§ 12", "€ 12");
+    let err = crate::error::parse::<Transmission<_835>>(&euro).unwrap_err();
+    assert_eq!(err.invalid_character, Some('€'));
+    assert_eq!(err.segment_index, 5);
+    let err = crate::error::parse_bytes::<Transmission<_835>>(euro.as_bytes()).unwrap_err();
+    assert_eq!(err.invalid_character, Some('€'));
+    assert_eq!(err.offset, euro.find('€').unwrap());
+}
+
+#[test]
+fn validate_835_structure() {
+    use crate::envelope::Version;
+    use crate::validation::{validate_structure, TransactionStructure};
+    let str = r#"ISA*00*          *00*          *ZZ*SUBMITTERS ID  *ZZ*RECEIVERS ID   *200101*1253*^*00501*000000905*0*T*|~
+GS*HP*SENDER CODE*RECEIVER CODE*20200101*0802*1*X*005010X221A1~
+ST*835*10060875~
+BPR*I*150*C*CHK************20190816~
+TRN*1*CK NUMBER 1*1234567890~
+N1*PR*PAYER~
+N1*PE*PROVIDER*XX*1123454567~
+LX*1~
+CLP*PATACCT*1*150*150**MC*CLAIMNUMBER*11*1~
+NM1*QC*1*PATIENT*JANE****MI*123456~
+SVC*HC|99213*150*150**1~
+SE*10*10060875~
+GE*1*1~
+IEA*1*000000905~
+"#;
+    let (_, obj) = Transmission::<_835>::parse(str).unwrap();
+    let set = &obj.functional_group[0].segments[0];
+    assert_eq!(set.validate_structure().unwrap(), vec![]);
+
+    let str = str.replace("NM1*QC*1*PATIENT*JANE****MI*123456~\n", "");
+    let (_, obj) = Transmission::<_835>::parse(&str).unwrap();
+    let set = &obj.functional_group[0].segments[0];
+    let errors = validate_structure(set, V005010::transaction_structure("835")).unwrap();
+    let errors: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        errors,
+        vec!["NM1 at 8 in loop 2100: Mandatory segment missing"]
+    );
+}
+
+#[test]
+fn parse_835_borrowed() {
+    use crate::raw::RawInterchangeRef;
+    let str = r#"ISA*00*          *00*          *ZZ*SUBMITTERS ID  *ZZ*RECEIVERS ID   *200101*1253*^*00501*000000905*0*T*|~
+GS*HP*SENDER CODE*RECEIVER CODE*20200101*0802*1*X*005010X221A1~
+ST*835*10060875~
+BPR*I*150*C*CHK************20190816~
+TRN*1*CK NUMBER 1*1234567890~
+N1*PR*PAYER~
+N1*PE*PROVIDER*XX*1123454567~
+LX*1~
+CLP*PATACCT*1*150*150**MC*CLAIMNUMBER*11*1~
+NM1*QC*1*PATIENT*JANE****MI*123456~
+SVC*HC|99213|25*150*150**1*HC|99212~
+DTM*472*20190801~
+CAS*CO*45*0~
+LQ*HE*N206~
+PLB*1123454567*20191231*WO|FCN*3.99~
+SE*14*10060875~
+GE*1*1~
+IEA*1*000000905~
+"#;
+    let (_, interchange) = RawInterchangeRef::parse(str).unwrap();
+    let transaction = interchange.transactions().next().unwrap();
+    let borrowed: _835Ref = transaction.to_typed().unwrap();
+    let owned: _835 = transaction.to_typed().unwrap();
+
+    // string elements refer to the input
+    let input = str.as_bytes().as_ptr_range();
+    assert_eq!(borrowed.bpr._02, "150");
+    assert!(input.contains(&borrowed.bpr._02.as_ptr()));
+    let clp = &borrowed.loop_2000[0].loop_2100[0].clp;
+    assert!(input.contains(&clp._07.unwrap().as_ptr()));
+    // omitted optional elements are `None`, present empty ones `Some("")`
+    assert_eq!(clp._05, Some(""));
+    assert_eq!(clp._10, None);
+    let svc = &borrowed.loop_2000[0].loop_2100[0].loop_2110[0].svc;
+    assert_eq!(svc._01._02, "99213");
+    assert_eq!(svc._06.as_ref().unwrap()._02, "99212");
+
+    assert_eq!(borrowed.into_owned(), owned);
+}
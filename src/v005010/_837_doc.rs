@@ -9,6 +9,137 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 837 - Health Care Claim
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0050 | ST | Transaction Set Header | M | 1
+/// 0100 | BHT | Beginning of Hierarchical Transaction | M | 1
+/// 0150 | REF | Reference Information | O | 3
+/// LOOP ID - 1000 | 10
+/// 1000 -> 0200 | NM1 | Individual or Organizational Name | O | 1
+/// 1000 -> 0250 | N2 | Additional Name Information | O | 2
+/// 1000 -> 0300 | N3 | Party Location | O | 2
+/// 1000 -> 0350 | N4 | Geographic Location | O | 1
+/// 1000 -> 0400 | REF | Reference Information | O | 2
+/// 1000 -> 0450 | PER | Administrative Communications Contact | O | 2
+/// LOOP ID - 2000 | >1
+/// 2000 -> 0010 | HL | Hierarchical Level | M | 1
+/// 2000 -> 0030 | PRV | Provider Information | O | 1
+/// 2000 -> 0050 | SBR | Subscriber Information | O | 1
+/// 2000 -> 0070 | PAT | Patient Information | O | 1
+/// 2000 -> 0090 | DTP | Date or Time or Period | O | 5
+/// 2000 -> 0100 | CUR | Currency | O | 1
+/// 2000 -> LOOP ID - 2010 | 10
+/// 2000 -> 2010 -> 0150 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2010 -> 0200 | N2 | Additional Name Information | O | 2
+/// 2000 -> 2010 -> 0250 | N3 | Party Location | O | 2
+/// 2000 -> 2010 -> 0300 | N4 | Geographic Location | O | 1
+/// 2000 -> 2010 -> 0320 | DMG | Demographic Information | O | 1
+/// 2000 -> 2010 -> 0350 | REF | Reference Information | O | 20
+/// 2000 -> 2010 -> 0400 | PER | Administrative Communications Contact | O | 2
+/// 2000 -> LOOP ID - 2300 | 100
+/// 2000 -> 2300 -> 1300 | CLM | Health Claim | O | 1
+/// 2000 -> 2300 -> 1350 | DTP | Date or Time or Period | O | 150
+/// 2000 -> 2300 -> 1400 | CL1 | Claim Codes | O | 1
+/// 2000 -> 2300 -> 1450 | DN1 | Orthodontic Information | O | 1
+/// 2000 -> 2300 -> 1500 | DN2 | Tooth Summary | O | 35
+/// 2000 -> 2300 -> 1550 | PWK | Disability Information | O | 10
+/// 2000 -> 2300 -> 1600 | CN1 | Contract Information | O | 1
+/// 2000 -> 2300 -> 1650 | DSB | Disability Information | O | 1
+/// 2000 -> 2300 -> 1700 | UR | Peer Review Organization or Utilization Review | O | 1
+/// 2000 -> 2300 -> 1750 | AMT | Monetary Amount Information | O | 40
+/// 2000 -> 2300 -> 1800 | REF | Reference Information | O | 30
+/// 2000 -> 2300 -> 1850 | K3 | File Information | O | 10
+/// 2000 -> 2300 -> 1900 | NTE | Note/Special Instruction | O | 20
+/// 2000 -> 2300 -> 1950 | CR1 | Ambulance Certification | O | 1
+/// 2000 -> 2300 -> 2000 | CR2 | Chiropractic Certification | O | 1
+/// 2000 -> 2300 -> 2050 | CR3 | Durable Medical Equipment Certification | O | 1
+/// 2000 -> 2300 -> 2100 | CR4 | Enteral or Parenteral Therapy Certification | O | 3
+/// 2000 -> 2300 -> 2150 | CR5 | Oxygen Therapy Certification | O | 1
+/// 2000 -> 2300 -> 2160 | CR6 | Home Health Care Certification | O | 1
+/// 2000 -> 2300 -> 2190 | CR8 | Pacemaker Certification | O | 9
+/// 2000 -> 2300 -> 2200 | CRC | Conditions Indicator | O | 100
+/// 2000 -> 2300 -> 2310 | HI | Health Care Information Codes | O | 25
+/// 2000 -> 2300 -> 2410 | QTY | Quantity Information | O | 10
+/// 2000 -> 2300 -> 2415 | HCP | Health Care Pricing | O | 1
+/// 2000 -> 2300 -> LOOP ID - 2305 | 6
+/// 2000 -> 2300 -> 2305 -> 2420 | CR7 | Home Health Treatment Plan Certification | O | 1
+/// 2000 -> 2300 -> 2305 -> 2430 | HSD | Health Care Services Delivery | O | 12
+/// 2000 -> 2300 -> LOOP ID - 2310 | 9
+/// 2000 -> 2300 -> 2310 -> 2500 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2300 -> 2310 -> 2550 | PRV | Provider Information | O | 1
+/// 2000 -> 2300 -> 2310 -> 2600 | N2 | Additional Name Information | O | 2
+/// 2000 -> 2300 -> 2310 -> 2650 | N3 | Party Location | O | 2
+/// 2000 -> 2300 -> 2310 -> 2700 | N4 | Geographic Location | O | 1
+/// 2000 -> 2300 -> 2310 -> 2710 | REF | Reference Information | O | 20
+/// 2000 -> 2300 -> 2310 -> 2750 | PER | Administrative Communications Contact | O | 2
+/// 2000 -> 2300 -> LOOP ID - 2320 | 10
+/// 2000 -> 2300 -> 2320 -> 2900 | SBR | Subscriber Information | O | 1
+/// 2000 -> 2300 -> 2320 -> 2950 | CAS | Claims Adjustment | O | 99
+/// 2000 -> 2300 -> 2320 -> 3000 | AMT | Monetary Amount Information | O | 15
+/// 2000 -> 2300 -> 2320 -> 3050 | DMG | Demographic Information | O | 1
+/// 2000 -> 2300 -> 2320 -> 3100 | OI | Other Health Insurance Information | O | 1
+/// 2000 -> 2300 -> 2320 -> 3150 | MIA | Medicare Inpatient Adjudication | O | 1
+/// 2000 -> 2300 -> 2320 -> 3200 | MOA | Medicare Outpatient Adjudication | O | 1
+/// 2000 -> 2300 -> 2320 -> LOOP ID - 2330 | 10
+/// 2000 -> 2300 -> 2320 -> 2330 -> 3250 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2300 -> 2320 -> 2330 -> 3300 | N2 | Additional Name Information | O | 2
+/// 2000 -> 2300 -> 2320 -> 2330 -> 3320 | N3 | Party Location | O | 2
+/// 2000 -> 2300 -> 2320 -> 2330 -> 3400 | N4 | Geographic Location | O | 1
+/// 2000 -> 2300 -> 2320 -> 2330 -> 3450 | PER | Administrative Communications Contact | O | 2
+/// 2000 -> 2300 -> 2320 -> 2330 -> 3500 | DTP | Date or Time or Period | O | 9
+/// 2000 -> 2300 -> 2320 -> 2330 -> 3550 | REF | Reference Information | O | >1
+/// 2000 -> 2300 -> LOOP ID - 2400 | >1
+/// 2000 -> 2300 -> 2400 -> 3650 | LX | Transaction Set Line Number | O | 1
+/// 2000 -> 2300 -> 2400 -> 3700 | SV1 | Professional Service | O | 1
+/// 2000 -> 2300 -> 2400 -> 3750 | SV2 | Institutional Service | O | 1
+/// 2000 -> 2300 -> 2400 -> 3800 | SV3 | Dental Service | O | 1
+/// 2000 -> 2300 -> 2400 -> 3820 | TOO | Tooth Identification | O | 32
+/// 2000 -> 2300 -> 2400 -> 3850 | SV4 | Drug Service | O | 1
+/// 2000 -> 2300 -> 2400 -> 4000 | SV5 | Durable Medical Equipment Service | O | 1
+/// 2000 -> 2300 -> 2400 -> 4050 | SV6 | Anesthesia Service | O | 1
+/// 2000 -> 2300 -> 2400 -> 4100 | SV7 | Drug Adjudication | O | 1
+/// 2000 -> 2300 -> 2400 -> 4150 | HI | Health Care Information Codes | O | 25
+/// 2000 -> 2300 -> 2400 -> 4200 | PWK | Disability Information | O | 10
+/// 2000 -> 2300 -> 2400 -> 4250 | CR1 | Ambulance Certification | O | 1
+/// 2000 -> 2300 -> 2400 -> 4300 | CR2 | Chiropractic Certification | O | 5
+/// 2000 -> 2300 -> 2400 -> 4350 | CR3 | Durable Medical Equipment Certification | O | 1
+/// 2000 -> 2300 -> 2400 -> 4400 | CR4 | Enteral or Parenteral Therapy Certification | O | 3
+/// 2000 -> 2300 -> 2400 -> 4450 | CR5 | Oxygen Therapy Certification | O | 1
+/// 2000 -> 2300 -> 2400 -> 4500 | CRC | Conditions Indicator | O | 3
+/// 2000 -> 2300 -> 2400 -> 4550 | DTP | Date or Time or Period | O | 15
+/// 2000 -> 2300 -> 2400 -> 4600 | QTY | Quantity Information | O | 5
+/// 2000 -> 2300 -> 2400 -> 4620 | MEA | Measurements | O | 20
+/// 2000 -> 2300 -> 2400 -> 4650 | CN1 | Contract Information | O | 1
+/// 2000 -> 2300 -> 2400 -> 4700 | REF | Reference Information | O | 30
+/// 2000 -> 2300 -> 2400 -> 4750 | AMT | Monetary Amount Information | O | 15
+/// 2000 -> 2300 -> 2400 -> 4800 | K3 | File Information | O | 10
+/// 2000 -> 2300 -> 2400 -> 4850 | NTE | Note/Special Instruction | O | 10
+/// 2000 -> 2300 -> 2400 -> 4880 | PS1 | Purchase Service | O | 1
+/// 2000 -> 2300 -> 2400 -> 4900 | IMM | Immunization Status | O | >1
+/// 2000 -> 2300 -> 2400 -> 4910 | HSD | Health Care Services Delivery | O | 1
+/// 2000 -> 2300 -> 2400 -> 4920 | HCP | Health Care Pricing | O | 1
+/// 2000 -> 2300 -> 2400 -> LOOP ID - 2410 | >1
+/// 2000 -> 2300 -> 2400 -> 2410 -> 4930 | LIN | Item Identification | O | 1
+/// 2000 -> 2300 -> 2400 -> 2410 -> 4940 | CTP | Pricing Information | O | 1
+/// 2000 -> 2300 -> 2400 -> 2410 -> 4950 | REF | Reference Information | O | 1
+/// 2000 -> 2300 -> 2400 -> LOOP ID - 2420 | 10
+/// 2000 -> 2300 -> 2400 -> 2420 -> 5000 | NM1 | Individual or Organizational Name | O | 1
+/// 2000 -> 2300 -> 2400 -> 2420 -> 5050 | PRV | Provider Information | O | 1
+/// 2000 -> 2300 -> 2400 -> 2420 -> 5100 | N2 | Additional Name Information | O | 2
+/// 2000 -> 2300 -> 2400 -> 2420 -> 5140 | N3 | Party Location | O | 2
+/// 2000 -> 2300 -> 2400 -> 2420 -> 5200 | N4 | Geographic Location | O | 1
+/// 2000 -> 2300 -> 2400 -> 2420 -> 5250 | REF | Reference Information | O | 20
+/// 2000 -> 2300 -> 2400 -> 2420 -> 5300 | PER | Administrative Communications Contact | O | 2
+/// 2000 -> 2300 -> 2400 -> LOOP ID - 2430 | >1
+/// 2000 -> 2300 -> 2400 -> 2430 -> 5400 | SVD | Service Line Adjudication | O | 1
+/// 2000 -> 2300 -> 2400 -> 2430 -> 5450 | CAS | Claims Adjustment | O | 99
+/// 2000 -> 2300 -> 2400 -> 2430 -> 5500 | DTP | Date or Time or Period | O | 9
+/// 2000 -> 2300 -> 2400 -> 2430 -> 5505 | AMT | Monetary Amount Information | O | 20
+/// 2000 -> 2300 -> 2400 -> LOOP ID - 2440 | >1
+/// 2000 -> 2300 -> 2400 -> 2440 -> 5510 | LQ | Industry Code Identification | O | 1
+/// 2000 -> 2300 -> 2400 -> 2440 -> 5520 | FRM | Supporting Documentation | M | 99
+/// 5550 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _837 {
     pub st: ST,
@@ -21,6 +152,16 @@ pub struct _837 {
 
 impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
     fn parse(input: &'a str) -> IResult<&'a str, _837> {
+        let _context = crate::error::transaction_set(
+            "_837",
+            &[
+                "AMT", "BHT", "CAS", "CL1", "CLM", "CN1", "CR1", "CR2", "CR3", "CR4", "CR5", "CR6",
+                "CR7", "CR8", "CRC", "CTP", "CUR", "DMG", "DN1", "DN2", "DSB", "DTP", "FRM", "HCP",
+                "HI", "HL", "HSD", "IMM", "K3", "LIN", "LQ", "LX", "MEA", "MIA", "MOA", "N2", "N3",
+                "N4", "NM1", "NTE", "OI", "PAT", "PER", "PRV", "PS1", "PWK", "QTY", "REF", "SBR",
+                "SE", "ST", "SV1", "SV2", "SV3", "SV4", "SV5", "SV6", "SV7", "SVD", "TOO", "UR",
+            ],
+        );
         let mut output = _837::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -32,6 +173,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
         let mut loop_1000 = vec![];
         let mut loop_rest = rest;
         while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_837Loop1000");
             let (rest, nm1) = NM1::parse(loop_rest)?;
             let (rest, n2) = many0(N2::parse).parse(rest)?;
             let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -54,6 +196,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
         let mut loop_2000 = vec![];
         let mut loop_rest = rest;
         while peek(opt(HL::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_837Loop2000");
             let (rest, hl) = HL::parse(loop_rest)?;
             let (rest, prv) = opt(PRV::parse).parse(rest)?;
             let (rest, sbr) = opt(SBR::parse).parse(rest)?;
@@ -64,6 +207,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
             // loop 2010
             let mut loop_2010 = vec![];
             while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_837Loop2010");
                 let (rest, nm1) = NM1::parse(loop_rest)?;
                 let (rest, n2) = opt(N2::parse).parse(rest)?;
                 let (rest, n3) = opt(N3::parse).parse(rest)?;
@@ -85,6 +229,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
             // loop 2300
             let mut loop_2300 = vec![];
             while peek(opt(CLM::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_837Loop2300");
                 let (rest, clm) = CLM::parse(loop_rest)?;
                 let (rest, dtp) = many0(DTP::parse).parse(rest)?;
                 let (rest, cl1) = opt(CL1::parse).parse(rest)?;
@@ -113,6 +258,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
                 // loop 2305
                 let mut loop_2305 = vec![];
                 while peek(opt(CR7::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_837Loop2305");
                     let (rest, cr7) = CR7::parse(loop_rest)?;
                     let (rest, hsd) = many0(HSD::parse).parse(rest)?;
                     loop_rest = rest;
@@ -121,6 +267,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
                 // loop 2310
                 let mut loop_2310 = vec![];
                 while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_837Loop2310");
                     let (rest, nm1) = NM1::parse(loop_rest)?;
                     let (rest, prv) = opt(PRV::parse).parse(rest)?;
                     let (rest, n2) = opt(N2::parse).parse(rest)?;
@@ -142,6 +289,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
                 // loop 2320
                 let mut loop_2320 = vec![];
                 while peek(opt(SBR::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_837Loop2320");
                     let (rest, sbr) = SBR::parse(loop_rest)?;
                     let (rest, cas) = opt(CAS::parse).parse(rest)?;
                     let (rest, amt) = many0(AMT::parse).parse(rest)?;
@@ -153,6 +301,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
                     // loop 2330
                     let mut loop_2330 = vec![];
                     while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
+                        let _context = crate::error::context("_837Loop2330");
                         let (rest, nm1) = NM1::parse(loop_rest)?;
                         let (rest, n2) = opt(N2::parse).parse(rest)?;
                         let (rest, n3) = opt(N3::parse).parse(rest)?;
@@ -185,6 +334,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
                 // loop 2400
                 let mut loop_2400 = vec![];
                 while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_837Loop2400");
                     let (rest, lx) = LX::parse(loop_rest)?;
                     let (rest, sv1) = opt(SV1::parse).parse(rest)?;
                     let (rest, sv2) = opt(SV2::parse).parse(rest)?;
@@ -218,6 +368,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
                     // loop 2410
                     let mut loop_2410 = vec![];
                     while peek(opt(LIN::parse)).parse(loop_rest)?.1.is_some() {
+                        let _context = crate::error::context("_837Loop2410");
                         let (rest, lin) = LIN::parse(loop_rest)?;
                         let (rest, ctp) = opt(CTP::parse).parse(rest)?;
                         let (rest, r#ref) = opt(REF::parse).parse(rest)?;
@@ -227,6 +378,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
                     // loop 2420
                     let mut loop_2420 = vec![];
                     while peek(opt(NM1::parse)).parse(loop_rest)?.1.is_some() {
+                        let _context = crate::error::context("_837Loop2420");
                         let (rest, nm1) = NM1::parse(loop_rest)?;
                         let (rest, prv) = opt(PRV::parse).parse(rest)?;
                         let (rest, n2) = opt(N2::parse).parse(rest)?;
@@ -248,6 +400,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
                     // loop 2430
                     let mut loop_2430 = vec![];
                     while peek(opt(SVD::parse)).parse(loop_rest)?.1.is_some() {
+                        let _context = crate::error::context("_837Loop2430");
                         let (rest, svd) = SVD::parse(loop_rest)?;
                         let (rest, cas) = many0(CAS::parse).parse(rest)?;
                         let (rest, dtp) = opt(DTP::parse).parse(rest)?;
@@ -258,6 +411,7 @@ impl<'a> Parser<&'a str, _837, nom::error::Error<&'a str>> for _837 {
                     // loop 2440
                     let mut loop_2440 = vec![];
                     while peek(opt(LQ::parse)).parse(loop_rest)?.1.is_some() {
+                        let _context = crate::error::context("_837Loop2440");
                         let (rest, lq) = LQ::parse(loop_rest)?;
                         let (rest, frm) = many0(FRM::parse).parse(rest)?;
                         loop_rest = rest;
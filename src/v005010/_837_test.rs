@@ -1,4 +1,5 @@
 use super::*;
+use crate::util::Parser;
 
 #[test]
 fn parse_837() {
@@ -632,3 +633,99 @@ fn parse_837_27() {
     println!("{rest}");
     println!("{obj:?}");
 }
+
+#[test]
+fn parse_837_error_context() {
+    let str = r#"ISA*00* *00* *ZZ*133052274 *ZZ*TXCSM0001 *160308*2119*^*00501*000005555*0*P*:~
+GS*HC*133052274*TXCSM0001*20160308*211916*5555*X*005010X222A1~
+ST*837*000000055*005010X222A1~
+BHT*0001*00*00011111*20160308*211916*CH~
+NM1*41*2*SUBMITTER ABC*****46*111111111~
+HL*1**20*1~
+NM1*85*2*BILLING NAME ABC*****XX*1111111111~
+HL*2*1*22*0~
+SBR*P*18*******MC~
+NM1*IL*1*LASTNAME*FIRST****MI*111111111~
+CLM*1111111111*210.01***11:B:1*Y*A*Y*Y~
+HI*ABK:Z00129~
+XYZ*1~
+LX*1~
+SV1*HC:99392:AM:25*150*UN*1***1~
+DTP*472*D8*20160307~
+SE*15*000000055~
+GE*1*5555~
+IEA*1*000005555~"#;
+    let err = crate::error::parse::<Transmission<_837>>(str).unwrap_err();
+    assert_eq!(err.found.as_deref(), Some("XYZ"));
+    assert_eq!(err.segment_index, 12);
+    assert_eq!(err.line, 13);
+    assert_eq!(err.offset, str.find("XYZ").unwrap());
+    assert_eq!(err.control_number.as_deref(), Some("000000055"));
+    assert_eq!(err.loop_name.as_deref(), Some("_837Loop2300"));
+    assert!(err.expected.contains(&"LX".to_string()));
+    assert!(err.expected.contains(&"SE".to_string()));
+    assert!(err.to_string().starts_with(&format!(
+        "unexpected segment XYZ at segment 12 (line 13, offset {}) in transaction set 000000055 while parsing _837Loop2300, expected one of ",
+        err.offset
+    )));
+}
+
+#[test]
+fn parse_837_composite_elements() {
+    let str = r#"ISA*00*          *00*          *ZZ*133052274      *ZZ*752280001      *160527*2139*^*00501*000004444*0*P*|~
+GS*HC*133052274*752280001*20160527*213905*4444*X*005010X222A1~
+ST*837*0044*005010X222A1~
+BHT*0019*00*00018091A*20160527*213905*CH~
+NM1*41*2*SUBMITTER ABC*****46*111111111~
+NM1*40*2*RECEIVER ABC*****46*TXCSM0001~
+HL*1**20*1~
+NM1*85*2*BILLING NAME ABC*****XX*1111111111~
+HL*2*1*22*0~
+SBR*P*18*******MC~
+NM1*IL*1*LASTNAME*FIRST****MI*111111111~
+CLM*1111111111*292***11||1*Y*A*Y*Y~
+HI*ABK|Z00129*ABF|J309~
+LX*1~
+SV1*HC|87880|QW*22*UN*1***1~
+SE*14*0044~
+GE*1*4444~
+IEA*1*000004444~
+"#;
+    let (rest, obj) = Transmission::<_837>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let claim = &obj.functional_group[0].segments[0].loop_2000[1].loop_2300[0];
+    let clm05 = claim.clm._05.as_ref().unwrap();
+    assert_eq!(clm05._01, "11");
+    assert_eq!(clm05._02, None);
+    assert_eq!(clm05._03.as_deref(), Some("1"));
+    assert_eq!(claim.hi[0]._01._01, "ABK");
+    assert_eq!(claim.hi[0]._01._02, "Z00129");
+    assert_eq!(claim.hi[0]._02.as_ref().unwrap()._02, "J309");
+    let sv101 = &claim.loop_2400[0].sv1.as_ref().unwrap()._01;
+    assert_eq!(sv101._02, "87880");
+    assert_eq!(sv101._03.as_deref(), Some("QW"));
+    assert_eq!(format!("{obj}"), str);
+}
+
+#[test]
+fn validate_837_syntax_notes() {
+    use crate::envelope::Version;
+    use crate::validation::{validate_syntax, ElementSyntaxError};
+    let str = include_str!("../../test-data/005010_837.edi");
+    let (_, obj) = Transmission::<_837>::parse(str).unwrap();
+    let set = &obj.functional_group[0].segments[0];
+    assert_eq!(validate_syntax(set, V005010::syntax_notes).unwrap(), vec![]);
+
+    // NM108 without NM109 violates P0809
+    let str = str.replacen("*****46*TGJ23~", "*****46~", 1);
+    let (_, obj) = Transmission::<_837>::parse(&str).unwrap();
+    let errors =
+        validate_syntax(&obj.functional_group[0].segments[0], V005010::syntax_notes).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].segment, "NM1");
+    assert_eq!(errors[0].segment_position, 3);
+    assert_eq!(errors[0].element_position, 9);
+    assert_eq!(errors[0].note, "P0809");
+    assert_eq!(errors[0].error, ElementSyntaxError::ConditionalMissing);
+    assert_eq!(errors[0].error.code(), "2");
+}
@@ -9,6 +9,163 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 850 - Purchase Order
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | BEG | Beginning Segment for Purchase Order | M | 1
+/// 0300 | CUR | Currency | O | 1
+/// 0400 | REF | Reference Information | O | >1
+/// 0500 | PER | Administrative Communications Contact | O | 3
+/// 0600 | TAX | Tax Reference | O | >1
+/// 0700 | FOB | F.O.B. Related Instructions | O | >1
+/// 0800 | CTP | Pricing Information | O | >1
+/// 0900 | PAM | Period Amount | O | 10
+/// 1000 | CSH | Sales Requirements | O | 5
+/// 1100 | TC2 | Commodity | O | >1
+/// LOOP ID - SAC | 25
+/// SAC -> 1200 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// SAC -> 1300 | CUR | Currency | O | 1
+/// 1400 | ITD | Terms of Sale/Deferred Terms of Sale | O | >1
+/// 1500 | DIS | Discount Detail | O | 20
+/// 1600 | INC | Installment Information | O | 1
+/// 1700 | DTM | Date/Time Reference | O | 10
+/// 1800 | LIN | Item Identification | O | 5
+/// 1900 | SI | Service Characteristic Identification | O | >1
+/// 2000 | PID | Product/Item Description | O | 200
+/// 2100 | MEA | Measurements | O | 40
+/// 2200 | PWK | Disability Information | O | 25
+/// 2300 | PKG | Marking, Packaging, Loading | O | 200
+/// 2400 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// 2500 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | >1
+/// 2600 | TD3 | Carrier Details (Equipment) | O | 12
+/// 2700 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// 2800 | MAN | Marks and Numbers Information | O | 10
+/// 2900 | PCT | Percent Amounts | O | >1
+/// 3000 | CTB | Restrictions/Conditions | O | 5
+/// 3100 | TXI | Tax Information | O | >1
+/// LOOP ID - LDT | 12
+/// LDT -> 3200 | LDT | Lead Time | O | 1
+/// LDT -> 3300 | QTY | Quantity Information | O | >1
+/// LDT -> 3400 | MTX | Text | O | >1
+/// LDT -> 3500 | REF | Reference Information | O | 3
+/// LOOP ID - AMT | >1
+/// AMT -> 3600 | AMT | Monetary Amount Information | O | 1
+/// AMT -> 3700 | REF | Reference Information | O | >1
+/// AMT -> 3800 | DTM | Date/Time Reference | O | 1
+/// AMT -> 3900 | PCT | Percent Amounts | O | >1
+/// AMT -> LOOP ID - FA1 | >1
+/// AMT -> FA1 -> 4000 | FA1 | Type of Financial Accounting Data | O | 1
+/// AMT -> FA1 -> 4100 | FA2 | Accounting Data | M | >1
+/// LOOP ID - N9 | 1000
+/// N9 -> 4200 | N9 | Extended Reference Information | O | 1
+/// N9 -> 4300 | DTM | Date/Time Reference | O | 10
+/// N9 -> 4400 | MTX | Text | O | >1
+/// N9 -> 4500 | PWK | Disability Information | O | >1
+/// N9 -> 4600 | EFI | Electronic Format Identification | O | >1
+/// LOOP ID - N1 | 200
+/// N1 -> 4700 | N1 | Party Identifier | O | 1
+/// N1 -> 4800 | N2 | Additional Name Information | O | 2
+/// N1 -> 4900 | N3 | Party Location | O | 2
+/// N1 -> 5000 | N4 | Geographic Location | O | 1
+/// N1 -> 5100 | REF | Reference Information | O | 12
+/// N1 -> 5200 | PER | Administrative Communications Contact | O | >1
+/// N1 -> 5300 | SI | Service Characteristic Identification | O | >1
+/// N1 -> 5400 | FOB | F.O.B. Related Instructions | O | 1
+/// N1 -> 5500 | TD1 | Carrier Details (Quantity and Weight) | O | 1
+/// N1 -> 5600 | TD3 | Carrier Details (Equipment) | O | 1
+/// N1 -> 5700 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 1
+/// N1 -> 5800 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | >1
+/// N1 -> 5900 | PKG | Marking, Packaging, Loading | O | 200
+/// N1 -> 6000 | LDT | Lead Time | O | 1
+/// LOOP ID - PO1 | 100000
+/// PO1 -> 0100 | PO1 | Baseline Item Data | M | 1
+/// PO1 -> 0200 | LIN | Item Identification | O | >1
+/// PO1 -> 0300 | SI | Service Characteristic Identification | O | >1
+/// PO1 -> 0400 | CUR | Currency | O | 1
+/// PO1 -> 0500 | PO3 | Additional Item Detail | O | 25
+/// PO1 -> 0600 | CTP | Pricing Information | O | >1
+/// PO1 -> 0700 | PAM | Period Amount | O | 10
+/// PO1 -> 0800 | MEA | Measurements | O | 40
+/// PO1 -> LOOP ID - PID | 1000
+/// PO1 -> PID -> 0900 | PID | Product/Item Description | O | 1
+/// PO1 -> PID -> 1000 | MEA | Measurements | O | 10
+/// PO1 -> 1100 | PWK | Disability Information | O | 25
+/// PO1 -> 1200 | PO4 | Item Physical Details | O | 1
+/// PO1 -> 1300 | REF | Reference Information | O | >1
+/// PO1 -> 1400 | PER | Administrative Communications Contact | O | 3
+/// PO1 -> LOOP ID - SAC | 25
+/// PO1 -> SAC -> 1500 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// PO1 -> SAC -> 1600 | CUR | Currency | O | 1
+/// PO1 -> 1700 | ITD | Terms of Sale/Deferred Terms of Sale | O | >1
+/// PO1 -> 1800 | DIS | Discount Detail | O | >1
+/// PO1 -> 1900 | INC | Installment Information | O | 1
+/// PO1 -> 2000 | DTM | Date/Time Reference | O | 10
+/// PO1 -> 2100 | TAX | Tax Reference | O | >1
+/// PO1 -> 2200 | FOB | F.O.B. Related Instructions | O | >1
+/// PO1 -> 2300 | SDQ | Destination Quantity | O | 500
+/// PO1 -> 2400 | MAN | Marks and Numbers Information | O | 10
+/// PO1 -> 2500 | TD1 | Carrier Details (Quantity and Weight) | O | 1
+/// PO1 -> 2600 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | >1
+/// PO1 -> 2700 | TD3 | Carrier Details (Equipment) | O | 10
+/// PO1 -> 2800 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// PO1 -> 2900 | PCT | Percent Amounts | O | >1
+/// PO1 -> 3000 | CTB | Restrictions/Conditions | O | 10
+/// PO1 -> 3100 | TXI | Tax Information | O | >1
+/// PO1 -> LOOP ID - LDT | 12
+/// PO1 -> LDT -> 3200 | LDT | Lead Time | O | 1
+/// PO1 -> LDT -> 3300 | QTY | Quantity Information | O | >1
+/// PO1 -> LDT -> 3400 | MTX | Text | O | >1
+/// PO1 -> LDT -> 3500 | REF | Reference Information | O | 3
+/// PO1 -> LOOP ID - QTY | >1
+/// PO1 -> QTY -> 3600 | QTY | Quantity Information | O | 1
+/// PO1 -> QTY -> 3700 | SI | Service Characteristic Identification | O | >1
+/// PO1 -> LOOP ID - SCH | 200
+/// PO1 -> SCH -> 3800 | SCH | Line Item Schedule | O | 1
+/// PO1 -> SCH -> 3900 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// PO1 -> SCH -> 4000 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | >1
+/// PO1 -> SCH -> 4100 | REF | Reference Information | O | >1
+/// PO1 -> SCH -> 4200 | LDT | Lead Time | O | 1
+/// PO1 -> LOOP ID - PKG | 200
+/// PO1 -> PKG -> 4300 | PKG | Marking, Packaging, Loading | O | 1
+/// PO1 -> PKG -> 4400 | MEA | Measurements | O | >1
+/// PO1 -> LOOP ID - N9 | 1000
+/// PO1 -> N9 -> 4500 | N9 | Extended Reference Information | O | 1
+/// PO1 -> N9 -> 4600 | DTM | Date/Time Reference | O | >1
+/// PO1 -> N9 -> 4700 | MTX | Text | O | >1
+/// PO1 -> LOOP ID - N1 | 200
+/// PO1 -> N1 -> 4800 | N1 | Party Identifier | O | 1
+/// PO1 -> N1 -> 4900 | N2 | Additional Name Information | O | 2
+/// PO1 -> N1 -> 5000 | N3 | Party Location | O | 2
+/// PO1 -> N1 -> 5100 | N4 | Geographic Location | O | 1
+/// PO1 -> N1 -> 5200 | REF | Reference Information | O | 12
+/// PO1 -> N1 -> 5300 | PER | Administrative Communications Contact | O | 3
+/// PO1 -> N1 -> 5400 | DMG | Demographic Information | O | 1
+/// PO1 -> LOOP ID - SPI | >1
+/// PO1 -> SPI -> 5500 | SPI | Specification Identifier | O | 1
+/// PO1 -> SPI -> 5600 | REF | Reference Information | O | >1
+/// PO1 -> SPI -> 5700 | DTM | Date/Time Reference | O | >1
+/// PO1 -> SPI -> 5800 | MSG | Message Text | O | >1
+/// PO1 -> SPI -> LOOP ID - N1 | 20
+/// PO1 -> SPI -> N1 -> 5900 | N1 | Party Identifier | O | 1
+/// PO1 -> SPI -> N1 -> 6000 | N3 | Party Location | O | 2
+/// PO1 -> SPI -> N1 -> 6100 | N4 | Geographic Location | O | 1
+/// PO1 -> SPI -> N1 -> 6200 | CB1 | Contract and Cost Accounting Information | O | 1
+/// PO1 -> LOOP ID - SLN | 1000
+/// PO1 -> SLN -> 6300 | SLN | Subline Item Detail | O | 1
+/// PO1 -> SLN -> 6400 | MTX | Text | O | >1
+/// PO1 -> SLN -> 6500 | SI | Service Characteristic Identification | O | >1
+/// PO1 -> SLN -> 6600 | PID | Product/Item Description | O | 1000
+/// PO1 -> LOOP ID - ADV | >1
+/// PO1 -> ADV -> 6700 | ADV | Advertising Demographic Information | O | 1
+/// PO1 -> ADV -> 6800 | DTM | Date/Time Reference | O | >1
+/// PO1 -> ADV -> 6900 | MTX | Text | O | >1
+/// PO1 -> LOOP ID - LM | >1
+/// PO1 -> LM -> 7000 | LM | Code Source Information | O | 1
+/// PO1 -> LM -> 7100 | LQ | Industry Code Identification | M | >1
+/// 0100 | CTT | Transaction Totals | O | 1
+/// 0200 | AMT | Monetary Amount Information | O | 1
+/// 0300 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _850 {
     pub st: ST,
@@ -53,6 +210,16 @@ pub struct _850 {
 
 impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
     fn parse(input: &'a str) -> IResult<&'a str, _850> {
+        let _context = crate::error::transaction_set(
+            "_850",
+            &[
+                "ADV", "AMT", "BEG", "CB1", "CSH", "CTB", "CTP", "CTT", "CUR", "DIS", "DMG", "DTM",
+                "EFI", "FA1", "FA2", "FOB", "INC", "ITD", "LDT", "LIN", "LM", "LQ", "MAN", "MEA",
+                "MSG", "MTX", "N1", "N2", "N3", "N4", "N9", "PAM", "PCT", "PER", "PID", "PKG",
+                "PO1", "PO3", "PO4", "PWK", "QTY", "REF", "SAC", "SCH", "SDQ", "SE", "SI", "SLN",
+                "SPI", "ST", "TAX", "TC2", "TD1", "TD3", "TD4", "TD5", "TXI",
+            ],
+        );
         let mut output = _850::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -81,6 +248,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
         let mut loop_sac = vec![];
         let mut loop_rest = rest;
         while peek(opt(SAC::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_850LoopSac");
             let (rest, sac) = SAC::parse(loop_rest)?;
             let (rest, cur) = opt(CUR::parse).parse(rest)?;
             loop_rest = rest;
@@ -130,6 +298,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
         let mut loop_ldt = vec![];
         let mut loop_rest = rest;
         while peek(opt(LDT::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_850LoopLdt");
             let (rest, ldt) = LDT::parse(loop_rest)?;
             let (rest, qty) = many0(QTY::parse).parse(rest)?;
             let (rest, mtx) = many0(MTX::parse).parse(rest)?;
@@ -149,6 +318,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
         let mut loop_amt = vec![];
         let mut loop_rest = rest;
         while peek(opt(AMT::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_850LoopAmt");
             let (rest, amt) = AMT::parse(loop_rest)?;
             let (rest, r#ref) = many0(REF::parse).parse(rest)?;
             let (rest, dtm) = opt(DTM::parse).parse(rest)?;
@@ -158,6 +328,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_fa1 = vec![];
             let mut loop_fa1_rest = rest;
             while peek(opt(FA1::parse)).parse(loop_fa1_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopFa1");
                 let (rest, fa1) = FA1::parse(loop_fa1_rest)?;
                 let (rest, fa2) = many0(FA2::parse).parse(rest)?;
                 loop_fa1_rest = rest;
@@ -180,6 +351,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
         let mut loop_n9 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N9::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_850LoopN9");
             let (rest, n9) = N9::parse(loop_rest)?;
             let (rest, dtm) = many0(DTM::parse).parse(rest)?;
             let (rest, mtx) = many0(MTX::parse).parse(rest)?;
@@ -201,6 +373,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
         let mut loop_n1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_850LoopN1");
             let (rest, n1) = N1::parse(loop_rest)?;
             let (rest, n2) = many0(N2::parse).parse(rest)?;
             let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -240,6 +413,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
         let mut loop_po1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(PO1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_850LoopPo1");
             let (rest, po1) = PO1::parse(loop_rest)?;
             let (rest, lin) = many0(LIN::parse).parse(rest)?;
             let (rest, si) = many0(SI::parse).parse(rest)?;
@@ -253,6 +427,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_pid = vec![];
             let mut loop_pid_rest = rest;
             while peek(opt(PID::parse)).parse(loop_pid_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopPid");
                 let (rest, pid) = PID::parse(loop_pid_rest)?;
                 let (rest, mea) = many0(MEA::parse).parse(rest)?;
                 loop_pid_rest = rest;
@@ -269,6 +444,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_sac = vec![];
             let mut loop_sac_rest = rest;
             while peek(opt(SAC::parse)).parse(loop_sac_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopSac");
                 let (rest, sac) = SAC::parse(loop_sac_rest)?;
                 let (rest, cur) = opt(CUR::parse).parse(rest)?;
                 loop_sac_rest = rest;
@@ -296,6 +472,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_ldt = vec![];
             let mut loop_ldt_rest = rest;
             while peek(opt(LDT::parse)).parse(loop_ldt_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopLdt");
                 let (rest, ldt) = LDT::parse(loop_ldt_rest)?;
                 let (rest, qty) = many0(QTY::parse).parse(rest)?;
                 let (rest, mtx) = many0(MTX::parse).parse(rest)?;
@@ -314,6 +491,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_qty = vec![];
             let mut loop_qty_rest = rest;
             while peek(opt(QTY::parse)).parse(loop_qty_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopQty");
                 let (rest, qty) = QTY::parse(loop_qty_rest)?;
                 let (rest, si) = many0(SI::parse).parse(rest)?;
                 loop_qty_rest = rest;
@@ -325,6 +503,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_sch = vec![];
             let mut loop_sch_rest = rest;
             while peek(opt(SCH::parse)).parse(loop_sch_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopSch");
                 let (rest, sch) = SCH::parse(loop_sch_rest)?;
                 let (rest, td1) = many0(TD1::parse).parse(rest)?;
                 let (rest, td5) = many0(TD5::parse).parse(rest)?;
@@ -345,6 +524,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_pkg = vec![];
             let mut loop_pkg_rest = rest;
             while peek(opt(PKG::parse)).parse(loop_pkg_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopPkg");
                 let (rest, pkg) = PKG::parse(loop_pkg_rest)?;
                 let (rest, mea) = many0(MEA::parse).parse(rest)?;
                 loop_pkg_rest = rest;
@@ -356,6 +536,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_n9 = vec![];
             let mut loop_n9_rest = rest;
             while peek(opt(N9::parse)).parse(loop_n9_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopN9Detail");
                 let (rest, n9) = N9::parse(loop_n9_rest)?;
                 let (rest, dtm) = many0(DTM::parse).parse(rest)?;
                 let (rest, mtx) = many0(MTX::parse).parse(rest)?;
@@ -368,6 +549,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_n1 = vec![];
             let mut loop_n1_rest = rest;
             while peek(opt(N1::parse)).parse(loop_n1_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopN1Detail");
                 let (rest, n1) = N1::parse(loop_n1_rest)?;
                 let (rest, n2) = many0(N2::parse).parse(rest)?;
                 let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -412,6 +594,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_lm = vec![];
             let mut loop_lm_rest = rest;
             while peek(opt(LM::parse)).parse(loop_lm_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopLm");
                 let (rest, lm) = LM::parse(loop_lm_rest)?;
                 let (rest, lq) = many0(LQ::parse).parse(rest)?;
                 loop_lm_rest = rest;
@@ -423,6 +606,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_spi = vec![];
             let mut loop_spi_rest = rest;
             while peek(opt(SPI::parse)).parse(loop_spi_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopSpi");
                 let (rest, spi) = SPI::parse(loop_spi_rest)?;
                 let (rest, r#ref) = many0(REF::parse).parse(rest)?;
                 let (rest, dtm) = many0(DTM::parse).parse(rest)?;
@@ -431,6 +615,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
                 let mut loop_n1_spi = vec![];
                 let mut loop_n1_spi_rest = rest;
                 while peek(opt(N1::parse)).parse(loop_n1_spi_rest)?.1.is_some() {
+                    let _context = crate::error::context("_850LoopN1Spi");
                     let (rest, n1) = N1::parse(loop_n1_spi_rest)?;
                     let (rest, n3) = many0(N3::parse).parse(rest)?;
                     let (rest, n4) = opt(N4::parse).parse(rest)?;
@@ -454,6 +639,7 @@ impl<'a> Parser<&'a str, _850, nom::error::Error<&'a str>> for _850 {
             let mut loop_adv = vec![];
             let mut loop_adv_rest = rest;
             while peek(opt(ADV::parse)).parse(loop_adv_rest)?.1.is_some() {
+                let _context = crate::error::context("_850LoopAdv");
                 let (rest, adv) = ADV::parse(loop_adv_rest)?;
                 let (rest, dtm) = many0(DTM::parse).parse(rest)?;
                 let (rest, mtx) = many0(MTX::parse).parse(rest)?;
@@ -661,6 +847,7 @@ pub struct _850LoopAdv {
 
 impl<'a> Parser<&'a str, _850LoopAdv, nom::error::Error<&'a str>> for _850LoopAdv {
     fn parse(input: &'a str) -> IResult<&'a str, _850LoopAdv> {
+        let _context = crate::error::context("_850LoopAdv");
         let mut output = _850LoopAdv::default();
         let (rest, obj) = ADV::parse(input)?;
         output.adv = obj;
@@ -725,6 +912,7 @@ pub struct _850LoopSln {
 
 impl<'a> Parser<&'a str, _850LoopSln, nom::error::Error<&'a str>> for _850LoopSln {
     fn parse(input: &'a str) -> IResult<&'a str, _850LoopSln> {
+        let _context = crate::error::context("_850LoopSln");
         let mut output = _850LoopSln::default();
         let (rest, obj) = SLN::parse(input)?;
         output.sln = obj;
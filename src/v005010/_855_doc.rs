@@ -9,6 +9,103 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 855 - Purchase Order Acknowledgment
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | BAK | Beginning Segment for Purchase Order Acknowledgment | M | 1
+/// 0300 | CUR | Currency | O | 1
+/// 0400 | REF | Reference Information | O | >1
+/// 0500 | PER | Administrative Communications Contact | O | 3
+/// 0600 | TAX | Tax Reference | O | >1
+/// 0700 | FOB | F.O.B. Related Instructions | O | >1
+/// 0800 | CTP | Pricing Information | O | >1
+/// 0900 | PAM | Period Amount | O | 10
+/// 1000 | CSH | Sales Requirements | O | 1
+/// LOOP ID - SAC | 25
+/// SAC -> 1100 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// SAC -> 1200 | CUR | Currency | O | 1
+/// 1300 | ITD | Terms of Sale/Deferred Terms of Sale | O | >1
+/// 1400 | DIS | Discount Detail | O | 20
+/// 1500 | INC | Installment Information | O | 1
+/// 1600 | DTM | Date/Time Reference | O | 10
+/// 1700 | LDT | Lead Time | O | 12
+/// 1800 | LIN | Item Identification | O | 5
+/// 1900 | SI | Service Characteristic Identification | O | >1
+/// 2000 | PID | Product/Item Description | O | 200
+/// 2100 | MEA | Measurements | O | 40
+/// 2200 | PWK | Disability Information | O | 25
+/// 2300 | PKG | Marking, Packaging, Loading | O | 200
+/// 2400 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// 2500 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | >1
+/// 2600 | TD3 | Carrier Details (Equipment) | O | 12
+/// 2700 | TD4 | Carrier Details (Special Handling, or Hazardous Materials, or Both) | O | 5
+/// 2800 | MAN | Marks and Numbers Information | O | 10
+/// 2900 | TXI | Tax Information | O | >1
+/// 3000 | CTB | Restrictions/Conditions | O | 5
+/// LOOP ID - N9 | 1000
+/// N9 -> 3100 | N9 | Extended Reference Information | O | 1
+/// N9 -> 3200 | DTM | Date/Time Reference | O | 10
+/// N9 -> 3300 | MTX | Text | O | >1
+/// N9 -> 3400 | PWK | Disability Information | O | >1
+/// N9 -> 3500 | EFI | Electronic Format Identification | O | >1
+/// LOOP ID - N1 | 200
+/// N1 -> 3600 | N1 | Party Identifier | O | 1
+/// N1 -> 3700 | N2 | Additional Name Information | O | 2
+/// N1 -> 3800 | N3 | Party Location | O | 2
+/// N1 -> 3900 | N4 | Geographic Location | O | 1
+/// N1 -> 4000 | REF | Reference Information | O | 12
+/// N1 -> 4100 | PER | Administrative Communications Contact | O | >1
+/// N1 -> 4200 | SI | Service Characteristic Identification | O | >1
+/// N1 -> 4300 | FOB | F.O.B. Related Instructions | O | 1
+/// N1 -> 4400 | DTM | Date/Time Reference | O | 1
+/// N1 -> 4500 | LDT | Lead Time | O | 1
+/// LOOP ID - PO1 | 100000
+/// PO1 -> 0100 | PO1 | Baseline Item Data | O | 1
+/// PO1 -> 0200 | LIN | Item Identification | O | >1
+/// PO1 -> 0300 | SI | Service Characteristic Identification | O | >1
+/// PO1 -> 0400 | PID | Product/Item Description | O | 1000
+/// PO1 -> 0500 | MEA | Measurements | O | 40
+/// PO1 -> 0600 | PWK | Disability Information | O | 25
+/// PO1 -> 0700 | PKG | Marking, Packaging, Loading | O | 200
+/// PO1 -> 0800 | PO4 | Item Physical Details | O | 1
+/// PO1 -> 0900 | REF | Reference Information | O | >1
+/// PO1 -> 1000 | PER | Administrative Communications Contact | O | 3
+/// PO1 -> LOOP ID - SAC | 25
+/// PO1 -> SAC -> 1100 | SAC | Service, Promotion, Allowance, or Charge Information | O | 1
+/// PO1 -> SAC -> 1200 | CUR | Currency | O | 1
+/// PO1 -> 1300 | CTP | Pricing Information | O | >1
+/// PO1 -> 1400 | PAM | Period Amount | O | 10
+/// PO1 -> 1500 | ACK | Line Item Acknowledgment | O | 104
+/// PO1 -> 1600 | DTM | Date/Time Reference | O | 10
+/// PO1 -> 1700 | CTB | Restrictions/Conditions | O | 10
+/// PO1 -> 1800 | TXI | Tax Information | O | >1
+/// PO1 -> 1900 | LDT | Lead Time | O | 12
+/// PO1 -> 2000 | MAN | Marks and Numbers Information | O | 10
+/// PO1 -> 2100 | SDQ | Destination Quantity | O | 500
+/// PO1 -> LOOP ID - SCH | 200
+/// PO1 -> SCH -> 2200 | SCH | Line Item Schedule | O | 1
+/// PO1 -> SCH -> 2300 | TD1 | Carrier Details (Quantity and Weight) | O | 2
+/// PO1 -> SCH -> 2400 | TD5 | Carrier Details (Routing Sequence/Transit Time) | O | >1
+/// PO1 -> SCH -> 2500 | REF | Reference Information | O | >1
+/// PO1 -> LOOP ID - N9 | 1000
+/// PO1 -> N9 -> 2600 | N9 | Extended Reference Information | O | 1
+/// PO1 -> N9 -> 2700 | DTM | Date/Time Reference | O | >1
+/// PO1 -> N9 -> 2800 | MTX | Text | O | >1
+/// PO1 -> LOOP ID - N1 | 200
+/// PO1 -> N1 -> 2900 | N1 | Party Identifier | O | 1
+/// PO1 -> N1 -> 3000 | N2 | Additional Name Information | O | 2
+/// PO1 -> N1 -> 3100 | N3 | Party Location | O | 2
+/// PO1 -> N1 -> 3200 | N4 | Geographic Location | O | 1
+/// PO1 -> N1 -> 3300 | REF | Reference Information | O | 12
+/// PO1 -> N1 -> 3400 | PER | Administrative Communications Contact | O | 3
+/// PO1 -> LOOP ID - LM | >1
+/// PO1 -> LM -> 3500 | LM | Code Source Information | O | 1
+/// PO1 -> LM -> 3600 | LQ | Industry Code Identification | M | >1
+/// LOOP ID - CTT | 1
+/// CTT -> 0100 | CTT | Transaction Totals | O | 1
+/// CTT -> 0200 | AMT | Monetary Amount Information | O | 1
+/// 0300 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _855 {
     pub st: ST,
@@ -49,6 +146,15 @@ pub struct _855 {
 
 impl<'a> Parser<&'a str, _855, nom::error::Error<&'a str>> for _855 {
     fn parse(input: &'a str) -> IResult<&'a str, _855> {
+        let _context = crate::error::transaction_set(
+            "_855",
+            &[
+                "ACK", "AMT", "BAK", "CSH", "CTB", "CTP", "CTT", "CUR", "DIS", "DTM", "EFI", "FOB",
+                "INC", "ITD", "LDT", "LIN", "LM", "LQ", "MAN", "MEA", "MTX", "N1", "N2", "N3",
+                "N4", "N9", "PAM", "PER", "PID", "PKG", "PO1", "PO4", "PWK", "REF", "SAC", "SCH",
+                "SDQ", "SE", "SI", "ST", "TAX", "TD1", "TD3", "TD4", "TD5", "TXI",
+            ],
+        );
         let mut output = _855::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -75,6 +181,7 @@ impl<'a> Parser<&'a str, _855, nom::error::Error<&'a str>> for _855 {
         let mut loop_sac = vec![];
         let mut loop_rest = rest;
         while peek(opt(SAC::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_855LoopSac");
             let (rest, sac) = SAC::parse(loop_rest)?;
             let (rest, cur) = opt(CUR::parse).parse(rest)?;
             loop_rest = rest;
@@ -124,6 +231,7 @@ impl<'a> Parser<&'a str, _855, nom::error::Error<&'a str>> for _855 {
         let mut loop_n9 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N9::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_855LoopN9");
             let (rest, n9) = N9::parse(loop_rest)?;
             let (rest, dtm) = many0(DTM::parse).parse(rest)?;
             let (rest, mtx) = many0(MTX::parse).parse(rest)?;
@@ -145,6 +253,7 @@ impl<'a> Parser<&'a str, _855, nom::error::Error<&'a str>> for _855 {
         let mut loop_n1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_855LoopN1");
             let (rest, n1) = N1::parse(loop_rest)?;
             let (rest, n2) = many0(N2::parse).parse(rest)?;
             let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -187,6 +296,7 @@ impl<'a> Parser<&'a str, _855, nom::error::Error<&'a str>> for _855 {
         let mut loop_ctt = vec![];
         let mut loop_rest = rest;
         while peek(opt(CTT::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_855LoopCtt");
             let (rest, ctt) = CTT::parse(loop_rest)?;
             let (rest, amt) = opt(AMT::parse).parse(rest)?;
             loop_rest = rest;
@@ -260,6 +370,7 @@ pub struct _855LoopPo1 {
 
 impl<'a> Parser<&'a str, _855LoopPo1, nom::error::Error<&'a str>> for _855LoopPo1 {
     fn parse(input: &'a str) -> IResult<&'a str, _855LoopPo1> {
+        let _context = crate::error::context("_855LoopPo1");
         let mut output = _855LoopPo1::default();
         let (rest, obj) = PO1::parse(input)?;
         output.po1 = obj;
@@ -286,6 +397,7 @@ impl<'a> Parser<&'a str, _855LoopPo1, nom::error::Error<&'a str>> for _855LoopPo
         let mut loop_sac = vec![];
         let mut loop_rest = rest;
         while peek(opt(SAC::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_855LoopSacPo1");
             let (rest, sac) = SAC::parse(loop_rest)?;
             let (rest, cur) = opt(CUR::parse).parse(rest)?;
             loop_rest = rest;
@@ -317,6 +429,7 @@ impl<'a> Parser<&'a str, _855LoopPo1, nom::error::Error<&'a str>> for _855LoopPo
         let mut loop_sch = vec![];
         let mut loop_rest = rest;
         while peek(opt(SCH::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_855LoopSch");
             let (rest, sch) = SCH::parse(loop_rest)?;
             let (rest, td1) = many0(TD1::parse).parse(rest)?;
             let (rest, td5) = many0(TD5::parse).parse(rest)?;
@@ -336,6 +449,7 @@ impl<'a> Parser<&'a str, _855LoopPo1, nom::error::Error<&'a str>> for _855LoopPo
         let mut loop_n9 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N9::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_855LoopN9Po1");
             let (rest, n9) = N9::parse(loop_rest)?;
             let (rest, dtm) = many0(DTM::parse).parse(rest)?;
             let (rest, mtx) = many0(MTX::parse).parse(rest)?;
@@ -349,6 +463,7 @@ impl<'a> Parser<&'a str, _855LoopPo1, nom::error::Error<&'a str>> for _855LoopPo
         let mut loop_n1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_855LoopN1Po1");
             let (rest, n1) = N1::parse(loop_rest)?;
             let (rest, n2) = many0(N2::parse).parse(rest)?;
             let (rest, n3) = many0(N3::parse).parse(rest)?;
@@ -372,6 +487,7 @@ impl<'a> Parser<&'a str, _855LoopPo1, nom::error::Error<&'a str>> for _855LoopPo
         let mut loop_lm = vec![];
         let mut loop_rest = rest;
         while peek(opt(LM::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_855LoopLm");
             let (rest, lm) = LM::parse(loop_rest)?;
             let (rest, lq) = many0(LQ::parse).parse(rest)?;
             loop_rest = rest;
@@ -9,6 +9,22 @@ use serde::{Deserialize, Serialize};
 use x12_types_macros::DisplayX12;
 
 /// 999 - Implementation Acknowledgment
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | AK1 | Functional Group Response Header | M | 1
+/// LOOP ID - 2000 | >1
+/// 2000 -> 0300 | AK2 | Transaction Set Response Header | O | 1
+/// 2000 -> LOOP ID - 2100 | >1
+/// 2000 -> 2100 -> 0400 | IK3 | Implementation Data Segment Note | O | 1
+/// 2000 -> 2100 -> 0500 | CTX | Context | O | 10
+/// 2000 -> 2100 -> LOOP ID - 2110 | 99
+/// 2000 -> 2100 -> 2110 -> 0600 | IK4 | Implementation Data Element Note | O | 1
+/// 2000 -> 2100 -> 2110 -> 0700 | CTX | Context | O | 10
+/// 2000 -> 0800 | IK5 | Implementation Transaction Set Response Trailer | M | 1
+/// 0900 | AK9 | Functional Group Response Trailer | M | 1
+/// 1000 | SE | Transaction Set Trailer | M | 1
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplayX12)]
 pub struct _999 {
     pub st: ST,
@@ -20,6 +36,10 @@ pub struct _999 {
 
 impl<'a> Parser<&'a str, _999, nom::error::Error<&'a str>> for _999 {
     fn parse(input: &'a str) -> IResult<&'a str, _999> {
+        let _context = crate::error::transaction_set(
+            "_999",
+            &["AK1", "AK2", "AK9", "CTX", "IK3", "IK4", "IK5", "SE", "ST"],
+        );
         let mut output = _999::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -29,17 +49,20 @@ impl<'a> Parser<&'a str, _999, nom::error::Error<&'a str>> for _999 {
         let mut loop_ak2 = vec![];
         let mut loop_rest = rest;
         while peek(opt(AK2::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_999LoopAK2");
             let (rest, ak2) = AK2::parse(loop_rest)?;
             // loop IK3
             let mut loop_ik3 = vec![];
             let mut ik3_rest = rest;
             while peek(opt(IK3::parse)).parse(ik3_rest)?.1.is_some() {
+                let _context = crate::error::context("_999LoopIK3");
                 let (rest, ik3) = IK3::parse(ik3_rest)?;
                 let (rest, ctx) = many0(CTX::parse).parse(rest)?;
                 // loop IK4
                 let mut loop_ik4 = vec![];
                 let mut ik4_rest = rest;
                 while peek(opt(IK4::parse)).parse(ik4_rest)?.1.is_some() {
+                    let _context = crate::error::context("_999LoopIK4");
                     let (rest, ik4) = IK4::parse(ik4_rest)?;
                     let (rest, ctx) = many0(CTX::parse).parse(rest)?;
                     ik4_rest = rest;
@@ -53,6 +76,7 @@ impl<'a> Parser<&'a str, _999, nom::error::Error<&'a str>> for _999 {
             loop_rest = rest;
             loop_ak2.push(_999LoopAK2 { ak2, loop_ik3, ik5 });
         }
+        output.loop_ak2 = loop_ak2;
         let rest = loop_rest;
         let (rest, ak9) = AK9::parse(rest)?;
         output.ak9 = ak9;
@@ -0,0 +1,35 @@
+use crate::envelope;
+use crate::v005010::*;
+
+envelope::any_transaction! {
+    /// Any transaction set of 005010
+    ///
+    /// Parsing dispatches on the Transaction Set Identifier Code (ST01).
+    /// Transaction sets without a typed binding are kept as [`RawTransaction`](crate::raw::RawTransaction).
+    AnyTransaction {
+        /// 270 - Eligibility, Coverage or Benefit Inquiry
+        "270" => _270,
+        /// 271 - Eligibility, Coverage or Benefit Information
+        "271" => _271,
+        /// 276 - Health Claim Status Request
+        "276" => _276,
+        /// 277 - Health Care Claim Status
+        "277" => _277,
+        /// 278 - Health Care Services Review Information
+        "278" => _278,
+        /// 820 - Payment Order/Remittance Advice
+        "820" => _820,
+        /// 834 - Benefit Enrollment and Maintenance
+        "834" => _834,
+        /// 835 - Health Care Claim Payment/Advice
+        "835" => _835,
+        /// 837 - Health Care Claim
+        "837" => _837,
+        /// 850 - Purchase Order
+        "850" => _850,
+        /// 855 - Purchase Order Acknowledgment
+        "855" => _855,
+        /// 999 - Implementation Acknowledgment
+        "999" => _999,
+    }
+}
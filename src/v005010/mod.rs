@@ -1,13 +1,12 @@
 //! v005010 repesents all entities of the 005010 specification.
 
-use crate::util::Parser;
-use nom::{
-    combinator::{opt, peek},
-    IResult, Parser as _,
-};
+use crate::envelope;
+use crate::util::reader;
 pub use segment::*;
-use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+pub use segment_ref::*;
+
+mod any_transaction;
+pub use any_transaction::*;
 
 mod _270_doc;
 pub use _270_doc::*;
@@ -70,66 +69,1800 @@ pub use _999_doc::*;
 mod _999_test;
 
 mod segment;
+mod segment_ref;
 #[cfg(test)]
 mod segments_test;
+#[cfg(test)]
+mod transmission_test;
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
-pub struct Transmission<T> {
-    pub isa: ISA,
-    pub functional_group: Vec<FunctionalGroup<T>>,
-    pub iea: IEA,
-}
+/// The envelope segments of 005010
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct V005010;
 
-impl<'a, T: Default + Parser<&'a str, T, nom::error::Error<&'a str>>>
-    Parser<&'a str, Transmission<T>, nom::error::Error<&'a str>> for Transmission<T>
-{
-    fn parse(input: &'a str) -> IResult<&'a str, Transmission<T>> {
-        let mut output = Transmission::default();
-        let (input, obj) = ISA::parse(input)?;
-        output.isa = obj;
-        // functional group
-        let (input, gs) = GS::parse(input)?;
-        let mut loop_rest = input;
-        let mut obj_arr = vec![];
-        while peek(opt(ST::parse)).parse(loop_rest)?.1.is_some() {
-            let (input, t_obj) = T::parse(loop_rest)?;
-            loop_rest = input;
-            obj_arr.push(t_obj);
-        }
-        let input = loop_rest;
-        let (input, ge) = GE::parse(input)?;
-        let fg = FunctionalGroup {
-            gs,
-            segments: obj_arr,
-            ge,
-        };
-        output.functional_group.push(fg);
-        let (input, obj) = IEA::parse(input)?;
-        output.iea = obj;
-        Ok((input, output))
-    }
-}
+envelope::impl_version!(V005010, "00501");
+
+/// An interchange of 005010, from ISA to IEA
+pub type Transmission<T> = envelope::Transmission<V005010, T>;
+
+/// A functional group of 005010, from GS to GE
+pub type FunctionalGroup<T> = envelope::FunctionalGroup<V005010, T>;
+
+/// Streaming reader yielding the envelope segments and one transaction set at a time
+pub type TransmissionReader<R, T> = reader::TransmissionReader<R, ISA, GS, T, GE, IEA>;
+
+/// Item of a [`TransmissionReader`]
+pub type Event<T> = reader::Event<ISA, GS, T, GE, IEA>;
 
-impl<T: Display> Display for Transmission<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut lines = vec![];
-        lines.push(format!("{}", self.isa));
-        for fg in &self.functional_group {
-            lines.push(format!("{}", fg.gs));
-            for segment in &fg.segments {
-                lines.push(format!("{segment}"));
-            }
-            lines.push(format!("{}", fg.ge));
-        }
-        lines.push(format!("{}", self.iea));
-        let all = lines.join("");
-        write!(f, "{all}")
-    }
+envelope::impl_transaction_set! {
+    _270 => st, se;
+    _271 => st, se;
+    _276 => st, se;
+    _277 => st, se;
+    _278 => st, se;
+    _820 => st, se;
+    _834 => st, se;
+    _835 => st, se;
+    _837 => st, se;
+    _850 => st, se;
+    _855 => st, se;
+    _999 => st, se;
 }
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
-pub struct FunctionalGroup<T> {
-    pub gs: GS,
-    pub segments: Vec<T>,
-    pub ge: GE,
+crate::validation::impl_transaction_structure! {
+    _270 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "BHT", M, 1),
+        segment_loop(
+            "2000",
+            usize::MAX,
+            &[
+                segment("0100", "HL", M, 1),
+                segment("0200", "TRN", O, 9),
+                segment_loop(
+                    "2100",
+                    usize::MAX,
+                    &[
+                        segment("0300", "NM1", M, 1),
+                        segment("0400", "REF", O, 9),
+                        segment("0500", "N2", O, 1),
+                        segment("0600", "N3", O, 1),
+                        segment("0700", "N4", O, 1),
+                        segment("0800", "PER", O, 3),
+                        segment("0900", "PRV", O, 1),
+                        segment("1000", "DMG", O, 1),
+                        segment("1100", "INS", O, 1),
+                        segment("1150", "HI", O, 1),
+                        segment("1200", "DTP", O, 9),
+                        segment("1250", "MPI", O, 9),
+                        segment_loop(
+                            "2110",
+                            99,
+                            &[
+                                segment("1300", "EQ", O, 1),
+                                segment("1350", "AMT", O, 2),
+                                segment("1400", "VEH", O, 1),
+                                segment("1500", "PDR", O, 1),
+                                segment("1600", "PDP", O, 1),
+                                segment("1700", "III", O, 10),
+                                segment("1800", "REF", O, 1),
+                                segment("1900", "DTP", O, 9),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("0100", "SE", M, 1),
+    ],
+    _271 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "BHT", M, 1),
+        segment_loop(
+            "2000",
+            usize::MAX,
+            &[
+                segment("0100", "HL", M, 1),
+                segment("0200", "TRN", O, 9),
+                segment("0250", "AAA", O, 9),
+                segment_loop(
+                    "2100",
+                    usize::MAX,
+                    &[
+                        segment("0300", "NM1", M, 1),
+                        segment("0400", "REF", O, 9),
+                        segment("0500", "N2", O, 1),
+                        segment("0600", "N3", O, 1),
+                        segment("0700", "N4", O, 1),
+                        segment("0800", "PER", O, 3),
+                        segment("0850", "AAA", O, 9),
+                        segment("0900", "PRV", O, 1),
+                        segment("1000", "DMG", O, 1),
+                        segment("1100", "INS", O, 1),
+                        segment("1150", "HI", O, 1),
+                        segment("1200", "DTP", O, 9),
+                        segment("1220", "LUI", O, 9),
+                        segment("1250", "MPI", O, 9),
+                        segment_loop(
+                            "2110",
+                            usize::MAX,
+                            &[
+                                segment("1300", "EB", O, 1),
+                                segment("1350", "HSD", O, 9),
+                                segment("1400", "REF", O, 9),
+                                segment("1500", "DTP", O, 20),
+                                segment("1600", "AAA", O, 9),
+                                segment("1700", "VEH", O, 1),
+                                segment("1750", "PID", O, 1),
+                                segment("1800", "PDR", O, 1),
+                                segment("1900", "PDP", O, 1),
+                                segment("2000", "LIN", O, 1),
+                                segment("2100", "EM", O, 1),
+                                segment("2200", "SD1", O, 1),
+                                segment("2300", "PKD", O, 1),
+                                segment("2500", "MSG", O, 10),
+                                segment_loop(
+                                    "2115",
+                                    10,
+                                    &[
+                                        segment("2600", "III", O, 1),
+                                        segment("2700", "DTP", O, 1),
+                                        segment("2800", "AMT", O, 1),
+                                        segment("2900", "PCT", O, 1),
+                                        segment_loop(
+                                            "2117",
+                                            usize::MAX,
+                                            &[
+                                                segment("3000", "LQ", O, 1),
+                                                segment("3100", "AMT", O, 1),
+                                                segment("3150", "PCT", O, 1),
+                                            ],
+                                        ),
+                                    ],
+                                ),
+                                segment("3200", "LS", O, 1),
+                                segment_loop(
+                                    "2120",
+                                    23,
+                                    &[
+                                        segment("3250", "NM1", O, 1),
+                                        segment("3300", "N2", O, 1),
+                                        segment("3400", "N3", O, 1),
+                                        segment("3500", "N4", O, 1),
+                                        segment("3600", "PER", O, 3),
+                                        segment("3700", "PRV", O, 1),
+                                    ],
+                                ),
+                                segment("3800", "LE", O, 1),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("0100", "SE", M, 1),
+    ],
+    _276 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "BHT", M, 1),
+        segment_loop(
+            "2000",
+            usize::MAX,
+            &[
+                segment("0100", "HL", M, 1),
+                segment("0400", "DMG", O, 1),
+                segment_loop(
+                    "2100",
+                    usize::MAX,
+                    &[
+                        segment("0500", "NM1", M, 1),
+                        segment("0800", "PER", O, 1),
+                        segment("0810", "DMG", O, 1),
+                        segment("0820", "REF", O, usize::MAX),
+                        segment("0830", "DTP", O, usize::MAX),
+                        segment("0840", "SVC", O, 1),
+                    ],
+                ),
+                segment_loop(
+                    "2200",
+                    usize::MAX,
+                    &[
+                        segment("0900", "TRN", O, 1),
+                        segment("1000", "REF", O, usize::MAX),
+                        segment("1100", "AMT", O, usize::MAX),
+                        segment("1200", "DTP", O, usize::MAX),
+                        segment("1300", "SVC", O, 1),
+                    ],
+                ),
+            ],
+        ),
+        segment("1600", "SE", M, 1),
+    ],
+    _277 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "BHT", M, 1),
+        segment_loop(
+            "2000",
+            usize::MAX,
+            &[
+                segment("0100", "HL", M, 1),
+                segment_loop(
+                    "2100",
+                    usize::MAX,
+                    &[
+                        segment("0500", "NM1", M, 1),
+                        segment("0600", "N3", O, 1),
+                        segment("0700", "N4", O, 1),
+                        segment("0750", "REF", O, usize::MAX),
+                        segment("0800", "PER", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "2200",
+                    usize::MAX,
+                    &[
+                        segment("0900", "TRN", O, 1),
+                        segment("1000", "STC", O, usize::MAX),
+                        segment("1100", "REF", O, usize::MAX),
+                        segment("1200", "DTP", O, usize::MAX),
+                        segment("1300", "QTY", O, usize::MAX),
+                        segment("1400", "AMT", O, usize::MAX),
+                        segment_loop(
+                            "2220",
+                            usize::MAX,
+                            &[
+                                segment("1800", "SVC", O, 1),
+                                segment("1900", "STC", O, usize::MAX),
+                                segment("2000", "REF", O, usize::MAX),
+                                segment("2100", "DTP", O, usize::MAX),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("2700", "SE", M, 1),
+    ],
+    _278 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "BHT", M, 1),
+        segment_loop(
+            "2000",
+            usize::MAX,
+            &[
+                segment("0100", "HL", M, 1),
+                segment("0200", "TRN", O, 9),
+                segment("0250", "AAA", O, 9),
+                segment("0300", "UM", O, 1),
+                segment("0350", "HCR", O, 1),
+                segment("0400", "REF", O, 9),
+                segment("0500", "DTP", O, 9),
+                segment("0600", "HI", O, 1),
+                segment("0700", "SV1", O, 1),
+                segment("0800", "SV2", O, 1),
+                segment("0900", "SV3", O, 1),
+                segment("1000", "TOO", O, 10),
+                segment("1100", "HSD", O, 1),
+                segment("1200", "CRC", O, 9),
+                segment("1300", "CL1", O, 1),
+                segment("1400", "CR1", O, 1),
+                segment("1500", "CR2", O, 1),
+                segment("1600", "CR4", O, 1),
+                segment("1700", "CR5", O, 1),
+                segment("1800", "CR6", O, 1),
+                segment("1900", "CR7", O, 1),
+                segment("2000", "CR8", O, 1),
+                segment("2100", "PWK", O, 10),
+                segment("2200", "MSG", O, 1),
+                segment_loop(
+                    "2010",
+                    usize::MAX,
+                    &[
+                        segment("2300", "NM1", O, 1),
+                        segment("2400", "REF", O, 9),
+                        segment("2500", "N2", O, 1),
+                        segment("2600", "N3", O, 1),
+                        segment("2700", "N4", O, 1),
+                        segment("2800", "PER", O, 3),
+                        segment("2900", "AAA", O, 9),
+                        segment("3000", "PRV", O, 1),
+                        segment("3100", "DMG", O, 1),
+                        segment("3200", "INS", O, 1),
+                        segment("3300", "DTP", O, 9),
+                    ],
+                ),
+            ],
+        ),
+        segment("0100", "SE", M, 1),
+    ],
+    _820 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "BPR", M, 1),
+        segment("0300", "NTE", O, usize::MAX),
+        segment("0400", "TRN", O, 1),
+        segment("0500", "CUR", O, 1),
+        segment("0600", "REF", O, usize::MAX),
+        segment("0700", "DTM", O, usize::MAX),
+        segment_loop(
+            "N1",
+            usize::MAX,
+            &[
+                segment("0800", "N1", O, 1),
+                segment("0900", "N2", O, usize::MAX),
+                segment("1000", "N3", O, usize::MAX),
+                segment("1100", "N4", O, 1),
+                segment("1200", "REF", O, usize::MAX),
+                segment("1300", "PER", O, usize::MAX),
+                segment("1400", "RDM", O, 1),
+                segment("1500", "DTM", O, 1),
+            ],
+        ),
+        segment_loop(
+            "ENT",
+            usize::MAX,
+            &[
+                segment("0050", "ENT", O, 1),
+                segment_loop(
+                    "FA1",
+                    usize::MAX,
+                    &[
+                        segment("0100", "FA1", O, 1),
+                        segment("0150", "FA2", M, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "NM1",
+                    usize::MAX,
+                    &[
+                        segment("0200", "NM1", O, 1),
+                        segment("0250", "N2", O, usize::MAX),
+                        segment("0300", "N3", O, usize::MAX),
+                        segment("0350", "N4", O, 1),
+                        segment("0400", "REF", O, usize::MAX),
+                        segment("0450", "PER", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "ADX",
+                    usize::MAX,
+                    &[
+                        segment("0500", "ADX", O, 1),
+                        segment("0550", "NTE", O, usize::MAX),
+                        segment("0600", "PER", O, usize::MAX),
+                        segment("0650", "DTM", O, 1),
+                        segment_loop(
+                            "REF",
+                            usize::MAX,
+                            &[
+                                segment("0700", "REF", O, 1),
+                                segment("0750", "DTM", O, usize::MAX),
+                            ],
+                        ),
+                        segment_loop(
+                            "IT1",
+                            usize::MAX,
+                            &[
+                                segment("0800", "IT1", O, 1),
+                                segment("0850", "RPA", O, 1),
+                                segment("0900", "QTY", O, 1),
+                                segment_loop(
+                                    "REF",
+                                    usize::MAX,
+                                    &[
+                                        segment("0950", "REF", O, 1),
+                                        segment("1000", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "SAC",
+                                    usize::MAX,
+                                    &[
+                                        segment("1050", "SAC", O, 1),
+                                        segment("1100", "TXI", O, usize::MAX),
+                                        segment("1150", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "SLN",
+                                    usize::MAX,
+                                    &[
+                                        segment("1200", "SLN", O, 1),
+                                        segment_loop(
+                                            "REF",
+                                            usize::MAX,
+                                            &[
+                                                segment("1250", "REF", O, 1),
+                                                segment("1300", "DTM", O, usize::MAX),
+                                            ],
+                                        ),
+                                        segment_loop(
+                                            "SAC",
+                                            usize::MAX,
+                                            &[
+                                                segment("1350", "SAC", O, 1),
+                                                segment("1400", "TXI", O, usize::MAX),
+                                                segment("1450", "DTM", O, usize::MAX),
+                                            ],
+                                        ),
+                                    ],
+                                ),
+                            ],
+                        ),
+                        segment_loop(
+                            "FA1",
+                            usize::MAX,
+                            &[
+                                segment("1500", "FA1", O, 1),
+                                segment("1550", "FA2", M, usize::MAX),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "RMR",
+                    usize::MAX,
+                    &[
+                        segment("1600", "RMR", O, 1),
+                        segment("1650", "NTE", O, usize::MAX),
+                        segment("1700", "REF", O, usize::MAX),
+                        segment("1750", "DTM", O, usize::MAX),
+                        segment("1800", "VEH", O, 1),
+                        segment_loop(
+                            "IT1",
+                            usize::MAX,
+                            &[
+                                segment("1850", "IT1", O, 1),
+                                segment("1900", "RPA", O, 1),
+                                segment("1950", "QTY", O, 1),
+                                segment_loop(
+                                    "REF",
+                                    usize::MAX,
+                                    &[
+                                        segment("2000", "REF", O, 1),
+                                        segment("2050", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "SAC",
+                                    usize::MAX,
+                                    &[
+                                        segment("2100", "SAC", O, 1),
+                                        segment("2150", "TXI", O, usize::MAX),
+                                        segment("2200", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "SLN",
+                                    usize::MAX,
+                                    &[
+                                        segment("2250", "SLN", O, 1),
+                                        segment_loop(
+                                            "REF",
+                                            usize::MAX,
+                                            &[
+                                                segment("2300", "REF", O, 1),
+                                                segment("2350", "DTM", O, usize::MAX),
+                                            ],
+                                        ),
+                                        segment_loop(
+                                            "SAC",
+                                            usize::MAX,
+                                            &[
+                                                segment("2400", "SAC", O, 1),
+                                                segment("2450", "TXI", O, usize::MAX),
+                                                segment("2500", "DTM", O, usize::MAX),
+                                            ],
+                                        ),
+                                    ],
+                                ),
+                            ],
+                        ),
+                        segment_loop(
+                            "ADX",
+                            usize::MAX,
+                            &[
+                                segment("2550", "ADX", O, 1),
+                                segment("2600", "NTE", O, usize::MAX),
+                                segment("2650", "PER", O, usize::MAX),
+                                segment_loop(
+                                    "REF",
+                                    usize::MAX,
+                                    &[
+                                        segment("2700", "REF", O, 1),
+                                        segment("2750", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "IT1",
+                                    usize::MAX,
+                                    &[
+                                        segment("2800", "IT1", O, 1),
+                                        segment("2850", "RPA", O, 1),
+                                        segment("2900", "QTY", O, 1),
+                                        segment_loop(
+                                            "REF",
+                                            usize::MAX,
+                                            &[
+                                                segment("2950", "REF", O, 1),
+                                                segment("3000", "DTM", O, usize::MAX),
+                                            ],
+                                        ),
+                                        segment_loop(
+                                            "SAC",
+                                            usize::MAX,
+                                            &[
+                                                segment("3050", "SAC", O, 1),
+                                                segment("3100", "TXI", O, usize::MAX),
+                                                segment("3150", "DTM", O, usize::MAX),
+                                            ],
+                                        ),
+                                        segment_loop(
+                                            "SLN",
+                                            usize::MAX,
+                                            &[
+                                                segment("3200", "SLN", O, 1),
+                                                segment_loop(
+                                                    "REF",
+                                                    usize::MAX,
+                                                    &[
+                                                        segment("3250", "REF", O, 1),
+                                                        segment("3300", "DTM", O, usize::MAX),
+                                                    ],
+                                                ),
+                                                segment_loop(
+                                                    "SAC",
+                                                    usize::MAX,
+                                                    &[
+                                                        segment("3350", "SAC", O, 1),
+                                                        segment("3400", "TXI", O, usize::MAX),
+                                                        segment("3450", "DTM", O, usize::MAX),
+                                                    ],
+                                                ),
+                                            ],
+                                        ),
+                                    ],
+                                ),
+                            ],
+                        ),
+                        segment_loop(
+                            "FA1",
+                            usize::MAX,
+                            &[
+                                segment("3500", "FA1", O, 1),
+                                segment("3550", "FA2", M, usize::MAX),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "ADX",
+                    usize::MAX,
+                    &[
+                        segment("3600", "ADX", O, 1),
+                        segment("3650", "NTE", O, usize::MAX),
+                        segment("3700", "PER", O, usize::MAX),
+                        segment_loop(
+                            "REF",
+                            usize::MAX,
+                            &[
+                                segment("3750", "REF", O, 1),
+                                segment("3800", "DTM", O, usize::MAX),
+                            ],
+                        ),
+                        segment_loop(
+                            "IT1",
+                            usize::MAX,
+                            &[
+                                segment("3850", "IT1", O, 1),
+                                segment("3900", "RPA", O, 1),
+                                segment("3950", "QTY", O, 1),
+                                segment_loop(
+                                    "REF",
+                                    usize::MAX,
+                                    &[
+                                        segment("4000", "REF", O, 1),
+                                        segment("4050", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "SAC",
+                                    usize::MAX,
+                                    &[
+                                        segment("4100", "SAC", O, 1),
+                                        segment("4150", "TXI", O, usize::MAX),
+                                        segment("4200", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "SLN",
+                                    usize::MAX,
+                                    &[
+                                        segment("4250", "SLN", O, 1),
+                                        segment_loop(
+                                            "REF",
+                                            usize::MAX,
+                                            &[
+                                                segment("4300", "REF", O, 1),
+                                                segment("4350", "DTM", O, usize::MAX),
+                                            ],
+                                        ),
+                                        segment_loop(
+                                            "SAC",
+                                            usize::MAX,
+                                            &[
+                                                segment("4400", "SAC", O, 1),
+                                                segment("4450", "TXI", O, usize::MAX),
+                                                segment("4500", "DTM", O, usize::MAX),
+                                            ],
+                                        ),
+                                    ],
+                                ),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "FA1",
+                    usize::MAX,
+                    &[
+                        segment("4550", "FA1", O, 1),
+                        segment("4600", "FA2", M, usize::MAX),
+                    ],
+                ),
+            ],
+        ),
+        segment_loop(
+            "RMR",
+            usize::MAX,
+            &[
+                segment("4650", "RMR", O, 1),
+                segment("4700", "NTE", O, usize::MAX),
+                segment("4750", "REF", O, usize::MAX),
+                segment("4800", "DTM", O, usize::MAX),
+                segment("4850", "VEH", O, 1),
+                segment_loop(
+                    "IT1",
+                    usize::MAX,
+                    &[
+                        segment("4900", "IT1", O, 1),
+                        segment("4950", "RPA", O, 1),
+                        segment("5000", "QTY", O, 1),
+                        segment_loop(
+                            "REF",
+                            usize::MAX,
+                            &[
+                                segment("5050", "REF", O, 1),
+                                segment("5100", "DTM", O, usize::MAX),
+                            ],
+                        ),
+                        segment_loop(
+                            "SAC",
+                            usize::MAX,
+                            &[
+                                segment("5150", "SAC", O, 1),
+                                segment("5200", "TXI", O, usize::MAX),
+                                segment("5250", "DTM", O, usize::MAX),
+                            ],
+                        ),
+                        segment_loop(
+                            "SLN",
+                            usize::MAX,
+                            &[
+                                segment("5300", "SLN", O, 1),
+                                segment_loop(
+                                    "REF",
+                                    usize::MAX,
+                                    &[
+                                        segment("5350", "REF", O, 1),
+                                        segment("5400", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "SAC",
+                                    usize::MAX,
+                                    &[
+                                        segment("5450", "SAC", O, 1),
+                                        segment("5500", "TXI", O, usize::MAX),
+                                        segment("5550", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "ADX",
+                    usize::MAX,
+                    &[
+                        segment("5600", "ADX", O, 1),
+                        segment("5650", "NTE", O, usize::MAX),
+                        segment("5700", "PER", O, usize::MAX),
+                        segment_loop(
+                            "REF",
+                            usize::MAX,
+                            &[
+                                segment("5750", "REF", O, 1),
+                                segment("5800", "DTM", O, usize::MAX),
+                            ],
+                        ),
+                        segment_loop(
+                            "IT1",
+                            usize::MAX,
+                            &[
+                                segment("5850", "IT1", O, 1),
+                                segment("5900", "RPA", O, 1),
+                                segment("5950", "QTY", O, 1),
+                                segment_loop(
+                                    "REF",
+                                    usize::MAX,
+                                    &[
+                                        segment("6000", "REF", O, 1),
+                                        segment("6050", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "SAC",
+                                    usize::MAX,
+                                    &[
+                                        segment("6100", "SAC", O, 1),
+                                        segment("6150", "TXI", O, usize::MAX),
+                                        segment("6200", "DTM", O, usize::MAX),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "SLN",
+                                    usize::MAX,
+                                    &[
+                                        segment("6250", "SLN", O, 1),
+                                        segment_loop(
+                                            "REF",
+                                            usize::MAX,
+                                            &[
+                                                segment("6300", "REF", O, 1),
+                                                segment("6350", "DTM", O, usize::MAX),
+                                            ],
+                                        ),
+                                        segment_loop(
+                                            "SAC",
+                                            usize::MAX,
+                                            &[
+                                                segment("6400", "SAC", O, 1),
+                                                segment("6450", "TXI", O, usize::MAX),
+                                                segment("6500", "DTM", O, usize::MAX),
+                                            ],
+                                        ),
+                                    ],
+                                ),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "FA1",
+                    usize::MAX,
+                    &[
+                        segment("6550", "FA1", O, 1),
+                        segment("6600", "FA2", M, usize::MAX),
+                    ],
+                ),
+            ],
+        ),
+        segment_loop(
+            "TXP",
+            usize::MAX,
+            &[
+                segment("6650", "TXP", O, 1),
+                segment("6700", "TXI", O, usize::MAX),
+                segment("6750", "REF", O, usize::MAX),
+                segment("6800", "DTM", O, usize::MAX),
+            ],
+        ),
+        segment_loop(
+            "DED",
+            usize::MAX,
+            &[
+                segment("6850", "DED", O, 1),
+            ],
+        ),
+        segment_loop(
+            "LX",
+            usize::MAX,
+            &[
+                segment("6900", "LX", O, 1),
+                segment("6950", "REF", O, usize::MAX),
+                segment("7000", "TRN", O, usize::MAX),
+                segment_loop(
+                    "NM1",
+                    usize::MAX,
+                    &[
+                        segment("7050", "NM1", O, 1),
+                        segment("7100", "N2", O, usize::MAX),
+                        segment("7150", "N3", O, usize::MAX),
+                        segment("7200", "N4", O, 1),
+                        segment("7250", "REF", O, usize::MAX),
+                        segment("7300", "PER", O, usize::MAX),
+                    ],
+                ),
+            ],
+        ),
+        segment_loop(
+            "N9",
+            1,
+            &[
+                segment("0100", "N9", O, 1),
+                segment("0200", "REF", O, usize::MAX),
+                segment_loop(
+                    "AMT",
+                    usize::MAX,
+                    &[
+                        segment("0300", "AMT", O, 1),
+                        segment("0400", "REF", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "N1",
+                    usize::MAX,
+                    &[
+                        segment("0500", "N1", O, 1),
+                        segment("0600", "N2", O, usize::MAX),
+                        segment("0700", "N3", O, usize::MAX),
+                        segment("0800", "N4", O, 1),
+                        segment("0900", "REF", O, usize::MAX),
+                        segment("1000", "PER", O, usize::MAX),
+                        segment("1100", "RDM", O, 1),
+                        segment("1200", "DTM", O, 1),
+                    ],
+                ),
+            ],
+        ),
+        segment_loop(
+            "RYL",
+            usize::MAX,
+            &[
+                segment("1300", "RYL", O, 1),
+                segment_loop(
+                    "NM1",
+                    usize::MAX,
+                    &[
+                        segment("1400", "NM1", O, 1),
+                        segment("1500", "N2", O, usize::MAX),
+                        segment("1600", "N3", O, usize::MAX),
+                        segment("1700", "N4", O, 1),
+                        segment("1800", "REF", O, usize::MAX),
+                        segment("1900", "PER", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "ASM",
+                    1,
+                    &[
+                        segment("2000", "ASM", O, 1),
+                        segment("2100", "ADX", O, 1),
+                    ],
+                ),
+            ],
+        ),
+        segment("2200", "SE", M, 1),
+    ],
+    _834 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "BGN", M, 1),
+        segment("0300", "REF", O, usize::MAX),
+        segment("0400", "DTP", O, usize::MAX),
+        segment("0500", "AMT", O, usize::MAX),
+        segment("0600", "QTY", O, usize::MAX),
+        segment_loop(
+            "1000",
+            usize::MAX,
+            &[
+                segment("0700", "N1", O, 1),
+                segment("0800", "N2", O, usize::MAX),
+                segment("0900", "N3", O, usize::MAX),
+                segment("1000", "N4", O, 1),
+                segment("1100", "PER", O, usize::MAX),
+                segment_loop(
+                    "1100",
+                    10,
+                    &[
+                        segment("1200", "ACT", O, 1),
+                        segment("1300", "REF", O, usize::MAX),
+                        segment("1400", "N3", O, 1),
+                        segment("1500", "N4", O, 1),
+                        segment("1600", "PER", O, usize::MAX),
+                        segment("1700", "DTP", O, 1),
+                        segment("1800", "AMT", O, 1),
+                    ],
+                ),
+            ],
+        ),
+        segment_loop(
+            "2000",
+            usize::MAX,
+            &[
+                segment("0100", "INS", O, 1),
+                segment("0200", "REF", O, usize::MAX),
+                segment("0300", "DTP", O, usize::MAX),
+                segment_loop(
+                    "2100",
+                    usize::MAX,
+                    &[
+                        segment("0400", "NM1", O, 1),
+                        segment("0500", "PER", O, 1),
+                        segment("0600", "N3", O, 1),
+                        segment("0700", "N4", O, 1),
+                        segment("0800", "DMG", O, 1),
+                        segment("0900", "PM", O, 1),
+                        segment("1000", "EC", O, usize::MAX),
+                        segment("1100", "ICM", O, 1),
+                        segment("1200", "AMT", O, usize::MAX),
+                        segment("1300", "HLH", O, 1),
+                        segment("1400", "HI", O, usize::MAX),
+                        segment("1500", "LUI", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "2200",
+                    usize::MAX,
+                    &[
+                        segment("1600", "DSB", O, 1),
+                        segment("1700", "DTP", O, usize::MAX),
+                        segment("1800", "AD1", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "2300",
+                    usize::MAX,
+                    &[
+                        segment("1900", "HD", O, 1),
+                        segment("2000", "DTP", O, usize::MAX),
+                        segment("2100", "AMT", O, usize::MAX),
+                        segment("2200", "REF", O, usize::MAX),
+                        segment("2300", "IDC", O, usize::MAX),
+                        segment_loop(
+                            "2310",
+                            usize::MAX,
+                            &[
+                                segment("2400", "LX", O, 1),
+                                segment("2500", "NM1", O, 1),
+                                segment("2600", "N1", O, usize::MAX),
+                                segment("2700", "N2", O, 1),
+                                segment("2800", "N3", O, usize::MAX),
+                                segment("2900", "N4", O, 1),
+                                segment("3000", "PER", O, usize::MAX),
+                                segment("3100", "PRV", O, 1),
+                                segment("3200", "DTP", O, usize::MAX),
+                                segment("3300", "PLA", O, 1),
+                            ],
+                        ),
+                        segment_loop(
+                            "2320",
+                            usize::MAX,
+                            &[
+                                segment("3400", "COB", O, 1),
+                                segment("3500", "REF", O, 5),
+                                segment("3600", "DTP", O, 2),
+                                segment_loop(
+                                    "2330",
+                                    usize::MAX,
+                                    &[
+                                        segment("3700", "NM1", O, 1),
+                                        segment("3800", "N2", O, 1),
+                                        segment("3900", "N3", O, usize::MAX),
+                                        segment("4000", "N4", O, 1),
+                                        segment("4100", "PER", O, 1),
+                                    ],
+                                ),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "2400",
+                    usize::MAX,
+                    &[
+                        segment("4200", "LC", O, 1),
+                        segment("4300", "AMT", O, usize::MAX),
+                        segment("4400", "DTP", O, usize::MAX),
+                        segment("4500", "REF", O, usize::MAX),
+                        segment_loop(
+                            "2410",
+                            usize::MAX,
+                            &[
+                                segment("4600", "BEN", O, 1),
+                                segment("4700", "NM1", O, 1),
+                                segment("4800", "N1", O, 1),
+                                segment("4900", "N2", O, 1),
+                                segment("5000", "N3", O, 1),
+                                segment("5100", "N4", O, 1),
+                                segment("5200", "DMG", O, 1),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "2500",
+                    usize::MAX,
+                    &[
+                        segment("5300", "FSA", O, 1),
+                        segment("5400", "AMT", O, usize::MAX),
+                        segment("5500", "DTP", O, usize::MAX),
+                        segment("5600", "REF", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "2600",
+                    usize::MAX,
+                    &[
+                        segment("5700", "RP", O, 1),
+                        segment("5800", "DTP", O, usize::MAX),
+                        segment("5900", "REF", O, usize::MAX),
+                        segment("6000", "INV", O, usize::MAX),
+                        segment("6100", "AMT", O, usize::MAX),
+                        segment("6200", "QTY", O, usize::MAX),
+                        segment("6300", "K3", O, usize::MAX),
+                        segment("6400", "REL", O, 1),
+                        segment_loop(
+                            "2610",
+                            usize::MAX,
+                            &[
+                                segment("6500", "NM1", O, 1),
+                                segment("6600", "N2", O, 1),
+                                segment("6700", "DMG", O, 1),
+                                segment("6800", "BEN", O, 1),
+                                segment("6900", "REF", O, usize::MAX),
+                                segment_loop(
+                                    "2620",
+                                    usize::MAX,
+                                    &[
+                                        segment("7000", "NX1", O, 1),
+                                        segment("7100", "N3", O, 1),
+                                        segment("7200", "N4", O, 1),
+                                        segment("7300", "DTP", O, usize::MAX),
+                                    ],
+                                ),
+                            ],
+                        ),
+                        segment_loop(
+                            "2630",
+                            usize::MAX,
+                            &[
+                                segment("7400", "FC", O, 1),
+                                segment("7500", "DTP", O, usize::MAX),
+                                segment_loop(
+                                    "2640",
+                                    usize::MAX,
+                                    &[
+                                        segment("7600", "INV", O, 1),
+                                        segment("7700", "DTP", O, usize::MAX),
+                                        segment("7800", "QTY", O, usize::MAX),
+                                        segment("7900", "ENT", O, usize::MAX),
+                                        segment("8000", "REF", O, usize::MAX),
+                                        segment("8100", "AMT", O, usize::MAX),
+                                        segment("8200", "K3", O, usize::MAX),
+                                    ],
+                                ),
+                            ],
+                        ),
+                        segment_loop(
+                            "2650",
+                            usize::MAX,
+                            &[
+                                segment("8300", "AIN", O, 1),
+                                segment("8400", "QTY", O, usize::MAX),
+                                segment("8500", "DTP", O, usize::MAX),
+                            ],
+                        ),
+                    ],
+                ),
+                segment("8600", "LS", O, 1),
+                segment_loop(
+                    "2700",
+                    usize::MAX,
+                    &[
+                        segment("8700", "LX", O, 1),
+                        segment_loop(
+                            "2750",
+                            usize::MAX,
+                            &[
+                                segment("8800", "N1", O, 1),
+                                segment("8900", "REF", M, 1),
+                                segment("9000", "DTP", O, 1),
+                            ],
+                        ),
+                    ],
+                ),
+                segment("9100", "LE", O, 1),
+            ],
+        ),
+        segment("0100", "SE", M, 1),
+    ],
+    _835 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "BPR", M, 1),
+        segment("0300", "NTE", O, usize::MAX),
+        segment("0400", "TRN", O, 1),
+        segment("0500", "CUR", O, 1),
+        segment("0600", "REF", O, usize::MAX),
+        segment("0700", "DTM", O, usize::MAX),
+        segment_loop(
+            "1000",
+            200,
+            &[
+                segment("0800", "N1", O, 1),
+                segment("0900", "N2", O, usize::MAX),
+                segment("1000", "N3", O, usize::MAX),
+                segment("1100", "N4", O, 1),
+                segment("1200", "REF", O, usize::MAX),
+                segment("1300", "PER", O, usize::MAX),
+                segment("1400", "RDM", O, 1),
+                segment("1500", "DTM", O, 1),
+            ],
+        ),
+        segment_loop(
+            "2000",
+            usize::MAX,
+            &[
+                segment("0030", "LX", O, 1),
+                segment("0050", "TS3", O, 1),
+                segment("0070", "TS2", O, 1),
+                segment_loop(
+                    "2100",
+                    usize::MAX,
+                    &[
+                        segment("0100", "CLP", M, 1),
+                        segment("0200", "CAS", O, 99),
+                        segment("0300", "NM1", M, 9),
+                        segment("0330", "MIA", O, 1),
+                        segment("0350", "MOA", O, 1),
+                        segment("0400", "REF", O, 99),
+                        segment("0500", "DTM", O, 9),
+                        segment("0600", "PER", O, 3),
+                        segment("0620", "AMT", O, 20),
+                        segment("0640", "QTY", O, 20),
+                        segment_loop(
+                            "2110",
+                            999,
+                            &[
+                                segment("0700", "SVC", O, 1),
+                                segment("0800", "DTM", O, 9),
+                                segment("0900", "CAS", O, 99),
+                                segment("1000", "REF", O, 99),
+                                segment("1100", "AMT", O, 20),
+                                segment("1200", "QTY", O, 20),
+                                segment("1300", "LQ", O, 99),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("0100", "PLB", O, usize::MAX),
+        segment("0200", "SE", M, 1),
+    ],
+    _837 => [
+        segment("0050", "ST", M, 1),
+        segment("0100", "BHT", M, 1),
+        segment("0150", "REF", O, 3),
+        segment_loop(
+            "1000",
+            10,
+            &[
+                segment("0200", "NM1", O, 1),
+                segment("0250", "N2", O, 2),
+                segment("0300", "N3", O, 2),
+                segment("0350", "N4", O, 1),
+                segment("0400", "REF", O, 2),
+                segment("0450", "PER", O, 2),
+            ],
+        ),
+        segment_loop(
+            "2000",
+            usize::MAX,
+            &[
+                segment("0010", "HL", M, 1),
+                segment("0030", "PRV", O, 1),
+                segment("0050", "SBR", O, 1),
+                segment("0070", "PAT", O, 1),
+                segment("0090", "DTP", O, 5),
+                segment("0100", "CUR", O, 1),
+                segment_loop(
+                    "2010",
+                    10,
+                    &[
+                        segment("0150", "NM1", O, 1),
+                        segment("0200", "N2", O, 2),
+                        segment("0250", "N3", O, 2),
+                        segment("0300", "N4", O, 1),
+                        segment("0320", "DMG", O, 1),
+                        segment("0350", "REF", O, 20),
+                        segment("0400", "PER", O, 2),
+                    ],
+                ),
+                segment_loop(
+                    "2300",
+                    100,
+                    &[
+                        segment("1300", "CLM", O, 1),
+                        segment("1350", "DTP", O, 150),
+                        segment("1400", "CL1", O, 1),
+                        segment("1450", "DN1", O, 1),
+                        segment("1500", "DN2", O, 35),
+                        segment("1550", "PWK", O, 10),
+                        segment("1600", "CN1", O, 1),
+                        segment("1650", "DSB", O, 1),
+                        segment("1700", "UR", O, 1),
+                        segment("1750", "AMT", O, 40),
+                        segment("1800", "REF", O, 30),
+                        segment("1850", "K3", O, 10),
+                        segment("1900", "NTE", O, 20),
+                        segment("1950", "CR1", O, 1),
+                        segment("2000", "CR2", O, 1),
+                        segment("2050", "CR3", O, 1),
+                        segment("2100", "CR4", O, 3),
+                        segment("2150", "CR5", O, 1),
+                        segment("2160", "CR6", O, 1),
+                        segment("2190", "CR8", O, 9),
+                        segment("2200", "CRC", O, 100),
+                        segment("2310", "HI", O, 25),
+                        segment("2410", "QTY", O, 10),
+                        segment("2415", "HCP", O, 1),
+                        segment_loop(
+                            "2305",
+                            6,
+                            &[
+                                segment("2420", "CR7", O, 1),
+                                segment("2430", "HSD", O, 12),
+                            ],
+                        ),
+                        segment_loop(
+                            "2310",
+                            9,
+                            &[
+                                segment("2500", "NM1", O, 1),
+                                segment("2550", "PRV", O, 1),
+                                segment("2600", "N2", O, 2),
+                                segment("2650", "N3", O, 2),
+                                segment("2700", "N4", O, 1),
+                                segment("2710", "REF", O, 20),
+                                segment("2750", "PER", O, 2),
+                            ],
+                        ),
+                        segment_loop(
+                            "2320",
+                            10,
+                            &[
+                                segment("2900", "SBR", O, 1),
+                                segment("2950", "CAS", O, 99),
+                                segment("3000", "AMT", O, 15),
+                                segment("3050", "DMG", O, 1),
+                                segment("3100", "OI", O, 1),
+                                segment("3150", "MIA", O, 1),
+                                segment("3200", "MOA", O, 1),
+                                segment_loop(
+                                    "2330",
+                                    10,
+                                    &[
+                                        segment("3250", "NM1", O, 1),
+                                        segment("3300", "N2", O, 2),
+                                        segment("3320", "N3", O, 2),
+                                        segment("3400", "N4", O, 1),
+                                        segment("3450", "PER", O, 2),
+                                        segment("3500", "DTP", O, 9),
+                                        segment("3550", "REF", O, usize::MAX),
+                                    ],
+                                ),
+                            ],
+                        ),
+                        segment_loop(
+                            "2400",
+                            usize::MAX,
+                            &[
+                                segment("3650", "LX", O, 1),
+                                segment("3700", "SV1", O, 1),
+                                segment("3750", "SV2", O, 1),
+                                segment("3800", "SV3", O, 1),
+                                segment("3820", "TOO", O, 32),
+                                segment("3850", "SV4", O, 1),
+                                segment("4000", "SV5", O, 1),
+                                segment("4050", "SV6", O, 1),
+                                segment("4100", "SV7", O, 1),
+                                segment("4150", "HI", O, 25),
+                                segment("4200", "PWK", O, 10),
+                                segment("4250", "CR1", O, 1),
+                                segment("4300", "CR2", O, 5),
+                                segment("4350", "CR3", O, 1),
+                                segment("4400", "CR4", O, 3),
+                                segment("4450", "CR5", O, 1),
+                                segment("4500", "CRC", O, 3),
+                                segment("4550", "DTP", O, 15),
+                                segment("4600", "QTY", O, 5),
+                                segment("4620", "MEA", O, 20),
+                                segment("4650", "CN1", O, 1),
+                                segment("4700", "REF", O, 30),
+                                segment("4750", "AMT", O, 15),
+                                segment("4800", "K3", O, 10),
+                                segment("4850", "NTE", O, 10),
+                                segment("4880", "PS1", O, 1),
+                                segment("4900", "IMM", O, usize::MAX),
+                                segment("4910", "HSD", O, 1),
+                                segment("4920", "HCP", O, 1),
+                                segment_loop(
+                                    "2410",
+                                    usize::MAX,
+                                    &[
+                                        segment("4930", "LIN", O, 1),
+                                        segment("4940", "CTP", O, 1),
+                                        segment("4950", "REF", O, 1),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "2420",
+                                    10,
+                                    &[
+                                        segment("5000", "NM1", O, 1),
+                                        segment("5050", "PRV", O, 1),
+                                        segment("5100", "N2", O, 2),
+                                        segment("5140", "N3", O, 2),
+                                        segment("5200", "N4", O, 1),
+                                        segment("5250", "REF", O, 20),
+                                        segment("5300", "PER", O, 2),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "2430",
+                                    usize::MAX,
+                                    &[
+                                        segment("5400", "SVD", O, 1),
+                                        segment("5450", "CAS", O, 99),
+                                        segment("5500", "DTP", O, 9),
+                                        segment("5505", "AMT", O, 20),
+                                    ],
+                                ),
+                                segment_loop(
+                                    "2440",
+                                    usize::MAX,
+                                    &[
+                                        segment("5510", "LQ", O, 1),
+                                        segment("5520", "FRM", M, 99),
+                                    ],
+                                ),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("5550", "SE", M, 1),
+    ],
+    _850 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "BEG", M, 1),
+        segment("0300", "CUR", O, 1),
+        segment("0400", "REF", O, usize::MAX),
+        segment("0500", "PER", O, 3),
+        segment("0600", "TAX", O, usize::MAX),
+        segment("0700", "FOB", O, usize::MAX),
+        segment("0800", "CTP", O, usize::MAX),
+        segment("0900", "PAM", O, 10),
+        segment("1000", "CSH", O, 5),
+        segment("1100", "TC2", O, usize::MAX),
+        segment_loop(
+            "SAC",
+            25,
+            &[
+                segment("1200", "SAC", O, 1),
+                segment("1300", "CUR", O, 1),
+            ],
+        ),
+        segment("1400", "ITD", O, usize::MAX),
+        segment("1500", "DIS", O, 20),
+        segment("1600", "INC", O, 1),
+        segment("1700", "DTM", O, 10),
+        segment("1800", "LIN", O, 5),
+        segment("1900", "SI", O, usize::MAX),
+        segment("2000", "PID", O, 200),
+        segment("2100", "MEA", O, 40),
+        segment("2200", "PWK", O, 25),
+        segment("2300", "PKG", O, 200),
+        segment("2400", "TD1", O, 2),
+        segment("2500", "TD5", O, usize::MAX),
+        segment("2600", "TD3", O, 12),
+        segment("2700", "TD4", O, 5),
+        segment("2800", "MAN", O, 10),
+        segment("2900", "PCT", O, usize::MAX),
+        segment("3000", "CTB", O, 5),
+        segment("3100", "TXI", O, usize::MAX),
+        segment_loop(
+            "LDT",
+            12,
+            &[
+                segment("3200", "LDT", O, 1),
+                segment("3300", "QTY", O, usize::MAX),
+                segment("3400", "MTX", O, usize::MAX),
+                segment("3500", "REF", O, 3),
+            ],
+        ),
+        segment_loop(
+            "AMT",
+            usize::MAX,
+            &[
+                segment("3600", "AMT", O, 1),
+                segment("3700", "REF", O, usize::MAX),
+                segment("3800", "DTM", O, 1),
+                segment("3900", "PCT", O, usize::MAX),
+                segment_loop(
+                    "FA1",
+                    usize::MAX,
+                    &[
+                        segment("4000", "FA1", O, 1),
+                        segment("4100", "FA2", M, usize::MAX),
+                    ],
+                ),
+            ],
+        ),
+        segment_loop(
+            "N9",
+            1000,
+            &[
+                segment("4200", "N9", O, 1),
+                segment("4300", "DTM", O, 10),
+                segment("4400", "MTX", O, usize::MAX),
+                segment("4500", "PWK", O, usize::MAX),
+                segment("4600", "EFI", O, usize::MAX),
+            ],
+        ),
+        segment_loop(
+            "N1",
+            200,
+            &[
+                segment("4700", "N1", O, 1),
+                segment("4800", "N2", O, 2),
+                segment("4900", "N3", O, 2),
+                segment("5000", "N4", O, 1),
+                segment("5100", "REF", O, 12),
+                segment("5200", "PER", O, usize::MAX),
+                segment("5300", "SI", O, usize::MAX),
+                segment("5400", "FOB", O, 1),
+                segment("5500", "TD1", O, 1),
+                segment("5600", "TD3", O, 1),
+                segment("5700", "TD4", O, 1),
+                segment("5800", "TD5", O, usize::MAX),
+                segment("5900", "PKG", O, 200),
+                segment("6000", "LDT", O, 1),
+            ],
+        ),
+        segment_loop(
+            "PO1",
+            100000,
+            &[
+                segment("0100", "PO1", M, 1),
+                segment("0200", "LIN", O, usize::MAX),
+                segment("0300", "SI", O, usize::MAX),
+                segment("0400", "CUR", O, 1),
+                segment("0500", "PO3", O, 25),
+                segment("0600", "CTP", O, usize::MAX),
+                segment("0700", "PAM", O, 10),
+                segment("0800", "MEA", O, 40),
+                segment_loop(
+                    "PID",
+                    1000,
+                    &[
+                        segment("0900", "PID", O, 1),
+                        segment("1000", "MEA", O, 10),
+                    ],
+                ),
+                segment("1100", "PWK", O, 25),
+                segment("1200", "PO4", O, 1),
+                segment("1300", "REF", O, usize::MAX),
+                segment("1400", "PER", O, 3),
+                segment_loop(
+                    "SAC",
+                    25,
+                    &[
+                        segment("1500", "SAC", O, 1),
+                        segment("1600", "CUR", O, 1),
+                    ],
+                ),
+                segment("1700", "ITD", O, usize::MAX),
+                segment("1800", "DIS", O, usize::MAX),
+                segment("1900", "INC", O, 1),
+                segment("2000", "DTM", O, 10),
+                segment("2100", "TAX", O, usize::MAX),
+                segment("2200", "FOB", O, usize::MAX),
+                segment("2300", "SDQ", O, 500),
+                segment("2400", "MAN", O, 10),
+                segment("2500", "TD1", O, 1),
+                segment("2600", "TD5", O, usize::MAX),
+                segment("2700", "TD3", O, 10),
+                segment("2800", "TD4", O, 5),
+                segment("2900", "PCT", O, usize::MAX),
+                segment("3000", "CTB", O, 10),
+                segment("3100", "TXI", O, usize::MAX),
+                segment_loop(
+                    "LDT",
+                    12,
+                    &[
+                        segment("3200", "LDT", O, 1),
+                        segment("3300", "QTY", O, usize::MAX),
+                        segment("3400", "MTX", O, usize::MAX),
+                        segment("3500", "REF", O, 3),
+                    ],
+                ),
+                segment_loop(
+                    "QTY",
+                    usize::MAX,
+                    &[
+                        segment("3600", "QTY", O, 1),
+                        segment("3700", "SI", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "SCH",
+                    200,
+                    &[
+                        segment("3800", "SCH", O, 1),
+                        segment("3900", "TD1", O, 2),
+                        segment("4000", "TD5", O, usize::MAX),
+                        segment("4100", "REF", O, usize::MAX),
+                        segment("4200", "LDT", O, 1),
+                    ],
+                ),
+                segment_loop(
+                    "PKG",
+                    200,
+                    &[
+                        segment("4300", "PKG", O, 1),
+                        segment("4400", "MEA", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "N9",
+                    1000,
+                    &[
+                        segment("4500", "N9", O, 1),
+                        segment("4600", "DTM", O, usize::MAX),
+                        segment("4700", "MTX", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "N1",
+                    200,
+                    &[
+                        segment("4800", "N1", O, 1),
+                        segment("4900", "N2", O, 2),
+                        segment("5000", "N3", O, 2),
+                        segment("5100", "N4", O, 1),
+                        segment("5200", "REF", O, 12),
+                        segment("5300", "PER", O, 3),
+                        segment("5400", "DMG", O, 1),
+                    ],
+                ),
+                segment_loop(
+                    "SPI",
+                    usize::MAX,
+                    &[
+                        segment("5500", "SPI", O, 1),
+                        segment("5600", "REF", O, usize::MAX),
+                        segment("5700", "DTM", O, usize::MAX),
+                        segment("5800", "MSG", O, usize::MAX),
+                        segment_loop(
+                            "N1",
+                            20,
+                            &[
+                                segment("5900", "N1", O, 1),
+                                segment("6000", "N3", O, 2),
+                                segment("6100", "N4", O, 1),
+                                segment("6200", "CB1", O, 1),
+                            ],
+                        ),
+                    ],
+                ),
+                segment_loop(
+                    "SLN",
+                    1000,
+                    &[
+                        segment("6300", "SLN", O, 1),
+                        segment("6400", "MTX", O, usize::MAX),
+                        segment("6500", "SI", O, usize::MAX),
+                        segment("6600", "PID", O, 1000),
+                    ],
+                ),
+                segment_loop(
+                    "ADV",
+                    usize::MAX,
+                    &[
+                        segment("6700", "ADV", O, 1),
+                        segment("6800", "DTM", O, usize::MAX),
+                        segment("6900", "MTX", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "LM",
+                    usize::MAX,
+                    &[
+                        segment("7000", "LM", O, 1),
+                        segment("7100", "LQ", M, usize::MAX),
+                    ],
+                ),
+            ],
+        ),
+        segment("0100", "CTT", O, 1),
+        segment("0200", "AMT", O, 1),
+        segment("0300", "SE", M, 1),
+    ],
+    _855 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "BAK", M, 1),
+        segment("0300", "CUR", O, 1),
+        segment("0400", "REF", O, usize::MAX),
+        segment("0500", "PER", O, 3),
+        segment("0600", "TAX", O, usize::MAX),
+        segment("0700", "FOB", O, usize::MAX),
+        segment("0800", "CTP", O, usize::MAX),
+        segment("0900", "PAM", O, 10),
+        segment("1000", "CSH", O, 1),
+        segment_loop(
+            "SAC",
+            25,
+            &[
+                segment("1100", "SAC", O, 1),
+                segment("1200", "CUR", O, 1),
+            ],
+        ),
+        segment("1300", "ITD", O, usize::MAX),
+        segment("1400", "DIS", O, 20),
+        segment("1500", "INC", O, 1),
+        segment("1600", "DTM", O, 10),
+        segment("1700", "LDT", O, 12),
+        segment("1800", "LIN", O, 5),
+        segment("1900", "SI", O, usize::MAX),
+        segment("2000", "PID", O, 200),
+        segment("2100", "MEA", O, 40),
+        segment("2200", "PWK", O, 25),
+        segment("2300", "PKG", O, 200),
+        segment("2400", "TD1", O, 2),
+        segment("2500", "TD5", O, usize::MAX),
+        segment("2600", "TD3", O, 12),
+        segment("2700", "TD4", O, 5),
+        segment("2800", "MAN", O, 10),
+        segment("2900", "TXI", O, usize::MAX),
+        segment("3000", "CTB", O, 5),
+        segment_loop(
+            "N9",
+            1000,
+            &[
+                segment("3100", "N9", O, 1),
+                segment("3200", "DTM", O, 10),
+                segment("3300", "MTX", O, usize::MAX),
+                segment("3400", "PWK", O, usize::MAX),
+                segment("3500", "EFI", O, usize::MAX),
+            ],
+        ),
+        segment_loop(
+            "N1",
+            200,
+            &[
+                segment("3600", "N1", O, 1),
+                segment("3700", "N2", O, 2),
+                segment("3800", "N3", O, 2),
+                segment("3900", "N4", O, 1),
+                segment("4000", "REF", O, 12),
+                segment("4100", "PER", O, usize::MAX),
+                segment("4200", "SI", O, usize::MAX),
+                segment("4300", "FOB", O, 1),
+                segment("4400", "DTM", O, 1),
+                segment("4500", "LDT", O, 1),
+            ],
+        ),
+        segment_loop(
+            "PO1",
+            100000,
+            &[
+                segment("0100", "PO1", O, 1),
+                segment("0200", "LIN", O, usize::MAX),
+                segment("0300", "SI", O, usize::MAX),
+                segment("0400", "PID", O, 1000),
+                segment("0500", "MEA", O, 40),
+                segment("0600", "PWK", O, 25),
+                segment("0700", "PKG", O, 200),
+                segment("0800", "PO4", O, 1),
+                segment("0900", "REF", O, usize::MAX),
+                segment("1000", "PER", O, 3),
+                segment_loop(
+                    "SAC",
+                    25,
+                    &[
+                        segment("1100", "SAC", O, 1),
+                        segment("1200", "CUR", O, 1),
+                    ],
+                ),
+                segment("1300", "CTP", O, usize::MAX),
+                segment("1400", "PAM", O, 10),
+                segment("1500", "ACK", O, 104),
+                segment("1600", "DTM", O, 10),
+                segment("1700", "CTB", O, 10),
+                segment("1800", "TXI", O, usize::MAX),
+                segment("1900", "LDT", O, 12),
+                segment("2000", "MAN", O, 10),
+                segment("2100", "SDQ", O, 500),
+                segment_loop(
+                    "SCH",
+                    200,
+                    &[
+                        segment("2200", "SCH", O, 1),
+                        segment("2300", "TD1", O, 2),
+                        segment("2400", "TD5", O, usize::MAX),
+                        segment("2500", "REF", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "N9",
+                    1000,
+                    &[
+                        segment("2600", "N9", O, 1),
+                        segment("2700", "DTM", O, usize::MAX),
+                        segment("2800", "MTX", O, usize::MAX),
+                    ],
+                ),
+                segment_loop(
+                    "N1",
+                    200,
+                    &[
+                        segment("2900", "N1", O, 1),
+                        segment("3000", "N2", O, 2),
+                        segment("3100", "N3", O, 2),
+                        segment("3200", "N4", O, 1),
+                        segment("3300", "REF", O, 12),
+                        segment("3400", "PER", O, 3),
+                    ],
+                ),
+                segment_loop(
+                    "LM",
+                    usize::MAX,
+                    &[
+                        segment("3500", "LM", O, 1),
+                        segment("3600", "LQ", M, usize::MAX),
+                    ],
+                ),
+            ],
+        ),
+        segment_loop(
+            "CTT",
+            1,
+            &[
+                segment("0100", "CTT", O, 1),
+                segment("0200", "AMT", O, 1),
+            ],
+        ),
+        segment("0300", "SE", M, 1),
+    ],
+    _999 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "AK1", M, 1),
+        segment_loop(
+            "2000",
+            usize::MAX,
+            &[
+                segment("0300", "AK2", O, 1),
+                segment_loop(
+                    "2100",
+                    usize::MAX,
+                    &[
+                        segment("0400", "IK3", O, 1),
+                        segment("0500", "CTX", O, 10),
+                        segment_loop(
+                            "2110",
+                            99,
+                            &[
+                                segment("0600", "IK4", O, 1),
+                                segment("0700", "CTX", O, 10),
+                            ],
+                        ),
+                    ],
+                ),
+                segment("0800", "IK5", M, 1),
+            ],
+        ),
+        segment("0900", "AK9", M, 1),
+        segment("1000", "SE", M, 1),
+    ],
 }
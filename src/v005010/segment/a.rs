@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AAA")]
 pub struct AAA {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -34,6 +35,7 @@ pub struct AAA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ACT")]
 pub struct ACT {
     #[serde(rename = "01")]
     pub _01: String,
@@ -68,6 +70,7 @@ pub struct ACT {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AD1")]
 pub struct AD1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -94,6 +97,7 @@ pub struct AD1 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AIN")]
 pub struct AIN {
     #[serde(rename = "01")]
     pub _01: String,
@@ -123,7 +127,7 @@ pub struct AIN {
     pub _13: Option<String>,
 }
 
-/// AMT - Monitary Amount Information
+/// AMT - Monetary Amount Information
 #[derive(
     Serialize,
     Deserialize,
@@ -136,6 +140,7 @@ pub struct AIN {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AMT")]
 pub struct AMT {
     #[serde(rename = "01")]
     pub _01: String,
@@ -149,6 +154,7 @@ pub struct AMT {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AK1")]
 pub struct AK1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -160,6 +166,7 @@ pub struct AK1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AK2")]
 pub struct AK2 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -171,6 +178,7 @@ pub struct AK2 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::AK9")]
 pub struct AK9 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -192,6 +200,7 @@ pub struct AK9 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ADX")]
 pub struct ADX {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -207,6 +216,7 @@ pub struct ADX {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ASM")]
 pub struct ASM {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -264,6 +274,7 @@ pub struct ASM {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ATN")]
 pub struct ATN {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -334,6 +345,7 @@ pub struct ATN {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ADV")]
 pub struct ADV {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -364,6 +376,7 @@ pub struct ADV {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ACK")]
 pub struct ACK {
     #[serde(rename = "01")]
     pub _01: String,
@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BEN")]
 pub struct BEN {
     #[serde(rename = "01")]
     pub _01: String,
@@ -43,6 +44,7 @@ pub struct BEN {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BGN")]
 pub struct BGN {
     #[serde(rename = "01")]
     pub _01: String,
@@ -68,6 +70,7 @@ pub struct BGN {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BHT")]
 pub struct BHT {
     #[serde(rename = "01")]
     pub _01: String,
@@ -87,6 +90,7 @@ pub struct BHT {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BPR")]
 pub struct BPR {
     #[serde(rename = "01")]
     pub _01: String,
@@ -145,6 +149,7 @@ pub struct BPR {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BEG")]
 pub struct BEG {
     #[serde(rename = "01")]
     pub _01: String,
@@ -173,6 +178,7 @@ pub struct BEG {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BAK")]
 pub struct BAK {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1,3 +1,4 @@
+use crate::util::impl_composite;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
@@ -6,6 +7,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CAS")]
 pub struct CAS {
     #[serde(rename = "01")]
     pub _01: String,
@@ -60,6 +62,7 @@ pub struct CAS {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CB1")]
 pub struct CB1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -71,6 +74,7 @@ pub struct CB1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CL1")]
 pub struct CL1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -86,6 +90,7 @@ pub struct CL1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CLM")]
 pub struct CLM {
     #[serde(rename = "01")]
     pub _01: String,
@@ -96,7 +101,7 @@ pub struct CLM {
     #[serde(rename = "04")]
     pub _04: Option<String>,
     #[serde(rename = "05")]
-    pub _05: Option<String>,
+    pub _05: Option<C023>,
     #[serde(rename = "06")]
     pub _06: Option<String>,
     #[serde(rename = "07")]
@@ -133,6 +138,7 @@ pub struct CLM {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CLP")]
 pub struct CLP {
     #[serde(rename = "01")]
     pub _01: String,
@@ -168,6 +174,7 @@ pub struct CLP {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CN1")]
 pub struct CN1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -187,6 +194,7 @@ pub struct CN1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::COB")]
 pub struct COB {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -202,6 +210,7 @@ pub struct COB {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CR1")]
 pub struct CR1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -238,6 +247,7 @@ pub struct CR1 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CR2")]
 pub struct CR2 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -278,6 +288,7 @@ pub struct CR2 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CR3")]
 pub struct CR3 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -304,6 +315,7 @@ pub struct CR3 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CR4")]
 pub struct CR4 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -378,6 +390,7 @@ pub struct CR4 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CR5")]
 pub struct CR5 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -430,6 +443,7 @@ pub struct CR5 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CR6")]
 pub struct CR6 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -488,6 +502,7 @@ pub struct CR6 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CR7")]
 pub struct CR7 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -510,6 +525,7 @@ pub struct CR7 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CR8")]
 pub struct CR8 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -544,6 +560,7 @@ pub struct CR8 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CRC")]
 pub struct CRC {
     #[serde(rename = "01")]
     pub _01: String,
@@ -574,6 +591,7 @@ pub struct CRC {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CTP")]
 pub struct CTP {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -612,6 +630,7 @@ pub struct CTP {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CUR")]
 pub struct CUR {
     #[serde(rename = "01")]
     pub _01: String,
@@ -661,6 +680,7 @@ pub struct CUR {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CTX")]
 pub struct CTX {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -697,6 +717,7 @@ pub struct CTX {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CTT")]
 pub struct CTT {
     #[serde(rename = "01")]
     pub _01: String,
@@ -717,6 +738,7 @@ pub struct CTT {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CTB")]
 pub struct CTB {
     /// 688 - Restrictions/Conditions Qualifier
     #[serde(rename = "01")]
@@ -754,6 +776,7 @@ pub struct CTB {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CSH")]
 pub struct CSH {
     /// 563 - Sales Requirement Code
     #[serde(rename = "01")]
@@ -786,3 +809,99 @@ pub struct CSH {
     #[serde(rename = "10")]
     pub _10: Option<String>,
 }
+
+/// C003 - Composite Medical Procedure Identifier
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct C003 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+}
+
+impl_composite!(C003 {
+    _01,
+    _02,
+    _03,
+    _04,
+    _05,
+    _06,
+    _07,
+    _08
+});
+
+/// C022 - Health Care Code Information
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct C022 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+    #[serde(rename = "04")]
+    pub _04: Option<String>,
+    #[serde(rename = "05")]
+    pub _05: Option<String>,
+    #[serde(rename = "06")]
+    pub _06: Option<String>,
+    #[serde(rename = "07")]
+    pub _07: Option<String>,
+    #[serde(rename = "08")]
+    pub _08: Option<String>,
+    #[serde(rename = "09")]
+    pub _09: Option<String>,
+}
+
+impl_composite!(C022 {
+    _01,
+    _02,
+    _03,
+    _04,
+    _05,
+    _06,
+    _07,
+    _08,
+    _09
+});
+
+/// C023 - Health Care Service Location Information
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct C023 {
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+}
+
+impl_composite!(C023 { _01, _02, _03 });
+
+/// C056 - Composite Race or Ethnicity Information
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct C056 {
+    /// 1109 - Race or Ethnicity Code
+    #[serde(rename = "01")]
+    pub _01: Option<String>,
+    /// 1270 - Code List Qualifier Code
+    #[serde(rename = "02")]
+    pub _02: Option<String>,
+    /// 1271 - Industry Code
+    #[serde(rename = "03")]
+    pub _03: Option<String>,
+}
+
+impl_composite!(C056 { _01, _02, _03 });
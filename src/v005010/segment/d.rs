@@ -1,3 +1,5 @@
+use super::C056;
+use crate::util::Repeated;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
@@ -15,6 +17,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DMG")]
 pub struct DMG {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -25,7 +28,7 @@ pub struct DMG {
     #[serde(rename = "04")]
     pub _04: Option<String>,
     #[serde(rename = "05")]
-    pub _05: Option<String>,
+    pub _05: Option<Repeated<C056>>,
     #[serde(rename = "06")]
     pub _06: Option<String>,
     #[serde(rename = "07")]
@@ -53,6 +56,7 @@ pub struct DMG {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DN1")]
 pub struct DN1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -77,6 +81,7 @@ pub struct DN1 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DN2")]
 pub struct DN2 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -105,6 +110,7 @@ pub struct DN2 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DSB")]
 pub struct DSB {
     #[serde(rename = "01")]
     pub _01: String,
@@ -137,6 +143,7 @@ pub struct DSB {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DTM")]
 pub struct DTM {
     #[serde(rename = "01")]
     pub _01: String,
@@ -165,6 +172,7 @@ pub struct DTM {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DTP")]
 pub struct DTP {
     #[serde(rename = "01")]
     pub _01: String,
@@ -178,6 +186,7 @@ pub struct DTP {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DED")]
 pub struct DED {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -244,6 +253,7 @@ pub struct DED {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DIS")]
 pub struct DIS {
     #[serde(rename = "01")]
     pub _01: Option<String>,
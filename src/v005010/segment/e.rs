@@ -1,3 +1,4 @@
+use crate::util::Repeated;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
@@ -15,6 +16,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::EC")]
 pub struct EC {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -43,6 +45,7 @@ pub struct EC {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ENT")]
 pub struct ENT {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -68,9 +71,10 @@ pub struct ENT {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::EQ")]
 pub struct EQ {
     #[serde(rename = "01")]
-    pub _01: String,
+    pub _01: Repeated<String>,
     #[serde(rename = "02")]
     pub _02: Option<String>,
 }
@@ -79,13 +83,14 @@ pub struct EQ {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::EB")]
 pub struct EB {
     #[serde(rename = "01")]
     pub _01: Option<String>,
     #[serde(rename = "02")]
     pub _02: Option<String>,
     #[serde(rename = "03")]
-    pub _03: Option<String>,
+    pub _03: Option<Repeated<String>>,
     #[serde(rename = "04")]
     pub _04: Option<String>,
     #[serde(rename = "05")]
@@ -136,6 +141,7 @@ pub struct EB {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::EM")]
 pub struct EM {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -193,6 +199,7 @@ pub struct EM {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::EMS")]
 pub struct EMS {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -259,6 +266,7 @@ pub struct EMS {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::EFI")]
 pub struct EFI {
     /// 783 - Security Level Code
     #[serde(rename = "01")]
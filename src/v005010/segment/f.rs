@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::FC")]
 pub struct FC {
     #[serde(rename = "01")]
     pub _01: String,
@@ -41,6 +42,7 @@ pub struct FC {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::FRM")]
 pub struct FRM {
     #[serde(rename = "01")]
     pub _01: String,
@@ -67,6 +69,7 @@ pub struct FRM {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::FSA")]
 pub struct FSA {
     #[serde(rename = "01")]
     pub _01: String,
@@ -92,6 +95,7 @@ pub struct FSA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::FA1")]
 pub struct FA1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -103,6 +107,7 @@ pub struct FA1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::FA2")]
 pub struct FA2 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -123,6 +128,7 @@ pub struct FA2 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::FOB")]
 pub struct FOB {
     #[serde(rename = "01")]
     pub _01: String,
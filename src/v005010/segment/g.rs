@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::GE")]
 pub struct GE {
     #[serde(rename = "01")]
     pub _01: String,
@@ -35,6 +36,7 @@ pub struct GE {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::GS")]
 pub struct GS {
     #[serde(rename = "01")]
     pub _01: String,
@@ -58,6 +60,7 @@ pub struct GS {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::G53")]
 pub struct G53 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1,3 +1,4 @@
+use super::C022;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
@@ -15,6 +16,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::HCP")]
 pub struct HCP {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -61,6 +63,7 @@ pub struct HCP {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::HCR")]
 pub struct HCR {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -83,6 +86,7 @@ pub struct HCR {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::HD")]
 pub struct HD {
     #[serde(rename = "01")]
     pub _01: String,
@@ -121,31 +125,32 @@ pub struct HD {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::HI")]
 pub struct HI {
     #[serde(rename = "01")]
-    pub _01: String,
+    pub _01: C022,
     #[serde(rename = "02")]
-    pub _02: Option<String>,
+    pub _02: Option<C022>,
     #[serde(rename = "03")]
-    pub _03: Option<String>,
+    pub _03: Option<C022>,
     #[serde(rename = "04")]
-    pub _04: Option<String>,
+    pub _04: Option<C022>,
     #[serde(rename = "05")]
-    pub _05: Option<String>,
+    pub _05: Option<C022>,
     #[serde(rename = "06")]
-    pub _06: Option<String>,
+    pub _06: Option<C022>,
     #[serde(rename = "07")]
-    pub _07: Option<String>,
+    pub _07: Option<C022>,
     #[serde(rename = "08")]
-    pub _08: Option<String>,
+    pub _08: Option<C022>,
     #[serde(rename = "09")]
-    pub _09: Option<String>,
+    pub _09: Option<C022>,
     #[serde(rename = "10")]
-    pub _10: Option<String>,
+    pub _10: Option<C022>,
     #[serde(rename = "11")]
-    pub _11: Option<String>,
+    pub _11: Option<C022>,
     #[serde(rename = "12")]
-    pub _12: Option<String>,
+    pub _12: Option<C022>,
 }
 
 /// HL - Hierarchical Level
@@ -161,6 +166,7 @@ pub struct HI {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::HL")]
 pub struct HL {
     #[serde(rename = "01")]
     pub _01: String,
@@ -185,6 +191,7 @@ pub struct HL {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::HLH")]
 pub struct HLH {
     #[serde(rename = "01")]
     pub _01: String,
@@ -215,6 +222,7 @@ pub struct HLH {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::HSD")]
 pub struct HSD {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1,3 +1,4 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
@@ -15,6 +16,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ICM")]
 pub struct ICM {
     #[serde(rename = "01")]
     pub _01: String,
@@ -43,6 +45,7 @@ pub struct ICM {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IDC")]
 pub struct IDC {
     #[serde(rename = "01")]
     pub _01: String,
@@ -63,8 +66,18 @@ pub struct IDC {
 /// 01 | I16 | Number of Included Functional Groups | 1 | M | N0 | 1/5
 /// 02 | I12 | Interchange Control Number | 1 | M | N0 | 9/9
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IEA")]
 pub struct IEA {
     /// I16 - Number of Included Functional Groups
     ///
@@ -72,6 +85,13 @@ pub struct IEA {
     /// - TYPE=N0
     /// - MIN=1
     /// - MAX=5
+    #[validate(
+        length(min = 1, message = "IEA-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 5>",
+            message = "IEA-01 must be a number of 1-5 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// I12 - Interchange Control Number
@@ -80,6 +100,13 @@ pub struct IEA {
     /// - TYPE=N0
     /// - MIN=9
     /// - MAX=9
+    #[validate(
+        length(min = 1, message = "IEA-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "IEA-02 must be a number of 9 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -97,6 +124,7 @@ pub struct IEA {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IMM")]
 pub struct IMM {
     #[serde(rename = "01")]
     pub _01: String,
@@ -125,6 +153,7 @@ pub struct IMM {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::INC")]
 pub struct INC {
     #[serde(rename = "01")]
     pub _01: String,
@@ -153,6 +182,7 @@ pub struct INC {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::INS")]
 pub struct INS {
     #[serde(rename = "01")]
     pub _01: String,
@@ -203,6 +233,7 @@ pub struct INS {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::INV")]
 pub struct INV {
     #[serde(rename = "01")]
     pub _01: String,
@@ -254,6 +285,7 @@ pub struct INV {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ISA")]
 pub struct ISA {
     /// I01 - Authorization Information Qualifier
     ///
@@ -261,7 +293,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
-    #[validate(length(equal = 2))]
+    #[validate(
+        length(min = 1, message = "ISA-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// I02 - Authorization Information
@@ -270,7 +308,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=10
     /// - MAX=10
-    #[validate(length(equal = 10, message = "I02 must be 10 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-02 is mandatory"),
+        custom(
+            function = "datatype::string::<10, 10>",
+            message = "ISA-02 must be 10 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
     /// I03 - Security Information Qualifier
@@ -279,7 +323,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
-    #[validate(length(equal = 2))]
+    #[validate(
+        length(min = 1, message = "ISA-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-03 must be 2 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
     /// I04 - Security Information
@@ -288,7 +338,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=10
     /// - MAX=10
-    #[validate(length(equal = 10, message = "I04 must be 10 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-04 is mandatory"),
+        custom(
+            function = "datatype::string::<10, 10>",
+            message = "ISA-04 must be 10 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
     /// I05 - Interchange ID Qualifier
@@ -297,6 +353,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
+    #[validate(
+        length(min = 1, message = "ISA-05 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-05 must be 2 characters"
+        )
+    )]
     #[serde(rename = "05")]
     pub _05: String,
     /// I06 - Interchange Sender ID
@@ -305,6 +368,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=15
     /// - MAX=15
+    #[validate(
+        length(min = 1, message = "ISA-06 is mandatory"),
+        custom(
+            function = "datatype::string::<15, 15>",
+            message = "ISA-06 must be 15 characters"
+        )
+    )]
     #[serde(rename = "06")]
     pub _06: String,
     /// I05 - Interchange ID Qualifier
@@ -313,6 +383,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
+    #[validate(
+        length(min = 1, message = "ISA-07 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-07 must be 2 characters"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
     /// I07 - Interchange Receiver ID
@@ -321,6 +398,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=15
     /// - MAX=15
+    #[validate(
+        length(min = 1, message = "ISA-08 is mandatory"),
+        custom(
+            function = "datatype::string::<15, 15>",
+            message = "ISA-08 must be 15 characters"
+        )
+    )]
     #[serde(rename = "08")]
     pub _08: String,
     /// I08 - Interchange Date
@@ -329,6 +413,13 @@ pub struct ISA {
     /// - TYPE=DT
     /// - MIN=6
     /// - MAX=6
+    #[validate(
+        length(min = 1, message = "ISA-09 is mandatory"),
+        custom(
+            function = "datatype::date::<6, 6>",
+            message = "ISA-09 must be a date of 6 digits"
+        )
+    )]
     #[serde(rename = "09")]
     pub _09: String,
     /// I09 - Interchange Time
@@ -337,6 +428,13 @@ pub struct ISA {
     /// - TYPE=TM
     /// - MIN=4
     /// - MAX=4
+    #[validate(
+        length(min = 1, message = "ISA-10 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 4>",
+            message = "ISA-10 must be a time of 4 digits"
+        )
+    )]
     #[serde(rename = "10")]
     pub _10: String,
     /// I10 - Interchange Control Standards Identifier
@@ -345,6 +443,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=1
     /// - MAX=1
+    #[validate(
+        length(min = 1, message = "ISA-11 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-11 must be 1 character"
+        )
+    )]
     #[serde(rename = "11")]
     pub _11: String,
     /// I11 - Interchange Control Version Number
@@ -353,6 +458,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=5
     /// - MAX=5
+    #[validate(
+        length(min = 1, message = "ISA-12 is mandatory"),
+        custom(
+            function = "datatype::string::<5, 5>",
+            message = "ISA-12 must be 5 characters"
+        )
+    )]
     #[serde(rename = "12")]
     pub _12: String,
     /// I12 - Interchange Control Number
@@ -361,6 +473,13 @@ pub struct ISA {
     /// - TYPE=N0
     /// - MIN=9
     /// - MAX=9
+    #[validate(
+        length(min = 1, message = "ISA-13 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "ISA-13 must be a number of 9 digits"
+        )
+    )]
     #[serde(rename = "13")]
     pub _13: String,
     /// I13 - Acknowledgment Requested
@@ -369,6 +488,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=1
     /// - MAX=1
+    #[validate(
+        length(min = 1, message = "ISA-14 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-14 must be 1 character"
+        )
+    )]
     #[serde(rename = "14")]
     pub _14: String,
     /// I14 - Usage Indicator
@@ -377,6 +503,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=1
     /// - MAX=1
+    #[validate(
+        length(min = 1, message = "ISA-15 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-15 must be 1 character"
+        )
+    )]
     #[serde(rename = "15")]
     pub _15: String,
     /// I15 - Component Element Separator
@@ -385,6 +518,13 @@ pub struct ISA {
     /// - TYPE=
     /// - MIN=1
     /// - MAX=1
+    #[validate(
+        length(min = 1, message = "ISA-16 is mandatory"),
+        custom(
+            function = "datatype::length::<1, 1>",
+            message = "ISA-16 must be 1 character"
+        )
+    )]
     #[serde(rename = "16")]
     pub _16: String,
 }
@@ -393,6 +533,7 @@ pub struct ISA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::III")]
 pub struct III {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -410,6 +551,7 @@ pub struct III {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IK3")]
 pub struct IK3 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -425,6 +567,7 @@ pub struct IK3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IK4")]
 pub struct IK4 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -440,6 +583,7 @@ pub struct IK4 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IK5")]
 pub struct IK5 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -459,6 +603,7 @@ pub struct IK5 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IT1")]
 pub struct IT1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -525,6 +670,7 @@ pub struct IT1 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ITD")]
 pub struct ITD {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::K3")]
 pub struct K3 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LC")]
 pub struct LC {
     #[serde(rename = "01")]
     pub _01: String,
@@ -45,6 +46,7 @@ pub struct LC {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LE")]
 pub struct LE {
     #[serde(rename = "01")]
     pub _01: String,
@@ -63,6 +65,7 @@ pub struct LE {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LIN")]
 pub struct LIN {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -141,6 +144,7 @@ pub struct LIN {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LM")]
 pub struct LM {
     // Agency Qualifier Code
     #[serde(rename = "01")]
@@ -163,6 +167,7 @@ pub struct LM {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LQ")]
 pub struct LQ {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -183,6 +188,7 @@ pub struct LQ {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LS")]
 pub struct LS {
     #[serde(rename = "01")]
     pub _01: String,
@@ -201,6 +207,7 @@ pub struct LS {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LUI")]
 pub struct LUI {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -227,6 +234,7 @@ pub struct LUI {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LX")]
 pub struct LX {
     #[serde(rename = "01")]
     pub _01: String,
@@ -245,6 +253,7 @@ pub struct LX {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LDT")]
 pub struct LDT {
     #[serde(rename = "01")]
     pub _01: String,
@@ -260,6 +269,7 @@ pub struct LDT {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LOC")]
 pub struct LOC {
     #[serde(rename = "01")]
     pub _01: Option<String>,
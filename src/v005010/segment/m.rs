@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MEA")]
 pub struct MEA {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -55,6 +56,7 @@ pub struct MEA {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MIA")]
 pub struct MIA {
     #[serde(rename = "01")]
     pub _01: String,
@@ -119,6 +121,7 @@ pub struct MIA {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MOA")]
 pub struct MOA {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -144,6 +147,7 @@ pub struct MOA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MSG")]
 pub struct MSG {
     #[serde(rename = "01")]
     pub _01: String,
@@ -157,6 +161,7 @@ pub struct MSG {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MPI")]
 pub struct MPI {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -187,6 +192,7 @@ pub struct MPI {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MTX")]
 pub struct MTX {
     /// 363 - Note Reference Code
     #[serde(rename = "01")]
@@ -221,6 +227,7 @@ pub struct MTX {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MAN")]
 pub struct MAN {
     /// 88 - Marks and Numbers Qualifier
     #[serde(rename = "01")]
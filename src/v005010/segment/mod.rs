@@ -40,3 +40,60 @@ mod u;
 pub use u::*;
 mod v;
 pub use v::*;
+
+crate::validation::impl_segment_syntax! {
+    N1 => ["R0203", "P0304"],
+    N4 => ["E0207", "C0605", "C0704"],
+    REF => ["R0203"],
+    PER => ["P0304", "P0506", "P0708"],
+    DTM => ["R020305", "C0403", "P0506"],
+    N9 => ["R0203", "C0605"],
+    QTY => ["R0204", "E0204"],
+    MEA => ["R03050608", "C0504", "C0604", "L07030506", "E0803"],
+    TD5 => ["R0204050612", "C0203", "C0708", "C1011", "C1312", "C1413", "C1512"],
+    LIN => ["P0405", "P0607", "P0809", "P1011", "P1213", "P1415", "P1617", "P1819", "P2021", "P2223", "P2425", "P2627", "P2829", "P3031"],
+    DMG => ["P0102", "P1011"],
+    NM1 => ["P0809", "C1110", "C1203"],
+    PRV => ["P0203"],
+    CAS => ["L050607", "C0605", "C0705", "L080910", "C0908", "C1008", "L111213", "C1211", "C1311", "L141516", "C1514", "C1614", "L171819", "C1817", "C1917"],
+    ADV => ["P0607"],
+}
+
+crate::validation::impl_segment_codes! {
+    AK9 => [1 => "715"],
+    AMT => [3 => "478"],
+    BAK => [1 => "353"],
+    BEG => [1 => "353"],
+    BGN => [1 => "353"],
+    BHT => [2 => "353"],
+    BPR => [1 => "305", 3 => "478"],
+    CAS => [1 => "1033"],
+    CLM => [8 => "1073"],
+    CLP => [2 => "1029", 6 => "1032"],
+    CRC => [2 => "1073"],
+    CUR => [1 => "98", 4 => "98", 7 => "98", 10 => "98", 13 => "98", 16 => "98", 19 => "98"],
+    ENT => [2 => "98", 5 => "98"],
+    GS => [7 => "455"],
+    HL => [4 => "736"],
+    IK5 => [1 => "717"],
+    INS => [1 => "1073"],
+    ISA => [14 => "I13", 15 => "I14"],
+    N1 => [1 => "98", 6 => "98"],
+    NM1 => [1 => "98", 2 => "1065"],
+    NX1 => [1 => "98"],
+    PLA => [2 => "98"],
+    PWK => [4 => "98"],
+    SBR => [9 => "1032"],
+    SV1 => [9 => "1073", 11 => "1073", 12 => "1073"],
+    TA1 => [4 => "I17"],
+}
+
+crate::validation::impl_segment_elements! {
+    AAA, ACK, AD1, ADV, AIN, AMT, BAK, BEG, BEN, BGN, CB1, CR2, CR3, CR4, CR5, CR6, CR7, CR8, CRC,
+    CSH, CTB, CTP, CTT, CUR, DIS, DMG, DN1, DN2, DSB, DTM, DTP, EC, EFI, ENT, FC, FOB, FRM, FSA, GE,
+    GS, HCP, HCR, HD, HI, HL, HLH, HSD, ICM, IDC, IEA, IMM, INC, INS, INV, ISA, ITD, K3, LC, LDT,
+    LE, LIN, LM, LQ, LS, LUI, LX, MAN, MEA, MIA, MOA, MTX, N1, N2, N3, N4, NM1, NTE, NX1, OI, PAM,
+    PAT, PER, PKG, PLA, PLB, PM, PO1, PO3, PO4, PRV, PS1, PWK, QTY, RDM, REF, REL, RP, SBR, SCH,
+    SDQ, SI, SPI, ST, STC, SV1, SV2, SV3, SV4, SV5, SV6, SV7, SVC, SVD, TA1, TAX, TC2, TD1, TD3,
+    TD4, TD5, TOO, TRN, TS2, TS3, UM,
+}
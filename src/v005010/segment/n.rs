@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N1")]
 pub struct N1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -43,6 +44,7 @@ pub struct N1 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N2")]
 pub struct N2 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -63,6 +65,7 @@ pub struct N2 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N3")]
 pub struct N3 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -83,6 +86,7 @@ pub struct N3 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N4")]
 pub struct N4 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -113,6 +117,7 @@ pub struct N4 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::NM1")]
 pub struct NM1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -153,6 +158,7 @@ pub struct NM1 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::NTE")]
 pub struct NTE {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -173,6 +179,7 @@ pub struct NTE {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::NX1")]
 pub struct NX1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -190,6 +197,7 @@ pub struct NX1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N9")]
 pub struct N9 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::OI")]
 pub struct OI {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PAT")]
 pub struct PAT {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -49,6 +50,7 @@ pub struct PAT {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PER")]
 pub struct PER {
     #[serde(rename = "01")]
     pub _01: String,
@@ -83,6 +85,7 @@ pub struct PER {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PLA")]
 pub struct PLA {
     #[serde(rename = "01")]
     pub _01: String,
@@ -109,6 +112,7 @@ pub struct PLA {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PLB")]
 pub struct PLB {
     #[serde(rename = "01")]
     pub _01: String,
@@ -153,6 +157,7 @@ pub struct PLB {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PM")]
 pub struct PM {
     #[serde(rename = "01")]
     pub _01: String,
@@ -181,6 +186,7 @@ pub struct PM {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PRV")]
 pub struct PRV {
     #[serde(rename = "01")]
     pub _01: String,
@@ -209,6 +215,7 @@ pub struct PRV {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PS1")]
 pub struct PS1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -231,6 +238,7 @@ pub struct PS1 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PWK")]
 pub struct PWK {
     #[serde(rename = "01")]
     pub _01: String,
@@ -256,6 +264,7 @@ pub struct PWK {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PDR")]
 pub struct PDR {
     #[serde(rename = "01")]
     pub _01: String,
@@ -363,6 +372,7 @@ pub struct PDR {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PDP")]
 pub struct PDP {
     #[serde(rename = "01")]
     pub _01: String,
@@ -470,6 +480,7 @@ pub struct PDP {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PID")]
 pub struct PID {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -495,6 +506,7 @@ pub struct PID {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PCT")]
 pub struct PCT {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -512,6 +524,7 @@ pub struct PCT {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PKD")]
 pub struct PKD {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -569,6 +582,7 @@ pub struct PKD {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PEN")]
 pub struct PEN {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -626,6 +640,7 @@ pub struct PEN {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PYD")]
 pub struct PYD {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -692,6 +707,7 @@ pub struct PYD {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PO1")]
 pub struct PO1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -758,6 +774,7 @@ pub struct PO1 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PO3")]
 pub struct PO3 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -786,6 +803,7 @@ pub struct PO3 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PO4")]
 pub struct PO4 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -838,6 +856,7 @@ pub struct PO4 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PAM")]
 pub struct PAM {
     /// 673 - Quantity Qualifier
     #[serde(rename = "01")]
@@ -899,6 +918,7 @@ pub struct PAM {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PKG")]
 pub struct PKG {
     /// 349 - Item Description Type
     #[serde(rename = "01")]
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
-/// QTY - Beginning Segment
+/// QTY - Quantity Information
 #[derive(
     Serialize,
     Deserialize,
@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::QTY")]
 pub struct QTY {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1,3 +1,4 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
@@ -15,6 +16,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::RDM")]
 pub struct RDM {
     #[serde(rename = "01")]
     pub _01: String,
@@ -50,6 +52,7 @@ pub struct RDM {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::REF")]
 pub struct REF {
     /// 128 - Reference Identification Qualifier
     ///
@@ -57,7 +60,13 @@ pub struct REF {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=3
-    #[validate(length(min = 2, max = 3))]
+    #[validate(
+        length(min = 1, message = "REF-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 3>",
+            message = "REF-01 must be 2-3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 127 - Reference Identification
@@ -66,7 +75,10 @@ pub struct REF {
     /// - TYPE=AN
     /// - MIN=1
     /// - MAX=50
-    #[validate(length(min = 1, max = 50))]
+    #[validate(custom(
+        function = "datatype::string::<1, 50>",
+        message = "REF-02 must be 1-50 characters"
+    ))]
     #[serde(rename = "02")]
     pub _02: Option<String>,
     /// 352 - Description
@@ -75,7 +87,10 @@ pub struct REF {
     /// - TYPE=AN
     /// - MIN=1
     /// - MAX=80
-    #[validate(length(min = 1, max = 80))]
+    #[validate(custom(
+        function = "datatype::string::<1, 80>",
+        message = "REF-03 must be 1-80 characters"
+    ))]
     #[serde(rename = "03")]
     pub _03: Option<String>,
     /// C040 - Reference Identifier
@@ -101,6 +116,7 @@ pub struct REF {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::REL")]
 pub struct REL {
     #[serde(rename = "01")]
     pub _01: String,
@@ -121,6 +137,7 @@ pub struct REL {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::RP")]
 pub struct RP {
     #[serde(rename = "01")]
     pub _01: String,
@@ -146,6 +163,7 @@ pub struct RP {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::RMR")]
 pub struct RMR {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -169,6 +187,7 @@ pub struct RMR {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::RPA")]
 pub struct RPA {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -186,6 +205,7 @@ pub struct RPA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::RYL")]
 pub struct RYL {
     #[serde(rename = "01")]
     pub _01: Option<String>,
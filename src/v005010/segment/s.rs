@@ -1,3 +1,4 @@
+use super::C003;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
@@ -15,6 +16,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SBR")]
 pub struct SBR {
     #[serde(rename = "01")]
     pub _01: String,
@@ -40,6 +42,7 @@ pub struct SBR {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SE")]
 pub struct SE {
     #[serde(rename = "01")]
     pub _01: String,
@@ -60,6 +63,7 @@ pub struct SE {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ST")]
 pub struct ST {
     #[serde(rename = "01")]
     pub _01: String,
@@ -82,6 +86,7 @@ pub struct ST {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::STC")]
 pub struct STC {
     /// STC01 - Composite data (e.g., "A2:21:65")
     #[serde(rename = "01")]
@@ -137,9 +142,10 @@ pub struct STC {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SV1")]
 pub struct SV1 {
     #[serde(rename = "01")]
-    pub _01: String,
+    pub _01: C003,
     #[serde(rename = "02")]
     pub _02: Option<String>,
     #[serde(rename = "03")]
@@ -195,11 +201,12 @@ pub struct SV1 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SV2")]
 pub struct SV2 {
     #[serde(rename = "01")]
     pub _01: String,
     #[serde(rename = "02")]
-    pub _02: Option<String>,
+    pub _02: Option<C003>,
     #[serde(rename = "03")]
     pub _03: Option<String>,
     #[serde(rename = "04")]
@@ -231,9 +238,10 @@ pub struct SV2 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SV3")]
 pub struct SV3 {
     #[serde(rename = "01")]
-    pub _01: String,
+    pub _01: C003,
     #[serde(rename = "02")]
     pub _02: Option<String>,
     #[serde(rename = "03")]
@@ -267,6 +275,7 @@ pub struct SV3 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SV4")]
 pub struct SV4 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -319,6 +328,7 @@ pub struct SV4 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SV5")]
 pub struct SV5 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -349,6 +359,7 @@ pub struct SV5 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SV6")]
 pub struct SV6 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -379,6 +390,7 @@ pub struct SV6 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SV7")]
 pub struct SV7 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -407,9 +419,10 @@ pub struct SV7 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SVC")]
 pub struct SVC {
     #[serde(rename = "01")]
-    pub _01: String,
+    pub _01: C003,
     #[serde(rename = "02")]
     pub _02: String,
     #[serde(rename = "03")]
@@ -419,7 +432,7 @@ pub struct SVC {
     #[serde(rename = "05")]
     pub _05: Option<String>,
     #[serde(rename = "06")]
-    pub _06: Option<String>,
+    pub _06: Option<C003>,
     #[serde(rename = "07")]
     pub _07: Option<String>,
 }
@@ -437,6 +450,7 @@ pub struct SVC {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SVD")]
 pub struct SVD {
     #[serde(rename = "01")]
     pub _01: String,
@@ -456,6 +470,7 @@ pub struct SVD {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SD1")]
 pub struct SD1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -513,6 +528,7 @@ pub struct SD1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SLN")]
 pub struct SLN {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -576,6 +592,7 @@ pub struct SLN {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SAC")]
 pub struct SAC {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -642,6 +659,7 @@ pub struct SAC {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SPI")]
 pub struct SPI {
     #[serde(rename = "01")]
     pub _01: String,
@@ -668,6 +686,7 @@ pub struct SPI {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SCH")]
 pub struct SCH {
     /// 380 - Quantity
     #[serde(rename = "01")]
@@ -706,6 +725,7 @@ pub struct SCH {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SI")]
 pub struct SI {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -764,6 +784,7 @@ pub struct SI {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SDQ")]
 pub struct SDQ {
     /// 355 - Unit or Basis for Measurement Code
     #[serde(rename = "01")]
@@ -1,7 +1,110 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
 
+/// TA1 - Interchange Acknowledgment
+///
+/// To report the status of processing a received interchange header and trailer or the non-delivery by a network provider
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|-------|--------|----|------|-------
+/// 01 | I12 | Interchange Control Number | 1 | M | N0 | 9/9
+/// 02 | I08 | Interchange Date | 1 | M | DT | 6/6
+/// 03 | I09 | Interchange Time | 1 | M | TM | 4/4
+/// 04 | I17 | Interchange Acknowledgment Code | 1 | M | ID | 1/1
+/// 05 | I18 | Interchange Note Code | 1 | M | ID | 3/3
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+#[serde(rename = "$x12::segment::TA1")]
+pub struct TA1 {
+    /// I12 - Interchange Control Number
+    ///
+    /// A control number assigned by the interchange sender
+    /// - TYPE=N0
+    /// - MIN=9
+    /// - MAX=9
+    #[validate(
+        length(min = 1, message = "TA1-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "TA1-01 must be a number of 9 digits"
+        )
+    )]
+    #[serde(rename = "01")]
+    pub _01: String,
+    /// I08 - Interchange Date
+    ///
+    /// Date of the interchange
+    /// - TYPE=DT
+    /// - MIN=6
+    /// - MAX=6
+    #[validate(
+        length(min = 1, message = "TA1-02 is mandatory"),
+        custom(
+            function = "datatype::date::<6, 6>",
+            message = "TA1-02 must be a date of 6 digits"
+        )
+    )]
+    #[serde(rename = "02")]
+    pub _02: String,
+    /// I09 - Interchange Time
+    ///
+    /// Time of the interchange
+    /// - TYPE=TM
+    /// - MIN=4
+    /// - MAX=4
+    #[validate(
+        length(min = 1, message = "TA1-03 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 4>",
+            message = "TA1-03 must be a time of 4 digits"
+        )
+    )]
+    #[serde(rename = "03")]
+    pub _03: String,
+    /// I17 - Interchange Acknowledgment Code
+    ///
+    /// This indicates the status of the receipt of the interchange control structure
+    /// - TYPE=ID
+    /// - MIN=1
+    /// - MAX=1
+    #[validate(
+        length(min = 1, message = "TA1-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "TA1-04 must be 1 character"
+        )
+    )]
+    #[serde(rename = "04")]
+    pub _04: String,
+    /// I18 - Interchange Note Code
+    ///
+    /// This numeric code indicates the error found processing the interchange control structure
+    /// - TYPE=ID
+    /// - MIN=3
+    /// - MAX=3
+    #[validate(
+        length(min = 1, message = "TA1-05 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "TA1-05 must be 3 characters"
+        )
+    )]
+    #[serde(rename = "05")]
+    pub _05: String,
+}
+
 /// TOO - Tooth Identification
 #[derive(
     Serialize,
@@ -15,6 +118,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TOO")]
 pub struct TOO {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -37,6 +141,7 @@ pub struct TOO {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TRN")]
 pub struct TRN {
     #[serde(rename = "01")]
     pub _01: String,
@@ -61,6 +166,7 @@ pub struct TRN {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TS2")]
 pub struct TS2 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -115,6 +221,7 @@ pub struct TS2 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TS3")]
 pub struct TS3 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -170,6 +277,7 @@ pub struct TS3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TXP")]
 pub struct TXP {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -195,6 +303,7 @@ pub struct TXP {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TXI")]
 pub struct TXI {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -261,6 +370,7 @@ pub struct TXI {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TAX")]
 pub struct TAX {
     #[serde(rename = "01")]
     pub _01: String,
@@ -303,6 +413,7 @@ pub struct TAX {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TC2")]
 pub struct TC2 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -323,6 +434,7 @@ pub struct TC2 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TD1")]
 pub struct TD1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -359,6 +471,7 @@ pub struct TD1 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TD3")]
 pub struct TD3 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -395,6 +508,7 @@ pub struct TD3 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TD4")]
 pub struct TD4 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -421,6 +535,7 @@ pub struct TD4 {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::TD5")]
 pub struct TD5 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -15,6 +15,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::UM")]
 pub struct UM {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -62,6 +63,7 @@ pub struct UM {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::UR")]
 pub struct UR {
     #[serde(rename = "01")]
     pub _01: String,
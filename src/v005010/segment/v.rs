@@ -5,6 +5,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::VEH")]
 pub struct VEH {
     #[serde(rename = "01")]
     pub _01: Option<String>,
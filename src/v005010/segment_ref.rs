@@ -0,0 +1,412 @@
+//! Borrowed variants of the segments of the 835, see [`_835Ref`](super::_835Ref).
+
+use super::segment::*;
+use crate::util::segment_ref;
+
+segment_ref! {
+    /// Borrowed [`AMT`]
+    AMTRef => AMT {
+        _01: String,
+        _02: String,
+        _03: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`BPR`]
+    BPRRef => BPR {
+        _01: String,
+        _02: String,
+        _03: String,
+        _04: String,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+        _10: Option<String>,
+        _11: Option<String>,
+        _12: Option<String>,
+        _13: Option<String>,
+        _14: Option<String>,
+        _15: Option<String>,
+        _16: Option<String>,
+        _17: Option<String>,
+        _18: Option<String>,
+        _19: Option<String>,
+        _20: Option<String>,
+        _21: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`CAS`]
+    CASRef => CAS {
+        _01: String,
+        _02: String,
+        _03: String,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+        _10: Option<String>,
+        _11: Option<String>,
+        _12: Option<String>,
+        _13: Option<String>,
+        _14: Option<String>,
+        _15: Option<String>,
+        _16: Option<String>,
+        _17: Option<String>,
+        _18: Option<String>,
+        _19: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`CLP`]
+    CLPRef => CLP {
+        _01: String,
+        _02: String,
+        _03: String,
+        _04: String,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+        _10: Option<String>,
+        _11: Option<String>,
+        _12: Option<String>,
+        _13: Option<String>,
+        _14: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`CUR`]
+    CURRef => CUR {
+        _01: String,
+        _02: String,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+        _10: Option<String>,
+        _11: Option<String>,
+        _12: Option<String>,
+        _13: Option<String>,
+        _14: Option<String>,
+        _15: Option<String>,
+        _16: Option<String>,
+        _17: Option<String>,
+        _18: Option<String>,
+        _19: Option<String>,
+        _20: Option<String>,
+        _21: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`DTM`]
+    DTMRef => DTM {
+        _01: String,
+        _02: Option<String>,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`LQ`]
+    LQRef => LQ {
+        _01: Option<String>,
+        _02: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`LX`]
+    LXRef => LX {
+        _01: String,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`MIA`]
+    MIARef => MIA {
+        _01: String,
+        _02: Option<String>,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+        _10: Option<String>,
+        _11: Option<String>,
+        _12: Option<String>,
+        _13: Option<String>,
+        _14: Option<String>,
+        _15: Option<String>,
+        _16: Option<String>,
+        _17: Option<String>,
+        _18: Option<String>,
+        _19: Option<String>,
+        _20: Option<String>,
+        _21: Option<String>,
+        _22: Option<String>,
+        _23: Option<String>,
+        _24: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`MOA`]
+    MOARef => MOA {
+        _01: Option<String>,
+        _02: Option<String>,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`N1`]
+    N1Ref => N1 {
+        _01: String,
+        _02: Option<String>,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`N2`]
+    N2Ref => N2 {
+        _01: String,
+        _02: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`N3`]
+    N3Ref => N3 {
+        _01: String,
+        _02: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`N4`]
+    N4Ref => N4 {
+        _01: Option<String>,
+        _02: Option<String>,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`NM1`]
+    NM1Ref => NM1 {
+        _01: String,
+        _02: String,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+        _10: Option<String>,
+        _11: Option<String>,
+        _12: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`NTE`]
+    NTERef => NTE {
+        _01: Option<String>,
+        _02: String,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`PER`]
+    PERRef => PER {
+        _01: String,
+        _02: Option<String>,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`PLB`]
+    PLBRef => PLB {
+        _01: String,
+        _02: String,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+        _10: Option<String>,
+        _11: Option<String>,
+        _12: Option<String>,
+        _13: Option<String>,
+        _14: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`QTY`]
+    QTYRef => QTY {
+        _01: String,
+        _02: Option<String>,
+        _03: Option<String>,
+        _04: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`RDM`]
+    RDMRef => RDM {
+        _01: String,
+        _02: Option<String>,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`REF`]
+    REFRef => REF {
+        _01: String,
+        _02: Option<String>,
+        _03: Option<String>,
+        _04: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`SE`]
+    SERef => SE {
+        _01: String,
+        _02: String,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`ST`]
+    STRef => ST {
+        _01: String,
+        _02: String,
+        _03: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`SVC`]
+    SVCRef => SVC {
+        _01: C003,
+        _02: String,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<C003>,
+        _07: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`TRN`]
+    TRNRef => TRN {
+        _01: String,
+        _02: String,
+        _03: Option<String>,
+        _04: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`TS2`]
+    TS2Ref => TS2 {
+        _01: Option<String>,
+        _02: Option<String>,
+        _03: Option<String>,
+        _04: Option<String>,
+        _05: Option<String>,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+        _10: Option<String>,
+        _11: Option<String>,
+        _12: Option<String>,
+        _13: Option<String>,
+        _14: Option<String>,
+        _15: Option<String>,
+        _16: Option<String>,
+        _17: Option<String>,
+        _18: Option<String>,
+        _19: Option<String>,
+    }
+}
+
+segment_ref! {
+    /// Borrowed [`TS3`]
+    TS3Ref => TS3 {
+        _01: String,
+        _02: String,
+        _03: String,
+        _04: String,
+        _05: String,
+        _06: Option<String>,
+        _07: Option<String>,
+        _08: Option<String>,
+        _09: Option<String>,
+        _10: Option<String>,
+        _11: Option<String>,
+        _12: Option<String>,
+        _13: Option<String>,
+        _14: Option<String>,
+        _15: Option<String>,
+        _16: Option<String>,
+        _17: Option<String>,
+        _18: Option<String>,
+        _19: Option<String>,
+        _20: Option<String>,
+        _21: Option<String>,
+        _22: Option<String>,
+        _23: Option<String>,
+        _24: Option<String>,
+    }
+}
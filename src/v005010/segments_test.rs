@@ -1,3 +1,4 @@
+use crate::util::Parser;
 use crate::v005010::*;
 
 #[test]
@@ -0,0 +1,304 @@
+use super::*;
+use crate::util::{Delimiters, Parser};
+
+#[test]
+fn parse_multiple_functional_groups() {
+    let str = r#"ISA*00*          *00*          *ZZ*EMEDNYBAT      *ZZ*ETIN           *110311*0521*^*00501*052127406*0*P*|~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127406*X*005010X231A1~ST*999*4001*005010X231A1~AK1*HC*28*005010X223A2~AK2*837*0028~IK5*A~AK9*A*1*1*1~SE*6*4001~GE*1*52127406~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127407*X*005010X231A1~ST*999*4002*005010X231A1~AK1*HC*29*005010X223A2~AK2*837*0029~IK5*A~AK9*A*1*1*1~SE*6*4002~GE*1*52127407~IEA*2*052127406~"#;
+    let (rest, obj) = Transmission::<_999>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.functional_group.len(), 2);
+    assert_eq!(obj.functional_group[0].gs._06, "52127406");
+    assert_eq!(obj.functional_group[1].gs._06, "52127407");
+    assert_eq!(obj.functional_group[1].segments[0].st._02, "4002");
+    assert_eq!(obj.iea._01, "2");
+}
+
+#[test]
+fn parse_mixed_transaction_sets() {
+    let str = r#"ISA*00*          *00*          *ZZ*EMEDNYBAT      *ZZ*ETIN           *110311*0521*^*00501*052127406*0*P*:~
+GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127406*X*005010X231A1~
+ST*999*4001*005010X231A1~
+AK1*HC*28~
+AK2*837*0028~
+IK5*A~
+AK9*A*1*1*1~
+SE*6*4001~
+ST*997*4002~
+AK1*HC*29~
+AK9*A*1*1*1~
+SE*4*4002~
+GE*2*52127406~
+IEA*1*052127406~
+"#;
+    let (rest, obj) = Transmission::<AnyTransaction>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let sets = &obj.functional_group[0].segments;
+    assert_eq!(sets.len(), 2);
+    assert!(matches!(&sets[0], AnyTransaction::_999(t) if t.st._02 == "4001"));
+    assert!(matches!(&sets[1], AnyTransaction::Raw(t) if t.segments.len() == 4));
+    assert_eq!(sets[1].id(), "997");
+    let s = format!("{obj}");
+    assert_eq!(s, str);
+}
+
+#[test]
+fn read_transmission_streaming() {
+    let str = r#"ISA*00*          *00*          *ZZ*EMEDNYBAT      *ZZ*ETIN           *110311*0521*^*00501*052127406*0*P*:~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127406*X*005010X231A1~ST*999*4001*005010X231A1~AK1*HC*28~AK2*837*0028~IK5*A~AK9*A*1*1*1~SE*6*4001~GE*1*52127406~IEA*1*052127406~"#;
+    let mut reader = TransmissionReader::<_, _999>::new(str.as_bytes());
+    let Some(Ok(Event::Interchange(isa))) = reader.next() else {
+        panic!("expected ISA");
+    };
+    assert_eq!(isa._11, "^");
+    assert_eq!(reader.delimiters().unwrap().repetition, Some('^'));
+    assert!(matches!(reader.next(), Some(Ok(Event::FunctionalGroup(_)))));
+    let Some(Ok(Event::Transaction(obj))) = reader.next() else {
+        panic!("expected a transaction set");
+    };
+    assert_eq!(obj.loop_ak2[0].ak2._02.as_deref(), Some("0028"));
+    assert!(matches!(
+        reader.next(),
+        Some(Ok(Event::FunctionalGroupEnd(_)))
+    ));
+    assert!(matches!(reader.next(), Some(Ok(Event::InterchangeEnd(_)))));
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn validate_envelope() {
+    let str = r#"ISA*00*          *00*          *ZZ*EMEDNYBAT      *ZZ*ETIN           *110311*0521*^*00501*052127406*0*P*:~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127406*X*005010X231A1~ST*999*4001*005010X231A1~AK1*HC*28~AK2*837*0028~IK5*A~AK9*A*1*1*1~SE*6*4001~ST*990*4002~B1*SCAC*12345*20220524*A~SE*2*4002~GE*2*52127406~IEA*1*052127406~"#;
+    let (_, obj) = Transmission::<AnyTransaction>::parse(str).unwrap();
+    let findings = obj.validate_envelope();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].element, "SE01");
+    assert_eq!(findings[0].control_number, "4002");
+    assert_eq!(findings[0].expected, "3");
+}
+
+#[test]
+fn render_with_writer() {
+    use crate::util::writer::{LineEnding, Writer};
+    let str = r#"ISA*00*          *00*          *ZZ*EMEDNYBAT      *ZZ*ETIN           *110311*0521*^*00501*052127406*0*P*:~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127406*X*005010X231A1~ST*999*4001*005010X231A1~AK1*HC*28~AK2*837*0028~IK5*A~AK9*A*1*1*1~SE*6*4001~GE*1*52127406~IEA*1*052127406~"#;
+    let (_, obj) = Transmission::<_999>::parse(str).unwrap();
+    let single_line = Writer::new().line_ending(LineEnding::None);
+    assert_eq!(single_line.to_string(&obj).unwrap(), str);
+
+    let delimiters = Delimiters {
+        element: '|',
+        component: '>',
+        repetition: Some('!'),
+        segment: '~',
+    };
+    let writer = Writer::new()
+        .delimiters(delimiters)
+        .line_ending(LineEnding::CrLf);
+    let rendered = writer.to_string(&obj).unwrap();
+    assert!(rendered.starts_with("ISA|00|          |00|"));
+    assert!(rendered.contains("|!|00501|052127406|0|P|>~\r\nGS|FA|"));
+    assert!(rendered.ends_with("~\r\nIEA|1|052127406~\r\n"));
+    let (_, reparsed) = Transmission::<_999>::parse(&rendered).unwrap();
+    assert_eq!(reparsed.delimiters, delimiters);
+    assert_eq!(reparsed.functional_group, obj.functional_group);
+
+    let mut out = vec![];
+    Writer::new()
+        .line_ending(LineEnding::CrLf)
+        .wrap(80)
+        .write(&mut out, &obj)
+        .unwrap();
+    let wrapped = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = wrapped.split_terminator("\r\n").collect();
+    assert!(lines[..lines.len() - 1].iter().all(|line| line.len() == 80));
+    assert_eq!(lines.concat(), str);
+}
+
+#[test]
+fn render_with_declared_delimiters() {
+    use crate::util::writer::{LineEnding, Writer};
+    let str = r#"ISA|00|          |00|          |ZZ|EMEDNYBAT      |ZZ|ETIN           |110311|0521|!|00501|052127406|0|P|>'GS|FA|EMEDNYBAT|ABCD|20110311|0521|52127406|X|005010X231A1'ST|999|4001|005010X231A1'AK1|HC|28'AK2|837|0028'IK5|A'AK9|A|1|1|1'SE|6|4001'GE|1|52127406'IEA|1|052127406'"#;
+    let (_, obj) = Transmission::<_999>::parse(str).unwrap();
+    let single_line = Writer::new().line_ending(LineEnding::None);
+    assert_eq!(single_line.to_string(&obj).unwrap(), str);
+    // the configured delimiters take precedence
+    let rendered = single_line
+        .delimiters(Delimiters::default())
+        .to_string(&obj)
+        .unwrap();
+    assert!(rendered.starts_with("ISA*00*"));
+    assert!(rendered.ends_with("~IEA*1*052127406~"));
+}
+
+#[test]
+fn render_loop_named_like_segment() {
+    use crate::util::writer::Writer;
+    // segments are tagged explicitly, a loop named like its first segment is walked
+    #[derive(serde::Serialize)]
+    struct LX {
+        lx: crate::v005010::LX,
+        ts3: Option<TS3>,
+    }
+    let value = LX {
+        lx: crate::v005010::LX {
+            _01: "1".to_string(),
+        },
+        ts3: None,
+    };
+    assert_eq!(Writer::new().to_string(&value).unwrap(), "LX*1~\n");
+}
+
+#[test]
+fn parse_with_declared_delimiters_on_other_thread() {
+    let str = r#"ISA|00|          |00|          |ZZ|EMEDNYBAT      |ZZ|ETIN           |110311|0521|!|00501|052127406|0|P|>'GS|FA|EMEDNYBAT|ABCD|20110311|0521|52127406|X|005010X231A1'ST|999|4001|005010X231A1'AK1|HC|28'AK2|837|0028'IK5|A'AK9|A|1|1|1'SE|6|4001'GE|1|52127406'IEA|1|052127406'"#;
+    let (_, obj) = Transmission::<_999>::parse(str).unwrap();
+    let delimiters = obj.delimiters;
+    let set = str.find("ST|").map(|start| &str[start..]).unwrap();
+    // the delimiters are passed along with the input, not taken from the parsing thread
+    let parsed = std::thread::spawn(move || _999::parse_with(set, &delimiters).map(|(_, set)| set))
+        .join()
+        .unwrap()
+        .unwrap();
+    assert_eq!(parsed, obj.functional_group[0].segments[0]);
+    // without them the default delimiters are used
+    assert!(_999::parse(set).is_err());
+}
+
+#[test]
+fn write_segment_by_segment() {
+    use crate::util::writer::Writer;
+    use std::io;
+
+    /// Records each write, failing once `limit` writes are recorded
+    struct Recorder {
+        writes: Vec<String>,
+        limit: usize,
+    }
+    impl io::Write for Recorder {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.writes.len() == self.limit {
+                return Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"));
+            }
+            self.writes.push(String::from_utf8(buf.to_vec()).unwrap());
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let str = r#"ISA*00*          *00*          *ZZ*EMEDNYBAT      *ZZ*ETIN           *110311*0521*^*00501*052127406*0*P*:~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127406*X*005010X231A1~ST*999*4001*005010X231A1~AK1*HC*28~AK2*837*0028~IK5*A~AK9*A*1*1*1~SE*6*4001~GE*1*52127406~IEA*1*052127406~"#;
+    let (_, obj) = Transmission::<_999>::parse(str).unwrap();
+    let mut out = Recorder {
+        writes: vec![],
+        limit: usize::MAX,
+    };
+    Writer::new().write(&mut out, &obj).unwrap();
+    assert_eq!(out.writes.len(), 10);
+    assert_eq!(out.writes[2], "ST*999*4001*005010X231A1~\n");
+    assert_eq!(out.writes.concat(), Writer::new().to_string(&obj).unwrap());
+
+    let mut out = Recorder {
+        writes: vec![],
+        limit: 3,
+    };
+    let err = Writer::new().write(&mut out, &obj).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    assert_eq!(out.writes.len(), 3);
+}
+
+#[test]
+fn acknowledge_interchange() {
+    let str = r#"ISA*00*          *00*          *ZZ*EMEDNYBAT      *ZZ*ETIN           *110311*0521*^*00501*052127406*0*P*:~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127406*X*005010X231A1~ST*999*4001*005010X231A1~AK1*HC*28~AK2*837*0028~IK5*A~AK9*A*1*1*1~SE*6*4001~GE*1*52127406~IEA*1*052127406~"#;
+    let (_, obj) = Transmission::<_999>::parse(str).unwrap();
+    assert_eq!(
+        obj.acknowledge().to_string(),
+        "TA1*052127406*110311*0521*A*000~\n"
+    );
+    let (_, obj) = Transmission::<_999>::parse(&str.replace("*^*00501*", "*U*00501*")).unwrap();
+    let ta1 = obj.acknowledge();
+    assert_eq!((ta1._04.as_str(), ta1._05.as_str()), ("R", "016"));
+    let (_, obj) = Transmission::<_999>::parse(&str.replace("*0*P*", "*0*X*")).unwrap();
+    assert_eq!(obj.acknowledge()._05, "020");
+}
+
+#[test]
+fn build_envelope() {
+    let builder = crate::envelope::EnvelopeBuilder::new()
+        .sender("ZZ", "EMEDNYBAT")
+        .receiver("ZZ", "ETIN")
+        .functional_id("FA")
+        .version("00501", "005010X231A1")
+        .timestamp(
+            chrono::NaiveDate::from_ymd_opt(2011, 3, 11)
+                .unwrap()
+                .and_hms_opt(5, 21, 0)
+                .unwrap(),
+        )
+        .interchange_control_number(52127406)
+        .group_control_number(7);
+    let isa: ISA = builder.isa().unwrap();
+    assert_eq!(isa.to_string(), "ISA*00*          *00*          *ZZ*EMEDNYBAT      *ZZ*ETIN           *110311*0521*^*00501*052127406*0*P*:~\n");
+    let gs: GS = builder.gs().unwrap();
+    assert_eq!(
+        gs.to_string(),
+        "GS*FA*EMEDNYBAT*ETIN*20110311*0521*7*X*005010X231A1~\n"
+    );
+    let ge: GE = builder.ge(2).unwrap();
+    assert_eq!((ge._01.as_str(), ge._02.as_str()), ("2", "7"));
+    let iea: IEA = builder.iea(1).unwrap();
+    assert_eq!((iea._01.as_str(), iea._02.as_str()), ("1", "052127406"));
+}
+
+#[test]
+fn split_and_merge_interchanges() {
+    use crate::envelope::{InMemoryAllocator, SplitLimit};
+    let str = r#"ISA*00*          *00*          *ZZ*EMEDNYBAT      *ZZ*ETIN           *110311*0521*^*00501*052127406*0*P*|~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127406*X*005010X231A1~ST*999*4001*005010X231A1~AK1*HC*28*005010X223A2~AK2*837*0028~IK5*A~AK9*A*1*1*1~SE*6*4001~GE*1*52127406~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127407*X*005010X231A1~ST*999*4002*005010X231A1~AK1*HC*29*005010X223A2~AK2*837*0029~IK5*A~AK9*A*1*1*1~SE*6*4002~GE*1*52127407~IEA*2*052127406~"#;
+    let (_, obj) = Transmission::<_999>::parse(str).unwrap();
+    let mut allocator = InMemoryAllocator::new();
+    let parts = obj
+        .clone()
+        .split(SplitLimit::TransactionSets(1), &mut allocator)
+        .unwrap();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].isa._13, "000000001");
+    assert_eq!(parts[1].isa._13, "000000002");
+    assert_eq!(parts[1].functional_group[0].gs._06, "2");
+    assert_eq!(parts[1].functional_group[0].segments[0].st._02, "0002");
+    assert_eq!(parts[1].functional_group[0].ge._01, "1");
+    assert_eq!(parts[1].iea._01, "1");
+    assert!(parts.iter().all(|part| part.validate_envelope().is_empty()));
+
+    let size = obj.to_string().len();
+    let parts = obj
+        .clone()
+        .split(SplitLimit::Bytes(size), &mut allocator)
+        .unwrap();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].functional_group.len(), 1);
+    assert_eq!(parts[0].functional_group[0].ge._01, "2");
+    let parts = obj
+        .split(SplitLimit::Bytes(size / 2 + 50), &mut allocator)
+        .unwrap();
+    assert_eq!(parts.len(), 2);
+    assert!(parts
+        .iter()
+        .all(|part| part.to_string().len() <= size / 2 + 50));
+
+    let merged = Transmission::merge(parts, &mut allocator).unwrap();
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].functional_group.len(), 1);
+    assert_eq!(merged[0].functional_group[0].segments.len(), 2);
+    assert_eq!(merged[0].iea._01, "1");
+    assert!(merged[0].validate_envelope().is_empty());
+}
+
+#[test]
+fn regroup_keeps_application_partners() {
+    let str = r#"ISA*00*          *00*          *ZZ*EMEDNYBAT      *ZZ*ETIN           *110311*0521*^*00501*052127406*0*P*|~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127406*X*005010X231A1~ST*999*4001*005010X231A1~AK1*HC*28*005010X223A2~AK2*837*0028~IK5*A~AK9*A*1*1*1~SE*6*4001~GE*1*52127406~GS*FA*EMEDNYBAT*EFGH*20110311*0521*52127407*X*005010X231A1~ST*999*4002*005010X231A1~AK1*HC*29*005010X223A2~AK2*837*0029~IK5*A~AK9*A*1*1*1~SE*6*4002~GE*1*52127407~GS*FA*EMEDNYBAT*ABCD*20110311*0521*52127408*X*005010X231A1~ST*999*4003*005010X231A1~AK1*HC*30*005010X223A2~AK2*837*0030~IK5*A~AK9*A*1*1*1~SE*6*4003~GE*1*52127408~IEA*3*052127406~"#;
+    let (_, mut obj) = Transmission::<_999>::parse(str).unwrap();
+    obj.regroup();
+    assert_eq!(obj.functional_group.len(), 2);
+    assert_eq!(obj.functional_group[0].gs._03, "ABCD");
+    assert_eq!(obj.functional_group[0].segments.len(), 2);
+    assert_eq!(obj.functional_group[0].segments[1].st._02, "4003");
+    assert_eq!(obj.functional_group[1].gs._03, "EFGH");
+    assert_eq!(obj.functional_group[1].segments.len(), 1);
+}
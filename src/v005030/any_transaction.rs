@@ -0,0 +1,13 @@
+use crate::envelope;
+use crate::v005030::*;
+
+envelope::any_transaction! {
+    /// Any transaction set of 005030
+    ///
+    /// Parsing dispatches on the Transaction Set Identifier Code (ST01).
+    /// Transaction sets without a typed binding are kept as [`RawTransaction`](crate::raw::RawTransaction).
+    AnyTransaction {
+        /// 404 - Rail Carrier Shipment Information
+        "404" => _404,
+    }
+}
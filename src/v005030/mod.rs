@@ -1,6 +1,8 @@
 //! v005030 repesents all entities of the 005030 specification.
 
-use crate::util::Parser;
+use crate::envelope;
+use crate::util::{reader, Parser};
+pub use any_transaction::*;
 use nom::{
     combinator::{opt, peek},
     multi::many0,
@@ -8,9 +10,9 @@ use nom::{
 };
 pub use segment::*;
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
 use x12_types_macros::DisplayX12;
 
+mod any_transaction;
 mod segment;
 
 #[cfg(test)]
@@ -18,54 +20,136 @@ mod test_404;
 #[cfg(test)]
 mod test_segments;
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
-pub struct Transmission<T> {
-    pub isa: ISA,
-    pub functional_group: Vec<FunctionalGroup<T>>,
-    pub iea: IEA,
-}
+/// The envelope segments of 005030
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct V005030;
 
-impl<'a, T: Default + Parser<&'a str, T, nom::error::Error<&'a str>>>
-    Parser<&'a str, Transmission<T>, nom::error::Error<&'a str>> for Transmission<T>
-{
-    fn parse(input: &'a str) -> IResult<&'a str, Transmission<T>> {
-        let mut output = Transmission::default();
-        let (input, obj) = ISA::parse(input)?;
-        output.isa = obj;
-        // functional group
-        let (input, gs) = GS::parse(input)?;
-        let (input, t_obj) = T::parse(input)?;
-        let (input, ge) = GE::parse(input)?;
-        let fg = FunctionalGroup {
-            gs,
-            segments: vec![t_obj],
-            ge,
-        };
-        output.functional_group.push(fg);
-        let (input, obj) = IEA::parse(input)?;
-        output.iea = obj;
-        Ok((input, output))
-    }
-}
+envelope::impl_version!(V005030, "00503");
 
-impl<T: Display> Display for Transmission<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut lines = vec![];
-        lines.push(format!("{}", self.isa));
-        for fg in &self.functional_group {
-            lines.push(format!("{}", fg.gs));
-            for segment in &fg.segments {
-                lines.push(format!("{segment}"));
-            }
-            lines.push(format!("{}", fg.ge));
-        }
-        lines.push(format!("{}", self.iea));
-        let all = lines.join("");
-        write!(f, "{all}")
-    }
-}
+/// An interchange of 005030, from ISA to IEA
+pub type Transmission<T> = envelope::Transmission<V005030, T>;
+
+/// A functional group of 005030, from GS to GE
+pub type FunctionalGroup<T> = envelope::FunctionalGroup<V005030, T>;
+
+/// Streaming reader yielding the envelope segments and one transaction set at a time
+pub type TransmissionReader<R, T> = reader::TransmissionReader<R, ISA, GS, T, GE, IEA>;
+
+/// Item of a [`TransmissionReader`]
+pub type Event<T> = reader::Event<ISA, GS, T, GE, IEA>;
 
 /// 404 - Rail Carrier Shipment Information
+///
+/// POS | ID | NAME | REQ | MAX | REPEAT
+/// ----|----|------|-----|-----|-------
+/// 0100 | ST | Transaction Set Header | M | 1
+/// 0200 | ZC1 | Beginning Segment for Data Correction or Change | O | 1
+/// 0300 | BX | General Shipment Information | O | 1
+/// 0400 | BNX | Rail Shipment Information | O | 1
+/// 0500 | M3 | Release | M | 1
+/// 0600 | N9 | Extended Reference Information | M | 30
+/// 0700 | CM | Cargo Manifest | O | 2
+/// 0800 | M1 | Insurance | O | 1
+/// 0900 | DTM | Date/Time Reference | O | 5
+/// LOOP ID - N7 | 500
+/// N7 -> 1000 | N7 | Equipment Details | M | 1
+/// N7 -> 1010 | EM | Equipment Characteristics | O | 1
+/// N7 -> LOOP ID - VC | 21
+/// N7 -> VC -> 1100 | VC | Motor Vehicle Control | O | 1
+/// N7 -> VC -> LOOP ID - N1 | 2
+/// N7 -> VC -> N1 -> 1120 | N1 | Party Identification | O | 1
+/// N7 -> VC -> N1 -> 1140 | N3 | Party Location | O | 2
+/// N7 -> VC -> N1 -> 1160 | N4 | Geographic Location | O | 1
+/// N7 -> VC -> N1 -> 1180 | H3 | Special Handling Instructions | O | 1
+/// N7 -> 1300 | M7 | Seal Numbers | O | 5
+/// N7 -> 1400 | N5 | Equipment Ordered | O | 1
+/// N7 -> 1500 | IC | Intermodal Chassis Equipment | O | 1
+/// N7 -> 1600 | IM | Intermodal Movement Information | O | 1
+/// N7 -> 1700 | M12 | In-bond Indifying Information | O | 2
+/// N7 -> LOOP ID - E1 | 2
+/// N7 -> E1 -> 1710 | E1 | Empty Car Disposition - Pended Destination Consignee | O | 1
+/// N7 -> E1 -> 1720 | E4 | Empty Car Disposition - Pended Destination City | O | 1
+/// N7 -> E1 -> 1730 | E5 | Empty Car Disposition - Pended Destination Route | O | 13
+/// N7 -> E1 -> 1740 | PI | Price Authority Information | O | 1
+/// N7 -> 1750 | GA | Price Authority Information | O | 15
+/// N7 -> LOOP ID - REF | 99
+/// N7 -> REF -> 1770 | REF | Party Identification | O | 1
+/// N7 -> REF -> 1780 | N10 | Quantity and Description | O | 15
+/// N7 -> REF -> LOOP ID - N1 | 5
+/// N7 -> REF -> N1 -> 1790 | N1 | Party Identification | O | 1
+/// N7 -> REF -> N1 -> 1800 | N3 | Party Location | O | 1
+/// N7 -> REF -> N1 -> 1820 | N4 | Geographic Location | O | 1
+/// 1850 | NA | Cross-Reference Equipment | O | 10
+/// 1900 | F9 | Origin Station | M | 1
+/// 2000 | D9 | Destination Station | M | 1
+/// LOOP ID - N1 | 10
+/// N1 -> 2100 | N1 | Party Identification | M | 1
+/// N1 -> 2150 | N2 | Additional Name Information | O | 2
+/// N1 -> 2200 | N3 | Party Location | O | 2
+/// N1 -> 2300 | N4 | Geographic Location | O | 1
+/// N1 -> 2350 | REF | Party Identification | O | 2
+/// N1 -> 2400 | PER | Administrative Communication Contract | O | 2
+/// N1 -> 2520 | BL | Billing Information | O | 12
+/// LOOP ID - S1 | 12
+/// S1 -> 4300 | S1 | Stop-off Name | O | 1
+/// S1 -> 4400 | S2 | Stop-off Address | O | 2
+/// S1 -> 4480 | S9 | Stop-off Name | O | 1
+/// S1 -> 4490 | N1 | Party Identification | O | 1
+/// S1 -> 4500 | N2 | Additional Name Information | O | 1
+/// S1 -> 4510 | N3 | Party Location | O | 1
+/// S1 -> 4520 | N4 | Geographic Location | O | 1
+/// S1 -> 4530 | PER | Administrative Communication Contract | O | 1
+/// 4600 | R2 | Route Information | O | 13
+/// 4800 | R9 | Code Identification | O | 1
+/// LOOP ID - E1 | 2
+/// E1 -> 4900 | E1 | Empty Car Disposition - Pended Destination Consignee | O | 1
+/// E1 -> 5000 | E4 | Empty Car Disposition - Pended Destination City | O | 1
+/// E1 -> 5100 | E5 | Empty Car Disposition - Pended Destination Route | O | 13
+/// E1 -> 5110 | PI | Price Authority Information | O | 1
+/// 5200 | H3 | Special Handling Instructions | O | 20
+/// 5300 | PS | Protective Service Instructions | O | 5
+/// LOOP ID - LX | 25
+/// LX -> 5400 | LX | Transaction Set Line Number | M | 1
+/// LX -> 5500 | L5 | Description, Marks and Numbers | M | 15
+/// LX -> LOOP ID - L0 | 25
+/// LX -> L0 -> 5700 | L0 | Line Item - Quantity and Weight | O | 1
+/// LX -> L0 -> 5750 | MEA | Measurements | O | 3
+/// LX -> L0 -> 5800 | L1 | Rates and Charges | O | 10
+/// LX -> L0 -> LOOP ID - PI | 30
+/// LX -> L0 -> PI -> 5900 | PI | Price Authority Information | O | 1
+/// LX -> L0 -> PI -> 5910 | CD | Shipment Condition | O | >1
+/// LX -> 6000 | X1 | Export License | O | 6
+/// LOOP ID - T1 | 64
+/// T1 -> 6100 | T1 | Transit Inbound Origin | O | 1
+/// T1 -> 6200 | T2 | Transit Inbound Lading | O | 30
+/// T1 -> 6300 | T3 | Transit Inbound Route | O | 12
+/// T1 -> 6400 | T6 | Transit Inbound Rates | O | 1
+/// T1 -> 6500 | T8 | Transit Inbound Rates | O | 99
+/// 6600 | L3 | Total Weight and Charges | O | 1
+/// 6700 | LS | Loop Header | O | 1
+/// LOOP ID - LH1 | 100
+/// LH1 -> 6800 | LH1 | Hazardous Identification Information | O | 1
+/// LH1 -> 6900 | LH2 | Hazardous Classification Information | O | 4
+/// LH1 -> 7000 | LH3 | Hazardous Material Shipping Name Information | O | 10
+/// LH1 -> 7100 | LFH | Free-form Hazardous Material Information | O | 20
+/// LH1 -> 7200 | LEP | EPA Required Data | O | 3
+/// LH1 -> 7300 | LH4 | Canadian Dangerous Requirements | O | 1
+/// LH1 -> 7400 | LHT | Hazardous Material Identification Reference Numbers | O | 3
+/// LH1 -> 7500 | LHR | Hazardous Material Identification Reference Numbers | O | 5
+/// LH1 -> 7550 | PER | Administrative Communication Contract | O | 5
+/// LH1 -> LOOP ID - N1 | >1
+/// LH1 -> N1 -> 7560 | N1 | Party Identification | O | 1
+/// LH1 -> N1 -> 7570 | N3 | Party Location | O | >1
+/// LH1 -> N1 -> 7580 | N4 | Geographic Location | O | 1
+/// LH1 -> N1 -> 7590 | PER | Administrative Communication Contract | O | >1
+/// 7600 | LE | Loop Trailer | O | 1
+/// 7700 | PER | Administrative Communication Contract | O | 5
+/// 7800 | LH2 | Hazardous Classification Information | O | 6
+/// 7900 | LHR | Hazardous Material Identification Reference Numbers | O | 1
+/// 8000 | LH6 | Hazardous Certification | O | 5
+/// 8100 | XH | Pro Forma - B13 Information | O | 1
+/// 8200 | X7 | Customs Information | O | 10
+/// 8400 | SE | Transaction Set Trailer | M | 1
 #[derive(Debug, Default, Clone, Serialize, Deserialize, DisplayX12)]
 pub struct _404 {
     pub st: ST,
@@ -105,6 +189,16 @@ pub struct _404 {
 
 impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
     fn parse(input: &'a str) -> IResult<&'a str, _404> {
+        let _context = crate::error::transaction_set(
+            "_404",
+            &[
+                "BL", "BNX", "BX", "CD", "CM", "D9", "DTM", "EM", "F9", "GA", "H3", "IC", "IM",
+                "L0", "L1", "L3", "L5", "LE", "LEP", "LFH", "LH1", "LH2", "LH3", "LH4", "LH6",
+                "LHR", "LHT", "LS", "LX", "M1", "M12", "M3", "M7", "MEA", "N1", "N2", "N3", "N4",
+                "N5", "N7", "N9", "NA", "PER", "PI", "PS", "R2", "R9", "REF", "SE", "ST", "X1",
+                "X7", "XH", "ZC1",
+            ],
+        );
         let mut output = _404::default();
         let (rest, obj) = ST::parse(input)?;
         output.st = obj;
@@ -128,6 +222,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
         let mut loop_n7 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N7::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_404LoopN7");
             let (rest, n7) = N7::parse(loop_rest)?;
             let (rest, em) = opt(EM::parse).parse(rest)?;
             let (rest, m7) = opt(M7::parse).parse(rest)?;
@@ -163,6 +258,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
         let mut loop_n1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_404LoopN1");
             let (rest, n1) = N1::parse(loop_rest)?;
             let (rest, n2) = opt(N2::parse).parse(rest)?;
             let (rest, n3) = opt(N3::parse).parse(rest)?;
@@ -195,6 +291,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
         let mut loop_lx = vec![];
         let mut loop_rest = rest;
         while peek(opt(LX::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_404LoopLX");
             let (rest, lx) = LX::parse(loop_rest)?;
             let (rest, l5) = L5::parse(rest)?;
             let (rest, x1) = opt(X1::parse).parse(rest)?;
@@ -202,6 +299,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
             // loop l0
             let mut loop_l0 = vec![];
             while peek(opt(L0::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_404LoopL0");
                 let (rest, l0) = opt(L0::parse).parse(loop_rest)?;
                 let (rest, mea) = opt(MEA::parse).parse(rest)?;
                 let (rest, l1) = opt(L1::parse).parse(rest)?;
@@ -209,6 +307,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
                 // loop pi
                 let mut loop_pi = vec![];
                 while peek(opt(PI::parse)).parse(loop_rest)?.1.is_some() {
+                    let _context = crate::error::context("_404LoopL0PI");
                     let (rest, pi) = opt(PI::parse).parse(loop_rest)?;
                     let (rest, cd) = many0(CD::parse).parse(rest)?;
                     loop_rest = rest;
@@ -238,6 +337,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
         let mut loop_lh1 = vec![];
         let mut loop_rest = rest;
         while peek(opt(LH1::parse)).parse(loop_rest)?.1.is_some() {
+            let _context = crate::error::context("_404LoopLH1");
             let (rest, lh1) = opt(LH1::parse).parse(loop_rest)?;
             let (rest, lh2) = many0(LH2::parse).parse(rest)?;
             let (rest, lh3) = many0(LH3::parse).parse(rest)?;
@@ -251,6 +351,7 @@ impl<'a> Parser<&'a str, _404, nom::error::Error<&'a str>> for _404 {
             // loop n1
             let mut loop_n1 = vec![];
             while peek(opt(N1::parse)).parse(loop_rest)?.1.is_some() {
+                let _context = crate::error::context("_404LoopLh1N1");
                 let (rest, n1) = opt(N1::parse).parse(loop_rest)?;
                 let (rest, n3) = many0(N3::parse).parse(rest)?;
                 let (rest, n4) = opt(N4::parse).parse(rest)?;
@@ -440,9 +541,194 @@ pub struct _404LoopRefN1 {
     pub n4: Option<N4>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
-pub struct FunctionalGroup<T> {
-    pub gs: GS,
-    pub segments: Vec<T>,
-    pub ge: GE,
+envelope::impl_transaction_set! {
+    _404 => st, se;
+}
+
+crate::validation::impl_transaction_structure! {
+    _404 => [
+        segment("0100", "ST", M, 1),
+        segment("0200", "ZC1", O, 1),
+        segment("0300", "BX", O, 1),
+        segment("0400", "BNX", O, 1),
+        segment("0500", "M3", M, 1),
+        segment("0600", "N9", M, 30),
+        segment("0700", "CM", O, 2),
+        segment("0800", "M1", O, 1),
+        segment("0900", "DTM", O, 5),
+        segment_loop(
+            "N7",
+            500,
+            &[
+                segment("1000", "N7", M, 1),
+                segment("1010", "EM", O, 1),
+                segment_loop(
+                    "VC",
+                    21,
+                    &[
+                        segment("1100", "VC", O, 1),
+                        segment_loop(
+                            "N1",
+                            2,
+                            &[
+                                segment("1120", "N1", O, 1),
+                                segment("1140", "N3", O, 2),
+                                segment("1160", "N4", O, 1),
+                                segment("1180", "H3", O, 1),
+                            ],
+                        ),
+                    ],
+                ),
+                segment("1300", "M7", O, 5),
+                segment("1400", "N5", O, 1),
+                segment("1500", "IC", O, 1),
+                segment("1600", "IM", O, 1),
+                segment("1700", "M12", O, 2),
+                segment_loop(
+                    "E1",
+                    2,
+                    &[
+                        segment("1710", "E1", O, 1),
+                        segment("1720", "E4", O, 1),
+                        segment("1730", "E5", O, 13),
+                        segment("1740", "PI", O, 1),
+                    ],
+                ),
+                segment("1750", "GA", O, 15),
+                segment_loop(
+                    "REF",
+                    99,
+                    &[
+                        segment("1770", "REF", O, 1),
+                        segment("1780", "N10", O, 15),
+                        segment_loop(
+                            "N1",
+                            5,
+                            &[
+                                segment("1790", "N1", O, 1),
+                                segment("1800", "N3", O, 1),
+                                segment("1820", "N4", O, 1),
+                            ],
+                        ),
+                    ],
+                ),
+            ],
+        ),
+        segment("1850", "NA", O, 10),
+        segment("1900", "F9", M, 1),
+        segment("2000", "D9", M, 1),
+        segment_loop(
+            "N1",
+            10,
+            &[
+                segment("2100", "N1", M, 1),
+                segment("2150", "N2", O, 2),
+                segment("2200", "N3", O, 2),
+                segment("2300", "N4", O, 1),
+                segment("2350", "REF", O, 2),
+                segment("2400", "PER", O, 2),
+                segment("2520", "BL", O, 12),
+            ],
+        ),
+        segment_loop(
+            "S1",
+            12,
+            &[
+                segment("4300", "S1", O, 1),
+                segment("4400", "S2", O, 2),
+                segment("4480", "S9", O, 1),
+                segment("4490", "N1", O, 1),
+                segment("4500", "N2", O, 1),
+                segment("4510", "N3", O, 1),
+                segment("4520", "N4", O, 1),
+                segment("4530", "PER", O, 1),
+            ],
+        ),
+        segment("4600", "R2", O, 13),
+        segment("4800", "R9", O, 1),
+        segment_loop(
+            "E1",
+            2,
+            &[
+                segment("4900", "E1", O, 1),
+                segment("5000", "E4", O, 1),
+                segment("5100", "E5", O, 13),
+                segment("5110", "PI", O, 1),
+            ],
+        ),
+        segment("5200", "H3", O, 20),
+        segment("5300", "PS", O, 5),
+        segment_loop(
+            "LX",
+            25,
+            &[
+                segment("5400", "LX", M, 1),
+                segment("5500", "L5", M, 15),
+                segment_loop(
+                    "L0",
+                    25,
+                    &[
+                        segment("5700", "L0", O, 1),
+                        segment("5750", "MEA", O, 3),
+                        segment("5800", "L1", O, 10),
+                        segment_loop(
+                            "PI",
+                            30,
+                            &[
+                                segment("5900", "PI", O, 1),
+                                segment("5910", "CD", O, usize::MAX),
+                            ],
+                        ),
+                    ],
+                ),
+                segment("6000", "X1", O, 6),
+            ],
+        ),
+        segment_loop(
+            "T1",
+            64,
+            &[
+                segment("6100", "T1", O, 1),
+                segment("6200", "T2", O, 30),
+                segment("6300", "T3", O, 12),
+                segment("6400", "T6", O, 1),
+                segment("6500", "T8", O, 99),
+            ],
+        ),
+        segment("6600", "L3", O, 1),
+        segment("6700", "LS", O, 1),
+        segment_loop(
+            "LH1",
+            100,
+            &[
+                segment("6800", "LH1", O, 1),
+                segment("6900", "LH2", O, 4),
+                segment("7000", "LH3", O, 10),
+                segment("7100", "LFH", O, 20),
+                segment("7200", "LEP", O, 3),
+                segment("7300", "LH4", O, 1),
+                segment("7400", "LHT", O, 3),
+                segment("7500", "LHR", O, 5),
+                segment("7550", "PER", O, 5),
+                segment_loop(
+                    "N1",
+                    usize::MAX,
+                    &[
+                        segment("7560", "N1", O, 1),
+                        segment("7570", "N3", O, usize::MAX),
+                        segment("7580", "N4", O, 1),
+                        segment("7590", "PER", O, usize::MAX),
+                    ],
+                ),
+            ],
+        ),
+        segment("7600", "LE", O, 1),
+        segment("7700", "PER", O, 5),
+        segment("7800", "LH2", O, 6),
+        segment("7900", "LHR", O, 1),
+        segment("8000", "LH6", O, 5),
+        segment("8100", "XH", O, 1),
+        segment("8200", "X7", O, 10),
+        segment("8400", "SE", M, 1),
+    ],
 }
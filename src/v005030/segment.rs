@@ -1,3 +1,4 @@
+use crate::util::datatype;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use x12_types_macros::{DisplaySegment, ParseSegment};
@@ -6,6 +7,7 @@ use x12_types_macros::{DisplaySegment, ParseSegment};
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BL")]
 pub struct BL {
     #[serde(rename = "01")]
     pub _01: String,
@@ -47,6 +49,7 @@ pub struct BL {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BNX")]
 pub struct BNX {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -62,6 +65,7 @@ pub struct BNX {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::BX")]
 pub struct BX {
     #[serde(rename = "01")]
     pub _01: String,
@@ -97,6 +101,7 @@ pub struct BX {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CD")]
 pub struct CD {
     #[serde(rename = "01")]
     pub _01: String,
@@ -126,6 +131,7 @@ pub struct CD {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::CM")]
 pub struct CM {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -167,6 +173,7 @@ pub struct CM {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::D9")]
 pub struct D9 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -198,6 +205,7 @@ pub struct D9 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::DTM")]
 pub struct DTM {
     #[serde(rename = "01")]
     pub _01: String,
@@ -217,6 +225,7 @@ pub struct DTM {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::E1")]
 pub struct E1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -230,6 +239,7 @@ pub struct E1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::E4")]
 pub struct E4 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -247,6 +257,7 @@ pub struct E4 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::E5")]
 pub struct E5 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -262,6 +273,7 @@ pub struct E5 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::EM")]
 pub struct EM {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -283,6 +295,7 @@ pub struct EM {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::F9")]
 pub struct F9 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -314,6 +327,7 @@ pub struct F9 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::GA")]
 pub struct GA {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -362,8 +376,18 @@ pub struct GA {
 /// 01 | 97 | Number of Transaction Sets Included | 1 | M | N0 | 1/6
 /// 02 | 28 | Group Control Number | 1 | M/Z | N0 | 1/9
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::GE")]
 pub struct GE {
     /// 97 - Number of Transaction Sets Included
     ///
@@ -371,6 +395,13 @@ pub struct GE {
     /// - TYPE=N0
     /// - MIN=1
     /// - MAX=6
+    #[validate(
+        length(min = 1, message = "GE-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 6>",
+            message = "GE-01 must be a number of 1-6 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 28 - Group Control Number
@@ -379,6 +410,13 @@ pub struct GE {
     /// - TYPE=N0
     /// - MIN=1
     /// - MAX=9
+    #[validate(
+        length(min = 1, message = "GE-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 9>",
+            message = "GE-02 must be a number of 1-9 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -398,13 +436,44 @@ pub struct GE {
 /// 07 | 455 | Responsible Agency Code | 1 | M | ID | 1/2
 /// 08 | 480 | Version / Release / Industry Identifier Code | 1 | M | AN | 1/12
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::GS")]
 pub struct GS {
+    #[validate(
+        length(min = 1, message = "GS-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "GS-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
+    #[validate(
+        length(min = 1, message = "GS-02 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 15>",
+            message = "GS-02 must be 2-15 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
+    #[validate(
+        length(min = 1, message = "GS-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 15>",
+            message = "GS-03 must be 2-15 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
     /// 373 - Date
@@ -413,6 +482,13 @@ pub struct GS {
     /// - TYPE=DT
     /// - MIN=8
     /// - MAX=8
+    #[validate(
+        length(min = 1, message = "GS-04 is mandatory"),
+        custom(
+            function = "datatype::date::<8, 8>",
+            message = "GS-04 must be a date of 8 digits"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
     /// 337 - Time
@@ -421,12 +497,40 @@ pub struct GS {
     /// - TYPE=TM
     /// - MIN=4
     /// - MAX=8
+    #[validate(
+        length(min = 1, message = "GS-05 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 8>",
+            message = "GS-05 must be a time of 4-8 digits"
+        )
+    )]
     #[serde(rename = "05")]
     pub _05: String,
+    #[validate(
+        length(min = 1, message = "GS-06 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 9>",
+            message = "GS-06 must be a number of 1-9 digits"
+        )
+    )]
     #[serde(rename = "06")]
     pub _06: String,
+    #[validate(
+        length(min = 1, message = "GS-07 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 2>",
+            message = "GS-07 must be 1-2 characters"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
+    #[validate(
+        length(min = 1, message = "GS-08 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 12>",
+            message = "GS-08 must be 1-12 characters"
+        )
+    )]
     #[serde(rename = "08")]
     pub _08: String,
 }
@@ -435,6 +539,7 @@ pub struct GS {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::H3")]
 pub struct H3 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -452,6 +557,7 @@ pub struct H3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IC")]
 pub struct IC {
     #[serde(rename = "01")]
     pub _01: String,
@@ -482,8 +588,18 @@ pub struct IC {
 /// 01 | I16 | Number of Included Functional Groups | 1 | M | N0 | 1/5
 /// 02 | I12 | Interchange Control Number | 1 | M | N0 | 9/9
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IEA")]
 pub struct IEA {
     /// I16 - Number of Included Functional Groups
     ///
@@ -491,6 +607,13 @@ pub struct IEA {
     /// - TYPE=N0
     /// - MIN=1
     /// - MAX=5
+    #[validate(
+        length(min = 1, message = "IEA-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 5>",
+            message = "IEA-01 must be a number of 1-5 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// I12 - Interchange Control Number
@@ -499,6 +622,13 @@ pub struct IEA {
     /// - TYPE=N0
     /// - MIN=9
     /// - MAX=9
+    #[validate(
+        length(min = 1, message = "IEA-02 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "IEA-02 must be a number of 9 digits"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -507,6 +637,7 @@ pub struct IEA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::IM")]
 pub struct IM {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -550,6 +681,7 @@ pub struct IM {
     DisplaySegment,
     ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ISA")]
 pub struct ISA {
     /// I01 - Authorization Information Qualifier
     ///
@@ -557,7 +689,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
-    #[validate(length(equal = 2))]
+    #[validate(
+        length(min = 1, message = "ISA-01 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-01 must be 2 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// I02 - Authorization Information
@@ -566,7 +704,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=10
     /// - MAX=10
-    #[validate(length(equal = 10, message = "I04 must be 10 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-02 is mandatory"),
+        custom(
+            function = "datatype::string::<10, 10>",
+            message = "ISA-02 must be 10 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
     /// I03 - Security Information Qualifier
@@ -575,7 +719,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
-    #[validate(length(equal = 2))]
+    #[validate(
+        length(min = 1, message = "ISA-03 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-03 must be 2 characters"
+        )
+    )]
     #[serde(rename = "03")]
     pub _03: String,
     /// I04 - Security Information
@@ -584,7 +734,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=10
     /// - MAX=10
-    #[validate(length(equal = 10, message = "I04 must be 10 characters long"))]
+    #[validate(
+        length(min = 1, message = "ISA-04 is mandatory"),
+        custom(
+            function = "datatype::string::<10, 10>",
+            message = "ISA-04 must be 10 characters"
+        )
+    )]
     #[serde(rename = "04")]
     pub _04: String,
     /// I05 - Interchange ID Qualifier
@@ -593,6 +749,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
+    #[validate(
+        length(min = 1, message = "ISA-05 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-05 must be 2 characters"
+        )
+    )]
     #[serde(rename = "05")]
     pub _05: String,
     /// I06 - Interchange Sender ID
@@ -601,6 +764,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=15
     /// - MAX=15
+    #[validate(
+        length(min = 1, message = "ISA-06 is mandatory"),
+        custom(
+            function = "datatype::string::<15, 15>",
+            message = "ISA-06 must be 15 characters"
+        )
+    )]
     #[serde(rename = "06")]
     pub _06: String,
     /// I05 - Interchange ID Qualifier
@@ -609,6 +779,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=2
     /// - MAX=2
+    #[validate(
+        length(min = 1, message = "ISA-07 is mandatory"),
+        custom(
+            function = "datatype::string::<2, 2>",
+            message = "ISA-07 must be 2 characters"
+        )
+    )]
     #[serde(rename = "07")]
     pub _07: String,
     /// I07 - Interchange Receiver ID
@@ -617,6 +794,13 @@ pub struct ISA {
     /// - TYPE=AN
     /// - MIN=15
     /// - MAX=15
+    #[validate(
+        length(min = 1, message = "ISA-08 is mandatory"),
+        custom(
+            function = "datatype::string::<15, 15>",
+            message = "ISA-08 must be 15 characters"
+        )
+    )]
     #[serde(rename = "08")]
     pub _08: String,
     /// I08 - Interchange Date
@@ -625,6 +809,13 @@ pub struct ISA {
     /// - TYPE=DT
     /// - MIN=6
     /// - MAX=6
+    #[validate(
+        length(min = 1, message = "ISA-09 is mandatory"),
+        custom(
+            function = "datatype::date::<6, 6>",
+            message = "ISA-09 must be a date of 6 digits"
+        )
+    )]
     #[serde(rename = "09")]
     pub _09: String,
     /// I09 - Interchange Time
@@ -633,6 +824,13 @@ pub struct ISA {
     /// - TYPE=TM
     /// - MIN=4
     /// - MAX=4
+    #[validate(
+        length(min = 1, message = "ISA-10 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 4>",
+            message = "ISA-10 must be a time of 4 digits"
+        )
+    )]
     #[serde(rename = "10")]
     pub _10: String,
     /// I10 - Interchange Control Standards Identifier
@@ -641,6 +839,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=1
     /// - MAX=1
+    #[validate(
+        length(min = 1, message = "ISA-11 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-11 must be 1 character"
+        )
+    )]
     #[serde(rename = "11")]
     pub _11: String,
     /// I11 - Interchange Control Version Number
@@ -649,6 +854,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=5
     /// - MAX=5
+    #[validate(
+        length(min = 1, message = "ISA-12 is mandatory"),
+        custom(
+            function = "datatype::string::<5, 5>",
+            message = "ISA-12 must be 5 characters"
+        )
+    )]
     #[serde(rename = "12")]
     pub _12: String,
     /// I12 - Interchange Control Number
@@ -657,6 +869,13 @@ pub struct ISA {
     /// - TYPE=N0
     /// - MIN=9
     /// - MAX=9
+    #[validate(
+        length(min = 1, message = "ISA-13 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "ISA-13 must be a number of 9 digits"
+        )
+    )]
     #[serde(rename = "13")]
     pub _13: String,
     /// I13 - Acknowledgment Requested
@@ -665,6 +884,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=1
     /// - MAX=1
+    #[validate(
+        length(min = 1, message = "ISA-14 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-14 must be 1 character"
+        )
+    )]
     #[serde(rename = "14")]
     pub _14: String,
     /// I14 - Usage Indicator
@@ -673,6 +899,13 @@ pub struct ISA {
     /// - TYPE=ID
     /// - MIN=1
     /// - MAX=1
+    #[validate(
+        length(min = 1, message = "ISA-15 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "ISA-15 must be 1 character"
+        )
+    )]
     #[serde(rename = "15")]
     pub _15: String,
     /// I15 - Component Element Separator
@@ -681,6 +914,13 @@ pub struct ISA {
     /// - TYPE=
     /// - MIN=1
     /// - MAX=1
+    #[validate(
+        length(min = 1, message = "ISA-16 is mandatory"),
+        custom(
+            function = "datatype::length::<1, 1>",
+            message = "ISA-16 must be 1 character"
+        )
+    )]
     #[serde(rename = "16")]
     pub _16: String,
 }
@@ -689,6 +929,7 @@ pub struct ISA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L0")]
 pub struct L0 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -726,6 +967,7 @@ pub struct L0 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L1")]
 pub struct L1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -777,6 +1019,7 @@ pub struct L1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L3")]
 pub struct L3 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -814,6 +1057,7 @@ pub struct L3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::L5")]
 pub struct L5 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -841,6 +1085,7 @@ pub struct L5 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LE")]
 pub struct LE {
     #[serde(rename = "01")]
     pub _01: String,
@@ -850,6 +1095,7 @@ pub struct LE {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LEP")]
 pub struct LEP {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -865,6 +1111,7 @@ pub struct LEP {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LFH")]
 pub struct LFH {
     #[serde(rename = "01")]
     pub _01: String,
@@ -888,6 +1135,7 @@ pub struct LFH {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LH1")]
 pub struct LH1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -915,10 +1163,11 @@ pub struct LH1 {
     pub _12: Option<String>,
 }
 
-/// LH2 - Hazardous Identification Information
+/// LH2 - Hazardous Classification Information
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LH2")]
 pub struct LH2 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -952,6 +1201,7 @@ pub struct LH2 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LH3")]
 pub struct LH3 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -967,6 +1217,7 @@ pub struct LH3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LH4")]
 pub struct LH4 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -998,6 +1249,7 @@ pub struct LH4 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LH6")]
 pub struct LH6 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1013,6 +1265,7 @@ pub struct LH6 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LHR")]
 pub struct LHR {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1026,6 +1279,7 @@ pub struct LHR {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LHT")]
 pub struct LHT {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1039,6 +1293,7 @@ pub struct LHT {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LS")]
 pub struct LS {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1048,6 +1303,7 @@ pub struct LS {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::LX")]
 pub struct LX {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1057,6 +1313,7 @@ pub struct LX {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M1")]
 pub struct M1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1088,6 +1345,7 @@ pub struct M1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M12")]
 pub struct M12 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1123,6 +1381,7 @@ pub struct M12 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M3")]
 pub struct M3 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1138,6 +1397,7 @@ pub struct M3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::M7")]
 pub struct M7 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1155,6 +1415,7 @@ pub struct M7 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::MEA")]
 pub struct MEA {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1186,6 +1447,7 @@ pub struct MEA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N1")]
 pub struct N1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1205,6 +1467,7 @@ pub struct N1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N10")]
 pub struct N10 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1238,6 +1501,7 @@ pub struct N10 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N2")]
 pub struct N2 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1249,6 +1513,7 @@ pub struct N2 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N3")]
 pub struct N3 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1260,6 +1525,7 @@ pub struct N3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N4")]
 pub struct N4 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1281,6 +1547,7 @@ pub struct N4 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N5")]
 pub struct N5 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1306,6 +1573,7 @@ pub struct N5 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N7")]
 pub struct N7 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1361,6 +1629,7 @@ pub struct N7 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::N9")]
 pub struct N9 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1382,6 +1651,7 @@ pub struct N9 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::NA")]
 pub struct NA {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1413,6 +1683,7 @@ pub struct NA {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PER")]
 pub struct PER {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1438,6 +1709,7 @@ pub struct PER {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PI")]
 pub struct PI {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1477,6 +1749,7 @@ pub struct PI {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::PS")]
 pub struct PS {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1512,6 +1785,7 @@ pub struct PS {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::R2")]
 pub struct R2 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1545,6 +1819,7 @@ pub struct R2 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::R9")]
 pub struct R9 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1570,6 +1845,7 @@ pub struct R9 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::REF")]
 pub struct REF {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1585,6 +1861,7 @@ pub struct REF {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::S1")]
 pub struct S1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1604,6 +1881,7 @@ pub struct S1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::S2")]
 pub struct S2 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1617,6 +1895,7 @@ pub struct S2 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::S9")]
 pub struct S9 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1645,8 +1924,18 @@ pub struct S9 {
 /// 01 | 96 | Number of Included Segments | 1 | M | N0 | 1/10
 /// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::SE")]
 pub struct SE {
     /// 96 - Number of Included Segments
     ///
@@ -1654,6 +1943,13 @@ pub struct SE {
     /// - TYPE=N0
     /// - MIN=1
     /// - MAX=10
+    #[validate(
+        length(min = 1, message = "SE-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<1, 10>",
+            message = "SE-01 must be a number of 1-10 digits"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 329 - Transaction Set Control Number
@@ -1662,6 +1958,13 @@ pub struct SE {
     /// - TYPE=AN
     /// - MIN=4
     /// - MAX=9
+    #[validate(
+        length(min = 1, message = "SE-02 is mandatory"),
+        custom(
+            function = "datatype::string::<4, 9>",
+            message = "SE-02 must be 4-9 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -1675,13 +1978,37 @@ pub struct SE {
 /// 01 | 143 | Transaction Set Identifier Code | 1 | M/Z | ID | 3/3
 /// 02 | 329 | Transaction Set Control Number | 1 | M | AN | 4/9
 #[derive(
-    Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ST")]
 pub struct ST {
     /// 143 - Transaction Set Identifier Code 3/3
+    #[validate(
+        length(min = 1, message = "ST-01 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "ST-01 must be 3 characters"
+        )
+    )]
     #[serde(rename = "01")]
     pub _01: String,
     /// 329 - Transaction Set Control Number 4/9
+    #[validate(
+        length(min = 1, message = "ST-02 is mandatory"),
+        custom(
+            function = "datatype::string::<4, 9>",
+            message = "ST-02 must be 4-9 characters"
+        )
+    )]
     #[serde(rename = "02")]
     pub _02: String,
 }
@@ -1690,6 +2017,7 @@ pub struct ST {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::T1")]
 pub struct T1 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1719,6 +2047,7 @@ pub struct T1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::T2")]
 pub struct T2 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1750,6 +2079,7 @@ pub struct T2 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::T3")]
 pub struct T3 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1771,6 +2101,7 @@ pub struct T3 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::T6")]
 pub struct T6 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1792,6 +2123,7 @@ pub struct T6 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::T8")]
 pub struct T8 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1799,10 +2131,83 @@ pub struct T8 {
     pub _02: String,
 }
 
+/// TA1 - Interchange Acknowledgment
+///
+/// To report the status of processing a received interchange header and trailer or the non-delivery by a network provider
+///
+/// REF | ID | NAME | REPEAT | REQ | TYPE | MIN/MAX
+/// ----|----|------|--------|----|------|-------
+/// 01 | I12 | Interchange Control Number | 1 | M | N0 | 9/9
+/// 02 | I08 | Interchange Date | 1 | M | DT | 6/6
+/// 03 | I09 | Interchange Time | 1 | M | TM | 4/4
+/// 04 | I17 | Interchange Acknowledgment Code | 1 | M | ID | 1/1
+/// 05 | I18 | Interchange Note Code | 1 | M | ID | 3/3
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Default,
+    Debug,
+    Validate,
+    PartialEq,
+    Eq,
+    DisplaySegment,
+    ParseSegment,
+)]
+#[serde(rename = "$x12::segment::TA1")]
+pub struct TA1 {
+    #[validate(
+        length(min = 1, message = "TA1-01 is mandatory"),
+        custom(
+            function = "datatype::numeric::<9, 9>",
+            message = "TA1-01 must be a number of 9 digits"
+        )
+    )]
+    #[serde(rename = "01")]
+    pub _01: String,
+    #[validate(
+        length(min = 1, message = "TA1-02 is mandatory"),
+        custom(
+            function = "datatype::date::<6, 6>",
+            message = "TA1-02 must be a date of 6 digits"
+        )
+    )]
+    #[serde(rename = "02")]
+    pub _02: String,
+    #[validate(
+        length(min = 1, message = "TA1-03 is mandatory"),
+        custom(
+            function = "datatype::time::<4, 4>",
+            message = "TA1-03 must be a time of 4 digits"
+        )
+    )]
+    #[serde(rename = "03")]
+    pub _03: String,
+    #[validate(
+        length(min = 1, message = "TA1-04 is mandatory"),
+        custom(
+            function = "datatype::string::<1, 1>",
+            message = "TA1-04 must be 1 character"
+        )
+    )]
+    #[serde(rename = "04")]
+    pub _04: String,
+    #[validate(
+        length(min = 1, message = "TA1-05 is mandatory"),
+        custom(
+            function = "datatype::string::<3, 3>",
+            message = "TA1-05 must be 3 characters"
+        )
+    )]
+    #[serde(rename = "05")]
+    pub _05: String,
+}
+
 /// VC - Motor Vehicle Control
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::VC")]
 pub struct VC {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1836,6 +2241,7 @@ pub struct VC {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::X1")]
 pub struct X1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1877,6 +2283,7 @@ pub struct X1 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::X7")]
 pub struct X7 {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1888,6 +2295,7 @@ pub struct X7 {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::XH")]
 pub struct XH {
     #[serde(rename = "01")]
     pub _01: String,
@@ -1909,6 +2317,7 @@ pub struct XH {
 #[derive(
     Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, DisplaySegment, ParseSegment,
 )]
+#[serde(rename = "$x12::segment::ZC1")]
 pub struct ZC1 {
     #[serde(rename = "01")]
     pub _01: Option<String>,
@@ -1929,3 +2338,25 @@ pub struct ZC1 {
     #[serde(rename = "09")]
     pub _09: Option<String>,
 }
+
+crate::validation::impl_segment_syntax! {
+    N1 => ["R0203", "P0304"],
+    N4 => ["E0207", "C0605", "C0704"],
+    REF => ["R0203"],
+    PER => ["P0304", "P0506", "P0708"],
+    DTM => ["R020305", "C0403", "P0506"],
+    N9 => ["R0203", "C0605"],
+    MEA => ["R03050608", "C0504", "C0604", "L07030506", "E0803"],
+}
+
+crate::validation::impl_segment_codes! {
+    GS => [7 => "455"],
+    ISA => [14 => "I13", 15 => "I14"],
+    M7 => [5 => "98"],
+    N1 => [1 => "98", 6 => "98"],
+    TA1 => [4 => "I17"],
+}
+
+crate::validation::impl_segment_elements! {
+    GE, GS, IEA, ISA, SE, ST, TA1,
+}
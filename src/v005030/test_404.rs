@@ -142,3 +142,56 @@ IEA*1*000097314~
     let s = format!("{obj}");
     assert_eq!(s, str);
 }
+
+#[test]
+fn parse_multiple_transaction_sets() {
+    let str = r#"ISA*00*XXXXX     *00*          *ZZ*XXXXXXX        *ZZ*XXXXXX         *230614*1235*^*00503*000099667*0*P*>~
+GS*SR*XXXXXXX*XXXXXX*20230614*1235*99667*X*005030~
+ST*404*0001~
+M3*R*20230614*0735~
+F9**ELIZABETH MARINE TE*NJ~
+D9**NEW YORK*NY~
+SE*5*0001~
+ST*404*0002~
+M3*R*20230615*0735~
+F9**ELIZABETH MARINE TE*NJ~
+D9**NEW YORK*NY~
+SE*5*0002~
+GE*2*99667~
+IEA*1*000099667~
+"#;
+    let (rest, obj) = Transmission::<_404>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(obj.functional_group[0].segments.len(), 2);
+    assert!(obj.validate_envelope().is_empty());
+    assert_eq!(
+        obj.acknowledge().to_string(),
+        "TA1*000099667*230614*1235*A*000~\n"
+    );
+    assert_eq!(obj.to_string(), str);
+}
+
+#[test]
+fn parse_mixed_transaction_sets() {
+    let str = r#"ISA*00*XXXXX     *00*          *ZZ*XXXXXXX        *ZZ*XXXXXX         *230614*1235*^*00503*000099667*0*P*>~
+GS*SR*XXXXXXX*XXXXXX*20230614*1235*99667*X*005030~
+ST*404*0001~
+M3*R*20230614*0735~
+F9**ELIZABETH MARINE TE*NJ~
+D9**NEW YORK*NY~
+SE*5*0001~
+ST*997*0002~
+AK1*SR*99667~
+AK9*A*1*1*1~
+SE*4*0002~
+GE*2*99667~
+IEA*1*000099667~
+"#;
+    let (rest, obj) = Transmission::<AnyTransaction>::parse(str).unwrap();
+    assert!(rest.is_empty());
+    let sets = &obj.functional_group[0].segments;
+    assert!(matches!(&sets[0], AnyTransaction::_404(t) if t.st._02 == "0001"));
+    assert!(matches!(&sets[1], AnyTransaction::Raw(t) if t.segments.len() == 4));
+    assert_eq!(sets[1].id(), "997");
+    assert_eq!(obj.to_string(), str);
+}
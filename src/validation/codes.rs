@@ -0,0 +1,484 @@
+//! Code lists of identifier (`ID`) elements.
+
+use super::visit_segments;
+use crate::util::writer;
+use serde::Serialize;
+use std::fmt::Display;
+
+/// The valid codes of a data element with their descriptions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CodeList {
+    /// Data element number, e.g. `353`
+    pub element: &'static str,
+    /// Data element name, e.g. `Transaction Set Purpose Code`
+    pub name: &'static str,
+    /// each code with its description
+    pub codes: &'static [(&'static str, &'static str)],
+    /// whether `codes` holds every code of the element, a partial list only describes the codes it
+    /// holds and is not checked by [`validate_codes`]
+    pub complete: bool,
+}
+
+impl CodeList {
+    /// Returns the embedded code list of the data element with the given number, e.g. `98`.
+    pub fn get(element: &str) -> Option<&'static CodeList> {
+        CODE_LISTS.iter().find(|list| list.element == element)
+    }
+
+    /// whether `code` is one of the codes
+    pub fn contains(&self, code: &str) -> bool {
+        self.description(code).is_some()
+    }
+
+    /// Returns the description of `code`, e.g. `Original` for `00` of element 353.
+    pub fn description(&self, code: &str) -> Option<&'static str> {
+        self.codes
+            .iter()
+            .find(|(value, _)| *value == code)
+            .map(|(_, description)| *description)
+    }
+}
+
+/// An element value which is not in the code list of the element
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeError {
+    /// Segment ID, e.g. `B2A`
+    pub segment: String,
+    /// position of the segment in the transaction set, counting the ST as 1, or 1 for a single segment
+    pub segment_position: usize,
+    /// position of the element in the segment
+    pub element_position: usize,
+    /// Data element number of the code list, e.g. `353`
+    pub element: String,
+    /// the invalid code
+    pub value: String,
+}
+
+impl Display for CodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{:02} '{}' is not a code of element {}",
+            self.segment, self.element_position, self.value, self.element
+        )
+    }
+}
+
+/// The code lists of the identifier elements of a segment
+pub trait SegmentCodes: Serialize {
+    /// element positions with the data element number of their code list, e.g. `(1, "353")`
+    const CODE_LISTS: &'static [(usize, &'static str)];
+
+    /// Returns the code list of the element at `position`, e.g. to describe its value.
+    fn code_list(position: usize) -> Option<&'static CodeList> {
+        Self::CODE_LISTS
+            .iter()
+            .find(|(list_position, _)| *list_position == position)
+            .and_then(|(_, element)| CodeList::get(element))
+    }
+
+    /// Checks the values of the elements with a code list.
+    fn validate_codes(&self) -> Result<Vec<CodeError>, writer::Error> {
+        validate_codes(self, |_| Self::CODE_LISTS)
+    }
+}
+
+/// Implements [`SegmentCodes`] for the given segments and defines `code_lists`, looking up the
+/// element positions with a code list by segment ID.
+macro_rules! impl_segment_codes {
+    ($($segment:ident => [$($position:literal => $element:literal),*],)*) => {
+        $(
+            impl $crate::validation::SegmentCodes for $segment {
+                const CODE_LISTS: &'static [(usize, &'static str)] = &[$(($position, $element)),*];
+            }
+        )*
+
+        /// Returns the element positions of the segment with the given ID which have a code list,
+        /// with the data element number of the list, e.g. `[(1, "353")]` for `B2A`.
+        #[allow(clippy::match_single_binding)]
+        pub fn code_lists(segment_id: &str) -> &'static [(usize, &'static str)] {
+            match segment_id {
+                $(stringify!($segment) => &[$(($position, $element)),*],)*
+                _ => &[],
+            }
+        }
+    };
+}
+pub(crate) use impl_segment_codes;
+
+/// Checks the elements with a code list of each segment in `value`, a segment, transaction set or loop.
+///
+/// The code lists of each segment are looked up by its ID with `code_lists`, e.g. `V::code_lists` of a
+/// [`Version`](crate::envelope::Version). Omitted elements and elements with a partial code list are not checked.
+/// Fails if `value` cannot be rendered.
+pub fn validate_codes<T, F>(value: &T, code_lists: F) -> Result<Vec<CodeError>, writer::Error>
+where
+    T: Serialize + ?Sized,
+    F: Fn(&str) -> &'static [(usize, &'static str)],
+{
+    let mut errors = vec![];
+    visit_segments(value, |segment_position, segment| {
+        for (element_position, element) in code_lists(segment.id) {
+            let Some(list) = CodeList::get(element).filter(|list| list.complete) else {
+                continue;
+            };
+            let value = segment.element(*element_position).unwrap_or_default();
+            if !value.is_empty() && !list.contains(value) {
+                errors.push(CodeError {
+                    segment: segment.id.to_string(),
+                    segment_position,
+                    element_position: *element_position,
+                    element: element.to_string(),
+                    value: value.to_string(),
+                });
+            }
+        }
+    })?;
+    Ok(errors)
+}
+
+/// The embedded code lists. The lists of elements with many codes, e.g. 98, are partial and hold
+/// the codes used by common transaction sets.
+static CODE_LISTS: &[CodeList] = &[
+    CodeList {
+        element: "98",
+        name: "Entity Identifier Code",
+        codes: &[
+            ("1P", "Provider"),
+            ("2B", "Third-Party Administrator"),
+            ("36", "Employer"),
+            ("40", "Receiver"),
+            ("41", "Submitter"),
+            ("45", "Drop-off Location"),
+            ("71", "Attending Physician"),
+            ("72", "Operating Physician"),
+            ("73", "Other Physician"),
+            ("74", "Corrected Insured"),
+            ("77", "Service Location"),
+            ("82", "Rendering Provider"),
+            ("85", "Billing Provider"),
+            ("87", "Pay-to Provider"),
+            ("AY", "Clearinghouse"),
+            ("BT", "Bill-to-Party"),
+            ("BY", "Buying Party (Purchaser)"),
+            ("CA", "Carrier"),
+            ("CB", "Customs Broker"),
+            ("CN", "Consignee"),
+            ("DA", "Delivery Address"),
+            ("DE", "Depositor"),
+            ("DK", "Ordering Physician"),
+            ("DN", "Referring Provider"),
+            ("DQ", "Supervising Physician"),
+            ("FA", "Facility"),
+            ("FS", "Final Scheduled Destination"),
+            ("FW", "Forwarder"),
+            ("GB", "Other Insured"),
+            ("HK", "Subscriber"),
+            ("IL", "Insured or Subscriber"),
+            ("LI", "Independent Lab"),
+            ("MF", "Manufacturer of Goods"),
+            ("ND", "Next Destination"),
+            ("OB", "Ordered By"),
+            ("P3", "Primary Care Provider"),
+            ("P4", "Prior Insurance Carrier"),
+            ("P5", "Plan Sponsor"),
+            ("PE", "Payee"),
+            ("PR", "Payer"),
+            ("PW", "Pickup Address"),
+            ("QC", "Patient"),
+            ("QD", "Responsible Party"),
+            ("QE", "Policyholder"),
+            ("QV", "Group Practice"),
+            ("R3", "Next Scheduled Destination"),
+            ("RE", "Party to receive commercial invoice remittance"),
+            ("RI", "Remit To"),
+            ("SF", "Ship From"),
+            ("SH", "Shipper"),
+            ("SJ", "Service Provider"),
+            ("SO", "Sold To If Different From Bill To"),
+            ("ST", "Ship To"),
+            ("SU", "Supplier/Manufacturer"),
+            ("TT", "Transfer To"),
+            ("VN", "Vendor"),
+            ("WH", "Warehouse"),
+            ("X3", "Utilization Management Organization"),
+        ],
+        complete: false,
+    },
+    CodeList {
+        element: "305",
+        name: "Transaction Handling Code",
+        codes: &[
+            ("C", "Payment Accompanies Remittance Advice"),
+            ("D", "Make Payment Only"),
+            ("H", "Notification Only"),
+            ("I", "Remittance Information Only"),
+            ("P", "Prenotification of Future Transfers"),
+            ("U", "Split Payment and Remittance"),
+            (
+                "X",
+                "Handling Party's Option to Split Payment and Remittance",
+            ),
+        ],
+        complete: false,
+    },
+    CodeList {
+        element: "353",
+        name: "Transaction Set Purpose Code",
+        codes: &[
+            ("00", "Original"),
+            ("01", "Cancellation"),
+            ("02", "Add"),
+            ("03", "Delete"),
+            ("04", "Change"),
+            ("05", "Replace"),
+            ("06", "Confirmation"),
+            ("07", "Duplicate"),
+            ("08", "Status"),
+            ("10", "Not Found"),
+            ("11", "Response"),
+            ("12", "Not Processed"),
+            ("13", "Request"),
+            ("14", "Advance Notification"),
+            ("15", "Re-Submission"),
+            ("16", "Proposed"),
+            ("17", "Cancel, to be Reissued"),
+            ("18", "Reissue"),
+            ("19", "Seller initiated change"),
+            ("20", "Final Transmission"),
+            ("21", "Transaction on Hold"),
+            ("22", "Information Copy"),
+            ("24", "Draft"),
+            ("25", "Incremental"),
+            ("26", "Replace - Specified Buyers Parts Only"),
+            ("27", "Verify"),
+            ("28", "Query"),
+            ("30", "Renewal"),
+            ("31", "Allowance/Addition"),
+            ("32", "Recovery/Deduction"),
+            ("33", "Request for Payment"),
+            ("34", "Payment Declined"),
+            ("35", "Request Authority"),
+            ("36", "Authority to Deduct (Reply)"),
+            ("37", "Authority Declined (Reply)"),
+            ("38", "No Financial Value"),
+            ("39", "Response to Proposed Trip Plan"),
+            ("40", "Commitment Advice"),
+            ("41", "Corrected and Verified"),
+            ("42", "Temporary Record"),
+            ("43", "Request Permission to Service"),
+            ("44", "Rejection"),
+            ("45", "Follow-up"),
+            ("49", "Original - No Response Necessary"),
+            ("50", "Register"),
+            ("51", "Historical Inquiry"),
+            ("52", "Response to Historical Inquiry"),
+            ("53", "Completion"),
+            ("54", "Approval"),
+            ("55", "Excavation"),
+            ("56", "Expiration Notification"),
+            ("77", "Simulation Exercise"),
+            ("CN", "Completion Notification"),
+            ("CO", "Corrected"),
+            ("EX", "Final Loading Configuration"),
+            ("GR", "Granted"),
+            ("PR", "Proposed Loading Configuration"),
+            ("RH", "Release Hold"),
+            ("RV", "Revised Loading Configuration"),
+            ("SU", "Status Update"),
+            ("ZZ", "Mutually Defined"),
+        ],
+        complete: true,
+    },
+    CodeList {
+        element: "455",
+        name: "Responsible Agency Code",
+        codes: &[
+            ("T", "Transportation Data Coordinating Committee (TDCC)"),
+            ("X", "Accredited Standards Committee X12"),
+        ],
+        complete: true,
+    },
+    CodeList {
+        element: "478",
+        name: "Credit/Debit Flag Code",
+        codes: &[("C", "Credit"), ("D", "Debit")],
+        complete: true,
+    },
+    CodeList {
+        element: "715",
+        name: "Functional Group Acknowledge Code",
+        codes: &[
+            ("A", "Accepted"),
+            ("E", "Accepted, But Errors Were Noted."),
+            ("M", "Rejected, Message Authentication Code (MAC) Failed"),
+            (
+                "P",
+                "Partially Accepted, At Least One Transaction Set Was Rejected",
+            ),
+            ("R", "Rejected"),
+            ("W", "Rejected, Assurance Failed Validity Tests"),
+            (
+                "X",
+                "Rejected, Content After Decryption Could Not Be Analyzed",
+            ),
+        ],
+        complete: true,
+    },
+    CodeList {
+        element: "717",
+        name: "Transaction Set Acknowledgment Code",
+        codes: &[
+            ("A", "Accepted"),
+            ("E", "Accepted But Errors Were Noted"),
+            ("M", "Rejected, Message Authentication Code (MAC) Failed"),
+            ("R", "Rejected"),
+            ("W", "Rejected, Assurance Failed Validity Tests"),
+            (
+                "X",
+                "Rejected, Content After Decryption Could Not Be Analyzed",
+            ),
+        ],
+        complete: true,
+    },
+    CodeList {
+        element: "736",
+        name: "Hierarchical Child Code",
+        codes: &[
+            (
+                "0",
+                "No Subordinate HL Segment in This Hierarchical Structure.",
+            ),
+            (
+                "1",
+                "Additional Subordinate HL Data Segment in This Hierarchical Structure.",
+            ),
+        ],
+        complete: true,
+    },
+    CodeList {
+        element: "1029",
+        name: "Claim Status Code",
+        codes: &[
+            ("1", "Processed as Primary"),
+            ("2", "Processed as Secondary"),
+            ("3", "Processed as Tertiary"),
+            ("4", "Denied"),
+            ("5", "Pended"),
+            ("10", "Received, but not in process"),
+            ("13", "Suspended"),
+            ("15", "Suspended - investigation with field"),
+            ("16", "Suspended - return with material"),
+            ("17", "Suspended - review pending"),
+            (
+                "19",
+                "Processed as Primary, Forwarded to Additional Payer(s)",
+            ),
+            (
+                "20",
+                "Processed as Secondary, Forwarded to Additional Payer(s)",
+            ),
+            (
+                "21",
+                "Processed as Tertiary, Forwarded to Additional Payer(s)",
+            ),
+            ("22", "Reversal of Previous Payment"),
+            ("23", "Not Our Claim, Forwarded to Additional Payer(s)"),
+            ("25", "Predetermination Pricing Only - No Payment"),
+            ("27", "Reviewed"),
+        ],
+        complete: true,
+    },
+    CodeList {
+        element: "1032",
+        name: "Claim Filing Indicator Code",
+        codes: &[
+            ("11", "Other Non-Federal Programs"),
+            ("12", "Preferred Provider Organization (PPO)"),
+            ("13", "Point of Service (POS)"),
+            ("14", "Exclusive Provider Organization (EPO)"),
+            ("15", "Indemnity Insurance"),
+            ("16", "Health Maintenance Organization (HMO) Medicare Risk"),
+            ("17", "Dental Maintenance Organization"),
+            ("AM", "Automobile Medical"),
+            ("BL", "Blue Cross/Blue Shield"),
+            ("CH", "Champus"),
+            ("CI", "Commercial Insurance Co."),
+            ("DS", "Disability"),
+            ("FI", "Federal Employees Program"),
+            ("HM", "Health Maintenance Organization"),
+            ("LM", "Liability Medical"),
+            ("MA", "Medicare Part A"),
+            ("MB", "Medicare Part B"),
+            ("MC", "Medicaid"),
+            ("OF", "Other Federal Program"),
+            ("TV", "Title V"),
+            ("VA", "Veterans Affairs Plan"),
+            ("WC", "Workers' Compensation Health Claim"),
+            ("ZZ", "Mutually Defined"),
+        ],
+        complete: false,
+    },
+    CodeList {
+        element: "1033",
+        name: "Claim Adjustment Group Code",
+        codes: &[
+            ("CO", "Contractual Obligations"),
+            ("CR", "Correction and Reversals"),
+            ("OA", "Other adjustments"),
+            ("PI", "Payor Initiated Reductions"),
+            ("PR", "Patient Responsibility"),
+        ],
+        complete: true,
+    },
+    CodeList {
+        element: "1065",
+        name: "Entity Type Qualifier",
+        codes: &[("1", "Person"), ("2", "Non-Person Entity")],
+        complete: true,
+    },
+    CodeList {
+        element: "1073",
+        name: "Yes/No Condition or Response Code",
+        codes: &[("N", "No"), ("U", "Unknown"), ("W", "Not Applicable"), ("Y", "Yes")],
+        complete: true,
+    },
+    CodeList {
+        element: "I13",
+        name: "Acknowledgment Requested",
+        codes: &[
+            ("0", "No Interchange Acknowledgment Requested"),
+            ("1", "Interchange Acknowledgment Requested (TA1)"),
+        ],
+        complete: true,
+    },
+    CodeList {
+        element: "I14",
+        name: "Interchange Usage Indicator",
+        codes: &[
+            ("I", "Information"),
+            ("P", "Production Data"),
+            ("T", "Test Data"),
+        ],
+        complete: true,
+    },
+    CodeList {
+        element: "I17",
+        name: "Interchange Acknowledgment Code",
+        codes: &[
+            (
+                "A",
+                "The Transmitted Interchange Control Structure Header and Trailer Have Been Received and Have No Errors.",
+            ),
+            (
+                "E",
+                "The Transmitted Interchange Control Structure Header and Trailer Have Been Received and Are Accepted But Errors Are Noted.",
+            ),
+            (
+                "R",
+                "The Transmitted Interchange Control Structure Header and Trailer are Rejected Because of Errors.",
+            ),
+        ],
+        complete: true,
+    },
+];
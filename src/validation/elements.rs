@@ -0,0 +1,146 @@
+//! Types and lengths of the elements of segments found in transaction sets.
+
+use super::{visit_segments, ElementSyntaxError};
+use crate::raw::RawSegmentRef;
+use crate::util::{datatype, writer, Parser};
+use serde::Serialize;
+use std::fmt::Display;
+use validator::{Validate, ValidationError};
+
+/// An element which fails its type or length check, see [`datatype`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ElementError {
+    /// Segment ID, e.g. `B2`
+    pub segment: String,
+    /// position of the segment in the transaction set, counting the ST as 1, or 1 for a single segment
+    pub segment_position: usize,
+    /// position of the element in the segment
+    pub element_position: usize,
+    /// the element as found in the segment, empty for a missing element
+    pub value: String,
+    pub error: ElementSyntaxError,
+}
+
+impl Display for ElementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{:02} '{}': {}",
+            self.segment, self.element_position, self.value, self.error
+        )
+    }
+}
+
+/// Checks the elements of `segment` with the `Validate` implementation of the typed segment `S`.
+///
+/// Returns the position and error of each failed element. A segment which cannot be parsed into `S` is not checked.
+pub fn check_elements<S>(segment: &RawSegmentRef) -> Vec<(usize, ElementSyntaxError)>
+where
+    S: Validate + for<'a> Parser<&'a str, S, nom::error::Error<&'a str>>,
+{
+    let Ok(Err(errors)) = segment.to_typed::<S>().map(|typed| typed.validate()) else {
+        return vec![];
+    };
+    let mut failed: Vec<(usize, ElementSyntaxError)> = errors
+        .field_errors()
+        .into_iter()
+        .filter_map(|(field, errors)| {
+            // the fields are named by position, e.g. `_04`
+            let position = field.strip_prefix('_')?.parse().ok()?;
+            let value = segment.element(position).unwrap_or_default();
+            let error = errors.first()?;
+            Some((position, element_syntax_error(error, value)))
+        })
+        .collect();
+    failed.sort_by_key(|(position, _)| *position);
+    failed
+}
+
+/// Maps a failed check of [`datatype`] or `length(min = 1)` to the error code of the element.
+fn element_syntax_error(error: &ValidationError, value: &str) -> ElementSyntaxError {
+    if value.is_empty() {
+        return ElementSyntaxError::MandatoryMissing;
+    }
+    let param = |name: &str| {
+        error
+            .params
+            .get(name)
+            .and_then(|param| param.as_u64())
+            .and_then(|param| usize::try_from(param).ok())
+    };
+    let (valid_characters, length) = match error.code.as_ref() {
+        "date" => return ElementSyntaxError::InvalidDate,
+        "time" => return ElementSyntaxError::InvalidTime,
+        "numeric" => (
+            datatype::numeric::<0, { usize::MAX }>(value).is_ok(),
+            value.chars().filter(char::is_ascii_digit).count(),
+        ),
+        "decimal" => (
+            datatype::decimal::<0, { usize::MAX }>(value).is_ok(),
+            value.chars().filter(char::is_ascii_digit).count(),
+        ),
+        "string" => (
+            datatype::string::<0, { usize::MAX }>(value).is_ok(),
+            value.chars().count(),
+        ),
+        _ => (true, value.chars().count()),
+    };
+    match (param("min"), param("max")) {
+        _ if !valid_characters => ElementSyntaxError::InvalidCharacter,
+        (Some(min), _) if length < min => ElementSyntaxError::TooShort,
+        (_, Some(max)) if length > max => ElementSyntaxError::TooLong,
+        _ => ElementSyntaxError::InvalidCharacter,
+    }
+}
+
+/// Defines `element_errors`, checking the elements of a segment with [`check_elements`] of the typed
+/// segment of the same ID.
+macro_rules! impl_segment_elements {
+    ($($segment:ident),* $(,)?) => {
+        /// Checks the types and lengths of the elements of `segment` and returns the position and error
+        /// of each failed element. Segments without a typed binding are not checked.
+        pub fn element_errors(
+            segment: &$crate::raw::RawSegmentRef,
+        ) -> Vec<(usize, $crate::validation::ElementSyntaxError)> {
+            $(
+                if segment.id == stringify!($segment) {
+                    return $crate::validation::check_elements::<$segment>(segment);
+                }
+            )*
+            vec![]
+        }
+    };
+}
+pub(crate) use impl_segment_elements;
+
+/// Checks the types and lengths of the elements of each segment in `value`, a segment, transaction set or loop.
+///
+/// The elements of each segment are checked by `element_errors`, e.g. `V::element_errors` of a
+/// [`Version`](crate::envelope::Version). Fails if `value` cannot be rendered.
+pub fn validate_elements<T, F>(
+    value: &T,
+    element_errors: F,
+) -> Result<Vec<ElementError>, writer::Error>
+where
+    T: Serialize + ?Sized,
+    F: Fn(&RawSegmentRef) -> Vec<(usize, ElementSyntaxError)>,
+{
+    let mut errors = vec![];
+    visit_segments(value, |segment_position, segment| {
+        errors.extend(
+            element_errors(segment)
+                .into_iter()
+                .map(|(element_position, error)| ElementError {
+                    segment: segment.id.to_string(),
+                    segment_position,
+                    element_position,
+                    value: segment
+                        .element(element_position)
+                        .unwrap_or_default()
+                        .to_string(),
+                    error,
+                }),
+        );
+    })?;
+    Ok(errors)
+}
@@ -0,0 +1,76 @@
+//! Validation of segments and transaction sets beyond the element checks of `Validate`.
+//!
+//! The syntax notes of the standard, relational conditions like `P0304` between the elements of a segment,
+//! are attached to the segments of each version by [`SegmentSyntax`] and looked up by segment ID with
+//! [`Version::syntax_notes`](crate::envelope::Version::syntax_notes). Violations are reported as
+//! [`SyntaxError`] with the data element syntax error code of AK404 (004010) or IK403 (005010).
+//!
+//! The segment positions and loops of the transaction sets, with their requirement, maximum use and
+//! repeat, are attached to the transaction sets by [`TransactionStructure`] and looked up by transaction
+//! set identifier with [`Version::transaction_structure`](crate::envelope::Version::transaction_structure).
+//! Violations are reported as [`StructureError`] with the segment syntax error code of AK304 (004010)
+//! or IK304 (005010).
+//!
+//! Code lists of identifier elements are embedded for frequently used data elements like 353
+//! (Transaction Set Purpose Code), 98 (Entity Identifier Code) and 1029 (Claim Status Code), see
+//! [`CodeList`]. They are attached to the segment elements of each version by [`SegmentCodes`] and
+//! looked up by segment ID with [`Version::code_lists`](crate::envelope::Version::code_lists). Elements
+//! without an embedded code list, or with a partial list like 98, are not checked.
+//!
+//! The types and lengths of the elements are checked by the `Validate` implementation of the typed segment
+//! with the same ID, looked up with [`Version::element_errors`](crate::envelope::Version::element_errors).
+//! Violations are reported as [`ElementError`].
+//!
+//! [`SetReport`] and [`GroupReport`] collect all findings of the transaction sets of a functional group,
+//! the content of a functional acknowledgment like the 997 of 004010.
+
+mod codes;
+mod elements;
+mod report;
+mod structure;
+#[cfg(test)]
+mod structure_test;
+mod syntax;
+pub(crate) use codes::impl_segment_codes;
+pub use codes::*;
+pub(crate) use elements::impl_segment_elements;
+pub use elements::*;
+pub use report::*;
+pub(crate) use structure::impl_transaction_structure;
+pub use structure::*;
+pub use syntax::*;
+pub(crate) use syntax::{impl_segment_syntax, is_syntax_note};
+
+use crate::raw::RawSegmentRef;
+use crate::util::{writer, Delimiters, Parser};
+use serde::Serialize;
+
+/// Delimiters which do not occur in the data, to render values for the checks
+const DELIMITERS: Delimiters = Delimiters {
+    element: '\u{1d}',
+    component: '\u{1f}',
+    repetition: Some('\u{1e}'),
+    segment: '\u{1c}',
+};
+
+/// Renders `value` with [`DELIMITERS`] and calls `visit` with the position of each segment, counting from 1.
+///
+/// Returns the number of segments, or the error of a value which cannot be rendered.
+fn visit_segments<T>(
+    value: &T,
+    mut visit: impl FnMut(usize, &RawSegmentRef),
+) -> Result<usize, writer::Error>
+where
+    T: Serialize + ?Sized,
+{
+    let rendered = writer::to_string(value, DELIMITERS)?;
+    let _scope = DELIMITERS.scope();
+    let mut rest = rendered.as_str();
+    let mut segment_position = 0;
+    while let Ok((next, segment)) = RawSegmentRef::parse(rest) {
+        rest = next;
+        segment_position += 1;
+        visit(segment_position, &segment);
+    }
+    Ok(segment_position)
+}
@@ -0,0 +1,354 @@
+//! Findings of the transaction sets of a functional group, the content of a functional acknowledgment.
+
+use super::{
+    validate_codes, validate_elements, validate_structure, validate_syntax, visit_segments,
+    CodeError, ElementError, SegmentSyntaxError, StructureError, SyntaxError, DELIMITERS,
+};
+use crate::envelope::{
+    validate_transaction_set, FunctionalGroup, GroupHeader, Trailer, TransactionSet, Version,
+};
+use crate::error::ParseError;
+use crate::raw::{RawGroup, RawTransaction};
+use crate::util::{writer, Parser};
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Transaction Set Syntax Error Code, reported in AK502 (004010) or IK502 (005010)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionSetError {
+    /// 1 - Transaction Set Not Supported
+    NotSupported,
+    /// 2 - Transaction Set Trailer Missing
+    TrailerMissing,
+    /// 3 - Transaction Set Control Number in Header and Trailer Do Not Match
+    ControlNumberMismatch,
+    /// 4 - Number of Included Segments Does Not Match Actual Count
+    SegmentCountMismatch,
+    /// 5 - One or More Segments in Error
+    SegmentsInError,
+    /// 6 - Missing or Invalid Transaction Set Identifier
+    InvalidIdentifier,
+    /// 7 - Missing or Invalid Transaction Set Control Number
+    InvalidControlNumber,
+}
+
+impl TransactionSetError {
+    /// the code, e.g. `5`
+    pub fn code(self) -> &'static str {
+        match self {
+            TransactionSetError::NotSupported => "1",
+            TransactionSetError::TrailerMissing => "2",
+            TransactionSetError::ControlNumberMismatch => "3",
+            TransactionSetError::SegmentCountMismatch => "4",
+            TransactionSetError::SegmentsInError => "5",
+            TransactionSetError::InvalidIdentifier => "6",
+            TransactionSetError::InvalidControlNumber => "7",
+        }
+    }
+}
+
+impl Display for TransactionSetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            TransactionSetError::NotSupported => "Transaction Set Not Supported",
+            TransactionSetError::TrailerMissing => "Transaction Set Trailer Missing",
+            TransactionSetError::ControlNumberMismatch => {
+                "Transaction Set Control Number in Header and Trailer Do Not Match"
+            }
+            TransactionSetError::SegmentCountMismatch => {
+                "Number of Included Segments Does Not Match Actual Count"
+            }
+            TransactionSetError::SegmentsInError => "One or More Segments in Error",
+            TransactionSetError::InvalidIdentifier => {
+                "Missing or Invalid Transaction Set Identifier"
+            }
+            TransactionSetError::InvalidControlNumber => {
+                "Missing or Invalid Transaction Set Control Number"
+            }
+        };
+        write!(f, "{description}")
+    }
+}
+
+/// Functional Group Syntax Error Code, reported in AK905
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroupError {
+    /// 1 - Functional Group Not Supported
+    NotSupported,
+    /// 2 - Functional Group Version Not Supported
+    VersionNotSupported,
+    /// 3 - Functional Group Trailer Missing
+    TrailerMissing,
+    /// 4 - Group Control Number in the Functional Group Header and Trailer Do Not Agree
+    ControlNumberMismatch,
+    /// 5 - Number of Included Transaction Sets Does Not Match Actual Count
+    SetCountMismatch,
+    /// 6 - Group Control Number Violates Syntax
+    InvalidControlNumber,
+}
+
+impl GroupError {
+    /// the code, e.g. `5`
+    pub fn code(self) -> &'static str {
+        match self {
+            GroupError::NotSupported => "1",
+            GroupError::VersionNotSupported => "2",
+            GroupError::TrailerMissing => "3",
+            GroupError::ControlNumberMismatch => "4",
+            GroupError::SetCountMismatch => "5",
+            GroupError::InvalidControlNumber => "6",
+        }
+    }
+}
+
+impl Display for GroupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            GroupError::NotSupported => "Functional Group Not Supported",
+            GroupError::VersionNotSupported => "Functional Group Version Not Supported",
+            GroupError::TrailerMissing => "Functional Group Trailer Missing",
+            GroupError::ControlNumberMismatch => {
+                "Group Control Number in the Functional Group Header and Trailer Do Not Agree"
+            }
+            GroupError::SetCountMismatch => {
+                "Number of Included Transaction Sets Does Not Match Actual Count"
+            }
+            GroupError::InvalidControlNumber => "Group Control Number Violates Syntax",
+        };
+        write!(f, "{description}")
+    }
+}
+
+/// The findings of a transaction set, acknowledged by an AK2 loop (004010) or IK2 loop (005010)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SetReport {
+    /// Transaction Set Identifier Code (ST01)
+    pub transaction_set_id: String,
+    /// Transaction Set Control Number (ST02)
+    pub control_number: String,
+    /// errors of the transaction set as a whole, including `SegmentsInError` if any segment has findings
+    pub errors: Vec<TransactionSetError>,
+    pub structure: Vec<StructureError>,
+    pub syntax: Vec<SyntaxError>,
+    /// values outside a complete code list, partial lists are not checked
+    pub codes: Vec<CodeError>,
+    pub elements: Vec<ElementError>,
+}
+
+impl SetReport {
+    /// Checks the envelope, structure, syntax notes, code lists and element types of `set` against version `V`.
+    ///
+    /// A transaction set which cannot be rendered as X12 is not checked and reported as `NotSupported`.
+    pub fn validate<V, T>(set: &T) -> SetReport
+    where
+        V: Version,
+        T: TransactionSet + Serialize,
+    {
+        let mut report = SetReport {
+            control_number: set.control_number().to_string(),
+            errors: validate_transaction_set(set)
+                .into_iter()
+                .map(|finding| match finding.element {
+                    "SE01" => TransactionSetError::SegmentCountMismatch,
+                    _ => TransactionSetError::ControlNumberMismatch,
+                })
+                .collect(),
+            ..SetReport::default()
+        };
+        if report.check::<V, T>(set).is_err() {
+            report.errors.push(TransactionSetError::NotSupported);
+        }
+        if report.transaction_set_id.is_empty() {
+            report.errors.push(TransactionSetError::InvalidIdentifier);
+        }
+        if report.control_number.is_empty() {
+            report
+                .errors
+                .push(TransactionSetError::InvalidControlNumber);
+        }
+        if report.has_segment_findings() {
+            report.errors.push(TransactionSetError::SegmentsInError);
+        }
+        report
+    }
+
+    /// Fills the transaction set identifier and the segment findings of `set`.
+    fn check<V, T>(&mut self, set: &T) -> Result<(), writer::Error>
+    where
+        V: Version,
+        T: Serialize,
+    {
+        visit_segments(set, |segment_position, segment| {
+            if segment_position == 1 {
+                self.transaction_set_id = segment.element(1).unwrap_or_default().to_string();
+            }
+        })?;
+        self.structure =
+            validate_structure(set, V::transaction_structure(&self.transaction_set_id))?;
+        self.syntax = validate_syntax(set, V::syntax_notes)?;
+        self.codes = validate_codes(set, V::code_lists)?;
+        self.elements = validate_elements(set, V::element_errors)?;
+        Ok(())
+    }
+
+    /// Parses `set` into `T` and checks it like [`validate`](Self::validate).
+    ///
+    /// A transaction set which cannot be parsed is checked as found and rejected with `SegmentsInError`,
+    /// the segment at which parsing failed is reported as unexpected, or as missing at the end of the set.
+    pub fn validate_raw<V, T>(set: &RawTransaction) -> SetReport
+    where
+        V: Version,
+        T: TransactionSet + Serialize + for<'a> Parser<&'a str, T, nom::error::Error<&'a str>>,
+    {
+        let parsed = writer::to_string(set, DELIMITERS).map(|rendered| {
+            let _scope = DELIMITERS.scope();
+            crate::error::parse::<T>(&rendered)
+        });
+        let failure = match parsed {
+            Ok(Ok(typed)) => return SetReport::validate::<V, T>(&typed),
+            Ok(Err(failure)) => Some(failure),
+            Err(_) => None,
+        };
+        let mut report = SetReport::validate::<V, RawTransaction>(set);
+        if let Some(failure) = failure {
+            report.parse_failure(failure);
+        }
+        if !report
+            .errors
+            .contains(&TransactionSetError::SegmentsInError)
+        {
+            report.errors.push(TransactionSetError::SegmentsInError);
+        }
+        report
+    }
+
+    /// Reports the segment at which parsing failed, unless it has a finding of the structure.
+    fn parse_failure(&mut self, failure: ParseError) {
+        let segment_position = failure.segment_index + 1;
+        if self
+            .structure
+            .iter()
+            .any(|error| error.segment_position == segment_position)
+        {
+            return;
+        }
+        let (segment, error) = match failure.found {
+            Some(found) => (found, SegmentSyntaxError::UnexpectedSegment),
+            None => (
+                failure.expected.into_iter().next().unwrap_or_default(),
+                SegmentSyntaxError::MandatoryMissing,
+            ),
+        };
+        // the loops are named after the transaction set, e.g. `_204Loop0300`
+        let loop_id = failure
+            .loop_name
+            .and_then(|name| Some(name.split_once("Loop")?.1.to_string()));
+        self.structure.push(StructureError {
+            segment,
+            segment_position,
+            loop_id,
+            error,
+        });
+        self.structure.sort_by_key(|error| error.segment_position);
+    }
+
+    /// Whether the transaction set has no findings
+    pub fn is_accepted(&self) -> bool {
+        self.errors.is_empty() && !self.has_segment_findings()
+    }
+
+    fn has_segment_findings(&self) -> bool {
+        !(self.structure.is_empty()
+            && self.syntax.is_empty()
+            && self.codes.is_empty()
+            && self.elements.is_empty())
+    }
+}
+
+/// The findings of a functional group and its transaction sets, acknowledged by a 997 (004010) or 999 (005010)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GroupReport {
+    /// Functional Identifier Code (GS01)
+    pub functional_id: String,
+    /// Group Control Number (GS06)
+    pub control_number: String,
+    /// Number of Transaction Sets Included (GE01), or the number received if GE01 is not a number
+    pub included: usize,
+    pub errors: Vec<GroupError>,
+    /// a report for each received transaction set
+    pub sets: Vec<SetReport>,
+}
+
+impl GroupReport {
+    /// Checks GE01/GE02 of `group` and each of its transaction sets against version `V`.
+    pub fn validate<V, T>(group: &FunctionalGroup<V, T>) -> GroupReport
+    where
+        V: Version,
+        T: TransactionSet + Serialize,
+    {
+        let (ge01, ge02) = group.ge.trailer();
+        GroupReport::new(
+            group.gs.functional_id(),
+            group.gs.control_number(),
+            (ge01, ge02),
+            group
+                .segments
+                .iter()
+                .map(SetReport::validate::<V, T>)
+                .collect(),
+        )
+    }
+
+    /// Checks GE01/GE02 of `group` and each of its transaction sets against version `V`, parsing them
+    /// into `T` like [`SetReport::validate_raw`].
+    pub fn validate_raw<V, T>(group: &RawGroup) -> GroupReport
+    where
+        V: Version,
+        T: TransactionSet + Serialize + for<'a> Parser<&'a str, T, nom::error::Error<&'a str>>,
+    {
+        GroupReport::new(
+            group.functional_id().unwrap_or_default(),
+            group.control_number().unwrap_or_default(),
+            (
+                group.ge.element(1).unwrap_or_default(),
+                group.ge.element(2).unwrap_or_default(),
+            ),
+            group
+                .transactions
+                .iter()
+                .map(SetReport::validate_raw::<V, T>)
+                .collect(),
+        )
+    }
+
+    fn new(
+        functional_id: &str,
+        control_number: &str,
+        (ge01, ge02): (&str, &str),
+        sets: Vec<SetReport>,
+    ) -> GroupReport {
+        let mut errors = vec![];
+        if control_number.is_empty() || !control_number.chars().all(|c| c.is_ascii_digit()) {
+            errors.push(GroupError::InvalidControlNumber);
+        }
+        if ge02 != control_number {
+            errors.push(GroupError::ControlNumberMismatch);
+        }
+        let included = ge01.trim().parse::<usize>().ok();
+        if included != Some(sets.len()) {
+            errors.push(GroupError::SetCountMismatch);
+        }
+        GroupReport {
+            functional_id: functional_id.to_string(),
+            control_number: control_number.to_string(),
+            included: included.unwrap_or(sets.len()),
+            errors,
+            sets,
+        }
+    }
+
+    /// Number of accepted transaction sets
+    pub fn accepted(&self) -> usize {
+        self.sets.iter().filter(|set| set.is_accepted()).count()
+    }
+}
@@ -0,0 +1,351 @@
+//! Segment usage and loop repeats of transaction sets.
+
+use super::visit_segments;
+use crate::util::writer;
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Requirement designator of a segment, the `REQ` column of the transaction set tables
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Requirement {
+    /// M - the segment must be present
+    Mandatory,
+    /// O - the segment may be omitted
+    Optional,
+}
+
+/// A position of a transaction set definition, a segment or a loop
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StructureEntry {
+    Segment {
+        /// position in the table, e.g. `0140`
+        position: &'static str,
+        /// Segment ID, e.g. `N1`
+        id: &'static str,
+        requirement: Requirement,
+        /// maximum use of the segment in each repeat of the enclosing loop
+        max: usize,
+    },
+    Loop {
+        /// Loop ID, e.g. `0100`
+        id: &'static str,
+        /// maximum repeat of the loop
+        repeat: usize,
+        /// the positions of the loop, starting with the segment which begins each repeat
+        entries: &'static [StructureEntry],
+    },
+}
+
+/// A segment position, used by `impl_transaction_structure!`
+pub const fn segment(
+    position: &'static str,
+    id: &'static str,
+    requirement: Requirement,
+    max: usize,
+) -> StructureEntry {
+    StructureEntry::Segment {
+        position,
+        id,
+        requirement,
+        max,
+    }
+}
+
+/// A loop position, used by `impl_transaction_structure!`
+pub const fn segment_loop(
+    id: &'static str,
+    repeat: usize,
+    entries: &'static [StructureEntry],
+) -> StructureEntry {
+    StructureEntry::Loop {
+        id,
+        repeat,
+        entries,
+    }
+}
+
+impl StructureEntry {
+    /// the ID of the segment at this position, or of the first segment of the loop
+    pub fn segment_id(&self) -> &'static str {
+        match self {
+            StructureEntry::Segment { id, .. } => id,
+            StructureEntry::Loop { entries, .. } => entries
+                .first()
+                .map(|entry| entry.segment_id())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// whether the segment is mandatory, a loop is mandatory if its first segment is
+    pub fn is_mandatory(&self) -> bool {
+        match self {
+            StructureEntry::Segment { requirement, .. } => *requirement == Requirement::Mandatory,
+            StructureEntry::Loop { entries, .. } => {
+                entries.first().is_some_and(|entry| entry.is_mandatory())
+            }
+        }
+    }
+
+    fn contains(&self, segment_id: &str) -> bool {
+        match self {
+            StructureEntry::Segment { id, .. } => *id == segment_id,
+            StructureEntry::Loop { entries, .. } => {
+                entries.iter().any(|entry| entry.contains(segment_id))
+            }
+        }
+    }
+}
+
+/// Segment Syntax Error Code, reported in AK304 (004010) or IK304 (005010)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SegmentSyntaxError {
+    /// 1 - Unrecognized segment ID
+    UnrecognizedSegment,
+    /// 2 - Unexpected segment
+    UnexpectedSegment,
+    /// 3 - Mandatory segment missing
+    MandatoryMissing,
+    /// 4 - Loop Occurs Over Maximum Times
+    LoopOverMaximum,
+    /// 5 - Segment Exceeds Maximum Use
+    ExceedsMaximumUse,
+    /// 6 - Segment Not in Defined Transaction Set
+    NotInTransactionSet,
+    /// 7 - Segment Not in Proper Sequence
+    NotInSequence,
+    /// 8 - Segment Has Data Element Errors
+    ElementErrors,
+}
+
+impl SegmentSyntaxError {
+    /// the code, e.g. `3`
+    pub fn code(self) -> &'static str {
+        match self {
+            SegmentSyntaxError::UnrecognizedSegment => "1",
+            SegmentSyntaxError::UnexpectedSegment => "2",
+            SegmentSyntaxError::MandatoryMissing => "3",
+            SegmentSyntaxError::LoopOverMaximum => "4",
+            SegmentSyntaxError::ExceedsMaximumUse => "5",
+            SegmentSyntaxError::NotInTransactionSet => "6",
+            SegmentSyntaxError::NotInSequence => "7",
+            SegmentSyntaxError::ElementErrors => "8",
+        }
+    }
+}
+
+impl Display for SegmentSyntaxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            SegmentSyntaxError::UnrecognizedSegment => "Unrecognized segment ID",
+            SegmentSyntaxError::UnexpectedSegment => "Unexpected segment",
+            SegmentSyntaxError::MandatoryMissing => "Mandatory segment missing",
+            SegmentSyntaxError::LoopOverMaximum => "Loop Occurs Over Maximum Times",
+            SegmentSyntaxError::ExceedsMaximumUse => "Segment Exceeds Maximum Use",
+            SegmentSyntaxError::NotInTransactionSet => "Segment Not in Defined Transaction Set",
+            SegmentSyntaxError::NotInSequence => "Segment Not in Proper Sequence",
+            SegmentSyntaxError::ElementErrors => "Segment Has Data Element Errors",
+        };
+        write!(f, "{description}")
+    }
+}
+
+/// A violated segment usage or loop repeat
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructureError {
+    /// Segment ID, e.g. `LH6`
+    pub segment: String,
+    /// position of the segment in the transaction set, counting the ST as 1,
+    /// or of the segment following a missing one
+    pub segment_position: usize,
+    /// the innermost loop of the segment, `None` outside of loops
+    pub loop_id: Option<String>,
+    pub error: SegmentSyntaxError,
+}
+
+impl Display for StructureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}", self.segment, self.segment_position)?;
+        if let Some(loop_id) = &self.loop_id {
+            write!(f, " in loop {loop_id}")?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
+
+/// The segment positions and loops of a transaction set
+pub trait TransactionStructure: Serialize {
+    /// the positions from ST to SE
+    const STRUCTURE: &'static [StructureEntry];
+
+    /// Checks the mandatory segments, the maximum use of each segment and the repeats of each loop.
+    fn validate_structure(&self) -> Result<Vec<StructureError>, writer::Error> {
+        validate_structure(self, Self::STRUCTURE)
+    }
+}
+
+/// Implements [`TransactionStructure`] for the given transaction sets and defines `transaction_structure`,
+/// looking up the positions by transaction set identifier.
+macro_rules! impl_transaction_structure {
+    ($($set:ident => [$($entry:expr),* $(,)?],)*) => {
+        $(
+            impl $crate::validation::TransactionStructure for $set {
+                const STRUCTURE: &'static [$crate::validation::StructureEntry] = {
+                    #[allow(unused_imports)]
+                    use $crate::validation::{segment, segment_loop, Requirement::{Mandatory as M, Optional as O}};
+                    &[$($entry),*]
+                };
+            }
+        )*
+
+        /// Returns the positions of the transaction set with the given identifier (ST01), e.g. `204`,
+        /// or an empty slice if the set has no definition.
+        pub fn transaction_structure(transaction_set_id: &str) -> &'static [$crate::validation::StructureEntry] {
+            $(
+                if stringify!($set).strip_prefix('_') == Some(transaction_set_id) {
+                    return <$set as $crate::validation::TransactionStructure>::STRUCTURE;
+                }
+            )*
+            let _ = transaction_set_id;
+            &[]
+        }
+    };
+}
+pub(crate) use impl_transaction_structure;
+
+/// The positions of the transaction set or an entered loop, while walking the segments
+struct Frame {
+    entries: &'static [StructureEntry],
+    loop_id: Option<&'static str>,
+    /// the current position
+    index: usize,
+    /// whether a segment was found at the current position, and how often
+    uses: usize,
+}
+
+impl Frame {
+    /// Returns the position matching `segment_id`, starting at the current one.
+    fn find(&self, segment_id: &str) -> Option<usize> {
+        // the first segment of a loop begins the next repeat, found in the enclosing frame
+        let start = match self.loop_id {
+            Some(_) => self.index.max(1),
+            None => self.index,
+        };
+        (start..self.entries.len()).find(|index| self.entries[*index].segment_id() == segment_id)
+    }
+
+    /// Reports the mandatory positions from the current one up to `end` as missing.
+    fn missing(&self, end: usize, segment_position: usize, errors: &mut Vec<StructureError>) {
+        let start = self.index + usize::from(self.uses > 0);
+        for entry in self.entries[start.min(end)..end].iter() {
+            if entry.is_mandatory() {
+                let loop_id = match entry {
+                    StructureEntry::Loop { id, .. } => Some(*id),
+                    StructureEntry::Segment { .. } => self.loop_id,
+                };
+                errors.push(StructureError {
+                    segment: entry.segment_id().to_string(),
+                    segment_position,
+                    loop_id: loop_id.map(str::to_string),
+                    error: SegmentSyntaxError::MandatoryMissing,
+                });
+            }
+        }
+    }
+}
+
+/// Checks the segments of a transaction set against its positions, e.g. `V::transaction_structure("204")`
+/// of a [`Version`](crate::envelope::Version).
+///
+/// Reports missing mandatory segments and loops, segments used more often than allowed, loops repeated
+/// more often than allowed and segments which are not expected at their place. Without positions,
+/// e.g. for a transaction set without definition, nothing is reported. Fails if `value` cannot be rendered.
+pub fn validate_structure<T>(
+    value: &T,
+    structure: &'static [StructureEntry],
+) -> Result<Vec<StructureError>, writer::Error>
+where
+    T: Serialize + ?Sized,
+{
+    if structure.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut errors = vec![];
+    let mut frames = vec![Frame {
+        entries: structure,
+        loop_id: None,
+        index: 0,
+        uses: 0,
+    }];
+    let segment_count = visit_segments(value, |segment_position, segment| {
+        let Some((depth, index)) = frames
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(depth, frame)| Some((depth, frame.find(segment.id)?)))
+        else {
+            let frame = frames
+                .last()
+                .expect("the transaction set frame is never left");
+            errors.push(StructureError {
+                segment: segment.id.to_string(),
+                segment_position,
+                loop_id: frame.loop_id.map(str::to_string),
+                error: match structure.iter().any(|entry| entry.contains(segment.id)) {
+                    true => SegmentSyntaxError::NotInSequence,
+                    false => SegmentSyntaxError::NotInTransactionSet,
+                },
+            });
+            return;
+        };
+        // the segment ends the loops entered below the found position
+        for frame in frames.drain(depth + 1..).rev() {
+            frame.missing(frame.entries.len(), segment_position, &mut errors);
+        }
+        let frame = frames.last_mut().expect("the found frame is kept");
+        if index == frame.index && frame.uses > 0 {
+            frame.uses += 1;
+        } else {
+            frame.missing(index, segment_position, &mut errors);
+            frame.index = index;
+            frame.uses = 1;
+        }
+        let loop_id = frame.loop_id;
+        let uses = frame.uses;
+        match frame.entries[index] {
+            StructureEntry::Segment { max, .. } => {
+                if uses > max {
+                    errors.push(StructureError {
+                        segment: segment.id.to_string(),
+                        segment_position,
+                        loop_id: loop_id.map(str::to_string),
+                        error: SegmentSyntaxError::ExceedsMaximumUse,
+                    });
+                }
+            }
+            StructureEntry::Loop {
+                id,
+                repeat,
+                entries,
+            } => {
+                if uses > repeat {
+                    errors.push(StructureError {
+                        segment: segment.id.to_string(),
+                        segment_position,
+                        loop_id: Some(id.to_string()),
+                        error: SegmentSyntaxError::LoopOverMaximum,
+                    });
+                }
+                frames.push(Frame {
+                    entries,
+                    loop_id: Some(id),
+                    index: 0,
+                    uses: 1,
+                });
+            }
+        }
+    })?;
+    for frame in frames.into_iter().rev() {
+        frame.missing(frame.entries.len(), segment_count + 1, &mut errors);
+    }
+    Ok(errors)
+}
@@ -0,0 +1,115 @@
+use super::{Requirement, StructureEntry, TransactionStructure};
+
+/// Renders the positions as the rows of a transaction set table, without the NAME column
+fn table_rows(entries: &[StructureEntry], path: &mut Vec<&'static str>, rows: &mut Vec<String>) {
+    let prefix: String = path.iter().map(|id| format!("{id} -> ")).collect();
+    let max = |max: usize| match max {
+        usize::MAX => ">1".to_string(),
+        max => max.to_string(),
+    };
+    for entry in entries {
+        match entry {
+            StructureEntry::Segment {
+                position,
+                id,
+                requirement,
+                max: max_use,
+            } => {
+                let requirement = match requirement {
+                    Requirement::Mandatory => "M",
+                    Requirement::Optional => "O",
+                };
+                rows.push(format!(
+                    "{prefix}{position} | {id} | {requirement} | {}",
+                    max(*max_use)
+                ));
+            }
+            StructureEntry::Loop {
+                id,
+                repeat,
+                entries,
+            } => {
+                rows.push(format!("{prefix}LOOP ID - {id} | {}", max(*repeat)));
+                path.push(id);
+                table_rows(entries, path, rows);
+                path.pop();
+            }
+        }
+    }
+}
+
+/// Returns the rows of the table in the doc comment of `pub struct {name}`, without the NAME column
+fn doc_rows(source: &str, name: &str) -> Vec<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let end = lines
+        .iter()
+        .position(|line| *line == format!("pub struct {name} {{"))
+        .unwrap_or_else(|| panic!("{name} is not defined"));
+    let docs: Vec<&str> = lines[..end]
+        .iter()
+        .rev()
+        .skip_while(|line| line.starts_with("#["))
+        .take_while(|line| line.starts_with("///"))
+        .map(|line| line.trim_start_matches('/').trim())
+        .collect();
+    docs.into_iter()
+        .rev()
+        .skip_while(|line| !line.starts_with("POS |"))
+        .skip(1)
+        .filter(|line| !line.starts_with("----"))
+        .map(|line| {
+            let mut cells: Vec<&str> = line.split('|').map(str::trim).collect();
+            while cells.last() == Some(&"") {
+                cells.pop();
+            }
+            if !cells[0].contains("LOOP ID - ") {
+                cells.remove(2);
+            }
+            cells.join(" | ")
+        })
+        .collect()
+}
+
+fn assert_table<T: TransactionStructure>(source: &str, name: &str) {
+    let mut rows = vec![];
+    table_rows(T::STRUCTURE, &mut vec![], &mut rows);
+    assert_eq!(rows, doc_rows(source, name), "positions of {name}");
+}
+
+#[test]
+fn structures_match_the_tables() {
+    use crate::{v003030, v004010, v005010, v005030};
+    let source = include_str!("../v003030/mod.rs");
+    assert_table::<v003030::_998>(source, "_998");
+
+    let source = include_str!("../v004010/mod.rs");
+    assert_table::<v004010::_204>(source, "_204");
+    assert_table::<v004010::_214>(source, "_214");
+    assert_table::<v004010::_301>(source, "_301");
+    assert_table::<v004010::_309>(source, "_309");
+    assert_table::<v004010::_310>(source, "_310");
+    assert_table::<v004010::_315>(source, "_315");
+    assert_table::<v004010::_322>(source, "_322");
+    assert_table::<v004010::_404>(source, "_404");
+    assert_table::<v004010::_810>(source, "_810");
+    assert_table::<v004010::_856>(source, "_856");
+    assert_table::<v004010::_940>(include_str!("../v004010/_940_doc.rs"), "_940");
+    assert_table::<v004010::_945>(include_str!("../v004010/_945_doc.rs"), "_945");
+    assert_table::<v004010::_997>(source, "_997");
+    assert_table::<v004010::_998>(source, "_998");
+
+    assert_table::<v005010::_270>(include_str!("../v005010/_270_doc.rs"), "_270");
+    assert_table::<v005010::_271>(include_str!("../v005010/_271_doc.rs"), "_271");
+    assert_table::<v005010::_276>(include_str!("../v005010/_276_doc.rs"), "_276");
+    assert_table::<v005010::_277>(include_str!("../v005010/_277_doc.rs"), "_277");
+    assert_table::<v005010::_278>(include_str!("../v005010/_278_doc.rs"), "_278");
+    assert_table::<v005010::_820>(include_str!("../v005010/_820_doc.rs"), "_820");
+    assert_table::<v005010::_834>(include_str!("../v005010/_834_doc.rs"), "_834");
+    assert_table::<v005010::_850>(include_str!("../v005010/_850_doc.rs"), "_850");
+    assert_table::<v005010::_835>(include_str!("../v005010/_835_doc.rs"), "_835");
+    assert_table::<v005010::_837>(include_str!("../v005010/_837_doc.rs"), "_837");
+    assert_table::<v005010::_855>(include_str!("../v005010/_855_doc.rs"), "_855");
+    assert_table::<v005010::_999>(include_str!("../v005010/_999_doc.rs"), "_999");
+
+    assert_table::<v005030::_404>(include_str!("../v005030/mod.rs"), "_404");
+}
@@ -0,0 +1,301 @@
+//! Syntax notes (relational conditions) of segments.
+
+use super::{visit_segments, DELIMITERS};
+use crate::util::writer;
+use serde::Serialize;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A relational condition between the elements of a segment, like `P0304`
+///
+/// The letter names the condition, followed by the two-digit positions of the elements,
+/// e.g. `C0605` requires the 5th element if the 6th is present.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SyntaxNote {
+    /// P - if any of the elements is present, all are required
+    Paired(Vec<usize>),
+    /// R - at least one of the elements is required
+    Required(Vec<usize>),
+    /// E - not more than one of the elements may be present
+    Exclusion(Vec<usize>),
+    /// C - if the first element is present, all others are required
+    Conditional(usize, Vec<usize>),
+    /// L - if the first element is present, at least one of the others is required
+    ListConditional(usize, Vec<usize>),
+}
+
+impl FromStr for SyntaxNote {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid syntax note: '{s}'");
+        let (kind, positions) = s.split_at_checked(1).ok_or_else(invalid)?;
+        if positions.len() < 4 || positions.len() % 2 != 0 {
+            return Err(invalid());
+        }
+        let positions = positions
+            .as_bytes()
+            .chunks(2)
+            .map(|position| {
+                std::str::from_utf8(position)
+                    .ok()?
+                    .parse()
+                    .ok()
+                    .filter(|position| *position > 0)
+            })
+            .collect::<Option<Vec<usize>>>()
+            .ok_or_else(invalid)?;
+        let (first, others) = (positions[0], positions[1..].to_vec());
+        match kind {
+            "P" => Ok(SyntaxNote::Paired(positions)),
+            "R" => Ok(SyntaxNote::Required(positions)),
+            "E" => Ok(SyntaxNote::Exclusion(positions)),
+            "C" => Ok(SyntaxNote::Conditional(first, others)),
+            "L" => Ok(SyntaxNote::ListConditional(first, others)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Whether `note` parses into a [`SyntaxNote`], for `impl_segment_syntax!` to reject invalid notes at compile time
+pub(crate) const fn is_syntax_note(note: &str) -> bool {
+    let bytes = note.as_bytes();
+    if bytes.len() < 5 || bytes.len() % 2 == 0 {
+        return false;
+    }
+    if !matches!(bytes[0], b'P' | b'R' | b'E' | b'C' | b'L') {
+        return false;
+    }
+    let mut idx = 1;
+    while idx < bytes.len() {
+        let (tens, ones) = (bytes[idx], bytes[idx + 1]);
+        if !tens.is_ascii_digit() || !ones.is_ascii_digit() || (tens == b'0' && ones == b'0') {
+            return false;
+        }
+        idx += 2;
+    }
+    true
+}
+
+impl Display for SyntaxNote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (kind, first, others) = match self {
+            SyntaxNote::Paired(positions) => ("P", None, positions),
+            SyntaxNote::Required(positions) => ("R", None, positions),
+            SyntaxNote::Exclusion(positions) => ("E", None, positions),
+            SyntaxNote::Conditional(first, others) => ("C", Some(first), others),
+            SyntaxNote::ListConditional(first, others) => ("L", Some(first), others),
+        };
+        write!(f, "{kind}")?;
+        for position in first.into_iter().chain(others) {
+            write!(f, "{position:02}")?;
+        }
+        Ok(())
+    }
+}
+
+impl SyntaxNote {
+    /// Checks this note against the presence of the elements, `present[0]` being the first element.
+    ///
+    /// Returns the position of each offending element with its error.
+    pub fn check(&self, present: &[bool]) -> Vec<(usize, ElementSyntaxError)> {
+        let is_present = |position: &usize| present.get(position - 1).copied().unwrap_or_default();
+        let missing = |positions: &[usize]| {
+            positions
+                .iter()
+                .filter(|position| !is_present(position))
+                .map(|position| (*position, ElementSyntaxError::ConditionalMissing))
+                .collect()
+        };
+        match self {
+            SyntaxNote::Paired(positions) if positions.iter().any(is_present) => missing(positions),
+            SyntaxNote::Required(positions) if !positions.iter().any(is_present) => {
+                vec![(positions[0], ElementSyntaxError::ConditionalMissing)]
+            }
+            SyntaxNote::Exclusion(positions) => positions
+                .iter()
+                .filter(|position| is_present(position))
+                .skip(1)
+                .map(|position| (*position, ElementSyntaxError::ExclusionViolated))
+                .collect(),
+            SyntaxNote::Conditional(first, others) if is_present(first) => missing(others),
+            SyntaxNote::ListConditional(first, others)
+                if is_present(first) && !others.iter().any(is_present) =>
+            {
+                vec![(others[0], ElementSyntaxError::ConditionalMissing)]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// Data Element Syntax Error Code, reported in AK404 (004010) or IK403 (005010)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ElementSyntaxError {
+    /// 1 - Mandatory data element missing
+    MandatoryMissing,
+    /// 2 - Conditional required data element missing
+    ConditionalMissing,
+    /// 3 - Too many data elements
+    TooManyElements,
+    /// 4 - Data element too short
+    TooShort,
+    /// 5 - Data element too long
+    TooLong,
+    /// 6 - Invalid character in data element
+    InvalidCharacter,
+    /// 7 - Invalid code value
+    InvalidCode,
+    /// 8 - Invalid Date
+    InvalidDate,
+    /// 9 - Invalid Time
+    InvalidTime,
+    /// 10 - Exclusion condition violated
+    ExclusionViolated,
+}
+
+impl ElementSyntaxError {
+    /// the code, e.g. `2`
+    pub fn code(self) -> &'static str {
+        match self {
+            ElementSyntaxError::MandatoryMissing => "1",
+            ElementSyntaxError::ConditionalMissing => "2",
+            ElementSyntaxError::TooManyElements => "3",
+            ElementSyntaxError::TooShort => "4",
+            ElementSyntaxError::TooLong => "5",
+            ElementSyntaxError::InvalidCharacter => "6",
+            ElementSyntaxError::InvalidCode => "7",
+            ElementSyntaxError::InvalidDate => "8",
+            ElementSyntaxError::InvalidTime => "9",
+            ElementSyntaxError::ExclusionViolated => "10",
+        }
+    }
+}
+
+impl Display for ElementSyntaxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            ElementSyntaxError::MandatoryMissing => "Mandatory data element missing",
+            ElementSyntaxError::ConditionalMissing => "Conditional required data element missing",
+            ElementSyntaxError::TooManyElements => "Too many data elements",
+            ElementSyntaxError::TooShort => "Data element too short",
+            ElementSyntaxError::TooLong => "Data element too long",
+            ElementSyntaxError::InvalidCharacter => "Invalid character in data element",
+            ElementSyntaxError::InvalidCode => "Invalid code value",
+            ElementSyntaxError::InvalidDate => "Invalid Date",
+            ElementSyntaxError::InvalidTime => "Invalid Time",
+            ElementSyntaxError::ExclusionViolated => "Exclusion condition violated",
+        };
+        write!(f, "{description}")
+    }
+}
+
+/// A violated syntax note
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxError {
+    /// Segment ID, e.g. `N1`
+    pub segment: String,
+    /// position of the segment in the transaction set, counting the ST as 1, or 1 for a single segment
+    pub segment_position: usize,
+    /// position of the offending element in the segment
+    pub element_position: usize,
+    /// the violated note, e.g. `P0304`
+    pub note: String,
+    pub error: ElementSyntaxError,
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{:02} violates {}: {}",
+            self.segment, self.element_position, self.note, self.error
+        )
+    }
+}
+
+/// The syntax notes of a segment
+pub trait SegmentSyntax: Serialize {
+    /// relational conditions like `P0304`
+    const SYNTAX_NOTES: &'static [&'static str];
+
+    /// Checks the syntax notes against the elements present in this segment.
+    fn validate_syntax(&self) -> Result<Vec<SyntaxError>, writer::Error> {
+        validate_syntax(self, |_| Self::SYNTAX_NOTES)
+    }
+}
+
+/// Implements [`SegmentSyntax`] for the given segments and defines `syntax_notes`, looking up the notes by segment ID.
+///
+/// A note which does not parse into a [`SyntaxNote`] fails the build.
+macro_rules! impl_segment_syntax {
+    ($($segment:ident => [$($note:literal),*],)*) => {
+        const _: () = {
+            $($(assert!(
+                $crate::validation::is_syntax_note($note),
+                concat!("invalid syntax note ", $note, " of ", stringify!($segment))
+            );)*)*
+        };
+
+        $(
+            impl $crate::validation::SegmentSyntax for $segment {
+                const SYNTAX_NOTES: &'static [&'static str] = &[$($note),*];
+            }
+        )*
+
+        /// Returns the syntax notes of the segment with the given ID, e.g. `["R0203", "P0304"]` for `N1`.
+        #[allow(clippy::match_single_binding)]
+        pub fn syntax_notes(segment_id: &str) -> &'static [&'static str] {
+            match segment_id {
+                $(stringify!($segment) => &[$($note),*],)*
+                _ => &[],
+            }
+        }
+    };
+}
+pub(crate) use impl_segment_syntax;
+
+/// Checks the syntax notes of each segment in `value`, a segment, transaction set or loop.
+///
+/// The notes of each segment are looked up by its ID with `syntax_notes`, e.g. `V::syntax_notes` of a [`Version`](crate::envelope::Version).
+/// Notes which do not parse into a [`SyntaxNote`] are skipped, the notes of the versions are checked when
+/// building the crate. Fails if `value` cannot be rendered.
+pub fn validate_syntax<T, F>(value: &T, syntax_notes: F) -> Result<Vec<SyntaxError>, writer::Error>
+where
+    T: Serialize + ?Sized,
+    F: Fn(&str) -> &'static [&'static str],
+{
+    let mut errors = vec![];
+    visit_segments(value, |segment_position, segment| {
+        let notes = syntax_notes(segment.id);
+        if notes.is_empty() {
+            return;
+        }
+        let present: Vec<bool> = segment
+            .elements()
+            .map(|element| {
+                element
+                    .chars()
+                    .any(|c| c != DELIMITERS.component && Some(c) != DELIMITERS.repetition)
+            })
+            .collect();
+        for note in notes {
+            let Ok(parsed) = note.parse::<SyntaxNote>() else {
+                continue;
+            };
+            errors.extend(
+                parsed
+                    .check(&present)
+                    .into_iter()
+                    .map(|(element_position, error)| SyntaxError {
+                        segment: segment.id.to_string(),
+                        segment_position,
+                        element_position,
+                        note: note.to_string(),
+                        error,
+                    }),
+            );
+        }
+    })?;
+    Ok(errors)
+}